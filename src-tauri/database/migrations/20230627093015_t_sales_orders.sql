@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS t_sales_orders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    customer_id INTEGER NOT NULL,
+    order_date DATE NOT NULL,
+    status VARCHAR(20) DEFAULT 'ordered' NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (customer_id) REFERENCES m_customers (id)
+);
+
+CREATE TABLE IF NOT EXISTS t_sales_order_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    sales_order_id INTEGER NOT NULL,
+    product_id INTEGER NOT NULL,
+    quantity INTEGER NOT NULL,
+    unit_price INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (sales_order_id) REFERENCES t_sales_orders (id),
+    FOREIGN KEY (product_id) REFERENCES m_products (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_t_sales_orders_customer_id ON t_sales_orders (customer_id);
+CREATE INDEX IF NOT EXISTS idx_t_sales_order_lines_sales_order_id ON t_sales_order_lines (sales_order_id);
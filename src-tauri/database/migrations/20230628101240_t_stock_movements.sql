@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS t_stock_movements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    product_id INTEGER NOT NULL,
+    movement_type VARCHAR(20) NOT NULL,
+    quantity INTEGER NOT NULL,
+    moved_on DATE NOT NULL,
+    note VARCHAR(255) DEFAULT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (product_id) REFERENCES m_products (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_t_stock_movements_product_id ON t_stock_movements (product_id);
@@ -0,0 +1 @@
+ALTER TABLE m_products ADD COLUMN tax_rate INTEGER DEFAULT 10 NOT NULL;
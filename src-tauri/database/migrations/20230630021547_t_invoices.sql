@@ -0,0 +1,49 @@
+CREATE TABLE IF NOT EXISTS t_invoices (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    invoice_number VARCHAR(20) NOT NULL UNIQUE,
+    customer_id INTEGER NOT NULL,
+    period_from DATE NOT NULL,
+    period_to DATE NOT NULL,
+    issued_on DATE NOT NULL,
+    rounding_mode VARCHAR(20) NOT NULL,
+    subtotal INTEGER NOT NULL,
+    tax_amount INTEGER NOT NULL,
+    total_amount INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (customer_id) REFERENCES m_customers (id)
+);
+
+CREATE TABLE IF NOT EXISTS t_invoice_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    invoice_id INTEGER NOT NULL,
+    sales_order_id INTEGER NOT NULL,
+    sales_order_line_id INTEGER NOT NULL UNIQUE,
+    product_id INTEGER NOT NULL,
+    product_name VARCHAR(255) NOT NULL,
+    quantity INTEGER NOT NULL,
+    unit_price INTEGER NOT NULL,
+    amount INTEGER NOT NULL,
+    tax_rate INTEGER NOT NULL,
+    tax_amount INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (invoice_id) REFERENCES t_invoices (id),
+    FOREIGN KEY (sales_order_id) REFERENCES t_sales_orders (id),
+    FOREIGN KEY (sales_order_line_id) REFERENCES t_sales_order_lines (id),
+    FOREIGN KEY (product_id) REFERENCES m_products (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_t_invoices_customer_id ON t_invoices (customer_id);
+CREATE INDEX IF NOT EXISTS idx_t_invoice_lines_invoice_id ON t_invoice_lines (invoice_id);
+
+-- Issued invoices are legal documents; their numbers must never change or be reused.
+CREATE TRIGGER IF NOT EXISTS trg_t_invoices_number_immutable
+BEFORE UPDATE OF invoice_number ON t_invoices
+BEGIN
+    SELECT RAISE(ABORT, 'invoice_number is immutable');
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_invoices_no_delete
+BEFORE DELETE ON t_invoices
+BEGIN
+    SELECT RAISE(ABORT, 'issued invoices cannot be deleted');
+END;
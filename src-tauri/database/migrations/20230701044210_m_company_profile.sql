@@ -0,0 +1,36 @@
+CREATE TABLE IF NOT EXISTS m_company_profile (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    registration_number VARCHAR(14) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    address VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+ALTER TABLE t_invoices ADD COLUMN issuer_registration_number VARCHAR(14) DEFAULT '' NOT NULL;
+ALTER TABLE t_invoices ADD COLUMN issuer_name VARCHAR(255) DEFAULT '' NOT NULL;
+ALTER TABLE t_invoices ADD COLUMN issuer_address VARCHAR(255) DEFAULT '' NOT NULL;
+ALTER TABLE t_invoices ADD COLUMN recipient_name VARCHAR(255) DEFAULT '' NOT NULL;
+
+UPDATE t_invoices SET recipient_name = (
+    SELECT name FROM m_customers WHERE m_customers.id = t_invoices.customer_id
+);
+
+CREATE TABLE IF NOT EXISTS t_invoice_tax_summaries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    invoice_id INTEGER NOT NULL,
+    tax_rate INTEGER NOT NULL,
+    taxable_amount INTEGER NOT NULL,
+    tax_amount INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    UNIQUE (invoice_id, tax_rate),
+    FOREIGN KEY (invoice_id) REFERENCES t_invoices (id)
+);
+
+-- Tax is now rounded once per rate. Invoices issued before keep the amounts they were billed with.
+INSERT INTO t_invoice_tax_summaries (invoice_id, tax_rate, taxable_amount, tax_amount)
+    SELECT invoice_id, tax_rate, SUM(amount), SUM(tax_amount)
+    FROM t_invoice_lines
+    GROUP BY invoice_id, tax_rate;
+
+ALTER TABLE t_invoice_lines DROP COLUMN tax_amount;
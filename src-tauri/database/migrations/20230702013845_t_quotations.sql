@@ -0,0 +1,28 @@
+CREATE TABLE IF NOT EXISTS t_quotations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    customer_id INTEGER NOT NULL,
+    quoted_on DATE NOT NULL,
+    valid_until DATE NOT NULL,
+    status VARCHAR(20) DEFAULT 'draft' NOT NULL,
+    sales_order_id INTEGER UNIQUE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CHECK (valid_until >= quoted_on),
+    FOREIGN KEY (customer_id) REFERENCES m_customers (id),
+    FOREIGN KEY (sales_order_id) REFERENCES t_sales_orders (id)
+);
+
+CREATE TABLE IF NOT EXISTS t_quotation_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    quotation_id INTEGER NOT NULL,
+    product_id INTEGER NOT NULL,
+    quantity INTEGER NOT NULL,
+    unit_price INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (quotation_id) REFERENCES t_quotations (id),
+    FOREIGN KEY (product_id) REFERENCES m_products (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_t_quotations_customer_id ON t_quotations (customer_id);
+CREATE INDEX IF NOT EXISTS idx_t_quotation_lines_quotation_id ON t_quotation_lines (quotation_id);
@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS t_payments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    customer_id INTEGER NOT NULL,
+    paid_on DATE NOT NULL,
+    amount INTEGER NOT NULL CHECK (amount > 0),
+    method VARCHAR(20) NOT NULL,
+    bank_fee INTEGER DEFAULT 0 NOT NULL CHECK (bank_fee >= 0),
+    note TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (customer_id) REFERENCES m_customers (id)
+);
+
+CREATE TABLE IF NOT EXISTS t_payment_allocations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    payment_id INTEGER NOT NULL,
+    invoice_id INTEGER NOT NULL,
+    amount INTEGER NOT NULL CHECK (amount > 0),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    FOREIGN KEY (payment_id) REFERENCES t_payments (id),
+    FOREIGN KEY (invoice_id) REFERENCES t_invoices (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_t_payments_customer_id ON t_payments (customer_id);
+CREATE INDEX IF NOT EXISTS idx_t_payment_allocations_payment_id ON t_payment_allocations (payment_id);
+CREATE INDEX IF NOT EXISTS idx_t_payment_allocations_invoice_id ON t_payment_allocations (invoice_id);
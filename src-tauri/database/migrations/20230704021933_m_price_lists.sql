@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS m_price_lists (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    customer_id INTEGER NOT NULL,
+    product_id INTEGER NOT NULL,
+    price INTEGER NOT NULL CHECK (price >= 0),
+    valid_from DATE,
+    valid_to DATE,
+    min_quantity INTEGER DEFAULT 1 NOT NULL CHECK (min_quantity >= 1),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CHECK (valid_from IS NULL OR valid_to IS NULL OR valid_to >= valid_from),
+    FOREIGN KEY (customer_id) REFERENCES m_customers (id),
+    FOREIGN KEY (product_id) REFERENCES m_products (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_m_price_lists_customer_id_product_id ON m_price_lists (customer_id, product_id);
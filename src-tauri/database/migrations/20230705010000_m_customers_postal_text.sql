@@ -0,0 +1,9 @@
+-- Postal codes were stored as integers, which dropped leading zeros (0600000 became 600000).
+-- Store them as 7-digit text, restoring the zeros of existing rows.
+ALTER TABLE m_customers ADD COLUMN postal_text VARCHAR(7) NOT NULL DEFAULT '';
+
+UPDATE m_customers SET postal_text = printf('%07d', postal);
+
+ALTER TABLE m_customers DROP COLUMN postal;
+
+ALTER TABLE m_customers RENAME COLUMN postal_text TO postal;
@@ -0,0 +1,2 @@
+-- Codes of soft-deleted products may be reused.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_m_products_code ON m_products (code) WHERE deleted_at IS NULL;
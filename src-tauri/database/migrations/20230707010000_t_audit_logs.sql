@@ -0,0 +1,393 @@
+-- Changes to master data and orders, recorded by triggers so that every write path is covered.
+-- The rows are stored as JSON before and after the change, and `actor` is whoever
+-- t_audit_context names at the time.
+CREATE TABLE IF NOT EXISTS t_audit_logs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type VARCHAR(32) NOT NULL,
+    entity_id INTEGER NOT NULL,
+    operation VARCHAR(16) NOT NULL,
+    before_json TEXT,
+    after_json TEXT,
+    actor VARCHAR(100),
+    created_at TIMESTAMP DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_t_audit_logs_entity ON t_audit_logs (entity_type, entity_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_t_audit_logs_created_at ON t_audit_logs (created_at);
+
+-- A single row naming the user the application acts for.
+CREATE TABLE IF NOT EXISTS t_audit_context (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    actor VARCHAR(100)
+);
+
+INSERT OR IGNORE INTO t_audit_context (id, actor) VALUES (1, NULL);
+
+CREATE TRIGGER IF NOT EXISTS trg_m_products_audit_insert AFTER INSERT ON m_products
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, after_json, actor)
+    VALUES (
+        'product',
+        NEW.id,
+        'create',
+        json_object(
+            'id', NEW.id,
+            'name', NEW.name,
+            'code', NEW.code,
+            'unit', NEW.unit,
+            'default_price', NEW.default_price,
+            'standard_stock_quantity', NEW.standard_stock_quantity,
+            'tax_rate', NEW.tax_rate,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at,
+            'deleted_at', NEW.deleted_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_products_audit_update AFTER UPDATE ON m_products
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, after_json, actor)
+    VALUES (
+        'product',
+        NEW.id,
+        CASE
+            WHEN OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL THEN 'delete'
+            WHEN OLD.deleted_at IS NOT NULL AND NEW.deleted_at IS NULL THEN 'restore'
+            ELSE 'update'
+        END,
+        json_object(
+            'id', OLD.id,
+            'name', OLD.name,
+            'code', OLD.code,
+            'unit', OLD.unit,
+            'default_price', OLD.default_price,
+            'standard_stock_quantity', OLD.standard_stock_quantity,
+            'tax_rate', OLD.tax_rate,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at,
+            'deleted_at', OLD.deleted_at
+        ),
+        json_object(
+            'id', NEW.id,
+            'name', NEW.name,
+            'code', NEW.code,
+            'unit', NEW.unit,
+            'default_price', NEW.default_price,
+            'standard_stock_quantity', NEW.standard_stock_quantity,
+            'tax_rate', NEW.tax_rate,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at,
+            'deleted_at', NEW.deleted_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_products_audit_delete AFTER DELETE ON m_products
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, actor)
+    VALUES (
+        'product',
+        OLD.id,
+        'delete',
+        json_object(
+            'id', OLD.id,
+            'name', OLD.name,
+            'code', OLD.code,
+            'unit', OLD.unit,
+            'default_price', OLD.default_price,
+            'standard_stock_quantity', OLD.standard_stock_quantity,
+            'tax_rate', OLD.tax_rate,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at,
+            'deleted_at', OLD.deleted_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_customers_audit_insert AFTER INSERT ON m_customers
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, after_json, actor)
+    VALUES (
+        'customer',
+        NEW.id,
+        'create',
+        json_object(
+            'id', NEW.id,
+            'name', NEW.name,
+            'postal', NEW.postal,
+            'address', NEW.address,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at,
+            'deleted_at', NEW.deleted_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_customers_audit_update AFTER UPDATE ON m_customers
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, after_json, actor)
+    VALUES (
+        'customer',
+        NEW.id,
+        CASE
+            WHEN OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL THEN 'delete'
+            WHEN OLD.deleted_at IS NOT NULL AND NEW.deleted_at IS NULL THEN 'restore'
+            ELSE 'update'
+        END,
+        json_object(
+            'id', OLD.id,
+            'name', OLD.name,
+            'postal', OLD.postal,
+            'address', OLD.address,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at,
+            'deleted_at', OLD.deleted_at
+        ),
+        json_object(
+            'id', NEW.id,
+            'name', NEW.name,
+            'postal', NEW.postal,
+            'address', NEW.address,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at,
+            'deleted_at', NEW.deleted_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_customers_audit_delete AFTER DELETE ON m_customers
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, actor)
+    VALUES (
+        'customer',
+        OLD.id,
+        'delete',
+        json_object(
+            'id', OLD.id,
+            'name', OLD.name,
+            'postal', OLD.postal,
+            'address', OLD.address,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at,
+            'deleted_at', OLD.deleted_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_price_lists_audit_insert AFTER INSERT ON m_price_lists
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, after_json, actor)
+    VALUES (
+        'price_list',
+        NEW.id,
+        'create',
+        json_object(
+            'id', NEW.id,
+            'customer_id', NEW.customer_id,
+            'product_id', NEW.product_id,
+            'price', NEW.price,
+            'valid_from', NEW.valid_from,
+            'valid_to', NEW.valid_to,
+            'min_quantity', NEW.min_quantity,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_price_lists_audit_update AFTER UPDATE ON m_price_lists
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, after_json, actor)
+    VALUES (
+        'price_list',
+        NEW.id,
+        'update',
+        json_object(
+            'id', OLD.id,
+            'customer_id', OLD.customer_id,
+            'product_id', OLD.product_id,
+            'price', OLD.price,
+            'valid_from', OLD.valid_from,
+            'valid_to', OLD.valid_to,
+            'min_quantity', OLD.min_quantity,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at
+        ),
+        json_object(
+            'id', NEW.id,
+            'customer_id', NEW.customer_id,
+            'product_id', NEW.product_id,
+            'price', NEW.price,
+            'valid_from', NEW.valid_from,
+            'valid_to', NEW.valid_to,
+            'min_quantity', NEW.min_quantity,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_m_price_lists_audit_delete AFTER DELETE ON m_price_lists
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, actor)
+    VALUES (
+        'price_list',
+        OLD.id,
+        'delete',
+        json_object(
+            'id', OLD.id,
+            'customer_id', OLD.customer_id,
+            'product_id', OLD.product_id,
+            'price', OLD.price,
+            'valid_from', OLD.valid_from,
+            'valid_to', OLD.valid_to,
+            'min_quantity', OLD.min_quantity,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_sales_orders_audit_insert AFTER INSERT ON t_sales_orders
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, after_json, actor)
+    VALUES (
+        'sales_order',
+        NEW.id,
+        'create',
+        json_object(
+            'id', NEW.id,
+            'customer_id', NEW.customer_id,
+            'order_date', NEW.order_date,
+            'status', NEW.status,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_sales_orders_audit_update AFTER UPDATE ON t_sales_orders
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, after_json, actor)
+    VALUES (
+        'sales_order',
+        NEW.id,
+        'update',
+        json_object(
+            'id', OLD.id,
+            'customer_id', OLD.customer_id,
+            'order_date', OLD.order_date,
+            'status', OLD.status,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at
+        ),
+        json_object(
+            'id', NEW.id,
+            'customer_id', NEW.customer_id,
+            'order_date', NEW.order_date,
+            'status', NEW.status,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_sales_orders_audit_delete AFTER DELETE ON t_sales_orders
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, actor)
+    VALUES (
+        'sales_order',
+        OLD.id,
+        'delete',
+        json_object(
+            'id', OLD.id,
+            'customer_id', OLD.customer_id,
+            'order_date', OLD.order_date,
+            'status', OLD.status,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_sales_order_lines_audit_insert AFTER INSERT ON t_sales_order_lines
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, after_json, actor)
+    VALUES (
+        'sales_order_line',
+        NEW.id,
+        'create',
+        json_object(
+            'id', NEW.id,
+            'sales_order_id', NEW.sales_order_id,
+            'product_id', NEW.product_id,
+            'quantity', NEW.quantity,
+            'unit_price', NEW.unit_price,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_sales_order_lines_audit_update AFTER UPDATE ON t_sales_order_lines
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, after_json, actor)
+    VALUES (
+        'sales_order_line',
+        NEW.id,
+        'update',
+        json_object(
+            'id', OLD.id,
+            'sales_order_id', OLD.sales_order_id,
+            'product_id', OLD.product_id,
+            'quantity', OLD.quantity,
+            'unit_price', OLD.unit_price,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at
+        ),
+        json_object(
+            'id', NEW.id,
+            'sales_order_id', NEW.sales_order_id,
+            'product_id', NEW.product_id,
+            'quantity', NEW.quantity,
+            'unit_price', NEW.unit_price,
+            'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_t_sales_order_lines_audit_delete AFTER DELETE ON t_sales_order_lines
+BEGIN
+    INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, actor)
+    VALUES (
+        'sales_order_line',
+        OLD.id,
+        'delete',
+        json_object(
+            'id', OLD.id,
+            'sales_order_id', OLD.sales_order_id,
+            'product_id', OLD.product_id,
+            'quantity', OLD.quantity,
+            'unit_price', OLD.unit_price,
+            'created_at', OLD.created_at,
+            'updated_at', OLD.updated_at
+        ),
+        (SELECT actor FROM t_audit_context WHERE id = 1)
+    );
+END;
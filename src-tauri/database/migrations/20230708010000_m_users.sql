@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS m_users (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name VARCHAR(100) NOT NULL UNIQUE,
+    -- Argon2 hash in the PHC string format, which carries its own salt and parameters.
+    password_hash TEXT NOT NULL,
+    role VARCHAR(10) NOT NULL CHECK (role IN ('admin', 'sales', 'viewer')),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
@@ -0,0 +1,40 @@
+-- The audit triggers are created by each connection as TEMP triggers instead, so that every
+-- connection names its own actor and no session state is kept in the database file.
+DROP TRIGGER IF EXISTS trg_m_products_audit_insert;
+DROP TRIGGER IF EXISTS trg_m_products_audit_update;
+DROP TRIGGER IF EXISTS trg_m_products_audit_delete;
+DROP TRIGGER IF EXISTS trg_m_customers_audit_insert;
+DROP TRIGGER IF EXISTS trg_m_customers_audit_update;
+DROP TRIGGER IF EXISTS trg_m_customers_audit_delete;
+DROP TRIGGER IF EXISTS trg_m_price_lists_audit_insert;
+DROP TRIGGER IF EXISTS trg_m_price_lists_audit_update;
+DROP TRIGGER IF EXISTS trg_m_price_lists_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_sales_orders_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_sales_orders_audit_update;
+DROP TRIGGER IF EXISTS trg_t_sales_orders_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_sales_order_lines_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_sales_order_lines_audit_update;
+DROP TRIGGER IF EXISTS trg_t_sales_order_lines_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_invoices_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_invoices_audit_update;
+DROP TRIGGER IF EXISTS trg_t_invoices_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_invoice_lines_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_invoice_lines_audit_update;
+DROP TRIGGER IF EXISTS trg_t_invoice_lines_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_payments_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_payments_audit_update;
+DROP TRIGGER IF EXISTS trg_t_payments_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_payment_allocations_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_payment_allocations_audit_update;
+DROP TRIGGER IF EXISTS trg_t_payment_allocations_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_quotations_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_quotations_audit_update;
+DROP TRIGGER IF EXISTS trg_t_quotations_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_quotation_lines_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_quotation_lines_audit_update;
+DROP TRIGGER IF EXISTS trg_t_quotation_lines_audit_delete;
+DROP TRIGGER IF EXISTS trg_t_stock_movements_audit_insert;
+DROP TRIGGER IF EXISTS trg_t_stock_movements_audit_update;
+DROP TRIGGER IF EXISTS trg_t_stock_movements_audit_delete;
+
+DROP TABLE IF EXISTS t_audit_context;
@@ -0,0 +1,22 @@
+use crate::adapters::controller::request::audit_log_request::SearchAuditLogRequest;
+use crate::application::usecase::audit_log::search_audit_log::{
+    SearchAuditLogInput, SearchAuditLogOutput, SearchAuditLogUsecase,
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn search_audit_log(
+    usecase: SearchAuditLogUsecase,
+    request: SearchAuditLogRequest,
+) -> Result<SearchAuditLogOutput, DomainError> {
+    let input = SearchAuditLogInput::new(
+        request.offset,
+        request.limit,
+        request.entity_type,
+        request.entity_id,
+        request.from,
+        request.to,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
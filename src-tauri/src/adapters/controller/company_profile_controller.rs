@@ -0,0 +1,25 @@
+use crate::adapters::controller::request::company_profile_request::SaveCompanyProfileRequest;
+use crate::application::usecase::company_profile::{
+    find_company_profile::{FindCompanyProfileOutput, FindCompanyProfileUsecase},
+    save_company_profile::{SaveCompanyProfileInput, SaveCompanyProfileUsecase},
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn find_company_profile(
+    usecase: FindCompanyProfileUsecase,
+) -> Result<FindCompanyProfileOutput, DomainError> {
+    let output = usecase.find().await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn save_company_profile(
+    usecase: SaveCompanyProfileUsecase,
+    request: SaveCompanyProfileRequest,
+) -> Result<(), DomainError> {
+    let input =
+        SaveCompanyProfileInput::new(request.registration_number, request.name, request.address);
+    usecase.save(input).await?;
+
+    Ok(())
+}
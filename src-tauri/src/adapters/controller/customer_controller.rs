@@ -1,26 +1,53 @@
-use std::{rc::Rc, error::Error};
+use crate::domain::error::DomainError;
 
-use crate::application::usecase::customer::{create_customer::{CreateCustomerOutput, CreateCustomerUsecase, CreateCustomerInput}, update_customer::{UpdateCustomerOutput, UpdateCustomerInput, UpdateCustomerUsecase}};
-use crate::adapters::controller::request::customer_request::{CreateCustomerRequest, UpdateCustomerRequest};
+use crate::application::usecase::customer::{create_customer::{CreateCustomerOutput, CreateCustomerUsecase, CreateCustomerInput}, delete_customer::{DeleteCustomerOutput, DeleteCustomerUsecase}, find_by_id_customer::FindByIDCustomerUsecase, search_customer::{SearchCustomerInput, SearchCustomerOutput, SearchCustomerUsecase}, update_customer::{UpdateCustomerOutput, UpdateCustomerInput, UpdateCustomerUsecase}, restore_customer::{RestoreCustomerOutput, RestoreCustomerUsecase}};
+use crate::adapters::controller::request::customer_request::{CreateCustomerRequest, UpdateCustomerRequest, RestoreCustomerRequest, FindByIDCustomerRequest, SearchCustomerRequest, DeleteCustomerRequest};
+use crate::domain::customer::Customer;
 
-pub(crate) async fn create (usecase: CreateCustomerUsecase, request: CreateCustomerRequest) -> Result<CreateCustomerOutput, Rc<dyn Error>> {
-    let input = CreateCustomerInput::new(request.name().to_string(), request.postal(), request.address().to_string());
+pub(crate) async fn find_by_id (usecase: FindByIDCustomerUsecase, request: FindByIDCustomerRequest) -> Result<Option<Customer>, DomainError> {
+    let output = usecase.find_by_id(&request.id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search (usecase: SearchCustomerUsecase, request: SearchCustomerRequest) -> Result<SearchCustomerOutput, DomainError> {
+    let input = SearchCustomerInput::new(
+        request.offset,
+        request.limit,
+        request.name,
+        request.address,
+        request.postal,
+        request.include_deleted,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn create (usecase: CreateCustomerUsecase, request: CreateCustomerRequest) -> Result<CreateCustomerOutput, DomainError> {
+    let input = CreateCustomerInput::new(request.name().to_string(), request.postal().to_string(), request.address().to_string());
     let output = usecase.create(input).await?;
 
     Ok(output)
 }
 
-pub(crate) async fn update (usecase: UpdateCustomerUsecase, request: UpdateCustomerRequest) -> Result<UpdateCustomerOutput, Rc<dyn Error>> {
-    let name = match request.name() {
-        Some(name) => Some(name.to_string()),
-        None => None
-    };
-    let address = match request.address() {
-        Some(address) => Some(address.to_string()),
-        None => None
-    };
-    let input = UpdateCustomerInput::new(request.id(), name, request.postal(), address);
+pub(crate) async fn update (usecase: UpdateCustomerUsecase, request: UpdateCustomerRequest) -> Result<UpdateCustomerOutput, DomainError> {
+    let name = request.name().as_ref().map(|name| name.to_string());
+    let address = request.address().as_ref().map(|address| address.to_string());
+    let input = UpdateCustomerInput::new(request.id(), name, request.postal().clone(), address, *request.expected_updated_at());
     let output = usecase.update(input).await?;
 
     Ok(output)
 }
+
+pub(crate) async fn delete (usecase: DeleteCustomerUsecase, request: DeleteCustomerRequest) -> Result<DeleteCustomerOutput, DomainError> {
+    let output = usecase.delete(&request.id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn restore (usecase: RestoreCustomerUsecase, request: RestoreCustomerRequest) -> Result<RestoreCustomerOutput, DomainError> {
+    let output = usecase.restore(&request.id()).await?;
+
+    Ok(output)
+}
@@ -0,0 +1,38 @@
+use crate::adapters::controller::request::document_request::{
+    ExportDeliverySlipPdfRequest, ExportInvoicePdfRequest, ExportQuotationPdfRequest,
+};
+use crate::application::usecase::document::{
+    find_delivery_slip_document::{
+        FindDeliverySlipDocumentOutput, FindDeliverySlipDocumentUsecase,
+    },
+    find_invoice_document::{FindInvoiceDocumentOutput, FindInvoiceDocumentUsecase},
+    find_quotation_document::{FindQuotationDocumentOutput, FindQuotationDocumentUsecase},
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn find_invoice_document(
+    usecase: FindInvoiceDocumentUsecase,
+    request: &ExportInvoicePdfRequest,
+) -> Result<FindInvoiceDocumentOutput, DomainError> {
+    let output = usecase.find(&request.invoice_id).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn find_delivery_slip_document(
+    usecase: FindDeliverySlipDocumentUsecase,
+    request: &ExportDeliverySlipPdfRequest,
+) -> Result<FindDeliverySlipDocumentOutput, DomainError> {
+    let output = usecase.find(&request.sales_order_id).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn find_quotation_document(
+    usecase: FindQuotationDocumentUsecase,
+    request: &ExportQuotationPdfRequest,
+) -> Result<FindQuotationDocumentOutput, DomainError> {
+    let output = usecase.find(&request.quotation_id).await?;
+
+    Ok(output)
+}
@@ -0,0 +1,91 @@
+use crate::adapters::controller::{
+    product_controller,
+    request::{
+        customer_request::SearchCustomerRequest, invoice_request::SearchInvoiceRequest,
+        product_request::SearchProductRequest, sales_order_request::SearchSalesOrderRequest,
+    },
+};
+use crate::application::usecase::{
+    customer::search_customer::{SearchCustomerInput, SearchCustomerOutput, SearchCustomerUsecase},
+    invoice::search_invoice::{SearchInvoiceInput, SearchInvoiceOutput, SearchInvoiceUsecase},
+    product::search_product::{SearchProductOutput, SearchProductUsecase},
+    sales_order::search_sales_order::{
+        SearchSalesOrderInput, SearchSalesOrderOutput, SearchSalesOrderUsecase,
+    },
+};
+use crate::domain::error::DomainError;
+
+// Exports read the records a page at a time in id order: the filters come from the request, and
+// the id the page starts after from the exporter.
+
+pub(crate) async fn search_product_page(
+    usecase: &SearchProductUsecase,
+    filter: &SearchProductRequest,
+    after_id: i64,
+    limit: i64,
+) -> Result<SearchProductOutput, DomainError> {
+    let input = product_controller::search_product_input(filter, None, Some(limit))
+        .with_after_id(after_id);
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_customer_page(
+    usecase: &SearchCustomerUsecase,
+    filter: &SearchCustomerRequest,
+    after_id: i64,
+    limit: i64,
+) -> Result<SearchCustomerOutput, DomainError> {
+    let input = SearchCustomerInput::new(
+        None,
+        Some(limit),
+        filter.name.clone(),
+        filter.address.clone(),
+        filter.postal.clone(),
+        filter.include_deleted,
+    )
+    .with_after_id(after_id);
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_sales_order_page(
+    usecase: &SearchSalesOrderUsecase,
+    filter: &SearchSalesOrderRequest,
+    after_id: i64,
+    limit: i64,
+) -> Result<SearchSalesOrderOutput, DomainError> {
+    let input = SearchSalesOrderInput::new(
+        None,
+        Some(limit),
+        filter.customer_id,
+        filter.status,
+        filter.order_date_from,
+        filter.order_date_to,
+    )
+    .with_after_id(after_id);
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_invoice_page(
+    usecase: &SearchInvoiceUsecase,
+    filter: &SearchInvoiceRequest,
+    after_id: i64,
+    limit: i64,
+) -> Result<SearchInvoiceOutput, DomainError> {
+    let input = SearchInvoiceInput::new(
+        None,
+        Some(limit),
+        filter.customer_id,
+        filter.issued_on_from,
+        filter.issued_on_to,
+    )
+    .with_after_id(after_id);
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
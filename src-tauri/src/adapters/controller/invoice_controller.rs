@@ -0,0 +1,50 @@
+use crate::adapters::controller::request::invoice_request::{
+    FindByIDInvoiceRequest, IssueInvoiceRequest, SearchInvoiceRequest,
+};
+use crate::application::usecase::invoice::{
+    find_by_id_invoice::{FindByIDInvoiceOutput, FindByIDInvoiceUsecase},
+    issue_invoice::{IssueInvoiceInput, IssueInvoiceOutput, IssueInvoiceUsecase},
+    search_invoice::{SearchInvoiceInput, SearchInvoiceOutput, SearchInvoiceUsecase},
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn find_by_id(
+    usecase: FindByIDInvoiceUsecase,
+    request: FindByIDInvoiceRequest,
+) -> Result<FindByIDInvoiceOutput, DomainError> {
+    let output = usecase.find_by_id(request.invoice_id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_invoice(
+    usecase: SearchInvoiceUsecase,
+    request: SearchInvoiceRequest,
+) -> Result<SearchInvoiceOutput, DomainError> {
+    let input = SearchInvoiceInput::new(
+        request.offset,
+        request.limit,
+        request.customer_id,
+        request.issued_on_from,
+        request.issued_on_to,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn issue_invoice(
+    usecase: IssueInvoiceUsecase,
+    request: IssueInvoiceRequest,
+) -> Result<IssueInvoiceOutput, DomainError> {
+    let input = IssueInvoiceInput::new(
+        request.customer_id,
+        request.period_from,
+        request.period_to,
+        request.issued_on,
+        request.rounding_mode,
+    );
+    let output = usecase.issue(input).await?;
+
+    Ok(output)
+}
@@ -1,3 +1,15 @@
+pub mod audit_log_controller;
+pub mod company_profile_controller;
 pub mod customer_controller;
+pub mod document_controller;
+pub mod export_controller;
+pub mod invoice_controller;
+pub mod price_list_controller;
 pub mod product_controller;
+pub mod quotation_controller;
+pub mod receivable_controller;
+pub mod reorder_alert_controller;
 pub mod request;
+pub mod sales_order_controller;
+pub mod stock_movement_controller;
+pub mod user_controller;
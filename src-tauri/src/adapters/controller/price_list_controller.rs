@@ -0,0 +1,94 @@
+use crate::adapters::controller::request::price_list_request::{
+    CreatePriceListRequest, DeletePriceListRequest, FindByIDPriceListRequest, ResolvePriceRequest,
+    SearchPriceListRequest, UpdatePriceListRequest,
+};
+use crate::application::usecase::price_list::{
+    create_price_list::{CreatePriceListInput, CreatePriceListOutput, CreatePriceListUsecase},
+    delete_price_list::{DeletePriceListOutput, DeletePriceListUsecase},
+    find_by_id_price_list::{FindByIDPriceListOutput, FindByIDPriceListUsecase},
+    resolve_price::{ResolvePriceInput, ResolvePriceOutput, ResolvePriceUsecase},
+    search_price_list::{SearchPriceListInput, SearchPriceListOutput, SearchPriceListUsecase},
+    update_price_list::{UpdatePriceListInput, UpdatePriceListOutput, UpdatePriceListUsecase},
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn find_by_id(
+    usecase: FindByIDPriceListUsecase,
+    request: FindByIDPriceListRequest,
+) -> Result<FindByIDPriceListOutput, DomainError> {
+    let output = usecase.find_by_id(request.price_list_id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_price_list(
+    usecase: SearchPriceListUsecase,
+    request: SearchPriceListRequest,
+) -> Result<SearchPriceListOutput, DomainError> {
+    let input = SearchPriceListInput::new(
+        request.offset,
+        request.limit,
+        request.customer_id,
+        request.product_id,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn create_price_list(
+    usecase: CreatePriceListUsecase,
+    request: CreatePriceListRequest,
+) -> Result<CreatePriceListOutput, DomainError> {
+    let input = CreatePriceListInput::new(
+        request.customer_id,
+        request.product_id,
+        request.price,
+        request.valid_from,
+        request.valid_to,
+        request.min_quantity,
+    );
+    let output = usecase.create(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn update_price_list(
+    usecase: UpdatePriceListUsecase,
+    request: UpdatePriceListRequest,
+) -> Result<UpdatePriceListOutput, DomainError> {
+    let input = UpdatePriceListInput::new(
+        request.id,
+        request.price,
+        request.valid_from,
+        request.valid_to,
+        request.min_quantity,
+    );
+    let output = usecase.update(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn delete_price_list(
+    usecase: DeletePriceListUsecase,
+    request: DeletePriceListRequest,
+) -> Result<DeletePriceListOutput, DomainError> {
+    let output = usecase.delete(request.price_list_id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn resolve_price(
+    usecase: ResolvePriceUsecase,
+    request: ResolvePriceRequest,
+) -> Result<ResolvePriceOutput, DomainError> {
+    let input = ResolvePriceInput::new(
+        request.customer_id,
+        request.product_id,
+        request.quantity,
+        request.date,
+    );
+    let output = usecase.resolve(input).await?;
+
+    Ok(output)
+}
@@ -2,34 +2,62 @@ use crate::adapters::controller::request::product_request::CreateProductRequest;
 use crate::application::usecase::product::create_product::CreateProductOutput;
 use crate::application::usecase::product::delete_product::{DeleteProductOutput, DeleteProductUsecase};
 use crate::application::usecase::product::find_by_id_product::{FindByIDProductUsecase, FindByIDProductOutput};
+use crate::application::usecase::product::import_product::{
+    ImportProductInput, ImportProductOutput, ImportProductRow, ImportProductUsecase,
+};
+use crate::application::usecase::product::restore_product::{RestoreProductOutput, RestoreProductUsecase};
 use crate::application::usecase::product::update_product::{
     UpdateProductInput, UpdateProductOutput, UpdateProductUsecase,
 };
 use crate::application::usecase::product::{
     create_product::{CreateProductInput, CreateProductUsecase},
-    search_product::{SearchProductInput, SearchProductOutput, SearchProductUsecase},
+    search_product::{NumberRange, SearchProductInput, SearchProductOutput, SearchProductUsecase},
 };
-use std::error::Error;
-use std::rc::Rc;
+use crate::domain::error::{DomainError, FieldError};
+use crate::domain::tax::TaxRate;
 
-use super::request::product_request::{SearchProductRequest, UpdateProductRequest, FindByIDProductRequest, DeleteProductRequest};
+use super::request::product_request::{ImportProductRequest, SearchProductRequest, UpdateProductRequest, FindByIDProductRequest, DeleteProductRequest, RestoreProductRequest};
 
-pub(crate) async fn find_by_id (usecase: FindByIDProductUsecase, request: FindByIDProductRequest) -> Result<FindByIDProductOutput, Box<dyn Error>> {
+pub(crate) async fn find_by_id (usecase: FindByIDProductUsecase, request: FindByIDProductRequest) -> Result<FindByIDProductOutput, DomainError> {
     let output = usecase.find_by_id(request.product_id()).await?;
 
     Ok(output)
 }
 
-pub(crate) async fn search_product(
-    usecase: SearchProductUsecase,
-    request: SearchProductRequest,
-) -> Result<SearchProductOutput, Box<dyn Error>> {
-    let offset: Option<i64> = *request.offset();
-    let limit: Option<i64> = *request.limit();
+/// Builds the search conditions of `request` for the page at `offset`.
+pub(crate) fn search_product_input(
+    request: &SearchProductRequest,
+    offset: Option<i64>,
+    limit: Option<i64>,
+) -> SearchProductInput {
     let name: Option<String> = request.name().clone();
     let code: Option<String> = request.code().clone();
+    let include_deleted: Option<bool> = *request.include_deleted();
 
-    let input = SearchProductInput::new(offset, limit, name, code);
+    SearchProductInput::new(offset, limit, name, code, include_deleted)
+        .with_text_match(
+            request.name_match().unwrap_or_default(),
+            request.code_match().unwrap_or_default(),
+        )
+        .with_default_price(NumberRange::new(
+            *request.default_price_min(),
+            *request.default_price_max(),
+        ))
+        .with_standard_stock_quantity(NumberRange::new(
+            *request.standard_stock_quantity_min(),
+            *request.standard_stock_quantity_max(),
+        ))
+        .with_sort(
+            request.sort_key().unwrap_or_default(),
+            request.sort_direction().unwrap_or_default(),
+        )
+}
+
+pub(crate) async fn search_product(
+    usecase: SearchProductUsecase,
+    request: SearchProductRequest,
+) -> Result<SearchProductOutput, DomainError> {
+    let input = search_product_input(&request, *request.offset(), *request.limit());
     let output = usecase.search(input).await?;
 
     Ok(output)
@@ -38,13 +66,14 @@ pub(crate) async fn search_product(
 pub(crate) async fn create_product(
     usecase: CreateProductUsecase,
     request: CreateProductRequest,
-) -> Result<CreateProductOutput, Rc<dyn Error>> {
+) -> Result<CreateProductOutput, DomainError> {
     let input = CreateProductInput::new(
         request.name,
         request.code,
         request.unit,
         request.default_price,
         request.standard_stock_quantity,
+        request.tax_rate,
     );
     let output = usecase.create(input).await?;
 
@@ -54,7 +83,7 @@ pub(crate) async fn create_product(
 pub(crate) async fn update_product(
     usecase: UpdateProductUsecase,
     request: UpdateProductRequest,
-) -> Result<UpdateProductOutput, Rc<dyn Error>> {
+) -> Result<UpdateProductOutput, DomainError> {
     let input = UpdateProductInput::new(
         request.id,
         request.name,
@@ -62,14 +91,179 @@ pub(crate) async fn update_product(
         request.unit,
         request.default_price,
         request.standard_stock_quantity,
-    );
+        request.tax_rate,
+    )
+    .with_expected_updated_at(request.expected_updated_at);
     let output = usecase.update(input).await?;
 
     Ok(output)
 }
 
-pub(crate) async fn delete_product(usecase: DeleteProductUsecase, request: DeleteProductRequest) -> Result<DeleteProductOutput, Rc<dyn Error>> {
+pub(crate) async fn delete_product(usecase: DeleteProductUsecase, request: DeleteProductRequest) -> Result<DeleteProductOutput, DomainError> {
     let output = usecase.delete(request.product_id()).await?;
 
     Ok(output)
-}
\ No newline at end of file
+}
+
+pub(crate) async fn restore_product(
+    usecase: RestoreProductUsecase,
+    request: RestoreProductRequest,
+) -> Result<RestoreProductOutput, DomainError> {
+    let output = usecase.restore(request.product_id()).await?;
+
+    Ok(output)
+}
+
+/// Column names accepted in the header row of an import file, in English or Japanese.
+const IMPORT_COLUMNS: [(&str, &[&str]); 6] = [
+    ("name", &["name", "商品名"]),
+    ("code", &["code", "商品コード"]),
+    ("unit", &["unit", "単位"]),
+    ("default_price", &["default_price", "単価", "標準単価"]),
+    ("standard_stock_quantity", &["standard_stock_quantity", "標準在庫数"]),
+    ("tax_rate", &["tax_rate", "税率"]),
+];
+const REQUIRED_IMPORT_COLUMNS: [&str; 3] = ["name", "code", "unit"];
+
+/// Amounts may be written with thousands separators; a blank cell is zero.
+fn parse_import_number(
+    value: &str,
+    field: &str,
+    errors: &mut Vec<FieldError>,
+) -> i64 {
+    let value = value.trim().replace(',', "");
+    if value.is_empty() {
+        return 0;
+    }
+    match value.parse() {
+        Ok(number) => number,
+        Err(_) => {
+            errors.push(FieldError::new(field, format!("not a number: {}", value)));
+            0
+        }
+    }
+}
+
+/// Accepts a percentage, with or without `%`, or the rate's name; a blank cell is the
+/// standard rate.
+fn parse_import_tax_rate(value: &str, errors: &mut Vec<FieldError>) -> TaxRate {
+    let value = value.trim();
+    let tax_rate = match value {
+        "" | "standard" | "標準" => Ok(TaxRate::Standard),
+        "reduced" | "軽減" => Ok(TaxRate::Reduced),
+        _ => value
+            .trim_end_matches('%')
+            .parse::<i64>()
+            .map_err(|_| format!("unsupported tax rate: {}", value))
+            .and_then(TaxRate::try_from),
+    };
+    tax_rate.unwrap_or_else(|message| {
+        errors.push(FieldError::new("tax_rate", message));
+        TaxRate::Standard
+    })
+}
+
+/// Reads the rows of an import file. The first record names the columns, and rows are
+/// numbered from it as in a spreadsheet.
+pub(crate) fn import_rows(records: Vec<Vec<String>>) -> Result<Vec<ImportProductRow>, DomainError> {
+    let mut records = records.into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| DomainError::validation("path", "the file has no header row"))?;
+    let columns: Vec<Option<&str>> = header
+        .iter()
+        .map(|name| {
+            IMPORT_COLUMNS
+                .iter()
+                .find(|(_, names)| names.contains(&name.trim()))
+                .map(|(column, _)| *column)
+        })
+        .collect();
+    if let Some(column) = REQUIRED_IMPORT_COLUMNS
+        .iter()
+        .find(|column| !columns.contains(&Some(**column)))
+    {
+        return Err(DomainError::validation(
+            "path",
+            format!("the file has no {} column", column),
+        ));
+    }
+
+    Ok(records
+        .enumerate()
+        .map(|(index, record)| {
+            let value = |column: &str| {
+                columns
+                    .iter()
+                    .position(|found| *found == Some(column))
+                    .and_then(|position| record.get(position))
+                    .map(|value| value.trim())
+                    .unwrap_or("")
+            };
+            let mut errors = Vec::new();
+            let product = CreateProductInput::new(
+                value("name").to_string(),
+                value("code").to_string(),
+                value("unit").to_string(),
+                parse_import_number(value("default_price"), "default_price", &mut errors),
+                parse_import_number(
+                    value("standard_stock_quantity"),
+                    "standard_stock_quantity",
+                    &mut errors,
+                ),
+                parse_import_tax_rate(value("tax_rate"), &mut errors),
+            );
+
+            ImportProductRow::new(index as i64 + 2, product, errors)
+        })
+        .collect())
+}
+
+pub(crate) async fn import_product(
+    usecase: ImportProductUsecase,
+    request: &ImportProductRequest,
+    records: Vec<Vec<String>>,
+) -> Result<ImportProductOutput, DomainError> {
+    let input = ImportProductInput::new(import_rows(records)?, request.dry_run);
+    let output = usecase.import(input).await?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{adapters::controller::product_controller::import_rows, domain::tax::TaxRate};
+
+    fn records(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|value| value.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn import_rows_test() {
+        let rows = import_rows(records(&[
+            &["商品コード", "商品名", "単位", "単価", "税率", "備考"],
+            &["pen-001", "ボールペン", "本", "1,200", "8%", "メモ"],
+            &["pen-002", "替芯", "本", "abc", "5"],
+        ]))
+        .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(*rows[0].row(), 2);
+        assert_eq!(rows[0].product().code(), "pen-001");
+        assert_eq!(*rows[0].product().default_price(), 1200);
+        assert_eq!(*rows[0].product().standard_stock_quantity(), 0);
+        assert_eq!(*rows[0].product().tax_rate(), TaxRate::Reduced);
+        assert!(rows[0].errors().is_empty());
+        let fields: Vec<&str> = rows[1]
+            .errors()
+            .iter()
+            .map(|error| error.field().as_str())
+            .collect();
+        assert_eq!(fields, vec!["default_price", "tax_rate"]);
+
+        let error = import_rows(records(&[&["name", "unit"]])).err().unwrap();
+        assert_eq!(error.fields()[0].field(), "path");
+    }
+}
@@ -0,0 +1,104 @@
+use crate::adapters::controller::request::{
+    quotation_request::{
+        ChangeQuotationStatusRequest, ConvertQuotationRequest, CreateQuotationRequest,
+        FindByIDQuotationRequest, SearchQuotationRequest, UpdateQuotationRequest,
+    },
+    sales_order_request::SalesOrderLineRequest,
+};
+use crate::application::usecase::quotation::{
+    change_quotation_status::{
+        ChangeQuotationStatusInput, ChangeQuotationStatusOutput, ChangeQuotationStatusUsecase,
+    },
+    convert_quotation::{ConvertQuotationInput, ConvertQuotationOutput, ConvertQuotationUsecase},
+    create_quotation::{
+        CreateQuotationInput, CreateQuotationOutput, CreateQuotationUsecase, QuotationLineInput,
+    },
+    find_by_id_quotation::{FindByIDQuotationOutput, FindByIDQuotationUsecase},
+    search_quotation::{SearchQuotationInput, SearchQuotationOutput, SearchQuotationUsecase},
+    update_quotation::{UpdateQuotationInput, UpdateQuotationOutput, UpdateQuotationUsecase},
+};
+use crate::domain::error::DomainError;
+
+fn to_line_inputs(lines: Vec<SalesOrderLineRequest>) -> Vec<QuotationLineInput> {
+    lines
+        .into_iter()
+        .map(|line| QuotationLineInput::new(line.product_id, line.quantity, line.unit_price))
+        .collect()
+}
+
+pub(crate) async fn find_by_id(
+    usecase: FindByIDQuotationUsecase,
+    request: FindByIDQuotationRequest,
+) -> Result<FindByIDQuotationOutput, DomainError> {
+    let output = usecase.find_by_id(request.quotation_id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_quotation(
+    usecase: SearchQuotationUsecase,
+    request: SearchQuotationRequest,
+) -> Result<SearchQuotationOutput, DomainError> {
+    let input = SearchQuotationInput::new(
+        request.offset,
+        request.limit,
+        request.customer_id,
+        request.status,
+        request.quoted_on_from,
+        request.quoted_on_to,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn create_quotation(
+    usecase: CreateQuotationUsecase,
+    request: CreateQuotationRequest,
+) -> Result<CreateQuotationOutput, DomainError> {
+    let input = CreateQuotationInput::new(
+        request.customer_id,
+        request.quoted_on,
+        request.valid_until,
+        to_line_inputs(request.lines),
+    );
+    let output = usecase.create(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn update_quotation(
+    usecase: UpdateQuotationUsecase,
+    request: UpdateQuotationRequest,
+) -> Result<UpdateQuotationOutput, DomainError> {
+    let input = UpdateQuotationInput::new(
+        request.id,
+        request.customer_id,
+        request.quoted_on,
+        request.valid_until,
+        request.lines.map(to_line_inputs),
+    );
+    let output = usecase.update(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn change_quotation_status(
+    usecase: ChangeQuotationStatusUsecase,
+    request: ChangeQuotationStatusRequest,
+) -> Result<ChangeQuotationStatusOutput, DomainError> {
+    let input = ChangeQuotationStatusInput::new(request.id, request.status, request.changed_on);
+    let output = usecase.change(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn convert_quotation(
+    usecase: ConvertQuotationUsecase,
+    request: ConvertQuotationRequest,
+) -> Result<ConvertQuotationOutput, DomainError> {
+    let input = ConvertQuotationInput::new(request.id, request.order_date);
+    let output = usecase.convert(input).await?;
+
+    Ok(output)
+}
@@ -0,0 +1,113 @@
+use crate::adapters::controller::request::receivable_request::{
+    AllocatePaymentRequest, FindByIDPaymentRequest, PaymentAllocationRequest, RecordPaymentRequest,
+    SearchAgingReportRequest, SearchCustomerBalanceRequest, SearchInvoiceBalanceRequest,
+    SearchPaymentRequest,
+};
+use crate::application::usecase::receivable::{
+    allocate_payment::{AllocatePaymentInput, AllocatePaymentOutput, AllocatePaymentUsecase},
+    find_by_id_payment::{FindByIDPaymentOutput, FindByIDPaymentUsecase},
+    record_payment::{
+        PaymentAllocationInput, RecordPaymentInput, RecordPaymentOutput, RecordPaymentUsecase,
+    },
+    search_aging_report::{
+        SearchAgingReportInput, SearchAgingReportOutput, SearchAgingReportUsecase,
+    },
+    search_customer_balance::{SearchCustomerBalanceOutput, SearchCustomerBalanceUsecase},
+    search_invoice_balance::{
+        SearchInvoiceBalanceInput, SearchInvoiceBalanceOutput, SearchInvoiceBalanceUsecase,
+    },
+    search_payment::{SearchPaymentInput, SearchPaymentOutput, SearchPaymentUsecase},
+};
+use crate::domain::error::DomainError;
+
+fn to_allocation_inputs(allocations: Vec<PaymentAllocationRequest>) -> Vec<PaymentAllocationInput> {
+    allocations
+        .into_iter()
+        .map(|allocation| PaymentAllocationInput::new(allocation.invoice_id, allocation.amount))
+        .collect()
+}
+
+pub(crate) async fn find_by_id(
+    usecase: FindByIDPaymentUsecase,
+    request: FindByIDPaymentRequest,
+) -> Result<FindByIDPaymentOutput, DomainError> {
+    let output = usecase.find_by_id(request.payment_id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_payment(
+    usecase: SearchPaymentUsecase,
+    request: SearchPaymentRequest,
+) -> Result<SearchPaymentOutput, DomainError> {
+    let input = SearchPaymentInput::new(
+        request.offset,
+        request.limit,
+        request.customer_id,
+        request.paid_on_from,
+        request.paid_on_to,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn record_payment(
+    usecase: RecordPaymentUsecase,
+    request: RecordPaymentRequest,
+) -> Result<RecordPaymentOutput, DomainError> {
+    let input = RecordPaymentInput::new(
+        request.customer_id,
+        request.paid_on,
+        request.amount,
+        request.method,
+        request.bank_fee,
+        request.note,
+        request.allocations.map(to_allocation_inputs),
+    );
+    let output = usecase.record(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn allocate_payment(
+    usecase: AllocatePaymentUsecase,
+    request: AllocatePaymentRequest,
+) -> Result<AllocatePaymentOutput, DomainError> {
+    let input = AllocatePaymentInput::new(
+        request.payment_id,
+        request.allocations.map(to_allocation_inputs),
+    );
+    let output = usecase.allocate(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_invoice_balance(
+    usecase: SearchInvoiceBalanceUsecase,
+    request: SearchInvoiceBalanceRequest,
+) -> Result<SearchInvoiceBalanceOutput, DomainError> {
+    let input = SearchInvoiceBalanceInput::new(request.customer_id, request.only_outstanding);
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_customer_balance(
+    usecase: SearchCustomerBalanceUsecase,
+    request: SearchCustomerBalanceRequest,
+) -> Result<SearchCustomerBalanceOutput, DomainError> {
+    let output = usecase.search(&request.customer_id).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_aging_report(
+    usecase: SearchAgingReportUsecase,
+    request: SearchAgingReportRequest,
+) -> Result<SearchAgingReportOutput, DomainError> {
+    let input = SearchAgingReportInput::new(request.as_of, request.customer_id);
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
@@ -0,0 +1,15 @@
+use crate::adapters::controller::request::reorder_alert_request::SearchReorderAlertRequest;
+use crate::application::usecase::reorder_alert::search_reorder_alert::{
+    SearchReorderAlertInput, SearchReorderAlertOutput, SearchReorderAlertUsecase,
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn search_reorder_alert(
+    usecase: SearchReorderAlertUsecase,
+    request: SearchReorderAlertRequest,
+) -> Result<SearchReorderAlertOutput, DomainError> {
+    let input = SearchReorderAlertInput::new(*request.product_id());
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
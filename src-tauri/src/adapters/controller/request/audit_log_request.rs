@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::audit_log::{AuditEntity, AuditLogDate};
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchAuditLogRequest {
+    pub entity_type: Option<AuditEntity>,
+    pub entity_id: Option<i64>,
+    pub from: Option<AuditLogDate>,
+    pub to: Option<AuditLogDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct RestoreBackupRequest {
+    pub file_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SaveBackupScheduleRequest {
+    /// Zero turns the automatic backups off.
+    pub interval_hours: u32,
+    pub retention: u32,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateCompanyDatabaseRequest {
+    pub name: String,
+    /// Defaults to a new file in the data directory.
+    pub database_file: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OpenCompanyDatabaseRequest {
+    pub name: String,
+    pub database_file: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SwitchCompanyDatabaseRequest {
+    pub name: String,
+}
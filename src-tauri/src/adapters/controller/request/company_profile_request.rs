@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::company_profile::{CompanyAddress, CompanyName, RegistrationNumber};
+
+#[derive(Serialize, Deserialize)]
+pub struct SaveCompanyProfileRequest {
+    pub registration_number: RegistrationNumber,
+    pub name: CompanyName,
+    pub address: CompanyAddress,
+}
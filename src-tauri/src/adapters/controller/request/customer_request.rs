@@ -1,7 +1,28 @@
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
 use crate::domain::customer::{Postal, Id, Name, Address};
 
+#[derive(Deserialize, Serialize)]
+pub struct FindByIDCustomerRequest {
+    id: Id,
+}
+impl FindByIDCustomerRequest {
+    pub fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SearchCustomerRequest {
+    pub name: Option<Name>,
+    pub address: Option<Address>,
+    pub postal: Option<Postal>,
+    pub include_deleted: Option<bool>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct CreateCustomerRequest {
     name: Name,
@@ -13,8 +34,8 @@ impl CreateCustomerRequest {
         &self.name
     }
 
-    pub fn postal(&self) -> Postal {
-        self.postal
+    pub fn postal(&self) -> &Postal {
+        &self.postal
     }
 
     pub fn address(&self) -> &Address {
@@ -28,6 +49,8 @@ pub struct UpdateCustomerRequest {
     name: Option<Name>,
     postal: Option<Postal>,
     address: Option<Address>,   
+    /// The `updated_at` of the customer as shown to the user.
+    expected_updated_at: Option<PrimitiveDateTime>,
 }
 impl UpdateCustomerRequest {
     pub fn id(&self) -> Id {
@@ -38,11 +61,35 @@ impl UpdateCustomerRequest {
         &self.name
     }
 
-    pub fn postal(&self) -> Option<Postal> {
-        self.postal
+    pub fn postal(&self) -> &Option<Postal> {
+        &self.postal
     }
 
     pub fn address(&self) -> &Option<Address> {
         &self.address
     }
+
+    pub fn expected_updated_at(&self) -> &Option<PrimitiveDateTime> {
+        &self.expected_updated_at
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct DeleteCustomerRequest {
+    id: Id,
+}
+impl DeleteCustomerRequest {
+    pub fn id(&self) -> Id {
+        self.id
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RestoreCustomerRequest {
+    id: Id,
+}
+impl RestoreCustomerRequest {
+    pub fn id(&self) -> Id {
+        self.id
+    }
 }
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{invoice::InvoiceId, quotation::QuotationId, sales_order::SalesOrderId};
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportInvoicePdfRequest {
+    pub invoice_id: InvoiceId,
+    /// Destination chosen by the user, usually through a save dialog.
+    pub path: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportDeliverySlipPdfRequest {
+    pub sales_order_id: SalesOrderId,
+    pub path: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportQuotationPdfRequest {
+    pub quotation_id: QuotationId,
+    pub path: String,
+}
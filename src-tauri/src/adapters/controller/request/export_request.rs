@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    customer_request::SearchCustomerRequest, invoice_request::SearchInvoiceRequest,
+    product_request::SearchProductRequest, sales_order_request::SearchSalesOrderRequest,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// UTF-8 with a byte order mark, so that Excel detects the encoding.
+    CsvUtf8,
+    CsvShiftJis,
+    Xlsx,
+}
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::CsvUtf8 | ExportFormat::CsvShiftJis => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+/// `filter` takes the same conditions as the search; its `limit`, `offset` and sort are ignored
+/// and every matching record is exported in id order.
+#[derive(Serialize, Deserialize)]
+pub struct ExportProductRequest {
+    pub path: String,
+    pub format: ExportFormat,
+    pub filter: SearchProductRequest,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportCustomerRequest {
+    pub path: String,
+    pub format: ExportFormat,
+    pub filter: SearchCustomerRequest,
+}
+
+/// Exports a row per order line.
+#[derive(Serialize, Deserialize)]
+pub struct ExportSalesOrderRequest {
+    pub path: String,
+    pub format: ExportFormat,
+    pub filter: SearchSalesOrderRequest,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExportInvoiceRequest {
+    pub path: String,
+    pub format: ExportFormat,
+    pub filter: SearchInvoiceRequest,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    customer,
+    invoice::{InvoiceDate, InvoiceId},
+    tax::RoundingMode,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct FindByIDInvoiceRequest {
+    invoice_id: InvoiceId,
+}
+impl FindByIDInvoiceRequest {
+    pub fn invoice_id(&self) -> &InvoiceId {
+        &self.invoice_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchInvoiceRequest {
+    pub customer_id: Option<customer::Id>,
+    pub issued_on_from: Option<InvoiceDate>,
+    pub issued_on_to: Option<InvoiceDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IssueInvoiceRequest {
+    pub customer_id: customer::Id,
+    pub period_from: InvoiceDate,
+    pub period_to: InvoiceDate,
+    pub issued_on: InvoiceDate,
+    #[serde(default)]
+    pub rounding_mode: RoundingMode,
+}
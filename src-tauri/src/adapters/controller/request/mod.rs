@@ -1,2 +1,16 @@
+pub mod audit_log_request;
+pub mod backup_request;
+pub mod company_database_request;
+pub mod company_profile_request;
 pub mod customer_request;
+pub mod document_request;
+pub mod export_request;
+pub mod invoice_request;
+pub mod price_list_request;
 pub mod product_request;
+pub mod quotation_request;
+pub mod receivable_request;
+pub mod reorder_alert_request;
+pub mod sales_order_request;
+pub mod stock_movement_request;
+pub mod user_request;
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    customer,
+    price_list::{PriceListDate, PriceListId, PriceListPrice, PriceListQuantity},
+    product::ProductId,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct FindByIDPriceListRequest {
+    price_list_id: PriceListId,
+}
+impl FindByIDPriceListRequest {
+    pub fn price_list_id(&self) -> &PriceListId {
+        &self.price_list_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchPriceListRequest {
+    pub customer_id: Option<customer::Id>,
+    pub product_id: Option<ProductId>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreatePriceListRequest {
+    pub customer_id: customer::Id,
+    pub product_id: ProductId,
+    pub price: PriceListPrice,
+    pub valid_from: Option<PriceListDate>,
+    pub valid_to: Option<PriceListDate>,
+    /// Defaults to 1.
+    pub min_quantity: Option<PriceListQuantity>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdatePriceListRequest {
+    pub id: PriceListId,
+    pub price: PriceListPrice,
+    pub valid_from: Option<PriceListDate>,
+    pub valid_to: Option<PriceListDate>,
+    pub min_quantity: Option<PriceListQuantity>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeletePriceListRequest {
+    price_list_id: PriceListId,
+}
+impl DeletePriceListRequest {
+    pub fn price_list_id(&self) -> &PriceListId {
+        &self.price_list_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ResolvePriceRequest {
+    pub customer_id: customer::Id,
+    pub product_id: ProductId,
+    pub quantity: PriceListQuantity,
+    pub date: PriceListDate,
+}
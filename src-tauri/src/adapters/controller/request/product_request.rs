@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 
-use crate::domain::product::ProductId;
+use crate::{
+    application::usecase::product::search_product::{ProductSortKey, SortDirection, TextMatch},
+    domain::{product::ProductId, tax::TaxRate},
+};
 
 #[derive(Serialize, Deserialize)]
 pub struct FindByIDProductRequest {
@@ -16,8 +20,17 @@ impl FindByIDProductRequest {
 pub struct SearchProductRequest {
     name: Option<String>,
     code: Option<String>,
+    name_match: Option<TextMatch>,
+    code_match: Option<TextMatch>,
+    default_price_min: Option<i64>,
+    default_price_max: Option<i64>,
+    standard_stock_quantity_min: Option<i64>,
+    standard_stock_quantity_max: Option<i64>,
+    sort_key: Option<ProductSortKey>,
+    sort_direction: Option<SortDirection>,
     limit: Option<i64>,
     offset: Option<i64>,
+    include_deleted: Option<bool>,
 }
 impl SearchProductRequest {
     pub fn name(&self) -> &Option<String> {
@@ -28,6 +41,38 @@ impl SearchProductRequest {
         &self.code
     }
 
+    pub fn name_match(&self) -> &Option<TextMatch> {
+        &self.name_match
+    }
+
+    pub fn code_match(&self) -> &Option<TextMatch> {
+        &self.code_match
+    }
+
+    pub fn default_price_min(&self) -> &Option<i64> {
+        &self.default_price_min
+    }
+
+    pub fn default_price_max(&self) -> &Option<i64> {
+        &self.default_price_max
+    }
+
+    pub fn standard_stock_quantity_min(&self) -> &Option<i64> {
+        &self.standard_stock_quantity_min
+    }
+
+    pub fn standard_stock_quantity_max(&self) -> &Option<i64> {
+        &self.standard_stock_quantity_max
+    }
+
+    pub fn sort_key(&self) -> &Option<ProductSortKey> {
+        &self.sort_key
+    }
+
+    pub fn sort_direction(&self) -> &Option<SortDirection> {
+        &self.sort_direction
+    }
+
     pub fn limit(&self) -> &Option<i64> {
         &self.limit
     }
@@ -35,6 +80,10 @@ impl SearchProductRequest {
     pub fn offset(&self) -> &Option<i64> {
         &self.offset
     }
+
+    pub fn include_deleted(&self) -> &Option<bool> {
+        &self.include_deleted
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -44,6 +93,8 @@ pub struct CreateProductRequest {
     pub unit: String,
     pub default_price: i64,
     pub standard_stock_quantity: i64,
+    #[serde(default)]
+    pub tax_rate: TaxRate,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -54,6 +105,10 @@ pub struct UpdateProductRequest {
     pub unit: Option<String>,
     pub default_price: Option<i64>,
     pub standard_stock_quantity: Option<i64>,
+    pub tax_rate: Option<TaxRate>,
+    /// The `updated_at` of the product as shown to the user. An update made since is reported
+    /// as a conflict instead of being overwritten.
+    pub expected_updated_at: Option<PrimitiveDateTime>,
 }
 
 
@@ -65,4 +120,23 @@ impl DeleteProductRequest {
     pub fn product_id(&self) -> &i64 {
         &self.product_id
     }
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RestoreProductRequest {
+    product_id: ProductId,
+}
+impl RestoreProductRequest {
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ImportProductRequest {
+    /// A CSV file in UTF-8 or Shift_JIS whose first row names the columns.
+    pub path: String,
+    /// Only reports what the import would do.
+    #[serde(default)]
+    pub dry_run: bool,
+}
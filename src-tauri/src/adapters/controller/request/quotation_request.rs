@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    adapters::controller::request::sales_order_request::SalesOrderLineRequest,
+    domain::{
+        customer,
+        quotation::{QuotationDate, QuotationId, QuotationStatus},
+        sales_order::SalesOrderDate,
+    },
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct FindByIDQuotationRequest {
+    quotation_id: QuotationId,
+}
+impl FindByIDQuotationRequest {
+    pub fn quotation_id(&self) -> &QuotationId {
+        &self.quotation_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchQuotationRequest {
+    pub customer_id: Option<customer::Id>,
+    pub status: Option<QuotationStatus>,
+    pub quoted_on_from: Option<QuotationDate>,
+    pub quoted_on_to: Option<QuotationDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateQuotationRequest {
+    pub customer_id: customer::Id,
+    pub quoted_on: QuotationDate,
+    pub valid_until: QuotationDate,
+    pub lines: Vec<SalesOrderLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateQuotationRequest {
+    pub id: QuotationId,
+    pub customer_id: Option<customer::Id>,
+    pub quoted_on: Option<QuotationDate>,
+    pub valid_until: Option<QuotationDate>,
+    pub lines: Option<Vec<SalesOrderLineRequest>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChangeQuotationStatusRequest {
+    pub id: QuotationId,
+    pub status: QuotationStatus,
+    pub changed_on: QuotationDate,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConvertQuotationRequest {
+    pub id: QuotationId,
+    pub order_date: SalesOrderDate,
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    customer,
+    invoice::InvoiceId,
+    receivable::{BankFee, PaymentAmount, PaymentDate, PaymentId, PaymentMethod, PaymentNote},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct FindByIDPaymentRequest {
+    payment_id: PaymentId,
+}
+impl FindByIDPaymentRequest {
+    pub fn payment_id(&self) -> &PaymentId {
+        &self.payment_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchPaymentRequest {
+    pub customer_id: Option<customer::Id>,
+    pub paid_on_from: Option<PaymentDate>,
+    pub paid_on_to: Option<PaymentDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PaymentAllocationRequest {
+    pub invoice_id: InvoiceId,
+    pub amount: PaymentAmount,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RecordPaymentRequest {
+    pub customer_id: customer::Id,
+    pub paid_on: PaymentDate,
+    pub amount: PaymentAmount,
+    pub method: PaymentMethod,
+    #[serde(default)]
+    pub bank_fee: BankFee,
+    pub note: Option<PaymentNote>,
+    /// Omit to allocate to the oldest open invoices.
+    pub allocations: Option<Vec<PaymentAllocationRequest>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AllocatePaymentRequest {
+    pub payment_id: PaymentId,
+    pub allocations: Option<Vec<PaymentAllocationRequest>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchInvoiceBalanceRequest {
+    pub customer_id: Option<customer::Id>,
+    pub only_outstanding: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchCustomerBalanceRequest {
+    pub customer_id: Option<customer::Id>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchAgingReportRequest {
+    pub as_of: PaymentDate,
+    pub customer_id: Option<customer::Id>,
+}
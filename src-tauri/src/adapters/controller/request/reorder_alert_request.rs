@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::product::ProductId;
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchReorderAlertRequest {
+    product_id: Option<ProductId>,
+}
+impl SearchReorderAlertRequest {
+    pub fn product_id(&self) -> &Option<ProductId> {
+        &self.product_id
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    customer,
+    product::ProductId,
+    sales_order::{SalesOrderDate, SalesOrderId, SalesOrderStatus},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct FindByIDSalesOrderRequest {
+    sales_order_id: SalesOrderId,
+}
+impl FindByIDSalesOrderRequest {
+    pub fn sales_order_id(&self) -> &SalesOrderId {
+        &self.sales_order_id
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchSalesOrderRequest {
+    pub customer_id: Option<customer::Id>,
+    pub status: Option<SalesOrderStatus>,
+    pub order_date_from: Option<SalesOrderDate>,
+    pub order_date_to: Option<SalesOrderDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SalesOrderLineRequest {
+    pub product_id: ProductId,
+    pub quantity: i64,
+    pub unit_price: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateSalesOrderRequest {
+    pub customer_id: customer::Id,
+    pub order_date: SalesOrderDate,
+    pub lines: Vec<SalesOrderLineRequest>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateSalesOrderRequest {
+    pub id: SalesOrderId,
+    pub customer_id: Option<customer::Id>,
+    pub order_date: Option<SalesOrderDate>,
+    pub status: Option<SalesOrderStatus>,
+    pub lines: Option<Vec<SalesOrderLineRequest>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CancelSalesOrderRequest {
+    sales_order_id: SalesOrderId,
+}
+impl CancelSalesOrderRequest {
+    pub fn sales_order_id(&self) -> &SalesOrderId {
+        &self.sales_order_id
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    product::ProductId,
+    stock_movement::{StockMovementDate, StockMovementNote, StockMovementType},
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostStockMovementRequest {
+    pub product_id: ProductId,
+    pub movement_type: StockMovementType,
+    pub quantity: i64,
+    pub moved_on: StockMovementDate,
+    pub note: Option<StockMovementNote>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchStockMovementRequest {
+    pub product_id: Option<ProductId>,
+    pub movement_type: Option<StockMovementType>,
+    pub moved_on_from: Option<StockMovementDate>,
+    pub moved_on_to: Option<StockMovementDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FindOnHandStockRequest {
+    product_id: ProductId,
+}
+impl FindOnHandStockRequest {
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+}
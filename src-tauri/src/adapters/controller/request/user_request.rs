@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::user::{Role, UserName};
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateUserRequest {
+    pub name: UserName,
+    pub password: String,
+    pub role: Role,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SignInUserRequest {
+    pub name: UserName,
+    pub password: String,
+}
@@ -0,0 +1,86 @@
+use crate::adapters::controller::request::sales_order_request::{
+    CancelSalesOrderRequest, CreateSalesOrderRequest, FindByIDSalesOrderRequest,
+    SalesOrderLineRequest, SearchSalesOrderRequest, UpdateSalesOrderRequest,
+};
+use crate::application::usecase::sales_order::{
+    cancel_sales_order::{CancelSalesOrderOutput, CancelSalesOrderUsecase},
+    create_sales_order::{
+        CreateSalesOrderInput, CreateSalesOrderOutput, CreateSalesOrderUsecase, SalesOrderLineInput,
+    },
+    find_by_id_sales_order::{FindByIDSalesOrderOutput, FindByIDSalesOrderUsecase},
+    search_sales_order::{SearchSalesOrderInput, SearchSalesOrderOutput, SearchSalesOrderUsecase},
+    update_sales_order::{UpdateSalesOrderInput, UpdateSalesOrderOutput, UpdateSalesOrderUsecase},
+};
+use crate::domain::error::DomainError;
+
+fn to_line_inputs(lines: Vec<SalesOrderLineRequest>) -> Vec<SalesOrderLineInput> {
+    lines
+        .into_iter()
+        .map(|line| SalesOrderLineInput::new(line.product_id, line.quantity, line.unit_price))
+        .collect()
+}
+
+pub(crate) async fn find_by_id(
+    usecase: FindByIDSalesOrderUsecase,
+    request: FindByIDSalesOrderRequest,
+) -> Result<FindByIDSalesOrderOutput, DomainError> {
+    let output = usecase.find_by_id(request.sales_order_id()).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_sales_order(
+    usecase: SearchSalesOrderUsecase,
+    request: SearchSalesOrderRequest,
+) -> Result<SearchSalesOrderOutput, DomainError> {
+    let input = SearchSalesOrderInput::new(
+        request.offset,
+        request.limit,
+        request.customer_id,
+        request.status,
+        request.order_date_from,
+        request.order_date_to,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn create_sales_order(
+    usecase: CreateSalesOrderUsecase,
+    request: CreateSalesOrderRequest,
+) -> Result<CreateSalesOrderOutput, DomainError> {
+    let input = CreateSalesOrderInput::new(
+        request.customer_id,
+        request.order_date,
+        to_line_inputs(request.lines),
+    );
+    let output = usecase.create(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn update_sales_order(
+    usecase: UpdateSalesOrderUsecase,
+    request: UpdateSalesOrderRequest,
+) -> Result<UpdateSalesOrderOutput, DomainError> {
+    let input = UpdateSalesOrderInput::new(
+        request.id,
+        request.customer_id,
+        request.order_date,
+        request.status,
+        request.lines.map(to_line_inputs),
+    );
+    let output = usecase.update(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn cancel_sales_order(
+    usecase: CancelSalesOrderUsecase,
+    request: CancelSalesOrderRequest,
+) -> Result<CancelSalesOrderOutput, DomainError> {
+    let output = usecase.cancel(request.sales_order_id()).await?;
+
+    Ok(output)
+}
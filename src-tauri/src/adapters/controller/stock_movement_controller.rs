@@ -0,0 +1,53 @@
+use crate::adapters::controller::request::stock_movement_request::{
+    FindOnHandStockRequest, PostStockMovementRequest, SearchStockMovementRequest,
+};
+use crate::application::usecase::stock_movement::{
+    find_on_hand_stock::{FindOnHandStockOutput, FindOnHandStockUsecase},
+    post_stock_movement::{PostStockMovementInput, PostStockMovementOutput, PostStockMovementUsecase},
+    search_stock_movement::{
+        SearchStockMovementInput, SearchStockMovementOutput, SearchStockMovementUsecase,
+    },
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn post_stock_movement(
+    usecase: PostStockMovementUsecase,
+    request: PostStockMovementRequest,
+) -> Result<PostStockMovementOutput, DomainError> {
+    let input = PostStockMovementInput::new(
+        request.product_id,
+        request.movement_type,
+        request.quantity,
+        request.moved_on,
+        request.note,
+    );
+    let output = usecase.post(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn search_stock_movement(
+    usecase: SearchStockMovementUsecase,
+    request: SearchStockMovementRequest,
+) -> Result<SearchStockMovementOutput, DomainError> {
+    let input = SearchStockMovementInput::new(
+        request.offset,
+        request.limit,
+        request.product_id,
+        request.movement_type,
+        request.moved_on_from,
+        request.moved_on_to,
+    );
+    let output = usecase.search(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn find_on_hand_stock(
+    usecase: FindOnHandStockUsecase,
+    request: FindOnHandStockRequest,
+) -> Result<FindOnHandStockOutput, DomainError> {
+    let output = usecase.find_on_hand(request.product_id()).await?;
+
+    Ok(output)
+}
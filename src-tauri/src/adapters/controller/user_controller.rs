@@ -0,0 +1,35 @@
+use crate::adapters::controller::request::user_request::{CreateUserRequest, SignInUserRequest};
+use crate::application::usecase::user::{
+    create_user::{CreateUserInput, CreateUserOutput, CreateUserUsecase},
+    search_user::{SearchUserOutput, SearchUserUsecase},
+    sign_in_user::{SignInUserInput, SignInUserOutput, SignInUserUsecase},
+};
+use crate::domain::error::DomainError;
+
+pub(crate) async fn search_user(
+    usecase: SearchUserUsecase,
+) -> Result<SearchUserOutput, DomainError> {
+    let output = usecase.search().await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn create_user(
+    usecase: CreateUserUsecase,
+    request: CreateUserRequest,
+) -> Result<CreateUserOutput, DomainError> {
+    let input = CreateUserInput::new(request.name, request.password, request.role);
+    let output = usecase.create(input).await?;
+
+    Ok(output)
+}
+
+pub(crate) async fn sign_in_user(
+    usecase: SignInUserUsecase,
+    request: SignInUserRequest,
+) -> Result<SignInUserOutput, DomainError> {
+    let input = SignInUserInput::new(request.name, request.password);
+    let output = usecase.sign_in(input).await?;
+
+    Ok(output)
+}
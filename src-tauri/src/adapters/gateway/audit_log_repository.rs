@@ -0,0 +1,280 @@
+use crate::application::{
+    repository::audit_log_repository::AuditLogAbstructRepository,
+    usecase::audit_log::search_audit_log::SearchAuditLogInput,
+};
+use crate::domain::audit_log::{AuditEntity, AuditLog, AuditOperation};
+use crate::domain::error::DomainError;
+use async_trait::async_trait;
+use sqlx::{pool::PoolConnection, Sqlite, SqlitePool};
+use std::str::FromStr;
+use time::PrimitiveDateTime;
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct AuditLogRow {
+    id: i64,
+    entity_type: String,
+    entity_id: i64,
+    operation: String,
+    before_json: Option<String>,
+    after_json: Option<String>,
+    actor: Option<String>,
+    created_at: PrimitiveDateTime,
+}
+impl AuditLogRow {
+    fn into_audit_log(self) -> Result<AuditLog, DomainError> {
+        let parse = |json: Option<String>| {
+            json.map(|json| serde_json::from_str(&json))
+                .transpose()
+                .map_err(DomainError::unexpected)
+        };
+
+        Ok(AuditLog::new(
+            self.id,
+            AuditEntity::from_str(&self.entity_type).map_err(DomainError::unexpected)?,
+            self.entity_id,
+            AuditOperation::from_str(&self.operation).map_err(DomainError::unexpected)?,
+            parse(self.before_json)?,
+            parse(self.after_json)?,
+            self.actor,
+            self.created_at,
+        ))
+    }
+}
+
+/// The log itself is written by the triggers each connection creates, see
+/// `infrastructure::audit`.
+pub struct SqliteAuditLogRepository {
+    pool: SqlitePool,
+}
+impl SqliteAuditLogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditLogAbstructRepository for SqliteAuditLogRepository {
+    async fn search(&self, input: &SearchAuditLogInput) -> Result<Vec<AuditLog>, DomainError> {
+        let mut conn = self.pool.acquire().await?;
+        let audit_logs = AuditLogRepository::search(&mut conn, input).await?;
+
+        Ok(audit_logs)
+    }
+}
+
+struct AuditLogRepository {}
+impl AuditLogRepository {
+    async fn search(
+        conn: &mut PoolConnection<Sqlite>,
+        input: &SearchAuditLogInput,
+    ) -> Result<Vec<AuditLog>, DomainError> {
+        let rows: Vec<AuditLogRow> = sqlx::query_as(
+            "SELECT * FROM t_audit_logs
+                WHERE entity_type = COALESCE(?, entity_type)
+                AND entity_id = COALESCE(?, entity_id)
+                AND date(created_at) >= COALESCE(?, date(created_at))
+                AND date(created_at) <= COALESCE(?, date(created_at))
+                ORDER BY created_at DESC, id DESC
+                LIMIT ? OFFSET ?",
+        )
+        .bind(input.entity_type().map(|entity_type| entity_type.as_str()))
+        .bind(input.entity_id())
+        .bind(input.from())
+        .bind(input.to())
+        .bind(input.limit())
+        .bind(input.offset())
+        .fetch_all(conn)
+        .await?;
+
+        rows.into_iter().map(AuditLogRow::into_audit_log).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+
+    use crate::{
+        adapters::gateway::{
+            audit_log_repository::SqliteAuditLogRepository,
+            customer_repository::SqliteCustomerRespository, fixtures,
+            product_repository::SqliteProductRepository,
+        },
+        application::{
+            repository::{
+                audit_log_repository::AuditLogAbstructRepository,
+                customer_repository::CustomerAbstructRepository,
+                product_repository::ProductAbstructRepository,
+            },
+            usecase::{
+                audit_log::search_audit_log::SearchAuditLogInput,
+                product::update_product::UpdateProductInput,
+            },
+        },
+        domain::audit_log::{AuditEntity, AuditOperation},
+        infrastructure::{
+            audit::{audited, AuditActor},
+            database::MIGRATOR,
+        },
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool_options: SqlitePoolOptions, connect_options: SqliteConnectOptions) {
+        let actor = AuditActor::default();
+        let pool = audited(pool_options, actor.clone())
+            .connect_with(connect_options)
+            .await
+            .unwrap();
+        let repository = SqliteAuditLogRepository::new(pool.clone());
+        let product_repository = SqliteProductRepository::new(pool.clone());
+        let customer_repository = SqliteCustomerRespository::new(pool.clone());
+
+        let product_id = *product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap()
+            .product_id();
+        actor.set_user(Some(String::from("tanaka")));
+        product_repository
+            .update(&UpdateProductInput::new(
+                product_id,
+                None,
+                None,
+                None,
+                Some(2500),
+                None,
+                None,
+            ))
+            .await
+            .unwrap();
+        product_repository.delete(&product_id).await.unwrap();
+        customer_repository
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+
+        let input = SearchAuditLogInput::new(
+            None,
+            None,
+            Some(AuditEntity::Product),
+            Some(product_id),
+            None,
+            None,
+        );
+        let audit_logs = repository.search(&input).await.unwrap();
+
+        let operations = audit_logs
+            .iter()
+            .map(|audit_log| *audit_log.operation())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            operations,
+            vec![
+                AuditOperation::Delete,
+                AuditOperation::Update,
+                AuditOperation::Create
+            ]
+        );
+        let update = &audit_logs[1];
+        assert_eq!(update.before().as_ref().unwrap()["default_price"], 2000);
+        assert_eq!(update.after().as_ref().unwrap()["default_price"], 2500);
+        assert_eq!(update.actor().as_deref(), Some("tanaka"));
+        assert_eq!(audit_logs[2].actor(), &None);
+        assert!(audit_logs[2].before().is_none());
+
+        let today = time::OffsetDateTime::now_utc().date();
+        let input = SearchAuditLogInput::new(None, None, None, None, Some(today), Some(today));
+        assert_eq!(repository.search(&input).await.unwrap().len(), 4);
+        let yesterday = today.previous_day().unwrap();
+        let input =
+            SearchAuditLogInput::new(None, None, None, None, Some(yesterday), Some(yesterday));
+        assert!(repository.search(&input).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn transaction_audit_test(
+        pool_options: SqlitePoolOptions,
+        connect_options: SqliteConnectOptions,
+    ) {
+        let pool = audited(pool_options, AuditActor::default())
+            .connect_with(connect_options)
+            .await
+            .unwrap();
+        let repository = SqliteAuditLogRepository::new(pool.clone());
+        fixtures::create_customer(&pool).await;
+        fixtures::create_product(&pool, "product001").await;
+        for sql in [
+            "INSERT INTO t_sales_orders (customer_id, order_date, status) VALUES (1, '2023-07-01', 'ordered')",
+            "INSERT INTO t_sales_order_lines (sales_order_id, product_id, quantity, unit_price)
+                VALUES (1, 1, 2, 1000)",
+            "INSERT INTO t_invoices (
+                invoice_number, customer_id, issuer_registration_number, issuer_name,
+                issuer_address, recipient_name, period_from, period_to, issued_on,
+                rounding_mode, subtotal, tax_amount, total_amount
+            ) VALUES (
+                'INV-202307-0001', 1, 'T1234567890123', '自社', '東京都', 'sample.inc',
+                '2023-07-01', '2023-07-31', '2023-07-31', 'round_down', 2000, 200, 2200
+            )",
+            "INSERT INTO t_invoice_lines (
+                invoice_id, sales_order_id, sales_order_line_id, product_id, product_name,
+                quantity, unit_price, amount, tax_rate
+            ) VALUES (1, 1, 1, 1, '商品1', 2, 1000, 2000, 10)",
+            "INSERT INTO t_payments (customer_id, paid_on, amount, method)
+                VALUES (1, '2023-08-31', 2200, 'bank_transfer')",
+            "INSERT INTO t_payment_allocations (payment_id, invoice_id, amount) VALUES (1, 1, 2200)",
+            "DELETE FROM t_payment_allocations",
+            "INSERT INTO t_quotations (customer_id, quoted_on, valid_until)
+                VALUES (1, '2023-06-01', '2023-06-30')",
+            "INSERT INTO t_quotation_lines (quotation_id, product_id, quantity, unit_price)
+                VALUES (1, 1, 2, 1000)",
+            "UPDATE t_quotations SET status = 'sent'",
+            "INSERT INTO t_stock_movements (product_id, movement_type, quantity, moved_on)
+                VALUES (1, 'shipment', -2, '2023-07-01')",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+
+        let operations = |entity| {
+            let repository = &repository;
+            async move {
+                let input = SearchAuditLogInput::new(None, None, Some(entity), None, None, None);
+                repository
+                    .search(&input)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|audit_log| *audit_log.operation())
+                    .collect::<Vec<_>>()
+            }
+        };
+        for entity in [
+            AuditEntity::Invoice,
+            AuditEntity::InvoiceLine,
+            AuditEntity::Payment,
+            AuditEntity::QuotationLine,
+            AuditEntity::StockMovement,
+        ] {
+            assert_eq!(operations(entity).await, vec![AuditOperation::Create]);
+        }
+        assert_eq!(
+            operations(AuditEntity::PaymentAllocation).await,
+            vec![AuditOperation::Delete, AuditOperation::Create]
+        );
+        assert_eq!(
+            operations(AuditEntity::Quotation).await,
+            vec![AuditOperation::Update, AuditOperation::Create]
+        );
+
+        let input = SearchAuditLogInput::new(
+            None,
+            None,
+            Some(AuditEntity::Quotation),
+            Some(1),
+            None,
+            None,
+        );
+        let update = &repository.search(&input).await.unwrap()[0];
+        assert_eq!(update.before().as_ref().unwrap()["status"], "draft");
+        assert_eq!(update.after().as_ref().unwrap()["status"], "sent");
+    }
+}
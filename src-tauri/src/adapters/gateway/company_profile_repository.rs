@@ -0,0 +1,92 @@
+use crate::application::{
+    repository::company_profile_repository::CompanyProfileAbstructRepository,
+    usecase::company_profile::save_company_profile::SaveCompanyProfileInput,
+};
+use crate::domain::company_profile::CompanyProfile;
+use async_trait::async_trait;
+use sqlx::{SqliteConnection, SqlitePool};
+use crate::adapters::gateway::{connection::SqliteConnector, CURRENT_TIMESTAMP_MS};
+use crate::domain::error::DomainError;
+use time::PrimitiveDateTime;
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct CompanyProfileRow {
+    registration_number: String,
+    name: String,
+    address: String,
+    updated_at: PrimitiveDateTime,
+}
+
+pub struct SqliteCompanyProfileRepository {
+    connector: SqliteConnector,
+}
+impl SqliteCompanyProfileRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl CompanyProfileAbstructRepository for SqliteCompanyProfileRepository {
+    async fn find(&self) -> Result<Option<CompanyProfile>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let company_profile = CompanyProfileRepository::find(&mut conn).await?;
+
+        Ok(company_profile)
+    }
+
+    async fn save(&self, input: &SaveCompanyProfileInput) -> Result<(), DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        CompanyProfileRepository::save(&mut conn, input).await?;
+
+        Ok(())
+    }
+}
+
+struct CompanyProfileRepository {}
+impl CompanyProfileRepository {
+    async fn find(conn: &mut SqliteConnection) -> Result<Option<CompanyProfile>, DomainError> {
+        let row = sqlx::query_as::<_, CompanyProfileRow>(
+            "SELECT registration_number, name, address, updated_at FROM m_company_profile WHERE id = 1",
+        )
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(row.map(|row| {
+            CompanyProfile::new(
+                row.registration_number,
+                row.name,
+                row.address,
+                row.updated_at,
+            )
+        }))
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        input: &SaveCompanyProfileInput,
+    ) -> Result<(), DomainError> {
+        sqlx::query(&format!(
+            "INSERT INTO m_company_profile (id, registration_number, name, address) VALUES (1, ?, ?, ?)
+                ON CONFLICT (id) DO UPDATE SET
+                    registration_number = excluded.registration_number,
+                    name = excluded.name,
+                    address = excluded.address,
+                    updated_at = {}",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(input.registration_number())
+        .bind(input.name())
+        .bind(input.address())
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+use async_std::sync::{Mutex, MutexGuard};
+use sqlx::{pool::PoolConnection, Sqlite, SqliteConnection, SqlitePool, Transaction};
+
+use crate::domain::error::DomainError;
+
+/// The transaction of a unit of work, shared by the repositories taken from it. It is taken
+/// out when the unit of work commits or is dropped.
+pub(crate) type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Sqlite>>>>;
+
+/// Where a gateway takes its connection from: the pool, or the transaction of the unit of
+/// work it belongs to. Transactions a gateway begins itself become savepoints in the latter.
+#[derive(Clone)]
+pub enum SqliteConnector {
+    Pool(SqlitePool),
+    Transaction(SharedTransaction),
+}
+impl SqliteConnector {
+    pub async fn acquire(&self) -> Result<SqliteConnectionGuard<'_>, DomainError> {
+        match self {
+            SqliteConnector::Pool(pool) => Ok(SqliteConnectionGuard::Pool(pool.acquire().await?)),
+            SqliteConnector::Transaction(transaction) => {
+                let transaction = transaction.lock().await;
+                if transaction.is_none() {
+                    return Err(DomainError::unexpected(
+                        "the unit of work is already finished",
+                    ));
+                }
+
+                Ok(SqliteConnectionGuard::Transaction(transaction))
+            }
+        }
+    }
+}
+impl From<SqlitePool> for SqliteConnector {
+    fn from(pool: SqlitePool) -> Self {
+        SqliteConnector::Pool(pool)
+    }
+}
+
+/// A connection of the pool, or the connection of a unit of work locked for one repository
+/// operation.
+pub enum SqliteConnectionGuard<'a> {
+    Pool(PoolConnection<Sqlite>),
+    Transaction(MutexGuard<'a, Option<Transaction<'static, Sqlite>>>),
+}
+impl Deref for SqliteConnectionGuard<'_> {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &SqliteConnection {
+        match self {
+            SqliteConnectionGuard::Pool(conn) => conn,
+            // `acquire` checked that the transaction is there.
+            SqliteConnectionGuard::Transaction(transaction) => transaction.as_ref().unwrap(),
+        }
+    }
+}
+impl DerefMut for SqliteConnectionGuard<'_> {
+    fn deref_mut(&mut self) -> &mut SqliteConnection {
+        match self {
+            SqliteConnectionGuard::Pool(conn) => conn,
+            SqliteConnectionGuard::Transaction(transaction) => transaction.as_mut().unwrap(),
+        }
+    }
+}
@@ -1,14 +1,30 @@
-use std::error::Error;
-use sqlx::{pool::PoolConnection, Sqlite, SqlitePool, sqlite::SqliteQueryResult, Execute};
+use crate::adapters::gateway::connection::SqliteConnector;
+use sqlx::{Connection, Sqlite, SqliteConnection, SqlitePool, sqlite::SqliteQueryResult};
 use time::PrimitiveDateTime;
 
-use crate::{application::{repository::customer_repository::{CustomerAbstructRepository, CreateCustomerResult, UpdateCustomerResult}, usecase::{customer::{create_customer::CreateCustomerInput, update_customer::UpdateCustomerInput}}}, domain::customer::{Customer, Id}};
+use crate::adapters::gateway::{escape_like, push_expected_updated_at, CURRENT_TIMESTAMP_MS};
+use crate::{
+    application::{
+        repository::customer_repository::{
+            CreateCustomerResult, CustomerAbstructRepository, DeleteCustomerResult,
+            RestoreCustomerResult, UpdateCustomerResult,
+        },
+        usecase::customer::{
+            create_customer::CreateCustomerInput, search_customer::SearchCustomerInput,
+            update_customer::UpdateCustomerInput,
+        },
+    },
+    domain::{
+        customer::{Customer, Id},
+        error::DomainError,
+    },
+};
 
 #[derive(sqlx::FromRow)]
 pub struct CustomerRow {
     id: i64,
     name: String,
-    postal: i64,
+    postal: String,
     address: String,
     created_at: PrimitiveDateTime,
     updated_at: PrimitiveDateTime,
@@ -16,48 +32,87 @@ pub struct CustomerRow {
 }
 
 pub struct SqliteCustomerRespository {
-    pool: SqlitePool,
+    connector: SqliteConnector,
 }
 impl SqliteCustomerRespository {
     pub fn new (pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
     }
 }
 impl From<SqlitePool> for SqliteCustomerRespository {
     fn from(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self::new(pool)
     }
 }
 
 #[async_trait::async_trait]
 impl CustomerAbstructRepository for SqliteCustomerRespository {
 
-    async fn find_by_id(&self, id: &Id) -> Result<Option<Customer>, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
-        let cusotmer = CustomerRepository::find_by_id(&mut conn, id).await?;
+    async fn find_by_id(&self, id: &Id) -> Result<Option<Customer>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let cusotmer = CustomerRepository::find_by_id(&mut conn, id, false).await?;
         
         Ok(cusotmer)
     }
 
-    async fn create(&self, input: CreateCustomerInput) -> Result<CreateCustomerResult, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
+    async fn find_by_id_including_deleted(&self, id: &Id) -> Result<Option<Customer>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let customer = CustomerRepository::find_by_id(&mut conn, id, true).await?;
+
+        Ok(customer)
+    }
+
+    async fn search(&self, input: &SearchCustomerInput) -> Result<Vec<Customer>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let customers = CustomerRepository::search(&mut conn, input).await?;
+
+        Ok(customers)
+    }
+
+    async fn create(&self, input: CreateCustomerInput) -> Result<CreateCustomerResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
         let result = CustomerRepository::create(&mut conn, input).await?;
 
         Ok(CreateCustomerResult::from(result.last_insert_rowid()))
     }
 
-    async fn update(&self, input: UpdateCustomerInput) -> Result<UpdateCustomerResult, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
-        CustomerRepository::update(&mut conn, &input).await?;
+    async fn update(&self, input: UpdateCustomerInput) -> Result<UpdateCustomerResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        CustomerRepository::update(&mut tx, &input).await?;
+        tx.commit().await?;
 
         Ok(UpdateCustomerResult::from(input.id()))
     }
+
+    async fn delete(&self, id: &Id) -> Result<DeleteCustomerResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = CustomerRepository::delete(&mut conn, id).await?;
+
+        Ok(DeleteCustomerResult::from(result.rows_affected() > 0))
+    }
+
+    async fn restore(&self, id: &Id) -> Result<RestoreCustomerResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = CustomerRepository::restore(&mut conn, id).await?;
+
+        Ok(RestoreCustomerResult::from(result.rows_affected() > 0))
+    }
 }
 
 pub struct CustomerRepository {}
 impl CustomerRepository {
-    async fn find_by_id(conn: &mut PoolConnection<Sqlite>, id: &Id) -> Result<Option<Customer>, Box<dyn Error>> {
-        let result = sqlx::query_as::<Sqlite, CustomerRow>("SELECT * FROM m_customers WHERE id = ?").bind(id).fetch_optional(conn).await?;
+    async fn find_by_id(conn: &mut SqliteConnection, id: &Id, include_deleted: bool) -> Result<Option<Customer>, DomainError> {
+        let result = sqlx::query_as::<Sqlite, CustomerRow>("SELECT * FROM m_customers WHERE id = ? AND (? OR deleted_at IS NULL)")
+            .bind(id)
+            .bind(include_deleted)
+            .fetch_optional(conn).await?;
 
         match result {
             Some(row) => {
@@ -67,7 +122,36 @@ impl CustomerRepository {
         }
     }
 
-    async fn create(conn: &mut PoolConnection<Sqlite>, input: CreateCustomerInput) -> Result<SqliteQueryResult, Box<dyn Error>> {
+    async fn search(conn: &mut SqliteConnection, input: &SearchCustomerInput) -> Result<Vec<Customer>, DomainError> {
+        let rows = sqlx::query_as::<Sqlite, CustomerRow>(
+            "SELECT * FROM m_customers
+                WHERE (? IS NULL OR name LIKE '%' || ? || '%' ESCAPE '\\')
+                AND (? IS NULL OR address LIKE '%' || ? || '%' ESCAPE '\\')
+                AND postal = COALESCE(?, postal)
+                AND (? OR deleted_at IS NULL)
+                AND (? IS NULL OR id > ?)
+                ORDER BY id
+                LIMIT ? OFFSET ?",
+        )
+            .bind(input.name())
+            .bind(input.name().as_deref().map(escape_like))
+            .bind(input.address())
+            .bind(input.address().as_deref().map(escape_like))
+            .bind(input.postal())
+            .bind(input.include_deleted())
+            .bind(input.after_id())
+            .bind(input.after_id())
+            .bind(input.limit())
+            .bind(input.offset())
+            .fetch_all(conn).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Customer::new(row.id, row.name, row.postal, row.address, row.created_at, row.updated_at, row.deleted_at))
+            .collect())
+    }
+
+    async fn create(conn: &mut SqliteConnection, input: CreateCustomerInput) -> Result<SqliteQueryResult, DomainError> {
         let result = sqlx::query("INSERT INTO m_customers (name, postal, address) VALUES (?, ?, ?)")
             .bind(input.name())
             .bind(input.postal())
@@ -77,10 +161,12 @@ impl CustomerRepository {
         Ok(result)
     }
 
-    async fn update(conn: &mut PoolConnection<Sqlite>, input: &UpdateCustomerInput) -> Result<Option<SqliteQueryResult>, Box<dyn Error>> {
-        let mut pre_query_builder = sqlx::query_builder::QueryBuilder::<Sqlite>::new("UPDATE m_customers SET");
+    /// Fails with a conflict carrying the stored customer when it was updated after
+    /// `input.expected_updated_at()`, which the update itself checks.
+    async fn update(conn: &mut SqliteConnection, input: &UpdateCustomerInput) -> Result<SqliteQueryResult, DomainError> {
+        let mut query_builder = sqlx::query_builder::QueryBuilder::<Sqlite>::new("UPDATE m_customers SET ");
 
-        let mut separated = pre_query_builder.separated(",");
+        let mut separated = query_builder.separated(", ");
         if let Some(name) = input.name() {
             separated.push("name = ");
             separated.push_bind_unseparated(name);
@@ -95,20 +181,42 @@ impl CustomerRepository {
             separated.push("address = ");
             separated.push_bind_unseparated(address);
         }
+        separated.push(format!("updated_at = {}", CURRENT_TIMESTAMP_MS));
 
-        let query_without_where = pre_query_builder.build();
-        if query_without_where.sql().ends_with("= ?") {
-            return Ok(None);
-        }
-
-        let mut query_builder = sqlx::query_builder::QueryBuilder::<Sqlite>::new(query_without_where.sql());
         query_builder.push(" WHERE id = ");
         query_builder.push_bind(input.id());
+        query_builder.push(" AND deleted_at IS NULL");
+        if let Some(expected_updated_at) = input.expected_updated_at() {
+            push_expected_updated_at(&mut query_builder, expected_updated_at);
+        }
+        let result = query_builder.build().execute(&mut *conn).await?;
+        if result.rows_affected() > 0 {
+            return Ok(result);
+        }
 
-        let query = query_builder.build();
-        let result = sqlx::query(query.sql()).execute(conn).await?;
+        match Self::find_by_id(&mut *conn, &input.id(), false).await? {
+            Some(current) => Err(DomainError::conflict_with(
+                format!("customer was updated by someone else: id={}", input.id()),
+                &current,
+            )),
+            None => Err(DomainError::not_found("customer", input.id())),
+        }
+    }
+
+    async fn delete(conn: &mut SqliteConnection, id: &Id) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!("UPDATE m_customers SET deleted_at = {0}, updated_at = {0} WHERE id = ? AND deleted_at IS NULL", CURRENT_TIMESTAMP_MS))
+            .bind(id)
+            .execute(conn).await?;
 
-        Ok(Some(result))
+        Ok(result)
+    }
+
+    async fn restore(conn: &mut SqliteConnection, id: &Id) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!("UPDATE m_customers SET deleted_at = NULL, updated_at = {} WHERE id = ? AND deleted_at IS NOT NULL", CURRENT_TIMESTAMP_MS))
+            .bind(id)
+            .execute(conn).await?;
+
+        Ok(result)
     }
 }
 
@@ -117,12 +225,12 @@ mod tests {
 
     use sqlx::SqlitePool;
 
-    use crate::{infrastructure::database::MIGRATOR, adapters::gateway::customer_repository::SqliteCustomerRespository, application::{repository::customer_repository::CustomerAbstructRepository, usecase::customer::create_customer::CreateCustomerInput}};
+    use crate::{infrastructure::database::MIGRATOR, adapters::gateway::{fixtures, customer_repository::SqliteCustomerRespository}, application::{repository::customer_repository::CustomerAbstructRepository, usecase::customer::{create_customer::CreateCustomerInput, search_customer::SearchCustomerInput, update_customer::UpdateCustomerInput}}};
 
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn find_by_id_test(pool: SqlitePool)  {
         let repository = SqliteCustomerRespository::from(pool);
-        let input = CreateCustomerInput::new(String::from("sample.inc"), 1234567, String::from("東京都"));
+        let input = fixtures::customer_input();
         let result = repository.create(input).await.unwrap();
 
         assert_eq!(result.customer_id(), 1);
@@ -131,10 +239,78 @@ mod tests {
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn create_test(pool: SqlitePool)  {
         let repository = SqliteCustomerRespository::from(pool);
-        let input = CreateCustomerInput::new(String::from("sample.inc"), 1234567, String::from("東京都"));
+        let input = fixtures::customer_input();
         let result = repository.create(input).await.unwrap();
         let product = repository.find_by_id(&result.customer_id()).await.unwrap();
 
-        assert_eq!(product.is_some(), true);
+        assert!(product.is_some());
     }
-}   
\ No newline at end of file
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn update_test(pool: SqlitePool)  {
+        let repository = SqliteCustomerRespository::from(pool.clone());
+        let input = fixtures::customer_input();
+        let customer_id = repository.create(input).await.unwrap().customer_id();
+        sqlx::query("UPDATE m_customers SET updated_at = '2023-07-01 09:00:00'").execute(&pool).await.unwrap();
+        let read = repository.find_by_id(&customer_id).await.unwrap().unwrap();
+
+        let input = UpdateCustomerInput::new(customer_id, None, Some(String::from("0600000")), None, Some(*read.updated_at()));
+        repository.update(input).await.unwrap();
+        let customer = repository.find_by_id(&customer_id).await.unwrap().unwrap();
+        assert_eq!(customer.postal(), "0600000");
+        assert_eq!(customer.name(), "sample.inc");
+        assert!(customer.updated_at() > read.updated_at());
+
+        let input = UpdateCustomerInput::new(customer_id, Some(String::from("stale.inc")), None, None, Some(*read.updated_at()));
+        let error = repository.update(input).await.err().unwrap();
+        assert_eq!(error.code(), "conflict");
+        let customer = repository.find_by_id(&customer_id).await.unwrap().unwrap();
+        assert_eq!(customer.name(), "sample.inc");
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn delete_and_restore_test(pool: SqlitePool)  {
+        let repository = SqliteCustomerRespository::from(pool.clone());
+        let backdate = || async {
+            sqlx::query("UPDATE m_customers SET created_at = '2023-07-01 09:00:00', updated_at = created_at").execute(&pool).await.unwrap();
+        };
+        let input = fixtures::customer_input();
+        let customer_id = repository.create(input).await.unwrap().customer_id();
+        backdate().await;
+        assert!(repository.delete(&customer_id).await.unwrap().result());
+        assert!(!repository.delete(&customer_id).await.unwrap().result());
+
+        assert!(repository.find_by_id(&customer_id).await.unwrap().is_none());
+        let customer = repository.find_by_id_including_deleted(&customer_id).await.unwrap().unwrap();
+        assert!(customer.updated_at() > customer.created_at());
+        backdate().await;
+        assert!(repository.restore(&customer_id).await.unwrap().result());
+        assert!(!repository.restore(&customer_id).await.unwrap().result());
+        let customer = repository.find_by_id(&customer_id).await.unwrap().unwrap();
+        assert!(customer.updated_at() > customer.created_at());
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool: SqlitePool)  {
+        let repository = SqliteCustomerRespository::from(pool);
+        repository.create(CreateCustomerInput::new(String::from("株式会社サンプル"), String::from("1234567"), String::from("東京都千代田区"))).await.unwrap();
+        repository.create(CreateCustomerInput::new(String::from("サンプル商事"), String::from("5300001"), String::from("大阪府大阪市"))).await.unwrap();
+
+        let input = SearchCustomerInput::new(None, None, Some(String::from("サンプル")), None, None, None);
+        assert_eq!(repository.search(&input).await.unwrap().len(), 2);
+        let input = SearchCustomerInput::new(None, None, None, Some(String::from("大阪")), None, None);
+        assert_eq!(repository.search(&input).await.unwrap().len(), 1);
+        let input = SearchCustomerInput::new(None, None, None, None, Some(String::from("1234567")), None);
+        let customers = repository.search(&input).await.unwrap();
+        assert_eq!(customers.len(), 1);
+        assert_eq!(customers[0].name(), "株式会社サンプル");
+        let input = SearchCustomerInput::new(Some(1), Some(1), None, None, None, None);
+        assert_eq!(repository.search(&input).await.unwrap().len(), 1);
+
+        repository.create(CreateCustomerInput::new(String::from("100%_商事"), String::from("1000001"), String::from("東京都港区"))).await.unwrap();
+        let input = SearchCustomerInput::new(None, None, Some(String::from("%")), None, None, None);
+        assert_eq!(repository.search(&input).await.unwrap().len(), 1);
+        let input = SearchCustomerInput::new(None, None, Some(String::from("_")), None, None, None);
+        assert_eq!(repository.search(&input).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::domain::error::DomainError;
+
+/// SQLite result codes that mean the database cannot be used right now, as opposed to the
+/// statement being wrong. See https://www.sqlite.org/rescode.html.
+const SQLITE_BUSY: &str = "5";
+const SQLITE_LOCKED: &str = "6";
+const SQLITE_READONLY: &str = "8";
+const SQLITE_CANTOPEN: &str = "14";
+const SQLITE_CONSTRAINT_FOREIGNKEY: &str = "787";
+const SQLITE_CONSTRAINT_PRIMARYKEY: &str = "1555";
+const SQLITE_CONSTRAINT_UNIQUE: &str = "2067";
+
+/// Whether `error` is a clash on a unique index, so that the caller can look up the record
+/// it clashed with.
+pub(crate) fn is_unique_violation(error: &sqlx::Error) -> bool {
+    matches!(
+        error
+            .as_database_error()
+            .and_then(|database_error| database_error.code())
+            .as_deref(),
+        Some(SQLITE_CONSTRAINT_UNIQUE)
+    )
+}
+
+impl From<sqlx::Error> for DomainError {
+    fn from(error: sqlx::Error) -> Self {
+        match &error {
+            sqlx::Error::Database(database_error) => match database_error.code().as_deref() {
+                Some(SQLITE_BUSY | SQLITE_LOCKED | SQLITE_READONLY | SQLITE_CANTOPEN) => {
+                    DomainError::database_unavailable(database_error.message())
+                }
+                Some(SQLITE_CONSTRAINT_PRIMARYKEY | SQLITE_CONSTRAINT_UNIQUE) => {
+                    DomainError::conflict("the record clashes with an existing one")
+                }
+                Some(SQLITE_CONSTRAINT_FOREIGNKEY) => {
+                    DomainError::conflict("the record is referenced by or refers to missing data")
+                }
+                _ => DomainError::unexpected(error),
+            },
+            sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::Configuration(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed => DomainError::database_unavailable(error),
+            _ => DomainError::unexpected(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use crate::{domain::error::DomainError, infrastructure::database::MIGRATOR};
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn from_sqlx_error_test(pool: SqlitePool) {
+        let error = sqlx::query("SELECT * FROM m_unknown")
+            .execute(&pool)
+            .await
+            .unwrap_err();
+        assert_eq!(DomainError::from(error).code(), "unexpected");
+
+        pool.close().await;
+        let error = sqlx::query("SELECT 1").execute(&pool).await.unwrap_err();
+        assert_eq!(DomainError::from(error).code(), "database_unavailable");
+    }
+}
@@ -0,0 +1,57 @@
+//! Records the tests start from, so that each test states only what differs.
+
+use sqlx::SqlitePool;
+
+use crate::{
+    adapters::gateway::{
+        customer_repository::SqliteCustomerRespository, product_repository::SqliteProductRepository,
+    },
+    application::{
+        repository::{
+            customer_repository::CustomerAbstructRepository,
+            product_repository::ProductAbstructRepository,
+        },
+        usecase::{
+            customer::create_customer::CreateCustomerInput,
+            product::create_product::CreateProductInput,
+        },
+    },
+    domain::{customer, product::ProductId, tax::TaxRate},
+};
+
+/// The customer `sample.inc` in Tokyo.
+pub(crate) fn customer_input() -> CreateCustomerInput {
+    CreateCustomerInput::new(
+        String::from("sample.inc"),
+        String::from("1234567"),
+        String::from("東京都"),
+    )
+}
+
+/// The product `商品1` sold by the piece at 2000 yen under the standard tax rate.
+pub(crate) fn product_input(code: &str) -> CreateProductInput {
+    CreateProductInput::new(
+        String::from("商品1"),
+        String::from(code),
+        String::from("個"),
+        2000,
+        10,
+        TaxRate::Standard,
+    )
+}
+
+pub(crate) async fn create_customer(pool: &SqlitePool) -> customer::Id {
+    SqliteCustomerRespository::new(pool.clone())
+        .create(customer_input())
+        .await
+        .unwrap()
+        .customer_id()
+}
+
+pub(crate) async fn create_product(pool: &SqlitePool, code: &str) -> ProductId {
+    *SqliteProductRepository::new(pool.clone())
+        .create(&product_input(code))
+        .await
+        .unwrap()
+        .product_id()
+}
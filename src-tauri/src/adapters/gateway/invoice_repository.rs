@@ -0,0 +1,594 @@
+use crate::application::{
+    repository::invoice_repository::{InvoiceAbstructRepository, IssueInvoiceResult},
+    usecase::invoice::search_invoice::SearchInvoiceInput,
+};
+use crate::domain::{
+    customer,
+    invoice::{
+        invoice_number, invoice_number_prefix, BillableLine, Invoice, InvoiceDate, InvoiceDraft,
+        InvoiceId, InvoiceIssuer, InvoiceLine, TaxSummary,
+    },
+    sales_order::SalesOrderStatus,
+    tax::{RoundingMode, TaxRate},
+};
+use async_trait::async_trait;
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+use crate::adapters::gateway::connection::SqliteConnector;
+use crate::domain::error::DomainError;
+use std::str::FromStr;
+use time::{Date, PrimitiveDateTime};
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct InvoiceRow {
+    id: i64,
+    invoice_number: String,
+    issuer_registration_number: String,
+    issuer_name: String,
+    issuer_address: String,
+    customer_id: i64,
+    recipient_name: String,
+    period_from: Date,
+    period_to: Date,
+    issued_on: Date,
+    rounding_mode: String,
+    subtotal: i64,
+    tax_amount: i64,
+    total_amount: i64,
+    created_at: PrimitiveDateTime,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct InvoiceLineRow {
+    sales_order_id: i64,
+    sales_order_line_id: i64,
+    product_id: i64,
+    product_name: String,
+    quantity: i64,
+    unit_price: i64,
+    amount: i64,
+    tax_rate: i64,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct TaxSummaryRow {
+    tax_rate: i64,
+    taxable_amount: i64,
+    tax_amount: i64,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct BillableLineRow {
+    sales_order_id: i64,
+    sales_order_line_id: i64,
+    product_id: i64,
+    product_name: String,
+    quantity: i64,
+    unit_price: i64,
+    tax_rate: i64,
+}
+
+pub struct SqliteInvoiceRepository {
+    connector: SqliteConnector,
+}
+impl SqliteInvoiceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl InvoiceAbstructRepository for SqliteInvoiceRepository {
+    async fn find_by_id(&self, id: &InvoiceId) -> Result<Option<Invoice>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let invoice = InvoiceRepository::find_by_id(&mut conn, id).await?;
+
+        Ok(invoice)
+    }
+
+    async fn search(&self, input: &SearchInvoiceInput) -> Result<Vec<Invoice>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let invoices = InvoiceRepository::search(&mut conn, input).await?;
+
+        Ok(invoices)
+    }
+
+    async fn search_billable_lines(
+        &self,
+        customer_id: &customer::Id,
+        period_from: &InvoiceDate,
+        period_to: &InvoiceDate,
+    ) -> Result<Vec<BillableLine>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let lines = InvoiceRepository::search_billable_lines(
+            &mut conn,
+            customer_id,
+            period_from,
+            period_to,
+        )
+        .await?;
+
+        Ok(lines)
+    }
+
+    async fn create(&self, draft: &InvoiceDraft) -> Result<IssueInvoiceResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let invoice_number =
+            InvoiceRepository::next_invoice_number(&mut tx, draft.issued_on()).await?;
+        let invoice_id = InvoiceRepository::create(&mut tx, &invoice_number, draft).await?;
+        InvoiceRepository::insert_lines(&mut tx, &invoice_id, draft.lines()).await?;
+        InvoiceRepository::insert_tax_summaries(&mut tx, &invoice_id, draft.tax_summaries())
+            .await?;
+        tx.commit().await?;
+
+        Ok(IssueInvoiceResult::new(invoice_id, invoice_number))
+    }
+}
+
+struct InvoiceRepository {}
+impl InvoiceRepository {
+    async fn find_by_id(
+        conn: &mut SqliteConnection,
+        id: &InvoiceId,
+    ) -> Result<Option<Invoice>, DomainError> {
+        let row = sqlx::query_as::<_, InvoiceRow>("SELECT * FROM t_invoices WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        match row {
+            None => Ok(None),
+            Some(row) => Ok(Some(Self::to_invoice(conn, row).await?)),
+        }
+    }
+
+    async fn search(
+        conn: &mut SqliteConnection,
+        input: &SearchInvoiceInput,
+    ) -> Result<Vec<Invoice>, DomainError> {
+        let order = match input.after_id() {
+            Some(_) => "id",
+            None => "issued_on DESC, id DESC",
+        };
+        let sql = format!(
+            "SELECT * FROM t_invoices
+                WHERE customer_id = COALESCE(?, customer_id)
+                AND issued_on >= COALESCE(?, issued_on)
+                AND issued_on <= COALESCE(?, issued_on)
+                AND (? IS NULL OR id > ?)
+                ORDER BY {}
+                LIMIT ? OFFSET ?",
+            order
+        );
+        let rows: Vec<InvoiceRow> = sqlx::query_as(&sql)
+            .bind(input.customer_id())
+            .bind(input.issued_on_from())
+            .bind(input.issued_on_to())
+            .bind(input.after_id())
+            .bind(input.after_id())
+            .bind(input.limit())
+            .bind(input.offset())
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut invoices = Vec::with_capacity(rows.len());
+        for row in rows {
+            invoices.push(Self::to_invoice(conn, row).await?);
+        }
+
+        Ok(invoices)
+    }
+
+    async fn search_billable_lines(
+        conn: &mut SqliteConnection,
+        customer_id: &customer::Id,
+        period_from: &InvoiceDate,
+        period_to: &InvoiceDate,
+    ) -> Result<Vec<BillableLine>, DomainError> {
+        let rows: Vec<BillableLineRow> = sqlx::query_as(
+            "SELECT
+                    l.sales_order_id,
+                    l.id AS sales_order_line_id,
+                    l.product_id,
+                    p.name AS product_name,
+                    l.quantity,
+                    l.unit_price,
+                    p.tax_rate
+                FROM t_sales_order_lines l
+                INNER JOIN t_sales_orders o ON o.id = l.sales_order_id
+                INNER JOIN m_products p ON p.id = l.product_id
+                WHERE o.customer_id = ?
+                AND o.status = ?
+                AND o.order_date >= ?
+                AND o.order_date <= ?
+                AND NOT EXISTS (SELECT 1 FROM t_invoice_lines i WHERE i.sales_order_line_id = l.id)
+                ORDER BY o.order_date, l.sales_order_id, l.id",
+        )
+        .bind(customer_id)
+        .bind(SalesOrderStatus::Delivered.as_str())
+        .bind(period_from)
+        .bind(period_to)
+        .fetch_all(conn)
+        .await?;
+
+        let mut lines = Vec::with_capacity(rows.len());
+        for row in rows {
+            lines.push(BillableLine::new(
+                row.sales_order_id,
+                row.sales_order_line_id,
+                row.product_id,
+                row.product_name,
+                row.quantity,
+                row.unit_price,
+                TaxRate::try_from(row.tax_rate).map_err(DomainError::unexpected)?,
+            ));
+        }
+
+        Ok(lines)
+    }
+
+    async fn next_invoice_number(
+        conn: &mut SqliteConnection,
+        issued_on: &InvoiceDate,
+    ) -> Result<String, DomainError> {
+        let prefix = invoice_number_prefix(issued_on);
+        let (issued_count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM t_invoices WHERE invoice_number LIKE ? || '%'")
+                .bind(&prefix)
+                .fetch_one(conn)
+                .await?;
+
+        Ok(invoice_number(issued_on, issued_count + 1))
+    }
+
+    async fn create(
+        conn: &mut SqliteConnection,
+        invoice_number: &str,
+        draft: &InvoiceDraft,
+    ) -> Result<InvoiceId, DomainError> {
+        let result = sqlx::query(
+            "INSERT INTO t_invoices (
+                invoice_number,
+                issuer_registration_number,
+                issuer_name,
+                issuer_address,
+                customer_id,
+                recipient_name,
+                period_from,
+                period_to,
+                issued_on,
+                rounding_mode,
+                subtotal,
+                tax_amount,
+                total_amount
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(invoice_number)
+        .bind(draft.issuer().registration_number())
+        .bind(draft.issuer().name())
+        .bind(draft.issuer().address())
+        .bind(draft.customer_id())
+        .bind(draft.recipient_name())
+        .bind(draft.period_from())
+        .bind(draft.period_to())
+        .bind(draft.issued_on())
+        .bind(draft.rounding_mode().as_str())
+        .bind(draft.subtotal())
+        .bind(draft.tax_amount())
+        .bind(draft.total_amount())
+        .execute(conn)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn insert_lines(
+        conn: &mut SqliteConnection,
+        invoice_id: &InvoiceId,
+        lines: &[InvoiceLine],
+    ) -> Result<(), DomainError> {
+        for line in lines {
+            sqlx::query(
+                "INSERT INTO t_invoice_lines (
+                    invoice_id,
+                    sales_order_id,
+                    sales_order_line_id,
+                    product_id,
+                    product_name,
+                    quantity,
+                    unit_price,
+                    amount,
+                    tax_rate
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(invoice_id)
+            .bind(line.sales_order_id())
+            .bind(line.sales_order_line_id())
+            .bind(line.product_id())
+            .bind(line.product_name())
+            .bind(line.quantity())
+            .bind(line.unit_price())
+            .bind(line.amount())
+            .bind(line.tax_rate().percent())
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn insert_tax_summaries(
+        conn: &mut SqliteConnection,
+        invoice_id: &InvoiceId,
+        tax_summaries: &[TaxSummary],
+    ) -> Result<(), DomainError> {
+        for tax_summary in tax_summaries {
+            sqlx::query(
+                "INSERT INTO t_invoice_tax_summaries (
+                    invoice_id,
+                    tax_rate,
+                    taxable_amount,
+                    tax_amount
+                ) VALUES (?, ?, ?, ?)",
+            )
+            .bind(invoice_id)
+            .bind(tax_summary.tax_rate().percent())
+            .bind(tax_summary.taxable_amount())
+            .bind(tax_summary.tax_amount())
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn to_invoice(
+        conn: &mut SqliteConnection,
+        row: InvoiceRow,
+    ) -> Result<Invoice, DomainError> {
+        let line_rows: Vec<InvoiceLineRow> = sqlx::query_as(
+            "SELECT
+                    sales_order_id,
+                    sales_order_line_id,
+                    product_id,
+                    product_name,
+                    quantity,
+                    unit_price,
+                    amount,
+                    tax_rate
+                FROM t_invoice_lines WHERE invoice_id = ? ORDER BY id",
+        )
+        .bind(row.id)
+        .fetch_all(&mut *conn)
+        .await?;
+        let tax_summary_rows: Vec<TaxSummaryRow> = sqlx::query_as(
+            "SELECT tax_rate, taxable_amount, tax_amount
+                FROM t_invoice_tax_summaries WHERE invoice_id = ? ORDER BY tax_rate DESC",
+        )
+        .bind(row.id)
+        .fetch_all(conn)
+        .await?;
+
+        let mut lines = Vec::with_capacity(line_rows.len());
+        for line in line_rows {
+            lines.push(InvoiceLine::new(
+                line.sales_order_id,
+                line.sales_order_line_id,
+                line.product_id,
+                line.product_name,
+                line.quantity,
+                line.unit_price,
+                line.amount,
+                TaxRate::try_from(line.tax_rate).map_err(DomainError::unexpected)?,
+            ));
+        }
+        let mut tax_summaries = Vec::with_capacity(tax_summary_rows.len());
+        for tax_summary in tax_summary_rows {
+            tax_summaries.push(TaxSummary::new(
+                TaxRate::try_from(tax_summary.tax_rate).map_err(DomainError::unexpected)?,
+                tax_summary.taxable_amount,
+                tax_summary.tax_amount,
+            ));
+        }
+
+        Ok(Invoice::new(
+            row.id,
+            row.invoice_number,
+            InvoiceIssuer::new(
+                row.issuer_registration_number,
+                row.issuer_name,
+                row.issuer_address,
+            ),
+            row.customer_id,
+            row.recipient_name,
+            row.period_from,
+            row.period_to,
+            row.issued_on,
+            RoundingMode::from_str(&row.rounding_mode).map_err(DomainError::unexpected)?,
+            row.subtotal,
+            row.tax_amount,
+            row.total_amount,
+            lines,
+            tax_summaries,
+            row.created_at,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use sqlx::SqlitePool;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository,
+            fixtures,
+            invoice_repository::SqliteInvoiceRepository,
+            product_repository::SqliteProductRepository,
+            sales_order_repository::SqliteSalesOrderRepository,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                invoice_repository::InvoiceAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                sales_order_repository::OrderAbstructRepository,
+            },
+            usecase::{
+                invoice::search_invoice::SearchInvoiceInput,
+                product::create_product::CreateProductInput,
+                sales_order::{
+                    create_sales_order::{CreateSalesOrderInput, SalesOrderLineInput},
+                    update_sales_order::UpdateSalesOrderInput,
+                },
+            },
+        },
+        domain::{
+            invoice::{InvoiceDraft, InvoiceIssuer},
+            sales_order::SalesOrderStatus,
+            tax::{RoundingMode, TaxRate},
+        },
+        infrastructure::database::MIGRATOR,
+    };
+
+    /// Creates a customer and a product with one order on June 10th and returns the customer id.
+    async fn create_order(pool: &SqlitePool, status: SalesOrderStatus) -> i64 {
+        let customer = SqliteCustomerRespository::new(pool.clone())
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product = SqliteProductRepository::new(pool.clone())
+            .create(&CreateProductInput::new(
+                String::from("商品1"),
+                format!("product{:03}", customer.customer_id()),
+                String::from("個"),
+                2000,
+                10,
+                TaxRate::Standard,
+            ))
+            .await
+            .unwrap();
+        let repository = SqliteSalesOrderRepository::new(pool.clone());
+        let result = repository
+            .create(&CreateSalesOrderInput::new(
+                customer.customer_id(),
+                Date::from_calendar_date(2023, Month::June, 10).unwrap(),
+                vec![SalesOrderLineInput::new(
+                    *product.product_id(),
+                    2,
+                    Some(2000),
+                )],
+            ))
+            .await
+            .unwrap();
+        repository
+            .update(&UpdateSalesOrderInput::new(
+                *result.sales_order_id(),
+                None,
+                None,
+                Some(status),
+                None,
+            ))
+            .await
+            .unwrap();
+
+        customer.customer_id()
+    }
+
+    async fn issue(repository: &SqliteInvoiceRepository, customer_id: i64) -> i64 {
+        let period_from = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+        let period_to = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+        let lines = repository
+            .search_billable_lines(&customer_id, &period_from, &period_to)
+            .await
+            .unwrap();
+        let draft = InvoiceDraft::calculate(
+            InvoiceIssuer::new(
+                String::from("T1234567890123"),
+                String::from("自社"),
+                String::from("東京都"),
+            ),
+            customer_id,
+            String::from("sample.inc"),
+            period_from,
+            period_to,
+            period_to,
+            RoundingMode::RoundDown,
+            lines,
+        );
+        let result = repository.create(&draft).await.unwrap();
+
+        *result.invoice_id()
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_billable_lines_test(pool: SqlitePool) {
+        let delivered = create_order(&pool, SalesOrderStatus::Delivered).await;
+        let ordered = create_order(&pool, SalesOrderStatus::Ordered).await;
+        let repository = SqliteInvoiceRepository::new(pool);
+        let period_from = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+        let period_to = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+
+        let lines = repository
+            .search_billable_lines(&delivered, &period_from, &period_to)
+            .await
+            .unwrap();
+        assert_eq!(lines.len(), 1);
+
+        let lines = repository
+            .search_billable_lines(&ordered, &period_from, &period_to)
+            .await
+            .unwrap();
+        assert!(lines.is_empty());
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let customer_id = create_order(&pool, SalesOrderStatus::Delivered).await;
+        let repository = SqliteInvoiceRepository::new(pool);
+        let invoice_id = issue(&repository, customer_id).await;
+        let invoice = repository.find_by_id(&invoice_id).await.unwrap().unwrap();
+
+        assert_eq!(invoice.invoice_number(), "INV-202306-0001");
+        assert_eq!(*invoice.subtotal(), 4000);
+        assert_eq!(*invoice.tax_amount(), 400);
+        assert_eq!(*invoice.total_amount(), 4400);
+        assert_eq!(invoice.lines().len(), 1);
+        assert_eq!(invoice.lines()[0].product_name(), "商品1");
+        assert_eq!(invoice.tax_summaries().len(), 1);
+        assert_eq!(invoice.issuer().registration_number(), "T1234567890123");
+        assert_eq!(invoice.recipient_name(), "sample.inc");
+
+        let input = SearchInvoiceInput::new(None, None, Some(customer_id), None, None);
+        let invoices = repository.search(&input).await.unwrap();
+        assert_eq!(invoices.len(), 1);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn invoice_number_immutable_test(pool: SqlitePool) {
+        let customer_id = create_order(&pool, SalesOrderStatus::Delivered).await;
+        let repository = SqliteInvoiceRepository::new(pool.clone());
+        let invoice_id = issue(&repository, customer_id).await;
+
+        let updated =
+            sqlx::query("UPDATE t_invoices SET invoice_number = 'INV-202306-9999' WHERE id = ?")
+                .bind(invoice_id)
+                .execute(&pool)
+                .await;
+        let deleted = sqlx::query("DELETE FROM t_invoices WHERE id = ?")
+            .bind(invoice_id)
+            .execute(&pool)
+            .await;
+
+        assert!(updated.is_err());
+        assert!(deleted.is_err());
+    }
+}
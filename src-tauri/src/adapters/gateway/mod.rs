@@ -1,2 +1,46 @@
+pub mod audit_log_repository;
+pub mod company_profile_repository;
+pub mod connection;
 pub mod customer_repository;
+pub mod error;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub mod invoice_repository;
+pub mod price_list_repository;
 pub mod product_repository;
+pub mod quotation_repository;
+pub mod receivable_repository;
+pub mod reorder_alert_repository;
+pub mod sales_order_repository;
+pub mod stock_movement_repository;
+pub mod unit_of_work;
+pub mod user_repository;
+
+use sqlx::{QueryBuilder, Sqlite};
+use time::PrimitiveDateTime;
+
+/// The current time to the millisecond, for `updated_at`. `CURRENT_TIMESTAMP` has whole seconds
+/// only, so two updates within a second would share a timestamp and slip past the check of an
+/// expected `updated_at`.
+pub(crate) const CURRENT_TIMESTAMP_MS: &str = "strftime('%Y-%m-%d %H:%M:%f', 'now')";
+
+/// Restricts an `UPDATE` to a row whose `updated_at` is still `expected_updated_at`, so that
+/// no rows are affected when someone else updated it in between. Both sides are compared to
+/// the millisecond, as the bound value is formatted differently from `CURRENT_TIMESTAMP_MS`.
+pub(crate) fn push_expected_updated_at<'args>(
+    query_builder: &mut QueryBuilder<'args, Sqlite>,
+    expected_updated_at: &'args PrimitiveDateTime,
+) {
+    query_builder.push(
+        " AND strftime('%Y-%m-%d %H:%M:%f', updated_at) = strftime('%Y-%m-%d %H:%M:%f', ",
+    );
+    query_builder.push_bind(expected_updated_at);
+    query_builder.push(")");
+}
+
+/// Escapes `\`, `%` and `_` in `text` so that `LIKE ... ESCAPE '\'` matches them literally.
+pub(crate) fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
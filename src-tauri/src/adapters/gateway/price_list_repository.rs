@@ -0,0 +1,344 @@
+use crate::application::{
+    repository::price_list_repository::{
+        CreatePriceListResult, DeletePriceListResult, PriceListAbstructRepository,
+        UpdatePriceListResult,
+    },
+    usecase::price_list::{
+        create_price_list::CreatePriceListInput, search_price_list::SearchPriceListInput,
+        update_price_list::UpdatePriceListInput,
+    },
+};
+use crate::domain::{
+    customer,
+    price_list::{PriceListEntry, PriceListId},
+    product::ProductId,
+};
+use async_trait::async_trait;
+use sqlx::{sqlite::SqliteQueryResult, SqliteConnection, SqlitePool};
+use crate::adapters::gateway::{connection::SqliteConnector, CURRENT_TIMESTAMP_MS};
+use crate::domain::error::DomainError;
+use time::{Date, PrimitiveDateTime};
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct PriceListRow {
+    id: i64,
+    customer_id: i64,
+    product_id: i64,
+    price: i64,
+    valid_from: Option<Date>,
+    valid_to: Option<Date>,
+    min_quantity: i64,
+    created_at: PrimitiveDateTime,
+    updated_at: PrimitiveDateTime,
+}
+impl PriceListRow {
+    fn into_entry(self) -> PriceListEntry {
+        PriceListEntry::new(
+            self.id,
+            self.customer_id,
+            self.product_id,
+            self.price,
+            self.valid_from,
+            self.valid_to,
+            self.min_quantity,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
+
+pub struct SqlitePriceListRepository {
+    connector: SqliteConnector,
+}
+impl SqlitePriceListRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl PriceListAbstructRepository for SqlitePriceListRepository {
+    async fn find_by_id(&self, id: &PriceListId) -> Result<Option<PriceListEntry>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let row = PriceListRepository::find_by_id(&mut conn, id).await?;
+
+        Ok(row.map(PriceListRow::into_entry))
+    }
+
+    async fn search(
+        &self,
+        input: &SearchPriceListInput,
+    ) -> Result<Vec<PriceListEntry>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let rows = PriceListRepository::search(&mut conn, input).await?;
+
+        Ok(rows.into_iter().map(PriceListRow::into_entry).collect())
+    }
+
+    async fn find_by_customer_and_product(
+        &self,
+        customer_id: &customer::Id,
+        product_id: &ProductId,
+    ) -> Result<Vec<PriceListEntry>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let rows =
+            PriceListRepository::find_by_customer_and_product(&mut conn, customer_id, product_id)
+                .await?;
+
+        Ok(rows.into_iter().map(PriceListRow::into_entry).collect())
+    }
+
+    async fn create(
+        &self,
+        input: &CreatePriceListInput,
+    ) -> Result<CreatePriceListResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = PriceListRepository::create(&mut conn, input).await?;
+
+        Ok(CreatePriceListResult::new(result.last_insert_rowid()))
+    }
+
+    async fn update(
+        &self,
+        input: &UpdatePriceListInput,
+    ) -> Result<UpdatePriceListResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        PriceListRepository::update(&mut conn, input).await?;
+
+        Ok(UpdatePriceListResult::new(*input.id()))
+    }
+
+    async fn delete(&self, id: &PriceListId) -> Result<DeletePriceListResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = PriceListRepository::delete(&mut conn, id).await?;
+
+        Ok(DeletePriceListResult::new(result.rows_affected() > 0))
+    }
+}
+
+struct PriceListRepository {}
+impl PriceListRepository {
+    async fn find_by_id(
+        conn: &mut SqliteConnection,
+        id: &PriceListId,
+    ) -> Result<Option<PriceListRow>, DomainError> {
+        let row = sqlx::query_as::<_, PriceListRow>("SELECT * FROM m_price_lists WHERE id = ?")
+            .bind(id)
+            .fetch_optional(conn)
+            .await?;
+
+        Ok(row)
+    }
+
+    async fn search(
+        conn: &mut SqliteConnection,
+        input: &SearchPriceListInput,
+    ) -> Result<Vec<PriceListRow>, DomainError> {
+        let rows = sqlx::query_as::<_, PriceListRow>(
+            "SELECT * FROM m_price_lists
+                WHERE customer_id = COALESCE(?, customer_id)
+                AND product_id = COALESCE(?, product_id)
+                ORDER BY customer_id, product_id, min_quantity, valid_from, id
+                LIMIT ? OFFSET ?",
+        )
+        .bind(input.customer_id())
+        .bind(input.product_id())
+        .bind(input.limit())
+        .bind(input.offset())
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows)
+    }
+
+    async fn find_by_customer_and_product(
+        conn: &mut SqliteConnection,
+        customer_id: &customer::Id,
+        product_id: &ProductId,
+    ) -> Result<Vec<PriceListRow>, DomainError> {
+        let rows = sqlx::query_as::<_, PriceListRow>(
+            "SELECT * FROM m_price_lists WHERE customer_id = ? AND product_id = ? ORDER BY id",
+        )
+        .bind(customer_id)
+        .bind(product_id)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows)
+    }
+
+    async fn create(
+        conn: &mut SqliteConnection,
+        input: &CreatePriceListInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(
+            "INSERT INTO m_price_lists (
+                customer_id,
+                product_id,
+                price,
+                valid_from,
+                valid_to,
+                min_quantity
+            ) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(input.customer_id())
+        .bind(input.product_id())
+        .bind(input.price())
+        .bind(input.valid_from())
+        .bind(input.valid_to())
+        .bind(input.min_quantity())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn update(
+        conn: &mut SqliteConnection,
+        input: &UpdatePriceListInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE m_price_lists SET
+                price = ?,
+                valid_from = ?,
+                valid_to = ?,
+                min_quantity = ?,
+                updated_at = {}
+            WHERE id = ?",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(input.price())
+        .bind(input.valid_from())
+        .bind(input.valid_to())
+        .bind(input.min_quantity())
+        .bind(input.id())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn delete(
+        conn: &mut SqliteConnection,
+        id: &PriceListId,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query("DELETE FROM m_price_lists WHERE id = ?")
+            .bind(id)
+            .execute(conn)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use sqlx::SqlitePool;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository,
+            fixtures,
+            price_list_repository::SqlitePriceListRepository,
+            product_repository::SqliteProductRepository,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                price_list_repository::PriceListAbstructRepository,
+                product_repository::ProductAbstructRepository,
+            },
+            usecase::{
+                price_list::{
+                    create_price_list::CreatePriceListInput,
+                    search_price_list::SearchPriceListInput,
+                    update_price_list::UpdatePriceListInput,
+                },
+            },
+        },
+        infrastructure::database::MIGRATOR,
+    };
+
+    async fn create_input(pool: &SqlitePool) -> CreatePriceListInput {
+        let customer = SqliteCustomerRespository::new(pool.clone())
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product = SqliteProductRepository::new(pool.clone())
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+
+        CreatePriceListInput::new(
+            customer.customer_id(),
+            *product.product_id(),
+            1800,
+            Some(Date::from_calendar_date(2023, Month::June, 1).unwrap()),
+            None,
+            None,
+        )
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqlitePriceListRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+        let entry = repository
+            .find_by_id(result.price_list_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(*entry.price(), 1800);
+        assert_eq!(*entry.min_quantity(), 1);
+        assert_eq!(*entry.valid_to(), None);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqlitePriceListRepository::new(pool);
+        repository.create(&input).await.unwrap();
+
+        let params = SearchPriceListInput::new(None, None, Some(*input.customer_id()), None);
+        assert_eq!(repository.search(&params).await.unwrap().len(), 1);
+
+        let entries = repository
+            .find_by_customer_and_product(input.customer_id(), &(*input.product_id() + 1))
+            .await
+            .unwrap();
+        assert_eq!(entries.len(), 0);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn update_and_delete_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqlitePriceListRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+
+        let params = UpdatePriceListInput::new(*result.price_list_id(), 1500, None, None, Some(10));
+        repository.update(&params).await.unwrap();
+        let entry = repository
+            .find_by_id(result.price_list_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*entry.price(), 1500);
+        assert_eq!(*entry.valid_from(), None);
+        assert_eq!(*entry.min_quantity(), 10);
+
+        let deleted = repository.delete(result.price_list_id()).await.unwrap();
+        let deleted_again = repository.delete(result.price_list_id()).await.unwrap();
+        assert!(*deleted.result());
+        assert!(!*deleted_again.result());
+    }
+}
@@ -1,19 +1,27 @@
 use crate::application::{
     repository::product_repository::{
         CreateProductResult, ProductAbstructRepository, UpdateProductResult, DeleteProductResult,
+        RestoreProductResult, UpsertProductResult,
     },
     usecase::product::{
-        create_product::CreateProductInput, search_product::SearchProductInput,
+        create_product::CreateProductInput,
+        search_product::{
+            NumberRange, ProductSortKey, SearchProductInput, SortDirection, TextMatch,
+        },
         update_product::UpdateProductInput,
     },
 };
-use crate::domain::product::{
-    Product, ProductId,
+use crate::domain::{
+    product::{Product, ProductId},
+    tax::TaxRate,
 };
 use async_trait::async_trait;
-use sqlx::{pool::PoolConnection, Execute, Sqlite, SqlitePool};
+use sqlx::{Connection, Sqlite, SqliteConnection, SqlitePool};
 use sqlx::{query_builder, sqlite::SqliteQueryResult};
-use std::error::Error;
+use crate::adapters::gateway::{escape_like, push_expected_updated_at, CURRENT_TIMESTAMP_MS};
+use crate::adapters::gateway::connection::SqliteConnector;
+use crate::adapters::gateway::error::is_unique_violation;
+use crate::domain::error::DomainError;
 use time::PrimitiveDateTime;
 
 #[derive(sqlx::FromRow, Debug)]
@@ -24,43 +32,90 @@ pub struct ProductRow {
     unit: String,
     default_price: i64,
     standard_stock_quantity: i64,
+    tax_rate: i64,
     created_at: PrimitiveDateTime,
     updated_at: PrimitiveDateTime,
     deleted_at: Option<PrimitiveDateTime>,
 }
+impl ProductRow {
+    fn into_product(self) -> Result<Product, DomainError> {
+        Ok(Product::new(
+            self.id,
+            self.name,
+            self.code,
+            self.unit,
+            self.default_price,
+            self.standard_stock_quantity,
+            TaxRate::try_from(self.tax_rate).map_err(DomainError::unexpected)?,
+            self.created_at,
+            self.updated_at,
+            self.deleted_at,
+        ))
+    }
+}
 
 pub struct SqliteProductRepository {
-    pool: SqlitePool,
+    connector: SqliteConnector,
 }
 
 impl SqliteProductRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
     }
 }
 
 #[async_trait]
 impl ProductAbstructRepository for SqliteProductRepository {
-    async fn find_by_id(&self, product_id: &ProductId) -> Result<Option<Product>, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
-        let product = ProductRepository::find_by_id(&mut conn, &product_id).await?;
+    async fn find_by_id(&self, product_id: &ProductId) -> Result<Option<Product>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let product = ProductRepository::find_by_id(&mut conn, product_id, false).await?;
+
+        Ok(product)
+    }
+
+    async fn find_by_id_including_deleted(
+        &self,
+        product_id: &ProductId,
+    ) -> Result<Option<Product>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let product = ProductRepository::find_by_id(&mut conn, product_id, true).await?;
 
         Ok(product)
     }
 
-    async fn search(&self, input: &SearchProductInput) -> Result<Vec<Product>, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
+    async fn find_by_code(&self, code: &str) -> Result<Option<Product>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let product = ProductRepository::find_by_code(&mut conn, code).await?;
+
+        Ok(product)
+    }
+
+    async fn search(&self, input: &SearchProductInput) -> Result<Vec<Product>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
         let products = ProductRepository::search(&mut conn, input).await?;
 
         Ok(products)
     }
 
+    async fn count(&self, input: &SearchProductInput) -> Result<i64, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let count = ProductRepository::count(&mut conn, input).await?;
+
+        Ok(count)
+    }
+
     async fn create(
         &self,
         product: &CreateProductInput,
-    ) -> Result<CreateProductResult, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
-        let result = ProductRepository::create(&mut conn, &product).await?;
+    ) -> Result<CreateProductResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = ProductRepository::create(&mut conn, product).await?;
         let create_product_result = CreateProductResult::new(result.last_insert_rowid());
 
         Ok(create_product_result)
@@ -69,121 +124,293 @@ impl ProductAbstructRepository for SqliteProductRepository {
     async fn update(
         &self,
         input: &UpdateProductInput,
-    ) -> Result<UpdateProductResult, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
-        ProductRepository::update(&mut conn, input).await?;
-        let update_product_result = UpdateProductResult::new(input.id().clone());
+    ) -> Result<UpdateProductResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        ProductRepository::update(&mut tx, input).await?;
+        tx.commit().await?;
+        let update_product_result = UpdateProductResult::new(*input.id());
 
         Ok(update_product_result)
     }
 
-    async fn delete(&self, product_id: &ProductId) -> Result<DeleteProductResult, Box<dyn Error>> {
-        let mut conn = self.pool.acquire().await?;
+    async fn upsert_by_code(
+        &self,
+        products: &[CreateProductInput],
+    ) -> Result<UpsertProductResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let mut created = 0;
+        let mut updated = 0;
+        for product in products {
+            match ProductRepository::find_by_code(&mut tx, product.code()).await? {
+                Some(current) => {
+                    ProductRepository::replace(&mut tx, current.id(), product).await?;
+                    updated += 1;
+                }
+                None => {
+                    ProductRepository::create(&mut tx, product).await?;
+                    created += 1;
+                }
+            }
+        }
+        tx.commit().await?;
+
+        Ok(UpsertProductResult::new(created, updated))
+    }
+
+    async fn delete(&self, product_id: &ProductId) -> Result<DeleteProductResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
         let result = ProductRepository::delete(&mut conn, product_id).await?;
         let is_success = result.rows_affected() > 0;
         let delete_product_result = DeleteProductResult::new(is_success);
     
         Ok(delete_product_result)
     }
+
+    async fn restore(&self, product_id: &ProductId) -> Result<RestoreProductResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = ProductRepository::restore(&mut conn, product_id).await?;
+
+        Ok(RestoreProductResult::new(result.rows_affected() > 0))
+    }
 }
 
 struct ProductRepository {}
 
 impl ProductRepository {
     async fn find_by_id(
-        conn: &mut PoolConnection<Sqlite>,
+        conn: &mut SqliteConnection,
         product_id: &ProductId,
-    ) -> Result<Option<Product>, Box<dyn Error>> {
-        let row = sqlx::query_as::<_, ProductRow>("SELECT * FROM m_products WHERE id in (?)")
+        include_deleted: bool,
+    ) -> Result<Option<Product>, DomainError> {
+        let row = sqlx::query_as::<_, ProductRow>(
+            "SELECT * FROM m_products WHERE id in (?) AND (? OR deleted_at IS NULL)",
+        )
             .bind(product_id)
+            .bind(include_deleted)
             .fetch_optional(conn)
             .await?;
 
         match row {
             None => Ok(None),
-            Some(row) => {
-                let product = Product::new(
-                    row.id,
-                    row.name,
-                    row.code,
-                    row.unit,
-                    row.default_price,
-                    row.standard_stock_quantity,
-                    row.created_at,
-                    row.updated_at,
-                    row.deleted_at,
-                );
-
-                Ok(Some(product))
+            Some(row) => Ok(Some(row.into_product()?)),
+        }
+    }
+
+    async fn find_by_code(
+        conn: &mut SqliteConnection,
+        code: &str,
+    ) -> Result<Option<Product>, DomainError> {
+        let row = sqlx::query_as::<_, ProductRow>(
+            "SELECT * FROM m_products WHERE code = ? AND deleted_at IS NULL",
+        )
+        .bind(code)
+        .fetch_optional(conn)
+        .await?;
+
+        match row {
+            None => Ok(None),
+            Some(row) => Ok(Some(row.into_product()?)),
+        }
+    }
+
+    /// Adds the search conditions after `WHERE`.
+    fn push_search_conditions(
+        query_builder: &mut query_builder::QueryBuilder<'_, Sqlite>,
+        input: &SearchProductInput,
+    ) {
+        query_builder.push("(");
+        query_builder.push_bind(*input.include_deleted());
+        query_builder.push(" OR deleted_at IS NULL)");
+        if let Some(name) = input.name() {
+            Self::push_text_condition(query_builder, "name", name, input.name_match());
+        }
+        if let Some(code) = input.code() {
+            Self::push_text_condition(query_builder, "code", code, input.code_match());
+        }
+        Self::push_range_condition(query_builder, "default_price", input.default_price());
+        Self::push_range_condition(
+            query_builder,
+            "standard_stock_quantity",
+            input.standard_stock_quantity(),
+        );
+    }
+
+    fn push_text_condition(
+        query_builder: &mut query_builder::QueryBuilder<'_, Sqlite>,
+        column: &str,
+        text: &str,
+        text_match: &TextMatch,
+    ) {
+        let escaped = escape_like(text);
+        query_builder.push(format!(" AND {} ", column));
+        match text_match {
+            TextMatch::Partial => {
+                query_builder.push("LIKE ");
+                query_builder.push_bind(format!("%{}%", escaped));
+                query_builder.push(" ESCAPE '\\'");
             }
+            TextMatch::Prefix => {
+                query_builder.push("LIKE ");
+                query_builder.push_bind(format!("{}%", escaped));
+                query_builder.push(" ESCAPE '\\'");
+            }
+            TextMatch::Exact => {
+                query_builder.push("= ");
+                query_builder.push_bind(text.to_string());
+            }
+        }
+    }
+
+    fn push_range_condition(
+        query_builder: &mut query_builder::QueryBuilder<'_, Sqlite>,
+        column: &str,
+        range: &NumberRange,
+    ) {
+        if let Some(min) = range.min() {
+            query_builder.push(format!(" AND {} >= ", column));
+            query_builder.push_bind(*min);
+        }
+        if let Some(max) = range.max() {
+            query_builder.push(format!(" AND {} <= ", column));
+            query_builder.push_bind(*max);
+        }
+    }
+
+    fn sort_column(sort_key: &ProductSortKey) -> &'static str {
+        match sort_key {
+            ProductSortKey::Id => "id",
+            ProductSortKey::Name => "name",
+            ProductSortKey::Code => "code",
+            ProductSortKey::DefaultPrice => "default_price",
+            ProductSortKey::StandardStockQuantity => "standard_stock_quantity",
+            ProductSortKey::CreatedAt => "created_at",
+            ProductSortKey::UpdatedAt => "updated_at",
         }
     }
 
     async fn search(
-        conn: &mut PoolConnection<Sqlite>,
+        conn: &mut SqliteConnection,
         input: &SearchProductInput,
-    ) -> Result<Vec<Product>, Box<dyn Error>> {
-        let rows: Vec<ProductRow> = sqlx::query_as(
-            "SELECT * FROM m_products WHERE name = COALESCE(?, name) AND code = COALESCE(?, code) LIMIT ? OFFSET ?",
-        )
-        .bind(input.name())
-        .bind(input.code())
-        .bind(input.limit())
-        .bind(input.offset())
-        .fetch_all(conn)
-        .await?;
+    ) -> Result<Vec<Product>, DomainError> {
+        let mut query_builder =
+            query_builder::QueryBuilder::<Sqlite>::new("SELECT * FROM m_products WHERE ");
+        Self::push_search_conditions(&mut query_builder, input);
+        if let Some(after_id) = input.after_id() {
+            query_builder.push(" AND id > ");
+            query_builder.push_bind(*after_id);
+            query_builder.push(" ORDER BY id");
+        } else {
+            let direction = match input.sort_direction() {
+                SortDirection::Asc => "ASC",
+                SortDirection::Desc => "DESC",
+            };
+            query_builder.push(format!(
+                " ORDER BY {} {}, id {}",
+                Self::sort_column(input.sort_key()),
+                direction,
+                direction
+            ));
+        }
+        query_builder.push(" LIMIT ");
+        query_builder.push_bind(*input.limit());
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(*input.offset());
+
+        let rows: Vec<ProductRow> = query_builder.build_query_as().fetch_all(conn).await?;
         let products = rows
             .into_iter()
-            .map(|row| {
-                Product::new(
-                    row.id,
-                    row.name,
-                    row.code,
-                    row.unit,
-                    row.default_price,
-                    row.standard_stock_quantity,
-                    row.created_at,
-                    row.updated_at,
-                    row.deleted_at,
-                )
-            })
-            .collect();
+            .map(ProductRow::into_product)
+            .collect::<Result<Vec<Product>, DomainError>>()?;
 
         Ok(products)
     }
 
+    async fn count(
+        conn: &mut SqliteConnection,
+        input: &SearchProductInput,
+    ) -> Result<i64, DomainError> {
+        let mut query_builder =
+            query_builder::QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM m_products WHERE ");
+        Self::push_search_conditions(&mut query_builder, input);
+        let (count,): (i64,) = query_builder.build_query_as().fetch_one(conn).await?;
+
+        Ok(count)
+    }
+
     async fn create(
-        conn: &mut PoolConnection<Sqlite>,
+        conn: &mut SqliteConnection,
         product: &CreateProductInput,
-    ) -> Result<SqliteQueryResult, Box<dyn Error>> {
+    ) -> Result<SqliteQueryResult, DomainError> {
         let result = sqlx::query(
             "INSERT INTO m_products (
                 name, 
                 code, 
                 unit, 
                 default_price, 
-                standard_stock_quantity
-            ) VALUES (?, ?, ?, ?, ?)",
+                standard_stock_quantity,
+                tax_rate
+            ) VALUES (?, ?, ?, ?, ?, ?)",
         )
         .bind(product.name())
         .bind(product.code())
         .bind(product.unit())
         .bind(product.default_price())
         .bind(product.standard_stock_quantity())
-        .execute(conn)
-        .await?;
+        .bind(product.tax_rate().percent())
+        .execute(&mut *conn)
+        .await;
 
-        Ok(result)
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => Err(Self::code_conflict(conn, product.code(), error).await),
+        }
+    }
+
+    /// Overwrites every column a product is created with, and bumps `updated_at`.
+    async fn replace(
+        conn: &mut SqliteConnection,
+        product_id: &ProductId,
+        product: &CreateProductInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE m_products SET
+                name = ?,
+                code = ?,
+                unit = ?,
+                default_price = ?,
+                standard_stock_quantity = ?,
+                tax_rate = ?,
+                updated_at = {}
+            WHERE id = ? AND deleted_at IS NULL",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(product.name())
+        .bind(product.code())
+        .bind(product.unit())
+        .bind(product.default_price())
+        .bind(product.standard_stock_quantity())
+        .bind(product.tax_rate().percent())
+        .bind(product_id)
+        .execute(&mut *conn)
+        .await;
+
+        match result {
+            Ok(result) => Ok(result),
+            Err(error) => Err(Self::code_conflict(conn, product.code(), error).await),
+        }
     }
 
+    /// Fails with a conflict carrying the stored product when it was updated after
+    /// `input.expected_updated_at()`, which the update itself checks.
     async fn update(
-        conn: &mut PoolConnection<Sqlite>,
+        conn: &mut SqliteConnection,
         input: &UpdateProductInput,
-    ) -> Result<Option<SqliteQueryResult>, Box<dyn Error>> {
-        let mut pre_query_builder =
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let mut query_builder =
             query_builder::QueryBuilder::<Sqlite>::new("UPDATE m_products SET ");
-
-        let mut separated = pre_query_builder.separated(", ");
+        let mut separated = query_builder.separated(", ");
         if let Some(name) = input.name() {
             separated.push("name = ");
             separated.push_bind_unseparated(name);
@@ -204,64 +431,116 @@ impl ProductRepository {
             separated.push("standard_stock_quantity = ");
             separated.push_bind_unseparated(standard_stock_quantity);
         }
-
-        let query_without_where = pre_query_builder.build();
-        let is_update_colums = query_without_where.sql().ends_with("= ?");
-        if !is_update_colums {
-            return Ok(None);
+        if let Some(tax_rate) = input.tax_rate() {
+            separated.push("tax_rate = ");
+            separated.push_bind_unseparated(tax_rate.percent());
         }
+        separated.push(format!("updated_at = {}", CURRENT_TIMESTAMP_MS));
 
-        let mut query_builder =
-            query_builder::QueryBuilder::<Sqlite>::new(query_without_where.sql());
         query_builder.push(" WHERE id = ");
         query_builder.push_bind(input.id());
-        let query = query_builder.build();
-        let result = sqlx::query(&query.sql()).execute(conn).await?;
+        query_builder.push(" AND deleted_at IS NULL");
+        if let Some(expected_updated_at) = input.expected_updated_at() {
+            push_expected_updated_at(&mut query_builder, expected_updated_at);
+        }
+        let result = match (query_builder.build().execute(&mut *conn).await, input.code()) {
+            (Ok(result), _) => result,
+            (Err(error), Some(code)) => return Err(Self::code_conflict(conn, code, error).await),
+            (Err(error), None) => return Err(error.into()),
+        };
+        if result.rows_affected() > 0 {
+            return Ok(result);
+        }
 
-        Ok(Some(result))
+        match Self::find_by_id(&mut *conn, input.id(), false).await? {
+            Some(current) => Err(DomainError::conflict_with(
+                format!("product was updated by someone else: id={}", input.id()),
+                &current,
+            )),
+            None => Err(DomainError::not_found("product", input.id())),
+        }
+    }
+
+    /// Maps a clash on the unique product code to a conflict carrying the product that already
+    /// uses `code`. Other errors are mapped as usual.
+    async fn code_conflict(
+        conn: &mut SqliteConnection,
+        code: &str,
+        error: sqlx::Error,
+    ) -> DomainError {
+        if !is_unique_violation(&error) {
+            return error.into();
+        }
+        match Self::find_by_code(conn, code).await {
+            Ok(Some(current)) => DomainError::conflict_with(
+                format!("product code is already used: code={}", code),
+                &current,
+            ),
+            _ => error.into(),
+        }
     }
 
-    async fn delete(conn: &mut PoolConnection<Sqlite>, product_id: &ProductId) -> Result<SqliteQueryResult, Box<dyn Error>> {
-        let result = sqlx::query("DELETE FROM m_products WHERE id = ?")
+    async fn delete(conn: &mut SqliteConnection, product_id: &ProductId) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE m_products SET deleted_at = {0}, updated_at = {0} WHERE id = ? AND deleted_at IS NULL",
+            CURRENT_TIMESTAMP_MS
+        ))
             .bind(product_id)
             .execute(conn).await?;
 
         Ok(result)
     }
+
+    async fn restore(
+        conn: &mut SqliteConnection,
+        product_id: &ProductId,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE m_products SET deleted_at = NULL, updated_at = {} WHERE id = ? AND deleted_at IS NOT NULL",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(product_id)
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use sqlx::SqlitePool;
+    use sqlx::{
+        sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+        SqlitePool,
+    };
 
     use crate::{
-        adapters::gateway::product_repository::{ProductRepository, SqliteProductRepository},
+        adapters::gateway::{
+            fixtures,
+            product_repository::{ProductRepository, SqliteProductRepository},
+        },
         application::{
             repository::product_repository::ProductAbstructRepository,
             usecase::product::{
                 create_product::CreateProductInput,
-                search_product::SearchProductInput,
+                search_product::{
+                    NumberRange, ProductSortKey, SearchProductInput, SortDirection, TextMatch,
+                },
                 update_product::UpdateProductInput,
             },
         },
+        domain::{error::DomainError, tax::TaxRate},
         infrastructure::database::MIGRATOR,
     };
 
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn find_by_id_test(pool: SqlitePool) {
         let repository = SqliteProductRepository::new(pool);
-        let input = CreateProductInput::new(
-            String::from("商品1"),
-            String::from("product001"),
-            String::from("個"),
-            2000,
-            10,
-        );
+        let input = fixtures::product_input("product001");
         let result = repository.create(&input).await.unwrap();
-        repository.create(&input).await.unwrap();
         let product_id = result.product_id();
-        let product = repository.find_by_id(&product_id).await.unwrap();
+        let product = repository.find_by_id(product_id).await.unwrap();
 
         match product {
             Some(product) => {
@@ -276,30 +555,99 @@ mod tests {
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn search_test(pool: SqlitePool) {
         let repository = SqliteProductRepository::new(pool);
-        let input = CreateProductInput::new(
-            String::from("商品1"),
-            String::from("product001"),
-            String::from("個"),
-            2000,
-            10,
-        );
+        let input = fixtures::product_input("product001");
         repository.create(&input).await.unwrap();
         let product_name = String::from("商品1");
-        let input = SearchProductInput::new(None, None, Some(product_name), None);
+        let input = SearchProductInput::new(None, None, Some(product_name), None, None);
         let products = repository.search(&input).await.unwrap();
 
         assert_eq!(products[0].code().to_string(), String::from("product001"));
     }
 
     #[sqlx::test(migrator = "MIGRATOR")]
-    async fn create_test(pool: SqlitePool) {
-        let input = CreateProductInput::new(
-            String::from("商品1"),
-            String::from("product001"),
-            String::from("個"),
-            2000,
-            10,
+    async fn search_conditions_test(pool: SqlitePool) {
+        let repository = SqliteProductRepository::new(pool);
+        for (name, code, default_price, standard_stock_quantity) in [
+            ("ボールペン 黒", "pen-001", 120, 50),
+            ("ボールペン 赤", "pen-002", 150, 30),
+            ("替芯 0.5", "refill_05", 80, 100),
+            ("ノート A4", "note-a4", 300, 10),
+        ] {
+            repository
+                .create(&CreateProductInput::new(
+                    String::from(name),
+                    String::from(code),
+                    String::from("個"),
+                    default_price,
+                    standard_stock_quantity,
+                    TaxRate::Standard,
+                ))
+                .await
+                .unwrap();
+        }
+        let codes = |products: Vec<crate::domain::product::Product>| {
+            products
+                .iter()
+                .map(|product| product.code().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let input = SearchProductInput::new(None, None, Some(String::from("ペン")), None, None);
+        assert_eq!(
+            codes(repository.search(&input).await.unwrap()),
+            vec!["pen-001", "pen-002"]
+        );
+        assert_eq!(repository.count(&input).await.unwrap(), 2);
+
+        let input = SearchProductInput::new(None, None, None, Some(String::from("pen")), None)
+            .with_text_match(TextMatch::Partial, TextMatch::Prefix);
+        assert_eq!(repository.count(&input).await.unwrap(), 2);
+        let input = SearchProductInput::new(None, None, None, Some(String::from("001")), None)
+            .with_text_match(TextMatch::Partial, TextMatch::Prefix);
+        assert_eq!(repository.count(&input).await.unwrap(), 0);
+        let input = SearchProductInput::new(None, None, Some(String::from("ペン")), None, None)
+            .with_text_match(TextMatch::Exact, TextMatch::Exact);
+        assert_eq!(repository.count(&input).await.unwrap(), 0);
+
+        // `_` is not a wildcard.
+        let input = SearchProductInput::new(None, None, None, Some(String::from("pen_001")), None);
+        assert_eq!(repository.count(&input).await.unwrap(), 0);
+        let input = SearchProductInput::new(None, None, None, Some(String::from("l_05")), None);
+        assert_eq!(
+            codes(repository.search(&input).await.unwrap()),
+            vec!["refill_05"]
+        );
+
+        let input = SearchProductInput::new(None, None, None, None, None)
+            .with_default_price(NumberRange::new(Some(100), Some(200)))
+            .with_standard_stock_quantity(NumberRange::new(None, Some(40)));
+        assert_eq!(
+            codes(repository.search(&input).await.unwrap()),
+            vec!["pen-002"]
+        );
+
+        let input = SearchProductInput::new(Some(1), Some(2), None, None, None)
+            .with_sort(ProductSortKey::DefaultPrice, SortDirection::Desc);
+        assert_eq!(
+            codes(repository.search(&input).await.unwrap()),
+            vec!["pen-002", "pen-001"]
+        );
+        assert_eq!(repository.count(&input).await.unwrap(), 4);
+
+        // Reading after an id goes in id order whatever the sort.
+        let input = SearchProductInput::new(None, Some(2), None, None, None)
+            .with_sort(ProductSortKey::DefaultPrice, SortDirection::Desc)
+            .with_after_id(1);
+        assert_eq!(
+            codes(repository.search(&input).await.unwrap()),
+            vec!["pen-002", "refill_05"]
         );
+        assert_eq!(repository.count(&input).await.unwrap(), 4);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let input = fixtures::product_input("product001");
         let mut conn = pool.acquire().await.unwrap();
         let result = ProductRepository::create(&mut conn, &input).await.unwrap();
 
@@ -308,16 +656,13 @@ mod tests {
 
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn update_test(pool: SqlitePool) {
-        let repository = SqliteProductRepository::new(pool);
-        let input = CreateProductInput::new(
-            String::from("商品1"),
-            String::from("product001"),
-            String::from("個"),
-            2000,
-            10,
-        );
+        let repository = SqliteProductRepository::new(pool.clone());
+        let input = fixtures::product_input("product001");
         let create_product_result = repository.create(&input).await.unwrap();
-        repository.create(&input).await.unwrap();
+        sqlx::query("UPDATE m_products SET created_at = '2023-07-01 09:00:00', updated_at = created_at")
+            .execute(&pool)
+            .await
+            .unwrap();
         let params = UpdateProductInput::new(
             *create_product_result.product_id(),
             Some(String::from("商品1更新後")),
@@ -325,26 +670,158 @@ mod tests {
             Some(String::from("個更新後")),
             None,
             None,
+            Some(TaxRate::Reduced),
         );
         let update_product_result = repository.update(&params).await.unwrap();
 
         assert_eq!(update_product_result.product_id(), create_product_result.product_id());
+        let product = repository
+            .find_by_id(create_product_result.product_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(product.name(), "商品1更新後");
+        assert_eq!(*product.tax_rate(), TaxRate::Reduced);
+        assert!(product.updated_at() > product.created_at());
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn update_conflict_test(pool: SqlitePool) {
+        let repository = SqliteProductRepository::new(pool);
+        let input = fixtures::product_input("product001");
+        let product_id = *repository.create(&input).await.unwrap().product_id();
+        let read = repository.find_by_id(&product_id).await.unwrap().unwrap();
+        let update = |default_price| {
+            UpdateProductInput::new(product_id, None, None, None, Some(default_price), None, None)
+                .with_expected_updated_at(Some(*read.updated_at()))
+        };
+
+        repository.update(&update(2500)).await.unwrap();
+        let error = repository.update(&update(3000)).await.unwrap_err();
+
+        match error {
+            DomainError::Conflict { current, .. } => {
+                assert_eq!(current.unwrap()["default_price"], 2500);
+            }
+            _ => panic!(),
+        }
+        let product = repository.find_by_id(&product_id).await.unwrap().unwrap();
+        assert_eq!(*product.default_price(), 2500);
+
+        let error = repository
+            .update(&UpdateProductInput::new(0, None, None, None, Some(1), None, None))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "not_found");
     }
 
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn delete(pool: SqlitePool) {
         let repository = SqliteProductRepository::new(pool);
-        let input = CreateProductInput::new(
-            String::from("商品1"),
-            String::from("product001"),
-            String::from("個"),
-            2000,
-            10,
-        );
+        let input = fixtures::product_input("product001");
         let result = repository.create(&input).await.unwrap();
         let product_id = result.product_id();
-        let result = repository.delete(&product_id).await.unwrap();
+        let result = repository.delete(product_id).await.unwrap();
+
+        assert!(*result.result());
+    }
 
-        assert_eq!(*result.result(), true);
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn soft_delete_and_restore_test(pool: SqlitePool) {
+        let repository = SqliteProductRepository::new(pool.clone());
+        let backdate = || async {
+            sqlx::query("UPDATE m_products SET created_at = '2023-07-01 09:00:00', updated_at = created_at")
+                .execute(&pool)
+                .await
+                .unwrap();
+        };
+        let input = fixtures::product_input("product001");
+        let product_id = *repository.create(&input).await.unwrap().product_id();
+        backdate().await;
+        repository.delete(&product_id).await.unwrap();
+
+        assert!(repository.find_by_id(&product_id).await.unwrap().is_none());
+        let product = repository.find_by_id_including_deleted(&product_id).await.unwrap().unwrap();
+        assert!(product.deleted_at().is_some());
+        assert!(product.updated_at() > product.created_at());
+        let search_input = SearchProductInput::new(None, None, None, None, None);
+        assert_eq!(repository.search(&search_input).await.unwrap().len(), 0);
+        let search_input = SearchProductInput::new(None, None, None, None, Some(true));
+        assert_eq!(repository.search(&search_input).await.unwrap().len(), 1);
+        assert!(!*repository.delete(&product_id).await.unwrap().result());
+
+        backdate().await;
+        assert!(*repository.restore(&product_id).await.unwrap().result());
+        assert!(!*repository.restore(&product_id).await.unwrap().result());
+        let product = repository.find_by_id(&product_id).await.unwrap().unwrap();
+        assert!(product.updated_at() > product.created_at());
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn unique_code_test(pool_options: SqlitePoolOptions, connect_options: SqliteConnectOptions) {
+        // One connection, so that every statement sees the state left by the previous one.
+        let pool = pool_options.max_connections(1).connect_with(connect_options).await.unwrap();
+        let repository = SqliteProductRepository::new(pool.clone());
+        let input = fixtures::product_input("product001");
+        let product_id = *repository.create(&input).await.unwrap().product_id();
+
+        match repository.create(&input).await.unwrap_err() {
+            DomainError::Conflict { current: Some(current), .. } => {
+                assert_eq!(current["id"], product_id);
+            }
+            error => panic!("expected a conflict with the stored product: {:?}", error),
+        }
+        let error = sqlx::query("INSERT INTO m_products (name, code, unit) VALUES ('商品2', 'product001', '個')")
+            .execute(&pool)
+            .await
+            .unwrap_err();
+        assert_eq!(DomainError::from(error).code(), "conflict");
+
+        repository.delete(&product_id).await.unwrap();
+        assert!(repository.find_by_code("product001").await.unwrap().is_none());
+        let product_id = *repository.create(&input).await.unwrap().product_id();
+        let product = repository.find_by_code("product001").await.unwrap().unwrap();
+        assert_eq!(*product.id(), product_id);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn upsert_by_code_test(pool: SqlitePool) {
+        let repository = SqliteProductRepository::new(pool.clone());
+        let input = |name: &str, code: &str, default_price: i64| {
+            CreateProductInput::new(
+                String::from(name),
+                String::from(code),
+                String::from("個"),
+                default_price,
+                10,
+                TaxRate::Standard,
+            )
+        };
+        let product_id = *repository
+            .create(&input("商品1", "product001", 2000))
+            .await
+            .unwrap()
+            .product_id();
+        sqlx::query("UPDATE m_products SET created_at = '2023-07-01 09:00:00', updated_at = created_at")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = repository
+            .upsert_by_code(&[
+                input("商品1改", "product001", 2500),
+                input("商品2", "product002", 3000),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(*result.created(), 1);
+        assert_eq!(*result.updated(), 1);
+        let product = repository.find_by_code("product001").await.unwrap().unwrap();
+        assert_eq!(*product.id(), product_id);
+        assert_eq!(product.name(), "商品1改");
+        assert_eq!(*product.default_price(), 2500);
+        assert!(product.updated_at() > product.created_at());
+        assert!(repository.find_by_code("product002").await.unwrap().is_some());
     }
 }
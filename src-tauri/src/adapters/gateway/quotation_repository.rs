@@ -0,0 +1,474 @@
+use crate::application::{
+    repository::quotation_repository::{
+        CreateQuotationResult, QuotationAbstructRepository, UpdateQuotationResult,
+    },
+    usecase::quotation::{
+        create_quotation::{CreateQuotationInput, QuotationLineInput},
+        search_quotation::SearchQuotationInput,
+        update_quotation::UpdateQuotationInput,
+    },
+};
+use crate::domain::{
+    quotation::{Quotation, QuotationId, QuotationLine, QuotationStatus},
+    sales_order::SalesOrderId,
+};
+use async_trait::async_trait;
+use sqlx::{query_builder, sqlite::SqliteQueryResult, Connection, Sqlite, SqliteConnection, SqlitePool};
+use crate::adapters::gateway::{connection::SqliteConnector, CURRENT_TIMESTAMP_MS};
+use crate::domain::error::DomainError;
+use std::str::FromStr;
+use time::{Date, PrimitiveDateTime};
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct QuotationRow {
+    id: i64,
+    customer_id: i64,
+    quoted_on: Date,
+    valid_until: Date,
+    status: String,
+    sales_order_id: Option<i64>,
+    created_at: PrimitiveDateTime,
+    updated_at: PrimitiveDateTime,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct QuotationLineRow {
+    id: i64,
+    product_id: i64,
+    quantity: i64,
+    unit_price: i64,
+}
+
+pub struct SqliteQuotationRepository {
+    connector: SqliteConnector,
+}
+impl SqliteQuotationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl QuotationAbstructRepository for SqliteQuotationRepository {
+    async fn find_by_id(&self, id: &QuotationId) -> Result<Option<Quotation>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let quotation = QuotationRepository::find_by_id(&mut conn, id).await?;
+
+        Ok(quotation)
+    }
+
+    async fn search(&self, input: &SearchQuotationInput) -> Result<Vec<Quotation>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let quotations = QuotationRepository::search(&mut conn, input).await?;
+
+        Ok(quotations)
+    }
+
+    async fn create(
+        &self,
+        input: &CreateQuotationInput,
+    ) -> Result<CreateQuotationResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let result = QuotationRepository::create(&mut tx, input).await?;
+        let quotation_id = result.last_insert_rowid();
+        QuotationRepository::insert_lines(&mut tx, &quotation_id, input.lines()).await?;
+        tx.commit().await?;
+
+        Ok(CreateQuotationResult::new(quotation_id))
+    }
+
+    async fn update(
+        &self,
+        input: &UpdateQuotationInput,
+    ) -> Result<UpdateQuotationResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        QuotationRepository::update(&mut tx, input).await?;
+        if let Some(lines) = input.lines() {
+            QuotationRepository::delete_lines(&mut tx, input.id()).await?;
+            QuotationRepository::insert_lines(&mut tx, input.id(), lines).await?;
+        }
+        tx.commit().await?;
+
+        Ok(UpdateQuotationResult::new(*input.id()))
+    }
+
+    async fn update_status(
+        &self,
+        id: &QuotationId,
+        current: &QuotationStatus,
+        next: &QuotationStatus,
+    ) -> Result<bool, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = QuotationRepository::update_status(&mut conn, id, current, next).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn mark_converted(
+        &self,
+        id: &QuotationId,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<bool, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = QuotationRepository::mark_converted(&mut conn, id, sales_order_id).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+struct QuotationRepository {}
+impl QuotationRepository {
+    async fn find_by_id(
+        conn: &mut SqliteConnection,
+        id: &QuotationId,
+    ) -> Result<Option<Quotation>, DomainError> {
+        let row = sqlx::query_as::<_, QuotationRow>("SELECT * FROM t_quotations WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        match row {
+            None => Ok(None),
+            Some(row) => Ok(Some(Self::to_quotation(conn, row).await?)),
+        }
+    }
+
+    async fn search(
+        conn: &mut SqliteConnection,
+        input: &SearchQuotationInput,
+    ) -> Result<Vec<Quotation>, DomainError> {
+        let rows: Vec<QuotationRow> = sqlx::query_as(
+            "SELECT * FROM t_quotations
+                WHERE customer_id = COALESCE(?, customer_id)
+                AND status = COALESCE(?, status)
+                AND quoted_on >= COALESCE(?, quoted_on)
+                AND quoted_on <= COALESCE(?, quoted_on)
+                ORDER BY quoted_on DESC, id DESC
+                LIMIT ? OFFSET ?",
+        )
+        .bind(input.customer_id())
+        .bind(input.status().map(|status| status.as_str()))
+        .bind(input.quoted_on_from())
+        .bind(input.quoted_on_to())
+        .bind(input.limit())
+        .bind(input.offset())
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut quotations = Vec::with_capacity(rows.len());
+        for row in rows {
+            quotations.push(Self::to_quotation(conn, row).await?);
+        }
+
+        Ok(quotations)
+    }
+
+    async fn create(
+        conn: &mut SqliteConnection,
+        input: &CreateQuotationInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(
+            "INSERT INTO t_quotations (customer_id, quoted_on, valid_until, status) VALUES (?, ?, ?, ?)",
+        )
+        .bind(input.customer_id())
+        .bind(input.quoted_on())
+        .bind(input.valid_until())
+        .bind(QuotationStatus::Draft.as_str())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn update(
+        conn: &mut SqliteConnection,
+        input: &UpdateQuotationInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let mut query_builder =
+            query_builder::QueryBuilder::<Sqlite>::new("UPDATE t_quotations SET ");
+
+        let mut separated = query_builder.separated(", ");
+        if let Some(customer_id) = input.customer_id() {
+            separated.push("customer_id = ");
+            separated.push_bind_unseparated(customer_id);
+        }
+        if let Some(quoted_on) = input.quoted_on() {
+            separated.push("quoted_on = ");
+            separated.push_bind_unseparated(quoted_on);
+        }
+        if let Some(valid_until) = input.valid_until() {
+            separated.push("valid_until = ");
+            separated.push_bind_unseparated(valid_until);
+        }
+        separated.push(format!("updated_at = {}", CURRENT_TIMESTAMP_MS));
+
+        query_builder.push(" WHERE id = ");
+        query_builder.push_bind(input.id());
+        let result = query_builder.build().execute(conn).await?;
+
+        Ok(result)
+    }
+
+    async fn update_status(
+        conn: &mut SqliteConnection,
+        id: &QuotationId,
+        current: &QuotationStatus,
+        next: &QuotationStatus,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE t_quotations SET status = ?, updated_at = {} WHERE id = ? AND status = ?",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(next.as_str())
+        .bind(id)
+        .bind(current.as_str())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn mark_converted(
+        conn: &mut SqliteConnection,
+        id: &QuotationId,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE t_quotations SET sales_order_id = ?, updated_at = {} WHERE id = ? AND sales_order_id IS NULL",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(sales_order_id)
+        .bind(id)
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn insert_lines(
+        conn: &mut SqliteConnection,
+        quotation_id: &QuotationId,
+        lines: &[QuotationLineInput],
+    ) -> Result<(), DomainError> {
+        for line in lines {
+            sqlx::query(
+                "INSERT INTO t_quotation_lines (
+                    quotation_id,
+                    product_id,
+                    quantity,
+                    unit_price
+                ) VALUES (?, ?, ?, ?)",
+            )
+            .bind(quotation_id)
+            .bind(line.product_id())
+            .bind(line.quantity())
+            .bind(line.unit_price())
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_lines(
+        conn: &mut SqliteConnection,
+        quotation_id: &QuotationId,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query("DELETE FROM t_quotation_lines WHERE quotation_id = ?")
+            .bind(quotation_id)
+            .execute(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn to_quotation(
+        conn: &mut SqliteConnection,
+        row: QuotationRow,
+    ) -> Result<Quotation, DomainError> {
+        let line_rows: Vec<QuotationLineRow> = sqlx::query_as(
+            "SELECT id, product_id, quantity, unit_price FROM t_quotation_lines WHERE quotation_id = ? ORDER BY id",
+        )
+        .bind(row.id)
+        .fetch_all(conn)
+        .await?;
+        let lines = line_rows
+            .into_iter()
+            .map(|line| {
+                QuotationLine::new(line.id, line.product_id, line.quantity, line.unit_price)
+            })
+            .collect();
+
+        Ok(Quotation::new(
+            row.id,
+            row.customer_id,
+            row.quoted_on,
+            row.valid_until,
+            QuotationStatus::from_str(&row.status).map_err(DomainError::unexpected)?,
+            lines,
+            row.sales_order_id,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use sqlx::SqlitePool;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository,
+            fixtures,
+            product_repository::SqliteProductRepository,
+            quotation_repository::SqliteQuotationRepository,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                quotation_repository::QuotationAbstructRepository,
+            },
+            usecase::{
+                quotation::{
+                    create_quotation::{CreateQuotationInput, QuotationLineInput},
+                    search_quotation::SearchQuotationInput,
+                    update_quotation::UpdateQuotationInput,
+                },
+            },
+        },
+        domain::quotation::QuotationStatus,
+        infrastructure::database::MIGRATOR,
+    };
+
+    async fn create_input(pool: &SqlitePool) -> CreateQuotationInput {
+        let customer = SqliteCustomerRespository::new(pool.clone())
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product = SqliteProductRepository::new(pool.clone())
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+
+        CreateQuotationInput::new(
+            customer.customer_id(),
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            Date::from_calendar_date(2023, Month::June, 30).unwrap(),
+            vec![QuotationLineInput::new(
+                *product.product_id(),
+                2,
+                Some(2000),
+            )],
+        )
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqliteQuotationRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+        let quotation = repository
+            .find_by_id(result.quotation_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(*quotation.status(), QuotationStatus::Draft);
+        assert_eq!(quotation.lines().len(), 1);
+        assert_eq!(quotation.total_amount(), 4000);
+        assert_eq!(*quotation.sales_order_id(), None);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqliteQuotationRepository::new(pool);
+        repository.create(&input).await.unwrap();
+
+        let params = SearchQuotationInput::new(
+            None,
+            None,
+            Some(*input.customer_id()),
+            Some(QuotationStatus::Draft),
+            Some(Date::from_calendar_date(2023, Month::June, 1).unwrap()),
+            None,
+        );
+        let quotations = repository.search(&params).await.unwrap();
+        assert_eq!(quotations.len(), 1);
+
+        let params =
+            SearchQuotationInput::new(None, None, None, Some(QuotationStatus::Sent), None, None);
+        let quotations = repository.search(&params).await.unwrap();
+        assert_eq!(quotations.len(), 0);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn update_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let product_id = *input.lines()[0].product_id();
+        let repository = SqliteQuotationRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+
+        let params = UpdateQuotationInput::new(
+            *result.quotation_id(),
+            None,
+            None,
+            Some(Date::from_calendar_date(2023, Month::July, 31).unwrap()),
+            Some(vec![
+                QuotationLineInput::new(product_id, 1, Some(1000)),
+                QuotationLineInput::new(product_id, 1, Some(500)),
+            ]),
+        );
+        repository.update(&params).await.unwrap();
+        let quotation = repository
+            .find_by_id(result.quotation_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            *quotation.valid_until(),
+            Date::from_calendar_date(2023, Month::July, 31).unwrap()
+        );
+        assert_eq!(quotation.total_amount(), 1500);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn update_status_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqliteQuotationRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+
+        let updated = repository
+            .update_status(
+                result.quotation_id(),
+                &QuotationStatus::Draft,
+                &QuotationStatus::Sent,
+            )
+            .await
+            .unwrap();
+        let updated_again = repository
+            .update_status(
+                result.quotation_id(),
+                &QuotationStatus::Draft,
+                &QuotationStatus::Sent,
+            )
+            .await
+            .unwrap();
+
+        assert!(updated);
+        assert!(!updated_again);
+    }
+}
@@ -0,0 +1,506 @@
+use crate::application::{
+    repository::receivable_repository::{ReceivableAbstructRepository, RecordPaymentResult},
+    usecase::receivable::{
+        record_payment::{PaymentAllocationInput, RecordPaymentInput},
+        search_payment::SearchPaymentInput,
+    },
+};
+use crate::domain::{
+    customer,
+    receivable::{
+        CustomerBalance, InvoiceBalance, Payment, PaymentAllocation, PaymentId, PaymentMethod,
+    },
+};
+use async_trait::async_trait;
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+use crate::adapters::gateway::connection::SqliteConnector;
+use crate::domain::error::DomainError;
+use std::str::FromStr;
+use time::{Date, PrimitiveDateTime};
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct PaymentRow {
+    id: i64,
+    customer_id: i64,
+    paid_on: Date,
+    amount: i64,
+    method: String,
+    bank_fee: i64,
+    note: Option<String>,
+    created_at: PrimitiveDateTime,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct PaymentAllocationRow {
+    id: i64,
+    invoice_id: i64,
+    amount: i64,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct InvoiceBalanceRow {
+    invoice_id: i64,
+    invoice_number: String,
+    customer_id: i64,
+    issued_on: Date,
+    total_amount: i64,
+    allocated_amount: i64,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct CustomerBalanceRow {
+    customer_id: i64,
+    customer_name: String,
+    invoiced_amount: i64,
+    credit_amount: i64,
+    allocated_amount: i64,
+}
+
+pub struct SqliteReceivableRepository {
+    connector: SqliteConnector,
+}
+impl SqliteReceivableRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl ReceivableAbstructRepository for SqliteReceivableRepository {
+    async fn find_payment_by_id(&self, id: &PaymentId) -> Result<Option<Payment>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let payment = ReceivableRepository::find_payment_by_id(&mut conn, id).await?;
+
+        Ok(payment)
+    }
+
+    async fn search_payments(
+        &self,
+        input: &SearchPaymentInput,
+    ) -> Result<Vec<Payment>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let payments = ReceivableRepository::search_payments(&mut conn, input).await?;
+
+        Ok(payments)
+    }
+
+    async fn create_payment(
+        &self,
+        input: &RecordPaymentInput,
+        allocations: &[PaymentAllocationInput],
+    ) -> Result<RecordPaymentResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let payment_id = ReceivableRepository::create_payment(&mut tx, input).await?;
+        ReceivableRepository::insert_allocations(&mut tx, &payment_id, allocations).await?;
+        tx.commit().await?;
+
+        Ok(RecordPaymentResult::new(payment_id))
+    }
+
+    async fn create_allocations(
+        &self,
+        payment_id: &PaymentId,
+        allocations: &[PaymentAllocationInput],
+    ) -> Result<(), DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        ReceivableRepository::insert_allocations(&mut tx, payment_id, allocations).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn search_invoice_balances(
+        &self,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<Vec<InvoiceBalance>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let balances =
+            ReceivableRepository::search_invoice_balances(&mut conn, customer_id).await?;
+
+        Ok(balances)
+    }
+
+    async fn search_customer_balances(
+        &self,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<Vec<CustomerBalance>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let balances =
+            ReceivableRepository::search_customer_balances(&mut conn, customer_id).await?;
+
+        Ok(balances)
+    }
+}
+
+struct ReceivableRepository {}
+impl ReceivableRepository {
+    async fn find_payment_by_id(
+        conn: &mut SqliteConnection,
+        id: &PaymentId,
+    ) -> Result<Option<Payment>, DomainError> {
+        let row = sqlx::query_as::<_, PaymentRow>(
+            "SELECT id, customer_id, paid_on, amount, method, bank_fee, note, created_at
+                FROM t_payments WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        match row {
+            None => Ok(None),
+            Some(row) => Ok(Some(Self::to_payment(conn, row).await?)),
+        }
+    }
+
+    async fn search_payments(
+        conn: &mut SqliteConnection,
+        input: &SearchPaymentInput,
+    ) -> Result<Vec<Payment>, DomainError> {
+        let rows: Vec<PaymentRow> = sqlx::query_as(
+            "SELECT id, customer_id, paid_on, amount, method, bank_fee, note, created_at
+                FROM t_payments
+                WHERE customer_id = COALESCE(?, customer_id)
+                AND paid_on >= COALESCE(?, paid_on)
+                AND paid_on <= COALESCE(?, paid_on)
+                ORDER BY paid_on DESC, id DESC
+                LIMIT ? OFFSET ?",
+        )
+        .bind(input.customer_id())
+        .bind(input.paid_on_from())
+        .bind(input.paid_on_to())
+        .bind(input.limit())
+        .bind(input.offset())
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut payments = Vec::with_capacity(rows.len());
+        for row in rows {
+            payments.push(Self::to_payment(conn, row).await?);
+        }
+
+        Ok(payments)
+    }
+
+    async fn create_payment(
+        conn: &mut SqliteConnection,
+        input: &RecordPaymentInput,
+    ) -> Result<PaymentId, DomainError> {
+        let result = sqlx::query(
+            "INSERT INTO t_payments (
+                customer_id,
+                paid_on,
+                amount,
+                method,
+                bank_fee,
+                note
+            ) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(input.customer_id())
+        .bind(input.paid_on())
+        .bind(input.amount())
+        .bind(input.method().as_str())
+        .bind(input.bank_fee())
+        .bind(input.note())
+        .execute(conn)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn insert_allocations(
+        conn: &mut SqliteConnection,
+        payment_id: &PaymentId,
+        allocations: &[PaymentAllocationInput],
+    ) -> Result<(), DomainError> {
+        for allocation in allocations {
+            sqlx::query(
+                "INSERT INTO t_payment_allocations (payment_id, invoice_id, amount) VALUES (?, ?, ?)",
+            )
+            .bind(payment_id)
+            .bind(allocation.invoice_id())
+            .bind(allocation.amount())
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn search_invoice_balances(
+        conn: &mut SqliteConnection,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<Vec<InvoiceBalance>, DomainError> {
+        let rows: Vec<InvoiceBalanceRow> = sqlx::query_as(
+            "SELECT
+                    i.id AS invoice_id,
+                    i.invoice_number,
+                    i.customer_id,
+                    i.issued_on,
+                    i.total_amount,
+                    COALESCE(SUM(a.amount), 0) AS allocated_amount
+                FROM t_invoices i
+                LEFT JOIN t_payment_allocations a ON a.invoice_id = i.id
+                WHERE i.customer_id = COALESCE(?, i.customer_id)
+                GROUP BY i.id
+                ORDER BY i.issued_on, i.id",
+        )
+        .bind(customer_id)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                InvoiceBalance::new(
+                    row.invoice_id,
+                    row.invoice_number,
+                    row.customer_id,
+                    row.issued_on,
+                    row.total_amount,
+                    row.allocated_amount,
+                )
+            })
+            .collect())
+    }
+
+    async fn search_customer_balances(
+        conn: &mut SqliteConnection,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<Vec<CustomerBalance>, DomainError> {
+        let rows: Vec<CustomerBalanceRow> = sqlx::query_as(
+            "SELECT
+                    c.id AS customer_id,
+                    c.name AS customer_name,
+                    COALESCE((SELECT SUM(i.total_amount) FROM t_invoices i WHERE i.customer_id = c.id), 0) AS invoiced_amount,
+                    COALESCE((SELECT SUM(p.amount + p.bank_fee) FROM t_payments p WHERE p.customer_id = c.id), 0) AS credit_amount,
+                    COALESCE((
+                        SELECT SUM(a.amount) FROM t_payment_allocations a
+                        INNER JOIN t_payments p ON p.id = a.payment_id
+                        WHERE p.customer_id = c.id
+                    ), 0) AS allocated_amount
+                FROM m_customers c
+                WHERE c.id = COALESCE(?, c.id)
+                AND (
+                    EXISTS (SELECT 1 FROM t_invoices i WHERE i.customer_id = c.id)
+                    OR EXISTS (SELECT 1 FROM t_payments p WHERE p.customer_id = c.id)
+                )
+                ORDER BY c.id",
+        )
+        .bind(customer_id)
+        .fetch_all(conn)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                CustomerBalance::new(
+                    row.customer_id,
+                    row.customer_name,
+                    row.invoiced_amount,
+                    row.credit_amount,
+                    row.allocated_amount,
+                )
+            })
+            .collect())
+    }
+
+    async fn to_payment(
+        conn: &mut SqliteConnection,
+        row: PaymentRow,
+    ) -> Result<Payment, DomainError> {
+        let allocation_rows: Vec<PaymentAllocationRow> = sqlx::query_as(
+            "SELECT id, invoice_id, amount FROM t_payment_allocations WHERE payment_id = ? ORDER BY id",
+        )
+        .bind(row.id)
+        .fetch_all(conn)
+        .await?;
+        let allocations = allocation_rows
+            .into_iter()
+            .map(|allocation| {
+                PaymentAllocation::new(allocation.id, allocation.invoice_id, allocation.amount)
+            })
+            .collect();
+
+        Ok(Payment::new(
+            row.id,
+            row.customer_id,
+            row.paid_on,
+            row.amount,
+            PaymentMethod::from_str(&row.method).map_err(DomainError::unexpected)?,
+            row.bank_fee,
+            row.note,
+            allocations,
+            row.created_at,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            fixtures, receivable_repository::SqliteReceivableRepository,
+            unit_of_work::SqliteUnitOfWorkFactory,
+        },
+        application::{
+            repository::receivable_repository::ReceivableAbstructRepository,
+            usecase::receivable::{
+                allocate_payment::{AllocatePaymentInput, AllocatePaymentUsecase},
+                record_payment::{
+                    PaymentAllocationInput, RecordPaymentInput, RecordPaymentUsecase,
+                },
+            },
+        },
+        domain::receivable::PaymentMethod,
+        infrastructure::database::MIGRATOR,
+    };
+
+    async fn create_invoice(
+        pool: &SqlitePool,
+        customer_id: i64,
+        month: Month,
+        total_amount: i64,
+    ) -> i64 {
+        let issued_on = Date::from_calendar_date(2023, month, 28).unwrap();
+        let result = sqlx::query(
+            "INSERT INTO t_invoices (
+                invoice_number, customer_id, issuer_registration_number, issuer_name,
+                issuer_address, recipient_name, period_from, period_to, issued_on,
+                rounding_mode, subtotal, tax_amount, total_amount
+            ) VALUES (
+                ?, ?, 'T1234567890123', '自社', '東京都', 'sample.inc', ?, ?, ?,
+                'round_down', ?, 0, ?
+            )",
+        )
+        .bind(format!("INV-2023{:02}-0001", month as u8))
+        .bind(customer_id)
+        .bind(issued_on)
+        .bind(issued_on)
+        .bind(issued_on)
+        .bind(total_amount)
+        .bind(total_amount)
+        .execute(pool)
+        .await
+        .unwrap();
+
+        result.last_insert_rowid()
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn record_payment_test(pool: SqlitePool) {
+        let customer_id = fixtures::create_customer(&pool).await;
+        let april = create_invoice(&pool, customer_id, Month::April, 10000).await;
+        let may = create_invoice(&pool, customer_id, Month::May, 5000).await;
+        let repository = Arc::new(SqliteReceivableRepository::new(pool.clone()));
+        let usecase = RecordPaymentUsecase::new(Arc::new(SqliteUnitOfWorkFactory::new(pool)));
+        let paid_on = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+
+        let input = RecordPaymentInput::new(
+            customer_id,
+            paid_on,
+            11560,
+            PaymentMethod::BankTransfer,
+            440,
+            None,
+            None,
+        );
+        let output = usecase.record(input).await.unwrap();
+        let payment = repository
+            .find_payment_by_id(output.result().payment_id())
+            .await
+            .unwrap()
+            .unwrap();
+        let balances = repository
+            .search_invoice_balances(&Some(customer_id))
+            .await
+            .unwrap();
+
+        assert_eq!(payment.allocated_amount(), 12000);
+        assert_eq!(*balances[0].invoice_id(), april);
+        assert_eq!(*balances[0].outstanding_amount(), 0);
+        assert_eq!(*balances[1].invoice_id(), may);
+        assert_eq!(*balances[1].outstanding_amount(), 3000);
+
+        let input = RecordPaymentInput::new(
+            customer_id,
+            paid_on,
+            1000,
+            PaymentMethod::Cash,
+            0,
+            None,
+            Some(vec![PaymentAllocationInput::new(april, 1000)]),
+        );
+        assert!(usecase.record(input).await.is_err());
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn over_payment_test(pool: SqlitePool) {
+        let customer_id = fixtures::create_customer(&pool).await;
+        create_invoice(&pool, customer_id, Month::April, 10000).await;
+        let repository = Arc::new(SqliteReceivableRepository::new(pool.clone()));
+        let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
+        let paid_on = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+
+        let input = RecordPaymentInput::new(
+            customer_id,
+            paid_on,
+            13000,
+            PaymentMethod::BankTransfer,
+            0,
+            Some(String::from("前受")),
+            None,
+        );
+        let output = RecordPaymentUsecase::new(unit_of_work.clone())
+            .record(input)
+            .await
+            .unwrap();
+        let balances = repository
+            .search_customer_balances(&Some(customer_id))
+            .await
+            .unwrap();
+
+        assert_eq!(*balances[0].outstanding_amount(), 0);
+        assert_eq!(*balances[0].unallocated_amount(), 3000);
+        assert_eq!(*balances[0].balance(), -3000);
+
+        let may = create_invoice(&pool, customer_id, Month::May, 5000).await;
+        let usecase = AllocatePaymentUsecase::new(unit_of_work);
+        usecase
+            .allocate(AllocatePaymentInput::new(
+                *output.result().payment_id(),
+                None,
+            ))
+            .await
+            .unwrap();
+        let balances = repository
+            .search_invoice_balances(&Some(customer_id))
+            .await
+            .unwrap();
+        let customer_balances = repository.search_customer_balances(&None).await.unwrap();
+
+        assert_eq!(*balances[1].invoice_id(), may);
+        assert_eq!(*balances[1].outstanding_amount(), 2000);
+        assert_eq!(*customer_balances[0].unallocated_amount(), 0);
+        assert_eq!(*customer_balances[0].balance(), 2000);
+        assert!(usecase
+            .allocate(AllocatePaymentInput::new(
+                *output.result().payment_id(),
+                None
+            ))
+            .await
+            .is_err());
+    }
+}
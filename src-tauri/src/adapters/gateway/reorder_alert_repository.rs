@@ -0,0 +1,135 @@
+use crate::application::repository::reorder_alert_repository::ReorderAlertAbstructRepository;
+use crate::domain::{
+    product::ProductId, reorder_alert::ReorderAlert, sales_order::SalesOrderStatus,
+};
+use async_trait::async_trait;
+use sqlx::{pool::PoolConnection, Sqlite, SqlitePool};
+use crate::domain::error::DomainError;
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct ProductStockRow {
+    id: i64,
+    code: String,
+    name: String,
+    standard_stock_quantity: i64,
+    on_hand_quantity: i64,
+    committed_quantity: i64,
+}
+
+pub struct SqliteReorderAlertRepository {
+    pool: SqlitePool,
+}
+impl SqliteReorderAlertRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReorderAlertAbstructRepository for SqliteReorderAlertRepository {
+    async fn search(&self, product_id: &Option<ProductId>) -> Result<Vec<ReorderAlert>, DomainError> {
+        let mut conn = self.pool.acquire().await?;
+        let reorder_alerts = ReorderAlertRepository::search(&mut conn, product_id).await?;
+
+        Ok(reorder_alerts)
+    }
+}
+
+struct ReorderAlertRepository {}
+impl ReorderAlertRepository {
+    async fn search(
+        conn: &mut PoolConnection<Sqlite>,
+        product_id: &Option<ProductId>,
+    ) -> Result<Vec<ReorderAlert>, DomainError> {
+        let rows: Vec<ProductStockRow> = sqlx::query_as(
+            "SELECT * FROM (
+                SELECT
+                    p.id,
+                    p.code,
+                    p.name,
+                    p.standard_stock_quantity,
+                    COALESCE((
+                        SELECT SUM(m.quantity) FROM t_stock_movements m WHERE m.product_id = p.id
+                    ), 0) AS on_hand_quantity,
+                    COALESCE((
+                        SELECT SUM(l.quantity) FROM t_sales_order_lines l
+                            INNER JOIN t_sales_orders o ON o.id = l.sales_order_id
+                            WHERE l.product_id = p.id AND o.status = ?
+                    ), 0) AS committed_quantity
+                FROM m_products p
+                WHERE p.id = COALESCE(?, p.id) AND p.deleted_at IS NULL
+            )
+            WHERE on_hand_quantity < standard_stock_quantity
+            ORDER BY (standard_stock_quantity - on_hand_quantity) DESC, id",
+        )
+        .bind(SalesOrderStatus::Ordered.as_str())
+        .bind(product_id)
+        .fetch_all(conn)
+        .await?;
+
+        let reorder_alerts = rows
+            .into_iter()
+            .filter_map(|row| {
+                ReorderAlert::evaluate(
+                    row.id,
+                    row.code,
+                    row.name,
+                    row.standard_stock_quantity,
+                    row.on_hand_quantity,
+                    row.committed_quantity,
+                )
+            })
+            .collect();
+
+        Ok(reorder_alerts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use sqlx::SqlitePool;
+
+    use crate::{
+        adapters::gateway::{fixtures, reorder_alert_repository::SqliteReorderAlertRepository},
+        application::repository::reorder_alert_repository::ReorderAlertAbstructRepository,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool: SqlitePool) {
+        fixtures::create_product(&pool, "product001").await;
+        fixtures::create_customer(&pool).await;
+        let mut conn = pool.acquire().await.unwrap();
+        sqlx::query(
+            "INSERT INTO t_sales_orders (id, customer_id, order_date, status) VALUES (1, 1, \"2023-06-01\", \"ordered\")",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO
+                t_sales_order_lines (sales_order_id, product_id, quantity, unit_price)
+                VALUES (1, 1, 3, 2000)",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO
+                t_stock_movements (product_id, movement_type, quantity, moved_on)
+                VALUES (1, \"receipt\", 8, \"2023-06-01\")",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+        let repository = SqliteReorderAlertRepository::new(pool);
+        let reorder_alerts = repository.search(&Some(1)).await.unwrap();
+
+        assert_eq!(reorder_alerts.len(), 1);
+        assert_eq!(*reorder_alerts[0].on_hand_quantity(), 8);
+        assert_eq!(*reorder_alerts[0].shortfall(), 2);
+        assert_eq!(*reorder_alerts[0].suggested_reorder_quantity(), 5);
+    }
+}
@@ -0,0 +1,431 @@
+use crate::application::{
+    repository::sales_order_repository::{
+        CancelSalesOrderResult, CreateSalesOrderResult, OrderAbstructRepository,
+        UpdateSalesOrderResult,
+    },
+    usecase::sales_order::{
+        create_sales_order::{CreateSalesOrderInput, SalesOrderLineInput},
+        search_sales_order::SearchSalesOrderInput,
+        update_sales_order::UpdateSalesOrderInput,
+    },
+};
+use crate::domain::sales_order::{SalesOrder, SalesOrderId, SalesOrderLine, SalesOrderStatus};
+use async_trait::async_trait;
+use sqlx::{query_builder, sqlite::SqliteQueryResult, Connection, Sqlite, SqliteConnection, SqlitePool};
+use crate::adapters::gateway::{connection::SqliteConnector, CURRENT_TIMESTAMP_MS};
+use crate::domain::error::DomainError;
+use std::str::FromStr;
+use time::{Date, PrimitiveDateTime};
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct SalesOrderRow {
+    id: i64,
+    customer_id: i64,
+    order_date: Date,
+    status: String,
+    created_at: PrimitiveDateTime,
+    updated_at: PrimitiveDateTime,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct SalesOrderLineRow {
+    id: i64,
+    product_id: i64,
+    quantity: i64,
+    unit_price: i64,
+    invoiced: bool,
+}
+
+pub struct SqliteSalesOrderRepository {
+    connector: SqliteConnector,
+}
+impl SqliteSalesOrderRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl OrderAbstructRepository for SqliteSalesOrderRepository {
+    async fn find_by_id(&self, id: &SalesOrderId) -> Result<Option<SalesOrder>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let sales_order = SalesOrderRepository::find_by_id(&mut conn, id).await?;
+
+        Ok(sales_order)
+    }
+
+    async fn search(&self, input: &SearchSalesOrderInput) -> Result<Vec<SalesOrder>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let sales_orders = SalesOrderRepository::search(&mut conn, input).await?;
+
+        Ok(sales_orders)
+    }
+
+    async fn create(
+        &self,
+        input: &CreateSalesOrderInput,
+    ) -> Result<CreateSalesOrderResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        let result = SalesOrderRepository::create(&mut tx, input).await?;
+        let sales_order_id = result.last_insert_rowid();
+        SalesOrderRepository::insert_lines(&mut tx, &sales_order_id, input.lines()).await?;
+        tx.commit().await?;
+
+        Ok(CreateSalesOrderResult::new(sales_order_id))
+    }
+
+    async fn update(
+        &self,
+        input: &UpdateSalesOrderInput,
+    ) -> Result<UpdateSalesOrderResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let mut tx = conn.begin().await?;
+        SalesOrderRepository::update(&mut tx, input).await?;
+        if let Some(lines) = input.lines() {
+            SalesOrderRepository::delete_lines(&mut tx, input.id()).await?;
+            SalesOrderRepository::insert_lines(&mut tx, input.id(), lines).await?;
+        }
+        tx.commit().await?;
+
+        Ok(UpdateSalesOrderResult::new(*input.id()))
+    }
+
+    async fn cancel(&self, id: &SalesOrderId) -> Result<CancelSalesOrderResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = SalesOrderRepository::cancel(&mut conn, id).await?;
+
+        Ok(CancelSalesOrderResult::new(result.rows_affected() > 0))
+    }
+}
+
+struct SalesOrderRepository {}
+impl SalesOrderRepository {
+    async fn find_by_id(
+        conn: &mut SqliteConnection,
+        id: &SalesOrderId,
+    ) -> Result<Option<SalesOrder>, DomainError> {
+        let row = sqlx::query_as::<_, SalesOrderRow>("SELECT * FROM t_sales_orders WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        match row {
+            None => Ok(None),
+            Some(row) => Ok(Some(Self::to_sales_order(conn, row).await?)),
+        }
+    }
+
+    async fn search(
+        conn: &mut SqliteConnection,
+        input: &SearchSalesOrderInput,
+    ) -> Result<Vec<SalesOrder>, DomainError> {
+        let order = match input.after_id() {
+            Some(_) => "id",
+            None => "order_date DESC, id DESC",
+        };
+        let sql = format!(
+            "SELECT * FROM t_sales_orders
+                WHERE customer_id = COALESCE(?, customer_id)
+                AND status = COALESCE(?, status)
+                AND order_date >= COALESCE(?, order_date)
+                AND order_date <= COALESCE(?, order_date)
+                AND (? IS NULL OR id > ?)
+                ORDER BY {}
+                LIMIT ? OFFSET ?",
+            order
+        );
+        let rows: Vec<SalesOrderRow> = sqlx::query_as(&sql)
+            .bind(input.customer_id())
+            .bind(input.status().map(|status| status.as_str()))
+            .bind(input.order_date_from())
+            .bind(input.order_date_to())
+            .bind(input.after_id())
+            .bind(input.after_id())
+            .bind(input.limit())
+            .bind(input.offset())
+            .fetch_all(&mut *conn)
+            .await?;
+
+        let mut sales_orders = Vec::with_capacity(rows.len());
+        for row in rows {
+            sales_orders.push(Self::to_sales_order(conn, row).await?);
+        }
+
+        Ok(sales_orders)
+    }
+
+    async fn create(
+        conn: &mut SqliteConnection,
+        input: &CreateSalesOrderInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(
+            "INSERT INTO t_sales_orders (customer_id, order_date, status) VALUES (?, ?, ?)",
+        )
+        .bind(input.customer_id())
+        .bind(input.order_date())
+        .bind(SalesOrderStatus::Ordered.as_str())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn update(
+        conn: &mut SqliteConnection,
+        input: &UpdateSalesOrderInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let mut query_builder =
+            query_builder::QueryBuilder::<Sqlite>::new("UPDATE t_sales_orders SET ");
+
+        let mut separated = query_builder.separated(", ");
+        if let Some(customer_id) = input.customer_id() {
+            separated.push("customer_id = ");
+            separated.push_bind_unseparated(customer_id);
+        }
+        if let Some(order_date) = input.order_date() {
+            separated.push("order_date = ");
+            separated.push_bind_unseparated(order_date);
+        }
+        if let Some(status) = input.status() {
+            separated.push("status = ");
+            separated.push_bind_unseparated(status.as_str());
+        }
+        separated.push(format!("updated_at = {}", CURRENT_TIMESTAMP_MS));
+
+        query_builder.push(" WHERE id = ");
+        query_builder.push_bind(input.id());
+        let result = query_builder.build().execute(conn).await?;
+
+        Ok(result)
+    }
+
+    async fn cancel(
+        conn: &mut SqliteConnection,
+        id: &SalesOrderId,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(&format!(
+            "UPDATE t_sales_orders SET status = ?, updated_at = {}
+                WHERE id = ? AND status != ?
+                AND NOT EXISTS (SELECT 1 FROM t_invoice_lines WHERE sales_order_id = t_sales_orders.id)",
+            CURRENT_TIMESTAMP_MS
+        ))
+        .bind(SalesOrderStatus::Cancelled.as_str())
+        .bind(id)
+        .bind(SalesOrderStatus::Cancelled.as_str())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn insert_lines(
+        conn: &mut SqliteConnection,
+        sales_order_id: &SalesOrderId,
+        lines: &[SalesOrderLineInput],
+    ) -> Result<(), DomainError> {
+        for line in lines {
+            sqlx::query(
+                "INSERT INTO t_sales_order_lines (
+                    sales_order_id,
+                    product_id,
+                    quantity,
+                    unit_price
+                ) VALUES (?, ?, ?, ?)",
+            )
+            .bind(sales_order_id)
+            .bind(line.product_id())
+            .bind(line.quantity())
+            .bind(line.unit_price())
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_lines(
+        conn: &mut SqliteConnection,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query("DELETE FROM t_sales_order_lines WHERE sales_order_id = ?")
+            .bind(sales_order_id)
+            .execute(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn to_sales_order(
+        conn: &mut SqliteConnection,
+        row: SalesOrderRow,
+    ) -> Result<SalesOrder, DomainError> {
+        let line_rows: Vec<SalesOrderLineRow> = sqlx::query_as(
+            "SELECT id, product_id, quantity, unit_price,
+                EXISTS (
+                    SELECT 1 FROM t_invoice_lines
+                    WHERE t_invoice_lines.sales_order_line_id = t_sales_order_lines.id
+                ) AS invoiced
+                FROM t_sales_order_lines WHERE sales_order_id = ? ORDER BY id",
+        )
+        .bind(row.id)
+        .fetch_all(conn)
+        .await?;
+        let lines = line_rows
+            .into_iter()
+            .map(|line| {
+                SalesOrderLine::new(
+                    line.id,
+                    line.product_id,
+                    line.quantity,
+                    line.unit_price,
+                    line.invoiced,
+                )
+            })
+            .collect();
+
+        Ok(SalesOrder::new(
+            row.id,
+            row.customer_id,
+            row.order_date,
+            SalesOrderStatus::from_str(&row.status).map_err(DomainError::unexpected)?,
+            lines,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use sqlx::SqlitePool;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository,
+            fixtures,
+            product_repository::SqliteProductRepository,
+            sales_order_repository::SqliteSalesOrderRepository,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                sales_order_repository::OrderAbstructRepository,
+            },
+            usecase::{
+                sales_order::{
+                    create_sales_order::{CreateSalesOrderInput, SalesOrderLineInput},
+                    search_sales_order::SearchSalesOrderInput,
+                    update_sales_order::UpdateSalesOrderInput,
+                },
+            },
+        },
+        domain::sales_order::SalesOrderStatus,
+        infrastructure::database::MIGRATOR,
+    };
+
+    async fn create_input(pool: &SqlitePool) -> CreateSalesOrderInput {
+        let customer = SqliteCustomerRespository::new(pool.clone())
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product = SqliteProductRepository::new(pool.clone())
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+
+        CreateSalesOrderInput::new(
+            customer.customer_id(),
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            vec![SalesOrderLineInput::new(*product.product_id(), 2, Some(2000))],
+        )
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqliteSalesOrderRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+        let sales_order = repository.find_by_id(result.sales_order_id()).await.unwrap();
+
+        match sales_order {
+            Some(sales_order) => {
+                assert_eq!(*sales_order.status(), SalesOrderStatus::Ordered);
+                assert_eq!(sales_order.lines().len(), 1);
+                assert_eq!(sales_order.total_amount(), 4000);
+            }
+            None => {
+                panic!();
+            }
+        }
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqliteSalesOrderRepository::new(pool);
+        repository.create(&input).await.unwrap();
+
+        let input = SearchSalesOrderInput::new(
+            None,
+            None,
+            Some(*input.customer_id()),
+            Some(SalesOrderStatus::Ordered),
+            Some(Date::from_calendar_date(2023, Month::June, 1).unwrap()),
+            None,
+        );
+        let sales_orders = repository.search(&input).await.unwrap();
+
+        assert_eq!(sales_orders.len(), 1);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn update_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let product_id = *input.lines()[0].product_id();
+        let repository = SqliteSalesOrderRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+
+        let params = UpdateSalesOrderInput::new(
+            *result.sales_order_id(),
+            None,
+            None,
+            Some(SalesOrderStatus::Delivered),
+            Some(vec![
+                SalesOrderLineInput::new(product_id, 1, Some(1000)),
+                SalesOrderLineInput::new(product_id, 1, Some(500)),
+            ]),
+        );
+        repository.update(&params).await.unwrap();
+        let sales_order = repository
+            .find_by_id(result.sales_order_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(*sales_order.status(), SalesOrderStatus::Delivered);
+        assert_eq!(sales_order.total_amount(), 1500);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn cancel_test(pool: SqlitePool) {
+        let input = create_input(&pool).await;
+        let repository = SqliteSalesOrderRepository::new(pool);
+        let result = repository.create(&input).await.unwrap();
+
+        let cancelled = repository.cancel(result.sales_order_id()).await.unwrap();
+        let cancelled_again = repository.cancel(result.sales_order_id()).await.unwrap();
+
+        assert!(*cancelled.result());
+        assert!(!*cancelled_again.result());
+    }
+}
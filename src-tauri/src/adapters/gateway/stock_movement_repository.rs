@@ -0,0 +1,214 @@
+use crate::application::{
+    repository::stock_movement_repository::{
+        PostStockMovementResult, StockMovementAbstructRepository,
+    },
+    usecase::stock_movement::{
+        post_stock_movement::PostStockMovementInput,
+        search_stock_movement::SearchStockMovementInput,
+    },
+};
+use crate::domain::product::ProductId;
+use crate::domain::stock_movement::{StockLevel, StockMovement, StockMovementType};
+use async_trait::async_trait;
+use sqlx::{sqlite::SqliteQueryResult, SqliteConnection, SqlitePool};
+use crate::adapters::gateway::connection::SqliteConnector;
+use crate::domain::error::DomainError;
+use std::str::FromStr;
+use time::{Date, PrimitiveDateTime};
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct StockMovementRow {
+    id: i64,
+    product_id: i64,
+    movement_type: String,
+    quantity: i64,
+    moved_on: Date,
+    note: Option<String>,
+    created_at: PrimitiveDateTime,
+}
+
+pub struct SqliteStockMovementRepository {
+    connector: SqliteConnector,
+}
+impl SqliteStockMovementRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            connector: SqliteConnector::from(pool),
+        }
+    }
+
+    pub(crate) fn with_connector(connector: SqliteConnector) -> Self {
+        Self { connector }
+    }
+}
+
+#[async_trait]
+impl StockMovementAbstructRepository for SqliteStockMovementRepository {
+    async fn search(
+        &self,
+        input: &SearchStockMovementInput,
+    ) -> Result<Vec<StockMovement>, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let stock_movements = StockMovementRepository::search(&mut conn, input).await?;
+
+        Ok(stock_movements)
+    }
+
+    async fn create(
+        &self,
+        input: &PostStockMovementInput,
+    ) -> Result<PostStockMovementResult, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let result = StockMovementRepository::create(&mut conn, input).await?;
+
+        Ok(PostStockMovementResult::new(result.last_insert_rowid()))
+    }
+
+    async fn find_on_hand(&self, product_id: &ProductId) -> Result<StockLevel, DomainError> {
+        let mut conn = self.connector.acquire().await?;
+        let on_hand_quantity = StockMovementRepository::sum_quantity(&mut conn, product_id).await?;
+
+        Ok(StockLevel::new(*product_id, on_hand_quantity))
+    }
+}
+
+struct StockMovementRepository {}
+impl StockMovementRepository {
+    async fn search(
+        conn: &mut SqliteConnection,
+        input: &SearchStockMovementInput,
+    ) -> Result<Vec<StockMovement>, DomainError> {
+        let rows: Vec<StockMovementRow> = sqlx::query_as(
+            "SELECT * FROM t_stock_movements
+                WHERE product_id = COALESCE(?, product_id)
+                AND movement_type = COALESCE(?, movement_type)
+                AND moved_on >= COALESCE(?, moved_on)
+                AND moved_on <= COALESCE(?, moved_on)
+                ORDER BY moved_on DESC, id DESC
+                LIMIT ? OFFSET ?",
+        )
+        .bind(input.product_id())
+        .bind(input.movement_type().map(|movement_type| movement_type.as_str()))
+        .bind(input.moved_on_from())
+        .bind(input.moved_on_to())
+        .bind(input.limit())
+        .bind(input.offset())
+        .fetch_all(conn)
+        .await?;
+
+        let mut stock_movements = Vec::with_capacity(rows.len());
+        for row in rows {
+            stock_movements.push(StockMovement::new(
+                row.id,
+                row.product_id,
+                StockMovementType::from_str(&row.movement_type).map_err(DomainError::unexpected)?,
+                row.quantity,
+                row.moved_on,
+                row.note,
+                row.created_at,
+            ));
+        }
+
+        Ok(stock_movements)
+    }
+
+    async fn create(
+        conn: &mut SqliteConnection,
+        input: &PostStockMovementInput,
+    ) -> Result<SqliteQueryResult, DomainError> {
+        let result = sqlx::query(
+            "INSERT INTO t_stock_movements (
+                product_id,
+                movement_type,
+                quantity,
+                moved_on,
+                note
+            ) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(input.product_id())
+        .bind(input.movement_type().as_str())
+        .bind(input.movement_type().signed_quantity(*input.quantity()))
+        .bind(input.moved_on())
+        .bind(input.note())
+        .execute(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn sum_quantity(
+        conn: &mut SqliteConnection,
+        product_id: &ProductId,
+    ) -> Result<i64, DomainError> {
+        let (on_hand_quantity,): (i64,) = sqlx::query_as(
+            "SELECT COALESCE(SUM(quantity), 0) FROM t_stock_movements WHERE product_id = ?",
+        )
+        .bind(product_id)
+        .fetch_one(conn)
+        .await?;
+
+        Ok(on_hand_quantity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            fixtures, stock_movement_repository::SqliteStockMovementRepository,
+        },
+        application::{
+            repository::stock_movement_repository::StockMovementAbstructRepository,
+            usecase::stock_movement::{
+                post_stock_movement::PostStockMovementInput,
+                search_stock_movement::SearchStockMovementInput,
+            },
+        },
+        domain::stock_movement::StockMovementType,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let product_id = fixtures::create_product(&pool, "product001").await;
+        let repository = SqliteStockMovementRepository::new(pool);
+        let input = PostStockMovementInput::new(
+            product_id,
+            StockMovementType::Shipment,
+            5,
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            Some(String::from("出荷")),
+        );
+        repository.create(&input).await.unwrap();
+
+        let input = SearchStockMovementInput::new(None, None, Some(product_id), None, None, None);
+        let stock_movements = repository.search(&input).await.unwrap();
+
+        assert_eq!(stock_movements.len(), 1);
+        assert_eq!(*stock_movements[0].quantity(), -5);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn find_on_hand_test(pool: SqlitePool) {
+        let product_id = fixtures::create_product(&pool, "product001").await;
+        let repository = SqliteStockMovementRepository::new(pool);
+
+        let stock_level = repository.find_on_hand(&product_id).await.unwrap();
+        assert_eq!(*stock_level.on_hand_quantity(), 0);
+
+        let input = PostStockMovementInput::new(
+            product_id,
+            StockMovementType::Receipt,
+            12,
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            None,
+        );
+        repository.create(&input).await.unwrap();
+        let stock_level = repository.find_on_hand(&product_id).await.unwrap();
+
+        assert_eq!(*stock_level.on_hand_quantity(), 12);
+    }
+}
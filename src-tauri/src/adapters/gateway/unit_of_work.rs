@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::adapters::gateway::{
+    company_profile_repository::SqliteCompanyProfileRepository,
+    connection::{SharedTransaction, SqliteConnector},
+    customer_repository::SqliteCustomerRespository,
+    invoice_repository::SqliteInvoiceRepository,
+    price_list_repository::SqlitePriceListRepository,
+    product_repository::SqliteProductRepository,
+    quotation_repository::SqliteQuotationRepository,
+    receivable_repository::SqliteReceivableRepository,
+    sales_order_repository::SqliteSalesOrderRepository,
+    stock_movement_repository::SqliteStockMovementRepository,
+};
+use crate::application::repository::{
+    company_profile_repository::CompanyProfileAbstructRepository,
+    customer_repository::CustomerAbstructRepository,
+    invoice_repository::InvoiceAbstructRepository,
+    price_list_repository::PriceListAbstructRepository,
+    product_repository::ProductAbstructRepository,
+    quotation_repository::QuotationAbstructRepository,
+    receivable_repository::ReceivableAbstructRepository,
+    sales_order_repository::OrderAbstructRepository,
+    stock_movement_repository::StockMovementAbstructRepository,
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use crate::domain::error::DomainError;
+
+/// One SQLite transaction shared by the repositories it hands out.
+pub struct SqliteUnitOfWork {
+    transaction: SharedTransaction,
+}
+impl SqliteUnitOfWork {
+    pub async fn begin(pool: &SqlitePool) -> Result<Self, DomainError> {
+        let transaction = pool.begin().await?;
+
+        Ok(Self {
+            transaction: Arc::new(Mutex::new(Some(transaction))),
+        })
+    }
+
+    fn connector(&self) -> SqliteConnector {
+        SqliteConnector::Transaction(self.transaction.clone())
+    }
+}
+impl Drop for SqliteUnitOfWork {
+    /// Rolls back now rather than when the last repository handed out is dropped.
+    fn drop(&mut self) {
+        if let Some(mut transaction) = self.transaction.try_lock() {
+            transaction.take();
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SqliteUnitOfWork {
+    fn company_profile_repository(&self) -> Arc<dyn CompanyProfileAbstructRepository> {
+        Arc::new(SqliteCompanyProfileRepository::with_connector(
+            self.connector(),
+        ))
+    }
+
+    fn customer_repository(&self) -> Arc<dyn CustomerAbstructRepository> {
+        Arc::new(SqliteCustomerRespository::with_connector(self.connector()))
+    }
+
+    fn invoice_repository(&self) -> Arc<dyn InvoiceAbstructRepository> {
+        Arc::new(SqliteInvoiceRepository::with_connector(self.connector()))
+    }
+
+    fn price_list_repository(&self) -> Arc<dyn PriceListAbstructRepository> {
+        Arc::new(SqlitePriceListRepository::with_connector(self.connector()))
+    }
+
+    fn product_repository(&self) -> Arc<dyn ProductAbstructRepository> {
+        Arc::new(SqliteProductRepository::with_connector(self.connector()))
+    }
+
+    fn quotation_repository(&self) -> Arc<dyn QuotationAbstructRepository> {
+        Arc::new(SqliteQuotationRepository::with_connector(self.connector()))
+    }
+
+    fn receivable_repository(&self) -> Arc<dyn ReceivableAbstructRepository> {
+        Arc::new(SqliteReceivableRepository::with_connector(self.connector()))
+    }
+
+    fn sales_order_repository(&self) -> Arc<dyn OrderAbstructRepository> {
+        Arc::new(SqliteSalesOrderRepository::with_connector(self.connector()))
+    }
+
+    fn stock_movement_repository(&self) -> Arc<dyn StockMovementAbstructRepository> {
+        Arc::new(SqliteStockMovementRepository::with_connector(
+            self.connector(),
+        ))
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), DomainError> {
+        let transaction = self
+            .transaction
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| DomainError::unexpected("the unit of work is already finished"))?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+pub struct SqliteUnitOfWorkFactory {
+    pool: SqlitePool,
+}
+impl SqliteUnitOfWorkFactory {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for SqliteUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, DomainError> {
+        let unit_of_work = SqliteUnitOfWork::begin(&self.pool).await?;
+
+        Ok(Box::new(unit_of_work))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{
+        sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+        SqlitePool,
+    };
+
+    use crate::{
+        adapters::gateway::{
+            fixtures, product_repository::SqliteProductRepository,
+            unit_of_work::SqliteUnitOfWorkFactory,
+        },
+        application::{
+            repository::{
+                product_repository::ProductAbstructRepository, unit_of_work::UnitOfWorkFactory,
+            },
+            usecase::product::update_product::UpdateProductInput,
+        },
+        infrastructure::{
+            audit::{audited, AuditActor},
+            database::MIGRATOR,
+        },
+    };
+
+    async fn count(pool: &SqlitePool, table: &str) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn commit_test(pool_options: SqlitePoolOptions, connect_options: SqliteConnectOptions) {
+        let pool = audited(pool_options, AuditActor::default())
+            .connect_with(connect_options)
+            .await
+            .unwrap();
+        let unit_of_work = SqliteUnitOfWorkFactory::new(pool.clone())
+            .begin()
+            .await
+            .unwrap();
+        let product_repository = unit_of_work.product_repository();
+        let result = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let product = product_repository
+            .find_by_id(result.product_id())
+            .await
+            .unwrap();
+        assert!(product.is_some());
+        unit_of_work
+            .customer_repository()
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        unit_of_work.commit().await.unwrap();
+
+        assert_eq!(count(&pool, "m_products").await, 1);
+        assert_eq!(count(&pool, "m_customers").await, 1);
+        assert_eq!(count(&pool, "t_audit_logs").await, 2);
+
+        let error = product_repository
+            .create(&fixtures::product_input("product002"))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "unexpected");
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn rollback_test(pool_options: SqlitePoolOptions, connect_options: SqliteConnectOptions) {
+        let pool = audited(pool_options, AuditActor::default())
+            .connect_with(connect_options)
+            .await
+            .unwrap();
+        let unit_of_work = SqliteUnitOfWorkFactory::new(pool.clone())
+            .begin()
+            .await
+            .unwrap();
+        let product_repository = unit_of_work.product_repository();
+        product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        unit_of_work
+            .customer_repository()
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let error = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "conflict");
+        drop(unit_of_work);
+
+        assert_eq!(count(&pool, "m_products").await, 0);
+        assert_eq!(count(&pool, "m_customers").await, 0);
+        assert_eq!(count(&pool, "t_audit_logs").await, 0);
+
+        // An update failing inside the unit of work rolls back to its savepoint only.
+        let unit_of_work = SqliteUnitOfWorkFactory::new(pool.clone())
+            .begin()
+            .await
+            .unwrap();
+        let product_repository = unit_of_work.product_repository();
+        product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let result = product_repository
+            .create(&fixtures::product_input("product002"))
+            .await
+            .unwrap();
+        let input = UpdateProductInput::new(
+            *result.product_id(),
+            None,
+            Some(String::from("product001")),
+            None,
+            None,
+            None,
+            None,
+        );
+        let error = product_repository.update(&input).await.unwrap_err();
+        assert_eq!(error.code(), "conflict");
+        unit_of_work.commit().await.unwrap();
+
+        let product = SqliteProductRepository::new(pool)
+            .find_by_code("product002")
+            .await
+            .unwrap();
+        assert!(product.is_some());
+    }
+}
@@ -0,0 +1,188 @@
+use crate::application::{
+    repository::user_repository::UserAbstructRepository,
+    usecase::user::{create_user::CreateUserInput, sign_in_user::SignInUserInput},
+};
+use crate::domain::error::DomainError;
+use crate::domain::user::{Role, User, UserId};
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use async_trait::async_trait;
+use sqlx::{pool::PoolConnection, Sqlite, SqlitePool};
+use std::str::FromStr;
+use time::PrimitiveDateTime;
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct UserRow {
+    id: i64,
+    name: String,
+    password_hash: String,
+    role: String,
+    created_at: PrimitiveDateTime,
+}
+impl UserRow {
+    fn into_user(self) -> Result<User, DomainError> {
+        Ok(User::new(
+            self.id,
+            self.name,
+            Role::from_str(&self.role).map_err(DomainError::unexpected)?,
+            self.created_at,
+        ))
+    }
+}
+
+fn hash_password(password: &str) -> Result<String, DomainError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(DomainError::unexpected)?;
+
+    Ok(password_hash.to_string())
+}
+
+fn verify_password(password: &str, password_hash: &str) -> Result<bool, DomainError> {
+    let password_hash = PasswordHash::new(password_hash).map_err(DomainError::unexpected)?;
+
+    Ok(Argon2::default()
+        .verify_password(password.as_bytes(), &password_hash)
+        .is_ok())
+}
+
+pub struct SqliteUserRepository {
+    pool: SqlitePool,
+}
+impl SqliteUserRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserAbstructRepository for SqliteUserRepository {
+    async fn search(&self) -> Result<Vec<User>, DomainError> {
+        let mut conn = self.pool.acquire().await?;
+        let users = UserRepository::search(&mut conn).await?;
+
+        Ok(users)
+    }
+
+    async fn count(&self) -> Result<i64, DomainError> {
+        let mut conn = self.pool.acquire().await?;
+        let count = UserRepository::count(&mut conn).await?;
+
+        Ok(count)
+    }
+
+    async fn create(&self, input: &CreateUserInput) -> Result<UserId, DomainError> {
+        let password_hash = hash_password(input.password())?;
+        let mut conn = self.pool.acquire().await?;
+        let user_id = UserRepository::create(&mut conn, input, &password_hash).await?;
+
+        Ok(user_id)
+    }
+
+    async fn authenticate(&self, input: &SignInUserInput) -> Result<Option<User>, DomainError> {
+        let mut conn = self.pool.acquire().await?;
+        let row = UserRepository::find_by_name(&mut conn, input.name()).await?;
+        match row {
+            Some(row) if verify_password(input.password(), &row.password_hash)? => {
+                Ok(Some(row.into_user()?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+struct UserRepository {}
+impl UserRepository {
+    async fn search(conn: &mut PoolConnection<Sqlite>) -> Result<Vec<User>, DomainError> {
+        let rows: Vec<UserRow> = sqlx::query_as("SELECT * FROM m_users ORDER BY id")
+            .fetch_all(conn)
+            .await?;
+
+        rows.into_iter().map(UserRow::into_user).collect()
+    }
+
+    async fn count(conn: &mut PoolConnection<Sqlite>) -> Result<i64, DomainError> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM m_users")
+            .fetch_one(conn)
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn find_by_name(
+        conn: &mut PoolConnection<Sqlite>,
+        name: &str,
+    ) -> Result<Option<UserRow>, DomainError> {
+        let row = sqlx::query_as("SELECT * FROM m_users WHERE name = ?")
+            .bind(name)
+            .fetch_optional(conn)
+            .await?;
+
+        Ok(row)
+    }
+
+    async fn create(
+        conn: &mut PoolConnection<Sqlite>,
+        input: &CreateUserInput,
+        password_hash: &str,
+    ) -> Result<UserId, DomainError> {
+        let result =
+            sqlx::query("INSERT INTO m_users (name, password_hash, role) VALUES (?, ?, ?)")
+                .bind(input.name())
+                .bind(password_hash)
+                .bind(input.role().as_str())
+                .execute(conn)
+                .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use crate::{
+        adapters::gateway::user_repository::SqliteUserRepository,
+        application::{
+            repository::user_repository::UserAbstructRepository,
+            usecase::user::{create_user::CreateUserInput, sign_in_user::SignInUserInput},
+        },
+        domain::user::Role,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn authenticate_test(pool: SqlitePool) {
+        let repository = SqliteUserRepository::new(pool.clone());
+        let input =
+            CreateUserInput::new("tanaka".to_string(), "password1".to_string(), Role::Sales);
+        let user_id = repository.create(&input).await.unwrap();
+        assert_eq!(repository.count().await.unwrap(), 1);
+
+        let password_hash: String = sqlx::query_scalar("SELECT password_hash FROM m_users")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(password_hash.starts_with("$argon2"));
+        assert!(!password_hash.contains("password1"));
+
+        let input = SignInUserInput::new("tanaka".to_string(), "password1".to_string());
+        let user = repository.authenticate(&input).await.unwrap().unwrap();
+        assert_eq!(*user.id(), user_id);
+        assert_eq!(*user.role(), Role::Sales);
+
+        let input = SignInUserInput::new("tanaka".to_string(), "password2".to_string());
+        assert!(repository.authenticate(&input).await.unwrap().is_none());
+        let input = SignInUserInput::new("suzuki".to_string(), "password1".to_string());
+        assert!(repository.authenticate(&input).await.unwrap().is_none());
+
+        let input =
+            CreateUserInput::new("tanaka".to_string(), "password3".to_string(), Role::Viewer);
+        let error = repository.create(&input).await.unwrap_err();
+        assert_eq!(error.code(), "conflict");
+    }
+}
@@ -0,0 +1,7 @@
+use crate::application::usecase::audit_log::search_audit_log::SearchAuditLogOutput;
+
+use super::response::audit_log_response::SearchAuditLogResponse;
+
+pub(crate) fn search_audit_log(output: SearchAuditLogOutput) -> SearchAuditLogResponse {
+    SearchAuditLogResponse::new(output)
+}
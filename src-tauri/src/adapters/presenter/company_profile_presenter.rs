@@ -0,0 +1,18 @@
+use crate::{
+    application::usecase::company_profile::find_company_profile::FindCompanyProfileOutput,
+    domain::company_profile::CompanyProfile,
+};
+
+use super::response::company_profile_response::{
+    FindCompanyProfileResponse, SaveCompanyProfileResponse,
+};
+
+pub(crate) fn find_company_profile(output: FindCompanyProfileOutput) -> FindCompanyProfileResponse {
+    FindCompanyProfileResponse::new(output.company_profile)
+}
+
+pub(crate) fn save_company_profile(
+    company_profile: Option<CompanyProfile>,
+) -> SaveCompanyProfileResponse {
+    SaveCompanyProfileResponse::new(company_profile)
+}
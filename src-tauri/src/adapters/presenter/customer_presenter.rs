@@ -1,5 +1,13 @@
-use crate::domain::customer::Customer;
-use super::response::customer_response::{CreateCustomerResponse, UpdateCustomerResponse};
+use crate::{application::usecase::customer::search_customer::SearchCustomerOutput, domain::customer::Customer};
+use super::response::customer_response::{CreateCustomerResponse, UpdateCustomerResponse, RestoreCustomerResponse, FindByIDCustomerResponse, SearchCustomerResponse, DeleteCustomerResponse};
+
+pub(crate) fn find_by_id_customer(customer: Option<Customer>) -> FindByIDCustomerResponse {
+    FindByIDCustomerResponse::from(customer)
+}
+
+pub(crate) fn search_customer(output: SearchCustomerOutput) -> SearchCustomerResponse {
+    SearchCustomerResponse::from(output)
+}
 
 pub(crate) fn create_customer(customer: Customer) -> CreateCustomerResponse {
     CreateCustomerResponse::from(customer)
@@ -7,4 +15,12 @@ pub(crate) fn create_customer(customer: Customer) -> CreateCustomerResponse {
 
 pub(crate) fn update_customer(customer: Option<Customer>) -> UpdateCustomerResponse {
     UpdateCustomerResponse::from(customer)
-}
\ No newline at end of file
+}
+
+pub(crate) fn delete_customer(result: bool) -> DeleteCustomerResponse {
+    DeleteCustomerResponse::from(result)
+}
+
+pub(crate) fn restore_customer(result: bool) -> RestoreCustomerResponse {
+    RestoreCustomerResponse::from(result)
+}
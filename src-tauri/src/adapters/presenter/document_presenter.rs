@@ -0,0 +1,189 @@
+use crate::{
+    application::usecase::document::{
+        find_delivery_slip_document::FindDeliverySlipDocumentOutput,
+        find_invoice_document::FindInvoiceDocumentOutput,
+        find_quotation_document::FindQuotationDocumentOutput,
+    },
+    domain::{
+        company_profile::CompanyProfile,
+        customer::{Customer, Postal, PostalCode},
+        invoice::TaxSummary,
+        product::Product,
+        tax::{RoundingMode, TaxRate},
+    },
+};
+
+use super::response::document_response::{
+    DocumentKind, DocumentLine, DocumentParty, DocumentTaxSummary, ExportPdfResponse, PrintDocument,
+};
+
+/// Formats a postal code as `〒123-4567`.
+fn format_postal(postal: &Postal) -> String {
+    match PostalCode::new(postal) {
+        Ok(postal) => format!("〒{}-{}", &postal.as_str()[..3], &postal.as_str()[3..]),
+        Err(_) => format!("〒{}", postal),
+    }
+}
+
+fn recipient(customer: &Customer) -> DocumentParty {
+    DocumentParty {
+        name: customer.name().clone(),
+        postal: Some(format_postal(customer.postal())),
+        address: customer.address().clone(),
+        registration_number: None,
+    }
+}
+
+fn tax_summaries(tax_summaries: &[TaxSummary]) -> Vec<DocumentTaxSummary> {
+    tax_summaries
+        .iter()
+        .map(|tax_summary| {
+            let label = match tax_summary.tax_rate() {
+                TaxRate::Standard => format!("{}%対象", tax_summary.tax_rate().percent()),
+                TaxRate::Reduced => format!("{}%対象(軽減税率)", tax_summary.tax_rate().percent()),
+            };
+            DocumentTaxSummary {
+                label,
+                taxable_amount: *tax_summary.taxable_amount(),
+                tax_amount: *tax_summary.tax_amount(),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn invoice_document(output: FindInvoiceDocumentOutput) -> PrintDocument {
+    let invoice = output.invoice;
+    let issuer = invoice.issuer();
+    let lines = invoice
+        .lines()
+        .iter()
+        .map(|line| DocumentLine {
+            description: line.product_name().to_string(),
+            quantity: *line.quantity(),
+            unit_price: *line.unit_price(),
+            amount: *line.amount(),
+            is_reduced_tax_rate: *line.tax_rate() == TaxRate::Reduced,
+        })
+        .collect();
+
+    PrintDocument {
+        kind: DocumentKind::Invoice,
+        number: invoice.invoice_number().to_string(),
+        issued_on: *invoice.issued_on(),
+        issuer: Some(DocumentParty {
+            name: issuer.name().clone(),
+            postal: None,
+            address: issuer.address().clone(),
+            registration_number: Some(issuer.registration_number().clone()),
+        }),
+        recipient: DocumentParty {
+            name: invoice.recipient_name().clone(),
+            ..recipient(&output.customer)
+        },
+        notes: vec![format!(
+            "対象期間: {} 〜 {}",
+            invoice.period_from(),
+            invoice.period_to()
+        )],
+        lines,
+        tax_summaries: tax_summaries(invoice.tax_summaries()),
+        subtotal: *invoice.subtotal(),
+        tax_amount: *invoice.tax_amount(),
+        total_amount: *invoice.total_amount(),
+    }
+}
+
+/// Lines priced from the product master, as on delivery slips and quotations. Tax is
+/// calculated per rate over the whole document.
+fn product_lines(
+    lines: Vec<(&Product, i64, i64)>,
+) -> (Vec<DocumentLine>, Vec<DocumentTaxSummary>, i64, i64) {
+    let summaries = TaxSummary::calculate(
+        lines
+            .iter()
+            .map(|(product, quantity, unit_price)| (*product.tax_rate(), quantity * unit_price)),
+        RoundingMode::default(),
+    );
+    let subtotal = summaries
+        .iter()
+        .map(|summary| *summary.taxable_amount())
+        .sum();
+    let tax_amount = summaries.iter().map(|summary| *summary.tax_amount()).sum();
+    let lines = lines
+        .into_iter()
+        .map(|(product, quantity, unit_price)| DocumentLine {
+            description: product.name().to_string(),
+            quantity,
+            unit_price,
+            amount: quantity * unit_price,
+            is_reduced_tax_rate: *product.tax_rate() == TaxRate::Reduced,
+        })
+        .collect();
+
+    (lines, tax_summaries(&summaries), subtotal, tax_amount)
+}
+
+fn issuer(company_profile: Option<CompanyProfile>) -> Option<DocumentParty> {
+    company_profile.map(|company_profile| DocumentParty {
+        name: company_profile.name().clone(),
+        postal: None,
+        address: company_profile.address().clone(),
+        registration_number: Some(company_profile.registration_number().clone()),
+    })
+}
+
+pub(crate) fn delivery_slip_document(output: FindDeliverySlipDocumentOutput) -> PrintDocument {
+    let sales_order = output.sales_order;
+    let (lines, tax_summaries, subtotal, tax_amount) = product_lines(
+        sales_order
+            .lines()
+            .iter()
+            .zip(output.products.iter())
+            .map(|(line, product)| (product, *line.quantity(), *line.unit_price()))
+            .collect(),
+    );
+
+    PrintDocument {
+        kind: DocumentKind::DeliverySlip,
+        number: format!("{:08}", sales_order.id()),
+        issued_on: *sales_order.order_date(),
+        issuer: issuer(output.company_profile),
+        recipient: recipient(&output.customer),
+        notes: vec![],
+        lines,
+        tax_summaries,
+        subtotal,
+        tax_amount,
+        total_amount: subtotal + tax_amount,
+    }
+}
+
+pub(crate) fn quotation_document(output: FindQuotationDocumentOutput) -> PrintDocument {
+    let quotation = output.quotation;
+    let (lines, tax_summaries, subtotal, tax_amount) = product_lines(
+        quotation
+            .lines()
+            .iter()
+            .zip(output.products.iter())
+            .map(|(line, product)| (product, *line.quantity(), *line.unit_price()))
+            .collect(),
+    );
+
+    PrintDocument {
+        kind: DocumentKind::Quotation,
+        number: format!("Q-{:08}", quotation.id()),
+        issued_on: *quotation.quoted_on(),
+        issuer: issuer(output.company_profile),
+        recipient: recipient(&output.customer),
+        notes: vec![format!("有効期限: {}", quotation.valid_until())],
+        lines,
+        tax_summaries,
+        subtotal,
+        tax_amount,
+        total_amount: subtotal + tax_amount,
+    }
+}
+
+pub(crate) fn export_pdf(path: String) -> ExportPdfResponse {
+    ExportPdfResponse::new(path)
+}
@@ -0,0 +1,37 @@
+use crate::domain::error::DomainError;
+
+use super::response::error_response::ErrorResponse;
+
+pub(crate) fn error(error: DomainError) -> ErrorResponse {
+    let code = error.code().to_string();
+    let message = error.to_string();
+    let fields = error.fields().to_vec();
+    let current = match error {
+        DomainError::Conflict { current, .. } => current,
+        _ => None,
+    };
+
+    ErrorResponse::new(code, message, fields, current)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{adapters::presenter::error_presenter, domain::error::DomainError};
+
+    #[test]
+    fn error_test() {
+        let response = error_presenter::error(DomainError::validation("unit", "unit is too long"));
+        let value = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(value["code"], "validation");
+        assert_eq!(value["message"], "unit is too long");
+        assert_eq!(value["fields"][0]["field"], "unit");
+        assert_eq!(value["current"], serde_json::Value::Null);
+
+        let response = error_presenter::error(DomainError::not_found("customer", 3));
+        let value = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(value["code"], "not_found");
+        assert_eq!(value["message"], "customer not found: id=3");
+    }
+}
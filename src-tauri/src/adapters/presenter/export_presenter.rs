@@ -0,0 +1,181 @@
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{
+    customer::Customer,
+    invoice::Invoice,
+    product::Product,
+    sales_order::{SalesOrder, SalesOrderStatus},
+};
+
+use super::response::export_response::{ExportCell, ExportResponse};
+
+fn header(names: &[&str]) -> Vec<ExportCell> {
+    names
+        .iter()
+        .map(|name| ExportCell::Text(name.to_string()))
+        .collect()
+}
+
+fn text(value: &str) -> ExportCell {
+    ExportCell::Text(value.to_string())
+}
+
+fn date(value: &Date) -> ExportCell {
+    ExportCell::Text(format!(
+        "{:04}-{:02}-{:02}",
+        value.year(),
+        u8::from(value.month()),
+        value.day()
+    ))
+}
+
+fn date_time(value: &PrimitiveDateTime) -> ExportCell {
+    ExportCell::Text(format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        value.year(),
+        u8::from(value.month()),
+        value.day(),
+        value.hour(),
+        value.minute(),
+        value.second()
+    ))
+}
+
+fn optional_date_time(value: &Option<PrimitiveDateTime>) -> ExportCell {
+    match value {
+        Some(value) => date_time(value),
+        None => ExportCell::Empty,
+    }
+}
+
+fn sales_order_status(status: &SalesOrderStatus) -> &'static str {
+    match status {
+        SalesOrderStatus::Ordered => "受注",
+        SalesOrderStatus::Delivered => "納品済",
+        SalesOrderStatus::Cancelled => "取消",
+    }
+}
+
+/// The code, name, unit, price, stock and tax rate columns can be imported again.
+pub(crate) fn product_header() -> Vec<ExportCell> {
+    header(&[
+        "商品ID",
+        "商品コード",
+        "商品名",
+        "単位",
+        "標準単価",
+        "標準在庫数",
+        "税率",
+        "登録日時",
+        "更新日時",
+        "削除日時",
+    ])
+}
+
+pub(crate) fn product_row(product: &Product) -> Vec<ExportCell> {
+    vec![
+        ExportCell::Number(*product.id()),
+        text(product.code()),
+        text(product.name()),
+        text(product.unit()),
+        ExportCell::Number(*product.default_price()),
+        ExportCell::Number(*product.standard_stock_quantity()),
+        ExportCell::Number(product.tax_rate().percent()),
+        date_time(product.created_at()),
+        date_time(product.updated_at()),
+        optional_date_time(product.deleted_at()),
+    ]
+}
+
+pub(crate) fn customer_header() -> Vec<ExportCell> {
+    header(&[
+        "顧客ID",
+        "顧客名",
+        "郵便番号",
+        "住所",
+        "登録日時",
+        "更新日時",
+        "削除日時",
+    ])
+}
+
+pub(crate) fn customer_row(customer: &Customer) -> Vec<ExportCell> {
+    vec![
+        ExportCell::Number(*customer.id()),
+        text(customer.name()),
+        text(customer.postal()),
+        text(customer.address()),
+        date_time(customer.created_at()),
+        date_time(customer.updated_at()),
+        optional_date_time(customer.deleted_at()),
+    ]
+}
+
+pub(crate) fn sales_order_header() -> Vec<ExportCell> {
+    header(&[
+        "受注ID",
+        "受注日",
+        "顧客ID",
+        "状態",
+        "明細ID",
+        "商品ID",
+        "数量",
+        "単価",
+        "金額",
+    ])
+}
+
+/// A row per line, each repeating the order.
+pub(crate) fn sales_order_rows(sales_order: &SalesOrder) -> Vec<Vec<ExportCell>> {
+    sales_order
+        .lines()
+        .iter()
+        .map(|line| {
+            vec![
+                ExportCell::Number(*sales_order.id()),
+                date(sales_order.order_date()),
+                ExportCell::Number(*sales_order.customer_id()),
+                text(sales_order_status(sales_order.status())),
+                ExportCell::Number(*line.id()),
+                ExportCell::Number(*line.product_id()),
+                ExportCell::Number(*line.quantity()),
+                ExportCell::Number(*line.unit_price()),
+                ExportCell::Number(line.amount()),
+            ]
+        })
+        .collect()
+}
+
+pub(crate) fn invoice_header() -> Vec<ExportCell> {
+    header(&[
+        "請求書ID",
+        "請求書番号",
+        "発行日",
+        "顧客ID",
+        "宛名",
+        "対象期間開始",
+        "対象期間終了",
+        "小計",
+        "消費税",
+        "合計",
+    ])
+}
+
+pub(crate) fn invoice_row(invoice: &Invoice) -> Vec<ExportCell> {
+    vec![
+        ExportCell::Number(*invoice.id()),
+        text(invoice.invoice_number()),
+        date(invoice.issued_on()),
+        ExportCell::Number(*invoice.customer_id()),
+        text(invoice.recipient_name()),
+        date(invoice.period_from()),
+        date(invoice.period_to()),
+        ExportCell::Number(*invoice.subtotal()),
+        ExportCell::Number(*invoice.tax_amount()),
+        ExportCell::Number(*invoice.total_amount()),
+    ]
+}
+
+pub(crate) fn export(path: String, rows: i64) -> ExportResponse {
+    ExportResponse::new(path, rows)
+}
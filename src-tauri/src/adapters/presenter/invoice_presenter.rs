@@ -0,0 +1,22 @@
+use crate::{
+    application::usecase::invoice::{
+        find_by_id_invoice::FindByIDInvoiceOutput, search_invoice::SearchInvoiceOutput,
+    },
+    domain::invoice::Invoice,
+};
+
+use super::response::invoice_response::{
+    FindByIDInvoiceResponse, IssueInvoiceResponse, SearchInvoiceResponse,
+};
+
+pub(crate) fn find_by_id(output: FindByIDInvoiceOutput) -> FindByIDInvoiceResponse {
+    FindByIDInvoiceResponse::new(output.invoice)
+}
+
+pub(crate) fn search_invoice(output: SearchInvoiceOutput) -> SearchInvoiceResponse {
+    SearchInvoiceResponse::new(output)
+}
+
+pub(crate) fn issue_invoice(invoice: Option<Invoice>) -> IssueInvoiceResponse {
+    IssueInvoiceResponse::new(invoice)
+}
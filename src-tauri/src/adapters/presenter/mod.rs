@@ -1,3 +1,16 @@
+pub mod audit_log_presenter;
+pub mod company_profile_presenter;
 pub mod customer_presenter;
+pub mod document_presenter;
+pub mod error_presenter;
+pub mod export_presenter;
+pub mod invoice_presenter;
+pub mod price_list_presenter;
 pub mod product_presenter;
+pub mod quotation_presenter;
+pub mod receivable_presenter;
+pub mod reorder_alert_presenter;
 pub mod response;
+pub mod sales_order_presenter;
+pub mod stock_movement_presenter;
+pub mod user_presenter;
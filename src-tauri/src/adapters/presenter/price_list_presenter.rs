@@ -0,0 +1,36 @@
+use crate::{
+    application::usecase::price_list::{
+        find_by_id_price_list::FindByIDPriceListOutput, resolve_price::ResolvePriceOutput,
+        search_price_list::SearchPriceListOutput,
+    },
+    domain::price_list::PriceListEntry,
+};
+
+use super::response::price_list_response::{
+    CreatePriceListResponse, DeletePriceListResponse, FindByIDPriceListResponse,
+    ResolvePriceResponse, SearchPriceListResponse, UpdatePriceListResponse,
+};
+
+pub(crate) fn find_by_id(output: FindByIDPriceListOutput) -> FindByIDPriceListResponse {
+    FindByIDPriceListResponse::new(output.price_list)
+}
+
+pub(crate) fn search_price_list(output: SearchPriceListOutput) -> SearchPriceListResponse {
+    SearchPriceListResponse::new(output)
+}
+
+pub(crate) fn create_price_list(price_list: Option<PriceListEntry>) -> CreatePriceListResponse {
+    CreatePriceListResponse::new(price_list)
+}
+
+pub(crate) fn update_price_list(price_list: Option<PriceListEntry>) -> UpdatePriceListResponse {
+    UpdatePriceListResponse::new(price_list)
+}
+
+pub(crate) fn delete_price_list(result: bool) -> DeletePriceListResponse {
+    DeletePriceListResponse::new(result)
+}
+
+pub(crate) fn resolve_price(output: ResolvePriceOutput) -> ResolvePriceResponse {
+    ResolvePriceResponse::new(output.resolved_price)
+}
@@ -2,30 +2,35 @@ use crate::{
     application::usecase::product::{
         create_product::CreateProductOutput, search_product::SearchProductOutput,
         update_product::UpdateProductOutput, find_by_id_product::FindByIDProductOutput, 
+        import_product::ImportProductOutput,
     },
-    domain::product::Product,
+    domain::{product::Product, stock_movement::OnHandQuantity},
 };
 
 use super::response::product_response::{
     CreateProductResponse, SearchProductResponse, UpdateProductResponse, FindByIDProductResponse, ProductForResponse, DeleteProductResponse,
+    RestoreProductResponse, ImportProductResponse,
+    ImportProductRowForResponse,
 };
 
-pub(crate) fn find_by_id(output: FindByIDProductOutput) -> FindByIDProductResponse {
-    let product: Option<ProductForResponse> = match output.product {
-        Some(product) => {
-            Some(ProductForResponse::new(
-                product.name().to_string(),
-                product.code().to_string(),
-                product.unit().to_string(),
-                *product.default_price(),
-                *product.standard_stock_quantity(),
-                *product.created_at(),
-                *product.updated_at(),
-                *product.deleted_at())
-            )
-        }
-        None => None
-    };
+pub(crate) fn find_by_id(
+    output: FindByIDProductOutput,
+    on_hand_quantity: OnHandQuantity,
+) -> FindByIDProductResponse {
+    let product: Option<ProductForResponse> = output.product.map(|product| {
+        ProductForResponse::new(
+            product.name().to_string(),
+            product.code().to_string(),
+            product.unit().to_string(),
+            *product.default_price(),
+            *product.standard_stock_quantity(),
+            on_hand_quantity,
+            *product.tax_rate(),
+            *product.created_at(),
+            *product.updated_at(),
+            *product.deleted_at(),
+        )
+    });
 
     FindByIDProductResponse::new(product)
 }
@@ -35,14 +40,14 @@ pub(crate) fn search_product(output: SearchProductOutput) -> SearchProductRespon
 }
 
 pub(crate) fn create_product(
-    output: CreateProductOutput,
+    _output: CreateProductOutput,
     product: Option<Product>,
 ) -> CreateProductResponse {
     CreateProductResponse::new(product)
 }
 
 pub(crate) fn update_product(
-    output: UpdateProductOutput,
+    _output: UpdateProductOutput,
     product: Option<Product>,
 ) -> UpdateProductResponse {
     UpdateProductResponse::new(product)
@@ -50,4 +55,26 @@ pub(crate) fn update_product(
 
 pub(crate) fn delete_product(result: bool) -> DeleteProductResponse {
     DeleteProductResponse::new(result)
-}
\ No newline at end of file
+}
+
+pub(crate) fn restore_product(result: bool) -> RestoreProductResponse {
+    RestoreProductResponse::new(result)
+}
+
+pub(crate) fn import_product(output: ImportProductOutput) -> ImportProductResponse {
+    let rows = output
+        .rows
+        .into_iter()
+        .map(|row| {
+            ImportProductRowForResponse::new(
+                *row.row(),
+                row.code().clone(),
+                *row.action(),
+                *row.product_id(),
+                row.errors().clone(),
+            )
+        })
+        .collect();
+
+    ImportProductResponse::new(output.applied, output.created, output.updated, rows)
+}
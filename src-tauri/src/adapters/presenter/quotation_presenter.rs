@@ -0,0 +1,37 @@
+use crate::{
+    application::usecase::quotation::{
+        find_by_id_quotation::FindByIDQuotationOutput, search_quotation::SearchQuotationOutput,
+    },
+    domain::{quotation::Quotation, sales_order::SalesOrder},
+};
+
+use super::response::quotation_response::{
+    ChangeQuotationStatusResponse, ConvertQuotationResponse, CreateQuotationResponse,
+    FindByIDQuotationResponse, SearchQuotationResponse, UpdateQuotationResponse,
+};
+
+pub(crate) fn find_by_id(output: FindByIDQuotationOutput) -> FindByIDQuotationResponse {
+    FindByIDQuotationResponse::new(output.quotation)
+}
+
+pub(crate) fn search_quotation(output: SearchQuotationOutput) -> SearchQuotationResponse {
+    SearchQuotationResponse::new(output)
+}
+
+pub(crate) fn create_quotation(quotation: Option<Quotation>) -> CreateQuotationResponse {
+    CreateQuotationResponse::new(quotation)
+}
+
+pub(crate) fn update_quotation(quotation: Option<Quotation>) -> UpdateQuotationResponse {
+    UpdateQuotationResponse::new(quotation)
+}
+
+pub(crate) fn change_quotation_status(
+    quotation: Option<Quotation>,
+) -> ChangeQuotationStatusResponse {
+    ChangeQuotationStatusResponse::new(quotation)
+}
+
+pub(crate) fn convert_quotation(sales_order: Option<SalesOrder>) -> ConvertQuotationResponse {
+    ConvertQuotationResponse::new(sales_order)
+}
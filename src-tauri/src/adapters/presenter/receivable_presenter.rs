@@ -0,0 +1,46 @@
+use crate::{
+    application::usecase::receivable::{
+        find_by_id_payment::FindByIDPaymentOutput, search_aging_report::SearchAgingReportOutput,
+        search_customer_balance::SearchCustomerBalanceOutput,
+        search_invoice_balance::SearchInvoiceBalanceOutput, search_payment::SearchPaymentOutput,
+    },
+    domain::receivable::Payment,
+};
+
+use super::response::receivable_response::{
+    AllocatePaymentResponse, FindByIDPaymentResponse, RecordPaymentResponse,
+    SearchAgingReportResponse, SearchCustomerBalanceResponse, SearchInvoiceBalanceResponse,
+    SearchPaymentResponse,
+};
+
+pub(crate) fn find_by_id(output: FindByIDPaymentOutput) -> FindByIDPaymentResponse {
+    FindByIDPaymentResponse::new(output.payment)
+}
+
+pub(crate) fn search_payment(output: SearchPaymentOutput) -> SearchPaymentResponse {
+    SearchPaymentResponse::new(output)
+}
+
+pub(crate) fn record_payment(payment: Option<Payment>) -> RecordPaymentResponse {
+    RecordPaymentResponse::new(payment)
+}
+
+pub(crate) fn allocate_payment(payment: Option<Payment>) -> AllocatePaymentResponse {
+    AllocatePaymentResponse::new(payment)
+}
+
+pub(crate) fn search_invoice_balance(
+    output: SearchInvoiceBalanceOutput,
+) -> SearchInvoiceBalanceResponse {
+    SearchInvoiceBalanceResponse::new(output)
+}
+
+pub(crate) fn search_customer_balance(
+    output: SearchCustomerBalanceOutput,
+) -> SearchCustomerBalanceResponse {
+    SearchCustomerBalanceResponse::new(output)
+}
+
+pub(crate) fn search_aging_report(output: SearchAgingReportOutput) -> SearchAgingReportResponse {
+    SearchAgingReportResponse::new(output)
+}
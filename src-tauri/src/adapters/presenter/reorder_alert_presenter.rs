@@ -0,0 +1,7 @@
+use crate::application::usecase::reorder_alert::search_reorder_alert::SearchReorderAlertOutput;
+
+use super::response::reorder_alert_response::SearchReorderAlertResponse;
+
+pub(crate) fn search_reorder_alert(output: SearchReorderAlertOutput) -> SearchReorderAlertResponse {
+    SearchReorderAlertResponse::new(output)
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::audit_log::search_audit_log::SearchAuditLogOutput,
+    domain::audit_log::AuditLog,
+};
+
+#[derive(Serialize, Debug)]
+pub struct SearchAuditLogResponse {
+    audit_logs: Vec<AuditLog>,
+}
+impl SearchAuditLogResponse {
+    pub fn new(output: SearchAuditLogOutput) -> Self {
+        Self {
+            audit_logs: output.audit_logs,
+        }
+    }
+}
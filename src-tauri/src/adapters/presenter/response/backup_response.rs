@@ -0,0 +1,80 @@
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+#[derive(Serialize, Debug)]
+pub struct BackupForResponse {
+    file_name: String,
+    label: String,
+    size: u64,
+    created_at: PrimitiveDateTime,
+}
+impl BackupForResponse {
+    pub fn new(file_name: String, label: String, size: u64, created_at: PrimitiveDateTime) -> Self {
+        Self {
+            file_name,
+            label,
+            size,
+            created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct BackupScheduleForResponse {
+    interval_hours: u32,
+    retention: u32,
+}
+impl BackupScheduleForResponse {
+    pub fn new(interval_hours: u32, retention: u32) -> Self {
+        Self {
+            interval_hours,
+            retention,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SearchBackupResponse {
+    backups: Vec<BackupForResponse>,
+    schedule: BackupScheduleForResponse,
+}
+impl SearchBackupResponse {
+    pub fn new(backups: Vec<BackupForResponse>, schedule: BackupScheduleForResponse) -> Self {
+        Self { backups, schedule }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreateBackupResponse {
+    backup: BackupForResponse,
+}
+impl CreateBackupResponse {
+    pub fn new(backup: BackupForResponse) -> Self {
+        Self { backup }
+    }
+}
+
+/// `safety_backup` holds the database as it was before the restore.
+#[derive(Serialize)]
+pub struct RestoreBackupResponse {
+    restored: BackupForResponse,
+    safety_backup: BackupForResponse,
+}
+impl RestoreBackupResponse {
+    pub fn new(restored: BackupForResponse, safety_backup: BackupForResponse) -> Self {
+        Self {
+            restored,
+            safety_backup,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SaveBackupScheduleResponse {
+    schedule: BackupScheduleForResponse,
+}
+impl SaveBackupScheduleResponse {
+    pub fn new(schedule: BackupScheduleForResponse) -> Self {
+        Self { schedule }
+    }
+}
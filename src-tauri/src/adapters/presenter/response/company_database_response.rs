@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+#[derive(Serialize, Debug)]
+pub struct CompanyDatabaseForResponse {
+    name: String,
+    database_file: String,
+    current: bool,
+}
+impl CompanyDatabaseForResponse {
+    pub fn new(name: String, database_file: String, current: bool) -> Self {
+        Self {
+            name,
+            database_file,
+            current,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SearchCompanyDatabaseResponse {
+    companies: Vec<CompanyDatabaseForResponse>,
+}
+impl SearchCompanyDatabaseResponse {
+    pub fn new(companies: Vec<CompanyDatabaseForResponse>) -> Self {
+        Self { companies }
+    }
+}
+
+/// Returned when a company database is created, opened or switched to.
+#[derive(Serialize)]
+pub struct CompanyDatabaseResponse {
+    company: CompanyDatabaseForResponse,
+}
+impl CompanyDatabaseResponse {
+    pub fn new(company: CompanyDatabaseForResponse) -> Self {
+        Self { company }
+    }
+}
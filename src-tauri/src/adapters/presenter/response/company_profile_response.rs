@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+use crate::domain::company_profile::CompanyProfile;
+
+#[derive(Serialize)]
+pub struct FindCompanyProfileResponse {
+    company_profile: Option<CompanyProfile>,
+}
+impl FindCompanyProfileResponse {
+    pub fn new(company_profile: Option<CompanyProfile>) -> Self {
+        Self { company_profile }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SaveCompanyProfileResponse {
+    company_profile: Option<CompanyProfile>,
+}
+impl SaveCompanyProfileResponse {
+    pub fn new(company_profile: Option<CompanyProfile>) -> Self {
+        Self { company_profile }
+    }
+}
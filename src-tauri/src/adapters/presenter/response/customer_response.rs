@@ -1,6 +1,26 @@
 use serde::Serialize;
 
-use crate::{domain::customer::Customer};
+use crate::{application::usecase::customer::search_customer::SearchCustomerOutput, domain::customer::Customer};
+
+#[derive(Serialize)]
+pub struct FindByIDCustomerResponse {
+    customer: Option<Customer>
+}
+impl From<Option<Customer>> for FindByIDCustomerResponse {
+    fn from(customer: Option<Customer>) -> Self {
+        Self { customer }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SearchCustomerResponse {
+    customers: Vec<Customer>
+}
+impl From<SearchCustomerOutput> for SearchCustomerResponse {
+    fn from(output: SearchCustomerOutput) -> Self {
+        Self { customers: output.customers }
+    }
+}
 
 #[derive(Serialize)]
 pub struct CreateCustomerResponse {
@@ -22,3 +42,22 @@ impl From<Option<Customer>> for UpdateCustomerResponse {
     }
 }
 
+#[derive(Serialize)]
+pub struct DeleteCustomerResponse {
+    result: bool
+}
+impl From<bool> for DeleteCustomerResponse {
+    fn from(result: bool) -> Self {
+        Self { result }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RestoreCustomerResponse {
+    result: bool
+}
+impl From<bool> for RestoreCustomerResponse {
+    fn from(result: bool) -> Self {
+        Self { result }
+    }
+}
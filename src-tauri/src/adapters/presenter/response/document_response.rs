@@ -0,0 +1,82 @@
+use serde::Serialize;
+use time::Date;
+
+/// Kinds of printed business documents.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentKind {
+    Invoice,
+    Quotation,
+    DeliverySlip,
+}
+impl DocumentKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            DocumentKind::Invoice => "請求書",
+            DocumentKind::Quotation => "御見積書",
+            DocumentKind::DeliverySlip => "納品書",
+        }
+    }
+
+    pub fn total_label(&self) -> &'static str {
+        match self {
+            DocumentKind::Invoice => "ご請求金額",
+            DocumentKind::Quotation => "御見積金額",
+            DocumentKind::DeliverySlip => "合計金額",
+        }
+    }
+}
+
+/// A name and address block, as printed in the header of a document.
+#[derive(Debug, Clone)]
+pub struct DocumentParty {
+    pub name: String,
+    pub postal: Option<String>,
+    pub address: String,
+    pub registration_number: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentLine {
+    pub description: String,
+    pub quantity: i64,
+    pub unit_price: i64,
+    pub amount: i64,
+    /// Marked with ※ as a reduced tax rate item (軽減税率対象).
+    pub is_reduced_tax_rate: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentTaxSummary {
+    pub label: String,
+    pub taxable_amount: i64,
+    pub tax_amount: i64,
+}
+
+/// Print model shared by invoices, quotations and delivery slips. Amounts are in yen and
+/// exclusive of tax unless stated otherwise.
+#[derive(Debug, Clone)]
+pub struct PrintDocument {
+    pub kind: DocumentKind,
+    pub number: String,
+    pub issued_on: Date,
+    pub issuer: Option<DocumentParty>,
+    pub recipient: DocumentParty,
+    /// Extra header lines such as the billing period or the quotation's validity.
+    pub notes: Vec<String>,
+    pub lines: Vec<DocumentLine>,
+    pub tax_summaries: Vec<DocumentTaxSummary>,
+    pub subtotal: i64,
+    pub tax_amount: i64,
+    pub total_amount: i64,
+}
+
+#[derive(Serialize)]
+pub struct ExportPdfResponse {
+    path: String,
+}
+impl ExportPdfResponse {
+    pub fn new(path: String) -> Self {
+        Self { path }
+    }
+}
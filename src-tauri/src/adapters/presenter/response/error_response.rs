@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+use crate::domain::error::FieldError;
+
+/// Error payload returned by every command. `code` is one of `validation`, `not_found`,
+/// `conflict`, `forbidden`, `database_unavailable` or `unexpected`.
+#[derive(Serialize, Debug)]
+pub struct ErrorResponse {
+    code: String,
+    message: String,
+    fields: Vec<FieldError>,
+    current: Option<serde_json::Value>,
+}
+impl ErrorResponse {
+    pub fn new(
+        code: String,
+        message: String,
+        fields: Vec<FieldError>,
+        current: Option<serde_json::Value>,
+    ) -> Self {
+        Self {
+            code,
+            message,
+            fields,
+            current,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+/// A cell of an exported sheet. Numbers stay numbers in spreadsheets, so that they can be
+/// summed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportCell {
+    Empty,
+    Text(String),
+    Number(i64),
+}
+
+#[derive(Serialize)]
+pub struct ExportResponse {
+    path: String,
+    /// Rows written, not counting the header.
+    rows: i64,
+}
+impl ExportResponse {
+    pub fn new(path: String, rows: i64) -> Self {
+        Self { path, rows }
+    }
+}
@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::invoice::search_invoice::SearchInvoiceOutput, domain::invoice::Invoice,
+};
+
+#[derive(Serialize)]
+pub struct FindByIDInvoiceResponse {
+    invoice: Option<Invoice>,
+}
+impl FindByIDInvoiceResponse {
+    pub fn new(invoice: Option<Invoice>) -> Self {
+        Self { invoice }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchInvoiceResponse {
+    invoices: Vec<Invoice>,
+}
+impl SearchInvoiceResponse {
+    pub fn new(output: SearchInvoiceOutput) -> Self {
+        Self {
+            invoices: output.invoices,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct IssueInvoiceResponse {
+    invoice: Option<Invoice>,
+}
+impl IssueInvoiceResponse {
+    pub fn new(invoice: Option<Invoice>) -> Self {
+        Self { invoice }
+    }
+}
@@ -1,2 +1,17 @@
+pub mod audit_log_response;
+pub mod backup_response;
+pub mod company_database_response;
+pub mod company_profile_response;
 pub mod customer_response;
+pub mod document_response;
+pub mod error_response;
+pub mod export_response;
+pub mod invoice_response;
+pub mod price_list_response;
 pub mod product_response;
+pub mod quotation_response;
+pub mod receivable_response;
+pub mod reorder_alert_response;
+pub mod sales_order_response;
+pub mod stock_movement_response;
+pub mod user_response;
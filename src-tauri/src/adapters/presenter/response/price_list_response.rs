@@ -0,0 +1,68 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::price_list::search_price_list::SearchPriceListOutput,
+    domain::price_list::{PriceListEntry, ResolvedPrice},
+};
+
+#[derive(Serialize)]
+pub struct FindByIDPriceListResponse {
+    price_list: Option<PriceListEntry>,
+}
+impl FindByIDPriceListResponse {
+    pub fn new(price_list: Option<PriceListEntry>) -> Self {
+        Self { price_list }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchPriceListResponse {
+    price_lists: Vec<PriceListEntry>,
+}
+impl SearchPriceListResponse {
+    pub fn new(output: SearchPriceListOutput) -> Self {
+        Self {
+            price_lists: output.price_lists,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreatePriceListResponse {
+    price_list: Option<PriceListEntry>,
+}
+impl CreatePriceListResponse {
+    pub fn new(price_list: Option<PriceListEntry>) -> Self {
+        Self { price_list }
+    }
+}
+
+#[derive(Serialize)]
+pub struct UpdatePriceListResponse {
+    price_list: Option<PriceListEntry>,
+}
+impl UpdatePriceListResponse {
+    pub fn new(price_list: Option<PriceListEntry>) -> Self {
+        Self { price_list }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DeletePriceListResponse {
+    result: bool,
+}
+impl DeletePriceListResponse {
+    pub fn new(result: bool) -> Self {
+        Self { result }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ResolvePriceResponse {
+    resolved_price: ResolvedPrice,
+}
+impl ResolvePriceResponse {
+    pub fn new(resolved_price: ResolvedPrice) -> Self {
+        Self { resolved_price }
+    }
+}
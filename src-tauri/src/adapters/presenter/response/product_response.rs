@@ -2,7 +2,14 @@ use serde::Serialize;
 use time::PrimitiveDateTime;
 
 use crate::{
-    application::usecase::product::{search_product::SearchProductOutput}, domain::product::Product,
+    application::usecase::product::{
+        import_product::ImportProductAction, search_product::SearchProductOutput,
+    },
+    domain::{
+        error::FieldError,
+        product::{Product, ProductId},
+        tax::TaxRate,
+    },
 };
 
 #[derive(Serialize)]
@@ -12,17 +19,22 @@ pub struct ProductForResponse {
     unit: String,
     default_price: i64,
     standard_stock_quantity: i64,
+    on_hand_quantity: i64,
+    tax_rate: TaxRate,
     created_at: PrimitiveDateTime,
     updated_at: PrimitiveDateTime,
     deleted_at: Option<PrimitiveDateTime>,
 }
 impl ProductForResponse {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         code: String,
         unit: String,
         default_price: i64,
         standard_stock_quantity: i64,
+        on_hand_quantity: i64,
+        tax_rate: TaxRate,
         created_at: PrimitiveDateTime,
         updated_at: PrimitiveDateTime,
         deleted_at: Option<PrimitiveDateTime>
@@ -34,6 +46,8 @@ impl ProductForResponse {
             unit,
             default_price,
             standard_stock_quantity,
+            on_hand_quantity,
+            tax_rate,
             created_at,
             updated_at,
             deleted_at
@@ -54,11 +68,13 @@ impl FindByIDProductResponse {
 #[derive(Serialize, Debug)]
 pub struct SearchProductResponse {
     products: Vec<Product>,
+    total: i64,
 }
 impl SearchProductResponse {
     pub fn new(output: SearchProductOutput) -> Self {
         Self {
             products: output.products,
+            total: output.total,
         }
     }
 }
@@ -92,3 +108,63 @@ impl DeleteProductResponse {
         Self { result }
     }
 }
+
+#[derive(Serialize)]
+pub struct RestoreProductResponse {
+    result: bool,
+}
+impl RestoreProductResponse {
+    pub fn new(result: bool) -> Self {
+        Self { result }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct ImportProductRowForResponse {
+    row: i64,
+    code: String,
+    action: ImportProductAction,
+    product_id: Option<ProductId>,
+    errors: Vec<FieldError>,
+}
+impl ImportProductRowForResponse {
+    pub fn new(
+        row: i64,
+        code: String,
+        action: ImportProductAction,
+        product_id: Option<ProductId>,
+        errors: Vec<FieldError>,
+    ) -> Self {
+        Self {
+            row,
+            code,
+            action,
+            product_id,
+            errors,
+        }
+    }
+}
+
+/// `applied` is false for a dry run and whenever a row has errors.
+#[derive(Serialize)]
+pub struct ImportProductResponse {
+    applied: bool,
+    created: i64,
+    updated: i64,
+    rows: Vec<ImportProductRowForResponse>,
+}
+impl ImportProductResponse {
+    pub fn new(
+        applied: bool,
+        created: i64,
+        updated: i64,
+        rows: Vec<ImportProductRowForResponse>,
+    ) -> Self {
+        Self {
+            applied,
+            created,
+            updated,
+            rows,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::quotation::search_quotation::SearchQuotationOutput,
+    domain::{quotation::Quotation, sales_order::SalesOrder},
+};
+
+#[derive(Serialize)]
+pub struct FindByIDQuotationResponse {
+    quotation: Option<Quotation>,
+}
+impl FindByIDQuotationResponse {
+    pub fn new(quotation: Option<Quotation>) -> Self {
+        Self { quotation }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchQuotationResponse {
+    quotations: Vec<Quotation>,
+}
+impl SearchQuotationResponse {
+    pub fn new(output: SearchQuotationOutput) -> Self {
+        Self {
+            quotations: output.quotations,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreateQuotationResponse {
+    quotation: Option<Quotation>,
+}
+impl CreateQuotationResponse {
+    pub fn new(quotation: Option<Quotation>) -> Self {
+        Self { quotation }
+    }
+}
+
+#[derive(Serialize)]
+pub struct UpdateQuotationResponse {
+    quotation: Option<Quotation>,
+}
+impl UpdateQuotationResponse {
+    pub fn new(quotation: Option<Quotation>) -> Self {
+        Self { quotation }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ChangeQuotationStatusResponse {
+    quotation: Option<Quotation>,
+}
+impl ChangeQuotationStatusResponse {
+    pub fn new(quotation: Option<Quotation>) -> Self {
+        Self { quotation }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ConvertQuotationResponse {
+    sales_order: Option<SalesOrder>,
+}
+impl ConvertQuotationResponse {
+    pub fn new(sales_order: Option<SalesOrder>) -> Self {
+        Self { sales_order }
+    }
+}
@@ -0,0 +1,88 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::receivable::{
+        search_aging_report::SearchAgingReportOutput,
+        search_customer_balance::SearchCustomerBalanceOutput,
+        search_invoice_balance::SearchInvoiceBalanceOutput, search_payment::SearchPaymentOutput,
+    },
+    domain::receivable::{AgingReport, CustomerBalance, InvoiceBalance, Payment},
+};
+
+#[derive(Serialize)]
+pub struct FindByIDPaymentResponse {
+    payment: Option<Payment>,
+}
+impl FindByIDPaymentResponse {
+    pub fn new(payment: Option<Payment>) -> Self {
+        Self { payment }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchPaymentResponse {
+    payments: Vec<Payment>,
+}
+impl SearchPaymentResponse {
+    pub fn new(output: SearchPaymentOutput) -> Self {
+        Self {
+            payments: output.payments,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RecordPaymentResponse {
+    payment: Option<Payment>,
+}
+impl RecordPaymentResponse {
+    pub fn new(payment: Option<Payment>) -> Self {
+        Self { payment }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AllocatePaymentResponse {
+    payment: Option<Payment>,
+}
+impl AllocatePaymentResponse {
+    pub fn new(payment: Option<Payment>) -> Self {
+        Self { payment }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchInvoiceBalanceResponse {
+    invoice_balances: Vec<InvoiceBalance>,
+}
+impl SearchInvoiceBalanceResponse {
+    pub fn new(output: SearchInvoiceBalanceOutput) -> Self {
+        Self {
+            invoice_balances: output.invoice_balances,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchCustomerBalanceResponse {
+    customer_balances: Vec<CustomerBalance>,
+}
+impl SearchCustomerBalanceResponse {
+    pub fn new(output: SearchCustomerBalanceOutput) -> Self {
+        Self {
+            customer_balances: output.customer_balances,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchAgingReportResponse {
+    aging_reports: Vec<AgingReport>,
+}
+impl SearchAgingReportResponse {
+    pub fn new(output: SearchAgingReportOutput) -> Self {
+        Self {
+            aging_reports: output.aging_reports,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::reorder_alert::search_reorder_alert::SearchReorderAlertOutput,
+    domain::reorder_alert::ReorderAlert,
+};
+
+#[derive(Serialize, Debug)]
+pub struct SearchReorderAlertResponse {
+    reorder_alerts: Vec<ReorderAlert>,
+}
+impl SearchReorderAlertResponse {
+    pub fn new(output: SearchReorderAlertOutput) -> Self {
+        Self {
+            reorder_alerts: output.reorder_alerts,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::sales_order::search_sales_order::SearchSalesOrderOutput,
+    domain::sales_order::SalesOrder,
+};
+
+#[derive(Serialize)]
+pub struct FindByIDSalesOrderResponse {
+    sales_order: Option<SalesOrder>,
+}
+impl FindByIDSalesOrderResponse {
+    pub fn new(sales_order: Option<SalesOrder>) -> Self {
+        Self { sales_order }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchSalesOrderResponse {
+    sales_orders: Vec<SalesOrder>,
+}
+impl SearchSalesOrderResponse {
+    pub fn new(output: SearchSalesOrderOutput) -> Self {
+        Self {
+            sales_orders: output.sales_orders,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreateSalesOrderResponse {
+    sales_order: Option<SalesOrder>,
+}
+impl CreateSalesOrderResponse {
+    pub fn new(sales_order: Option<SalesOrder>) -> Self {
+        Self { sales_order }
+    }
+}
+
+#[derive(Serialize)]
+pub struct UpdateSalesOrderResponse {
+    sales_order: Option<SalesOrder>,
+}
+impl UpdateSalesOrderResponse {
+    pub fn new(sales_order: Option<SalesOrder>) -> Self {
+        Self { sales_order }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CancelSalesOrderResponse {
+    result: bool,
+}
+impl CancelSalesOrderResponse {
+    pub fn new(result: bool) -> Self {
+        Self { result }
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Serialize;
+
+use crate::{
+    application::usecase::stock_movement::search_stock_movement::SearchStockMovementOutput,
+    domain::stock_movement::{StockLevel, StockMovement, StockMovementId},
+};
+
+#[derive(Serialize)]
+pub struct PostStockMovementResponse {
+    stock_movement_id: StockMovementId,
+    stock_level: StockLevel,
+}
+impl PostStockMovementResponse {
+    pub fn new(stock_movement_id: StockMovementId, stock_level: StockLevel) -> Self {
+        Self {
+            stock_movement_id,
+            stock_level,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchStockMovementResponse {
+    stock_movements: Vec<StockMovement>,
+}
+impl SearchStockMovementResponse {
+    pub fn new(output: SearchStockMovementOutput) -> Self {
+        Self {
+            stock_movements: output.stock_movements,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct FindOnHandStockResponse {
+    stock_level: StockLevel,
+}
+impl FindOnHandStockResponse {
+    pub fn new(stock_level: StockLevel) -> Self {
+        Self { stock_level }
+    }
+}
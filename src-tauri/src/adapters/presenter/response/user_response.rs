@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+use crate::domain::user::{User, UserId};
+
+#[derive(Serialize)]
+pub struct FindSessionResponse {
+    user: Option<User>,
+    /// False until the first user is created, when the frontend should offer to create one.
+    has_users: bool,
+}
+impl FindSessionResponse {
+    pub fn new(user: Option<User>, has_users: bool) -> Self {
+        Self { user, has_users }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SignInUserResponse {
+    user: User,
+}
+impl SignInUserResponse {
+    pub fn new(user: User) -> Self {
+        Self { user }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SearchUserResponse {
+    users: Vec<User>,
+}
+impl SearchUserResponse {
+    pub fn new(users: Vec<User>) -> Self {
+        Self { users }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreateUserResponse {
+    user_id: UserId,
+}
+impl CreateUserResponse {
+    pub fn new(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
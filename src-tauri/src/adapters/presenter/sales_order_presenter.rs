@@ -0,0 +1,31 @@
+use crate::{
+    application::usecase::sales_order::{
+        find_by_id_sales_order::FindByIDSalesOrderOutput, search_sales_order::SearchSalesOrderOutput,
+    },
+    domain::sales_order::SalesOrder,
+};
+
+use super::response::sales_order_response::{
+    CancelSalesOrderResponse, CreateSalesOrderResponse, FindByIDSalesOrderResponse,
+    SearchSalesOrderResponse, UpdateSalesOrderResponse,
+};
+
+pub(crate) fn find_by_id(output: FindByIDSalesOrderOutput) -> FindByIDSalesOrderResponse {
+    FindByIDSalesOrderResponse::new(output.sales_order)
+}
+
+pub(crate) fn search_sales_order(output: SearchSalesOrderOutput) -> SearchSalesOrderResponse {
+    SearchSalesOrderResponse::new(output)
+}
+
+pub(crate) fn create_sales_order(sales_order: Option<SalesOrder>) -> CreateSalesOrderResponse {
+    CreateSalesOrderResponse::new(sales_order)
+}
+
+pub(crate) fn update_sales_order(sales_order: Option<SalesOrder>) -> UpdateSalesOrderResponse {
+    UpdateSalesOrderResponse::new(sales_order)
+}
+
+pub(crate) fn cancel_sales_order(result: bool) -> CancelSalesOrderResponse {
+    CancelSalesOrderResponse::new(result)
+}
@@ -0,0 +1,26 @@
+use crate::{
+    application::usecase::stock_movement::{
+        find_on_hand_stock::FindOnHandStockOutput, post_stock_movement::PostStockMovementOutput,
+        search_stock_movement::SearchStockMovementOutput,
+    },
+    domain::stock_movement::StockLevel,
+};
+
+use super::response::stock_movement_response::{
+    FindOnHandStockResponse, PostStockMovementResponse, SearchStockMovementResponse,
+};
+
+pub(crate) fn post_stock_movement(
+    output: PostStockMovementOutput,
+    stock_level: StockLevel,
+) -> PostStockMovementResponse {
+    PostStockMovementResponse::new(*output.result().stock_movement_id(), stock_level)
+}
+
+pub(crate) fn search_stock_movement(output: SearchStockMovementOutput) -> SearchStockMovementResponse {
+    SearchStockMovementResponse::new(output)
+}
+
+pub(crate) fn find_on_hand_stock(output: FindOnHandStockOutput) -> FindOnHandStockResponse {
+    FindOnHandStockResponse::new(output.stock_level)
+}
@@ -0,0 +1,27 @@
+use crate::{
+    application::usecase::user::{
+        create_user::CreateUserOutput, search_user::SearchUserOutput,
+        sign_in_user::SignInUserOutput,
+    },
+    domain::user::User,
+};
+
+use super::response::user_response::{
+    CreateUserResponse, FindSessionResponse, SearchUserResponse, SignInUserResponse,
+};
+
+pub(crate) fn find_session(user: Option<User>, has_users: bool) -> FindSessionResponse {
+    FindSessionResponse::new(user, has_users)
+}
+
+pub(crate) fn sign_in_user(output: SignInUserOutput) -> SignInUserResponse {
+    SignInUserResponse::new(output.user)
+}
+
+pub(crate) fn search_user(output: SearchUserOutput) -> SearchUserResponse {
+    SearchUserResponse::new(output.users)
+}
+
+pub(crate) fn create_user(output: CreateUserOutput) -> CreateUserResponse {
+    CreateUserResponse::new(output.user_id)
+}
@@ -0,0 +1,8 @@
+use crate::application::usecase::audit_log::search_audit_log::SearchAuditLogInput;
+use crate::domain::audit_log::AuditLog;
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait AuditLogAbstructRepository: Send + Sync {
+    async fn search(&self, input: &SearchAuditLogInput) -> Result<Vec<AuditLog>, DomainError>;
+}
@@ -0,0 +1,10 @@
+use crate::application::usecase::company_profile::save_company_profile::SaveCompanyProfileInput;
+use crate::domain::company_profile::CompanyProfile;
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait CompanyProfileAbstructRepository: Send + Sync {
+    async fn find(&self) -> Result<Option<CompanyProfile>, DomainError>;
+    /// Creates the profile on first use and overwrites it afterwards.
+    async fn save(&self, input: &SaveCompanyProfileInput) -> Result<(), DomainError>;
+}
@@ -1,5 +1,13 @@
-use std::error::Error;
-use crate::{application::usecase::customer::{create_customer::{CreateCustomerInput}, update_customer::UpdateCustomerInput}, domain::customer::{Id, Customer}};
+use crate::{
+    application::usecase::customer::{
+        create_customer::CreateCustomerInput, search_customer::SearchCustomerInput,
+        update_customer::UpdateCustomerInput,
+    },
+    domain::{
+        customer::{Customer, Id},
+        error::DomainError,
+    },
+};
 
 
 pub struct CreateCustomerResult {
@@ -30,9 +38,43 @@ impl UpdateCustomerResult {
     } 
 }
 
+pub struct DeleteCustomerResult {
+    result: bool
+}
+impl From<bool> for DeleteCustomerResult {
+    fn from(result: bool) -> Self {
+       Self { result }
+    }
+}
+impl DeleteCustomerResult {
+    pub fn result(&self) -> bool {
+        self.result
+    }
+}
+
+pub struct RestoreCustomerResult {
+    result: bool
+}
+impl From<bool> for RestoreCustomerResult {
+    fn from(result: bool) -> Self {
+       Self { result }
+    }
+}
+impl RestoreCustomerResult {
+    pub fn result(&self) -> bool {
+        self.result
+    }
+}
+
 #[async_trait::async_trait]
-pub trait CustomerAbstructRepository {
-   async fn find_by_id(&self, id: &i64) -> Result<Option<Customer>, Box<dyn Error>>;
-   async fn create(&self, input: CreateCustomerInput) -> Result<CreateCustomerResult, Box<dyn Error>>;
-   async fn update(&self, input: UpdateCustomerInput) -> Result<UpdateCustomerResult, Box<dyn Error>>;
+pub trait CustomerAbstructRepository: Send + Sync {
+   /// Soft-deleted customers are not found.
+   async fn find_by_id(&self, id: &i64) -> Result<Option<Customer>, DomainError>;
+   /// Also finds soft-deleted customers, for documents that refer to them.
+   async fn find_by_id_including_deleted(&self, id: &Id) -> Result<Option<Customer>, DomainError>;
+   async fn search(&self, input: &SearchCustomerInput) -> Result<Vec<Customer>, DomainError>;
+   async fn create(&self, input: CreateCustomerInput) -> Result<CreateCustomerResult, DomainError>;
+   async fn update(&self, input: UpdateCustomerInput) -> Result<UpdateCustomerResult, DomainError>;
+   async fn delete(&self, id: &Id) -> Result<DeleteCustomerResult, DomainError>;
+   async fn restore(&self, id: &Id) -> Result<RestoreCustomerResult, DomainError>;
 }
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::application::usecase::invoice::search_invoice::SearchInvoiceInput;
+use crate::domain::{
+    customer,
+    invoice::{BillableLine, Invoice, InvoiceDate, InvoiceDraft, InvoiceId, InvoiceNumber},
+};
+use crate::domain::error::DomainError;
+
+#[derive(Debug)]
+pub struct IssueInvoiceResult {
+    invoice_id: InvoiceId,
+    invoice_number: InvoiceNumber,
+}
+impl IssueInvoiceResult {
+    pub fn new(invoice_id: InvoiceId, invoice_number: InvoiceNumber) -> Self {
+        Self {
+            invoice_id,
+            invoice_number,
+        }
+    }
+
+    pub fn invoice_id(&self) -> &InvoiceId {
+        &self.invoice_id
+    }
+
+    pub fn invoice_number(&self) -> &str {
+        &self.invoice_number
+    }
+}
+
+#[async_trait::async_trait]
+pub trait InvoiceAbstructRepository: Send + Sync {
+    async fn find_by_id(&self, id: &InvoiceId) -> Result<Option<Invoice>, DomainError>;
+    async fn search(&self, input: &SearchInvoiceInput) -> Result<Vec<Invoice>, DomainError>;
+    /// Lines of delivered orders for the customer within the period that are not on any invoice yet.
+    async fn search_billable_lines(
+        &self,
+        customer_id: &customer::Id,
+        period_from: &InvoiceDate,
+        period_to: &InvoiceDate,
+    ) -> Result<Vec<BillableLine>, DomainError>;
+    /// Numbers the draft and stores it. Numbers are assigned once and never reused.
+    async fn create(&self, draft: &InvoiceDraft) -> Result<IssueInvoiceResult, DomainError>;
+}
@@ -1,2 +1,13 @@
+pub mod audit_log_repository;
+pub mod company_profile_repository;
 pub mod customer_repository;
+pub mod invoice_repository;
+pub mod price_list_repository;
 pub mod product_repository;
+pub mod quotation_repository;
+pub mod receivable_repository;
+pub mod reorder_alert_repository;
+pub mod sales_order_repository;
+pub mod stock_movement_repository;
+pub mod unit_of_work;
+pub mod user_repository;
@@ -0,0 +1,76 @@
+use crate::application::usecase::price_list::{
+    create_price_list::CreatePriceListInput, search_price_list::SearchPriceListInput,
+    update_price_list::UpdatePriceListInput,
+};
+use crate::domain::{
+    customer,
+    price_list::{PriceListEntry, PriceListId},
+    product::ProductId,
+};
+use crate::domain::error::DomainError;
+
+#[derive(Debug)]
+pub struct CreatePriceListResult {
+    price_list_id: PriceListId,
+}
+impl CreatePriceListResult {
+    pub fn new(price_list_id: PriceListId) -> Self {
+        Self { price_list_id }
+    }
+
+    pub fn price_list_id(&self) -> &PriceListId {
+        &self.price_list_id
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdatePriceListResult {
+    price_list_id: PriceListId,
+}
+impl UpdatePriceListResult {
+    pub fn new(price_list_id: PriceListId) -> Self {
+        Self { price_list_id }
+    }
+
+    pub fn price_list_id(&self) -> &PriceListId {
+        &self.price_list_id
+    }
+}
+
+#[derive(Debug)]
+pub struct DeletePriceListResult {
+    result: bool,
+}
+impl DeletePriceListResult {
+    pub fn new(result: bool) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &bool {
+        &self.result
+    }
+}
+
+#[async_trait::async_trait]
+pub trait PriceListAbstructRepository: Send + Sync {
+    async fn find_by_id(&self, id: &PriceListId) -> Result<Option<PriceListEntry>, DomainError>;
+    async fn search(
+        &self,
+        input: &SearchPriceListInput,
+    ) -> Result<Vec<PriceListEntry>, DomainError>;
+    /// Every entry for the pair, whatever its validity period or minimum quantity.
+    async fn find_by_customer_and_product(
+        &self,
+        customer_id: &customer::Id,
+        product_id: &ProductId,
+    ) -> Result<Vec<PriceListEntry>, DomainError>;
+    async fn create(
+        &self,
+        input: &CreatePriceListInput,
+    ) -> Result<CreatePriceListResult, DomainError>;
+    async fn update(
+        &self,
+        input: &UpdatePriceListInput,
+    ) -> Result<UpdatePriceListResult, DomainError>;
+    async fn delete(&self, id: &PriceListId) -> Result<DeletePriceListResult, DomainError>;
+}
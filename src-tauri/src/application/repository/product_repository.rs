@@ -2,7 +2,7 @@ use crate::application::usecase::product::create_product::CreateProductInput;
 use crate::application::usecase::product::search_product::SearchProductInput;
 use crate::application::usecase::product::update_product::UpdateProductInput;
 use crate::domain::product::{Product, ProductId};
-use std::error::Error;
+use crate::domain::error::DomainError;
 
 #[derive(Debug)]
 pub struct CreateProductResult {
@@ -47,17 +47,67 @@ impl DeleteProductResult {
     }
 }
 
+#[derive(Debug)]
+pub struct RestoreProductResult {
+    result: bool,
+}
+impl RestoreProductResult {
+    pub fn new(result: bool) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &bool {
+        &self.result
+    }
+}
+
+#[derive(Debug)]
+pub struct UpsertProductResult {
+    created: i64,
+    updated: i64,
+}
+impl UpsertProductResult {
+    pub fn new(created: i64, updated: i64) -> Self {
+        Self { created, updated }
+    }
+
+    pub fn created(&self) -> &i64 {
+        &self.created
+    }
+
+    pub fn updated(&self) -> &i64 {
+        &self.updated
+    }
+}
+
 #[async_trait::async_trait]
-pub trait ProductAbstructRepository {
-    async fn find_by_id(&self, id: &ProductId) -> Result<Option<Product>, Box<dyn Error>>;
-    async fn search(&self, input: &SearchProductInput) -> Result<Vec<Product>, Box<dyn Error>>;
+pub trait ProductAbstructRepository: Send + Sync {
+    /// Soft-deleted products are not found.
+    async fn find_by_id(&self, id: &ProductId) -> Result<Option<Product>, DomainError>;
+    /// Also finds soft-deleted products, for documents that refer to them.
+    async fn find_by_id_including_deleted(
+        &self,
+        id: &ProductId,
+    ) -> Result<Option<Product>, DomainError>;
+    /// Soft-deleted products are not found, as their codes may be reused.
+    async fn find_by_code(&self, code: &str) -> Result<Option<Product>, DomainError>;
+    async fn search(&self, input: &SearchProductInput) -> Result<Vec<Product>, DomainError>;
+    /// Counts the products `search` would find without `offset` and `limit`.
+    async fn count(&self, input: &SearchProductInput) -> Result<i64, DomainError>;
     async fn create(
         &self,
         product: &CreateProductInput,
-    ) -> Result<CreateProductResult, Box<dyn Error>>;
+    ) -> Result<CreateProductResult, DomainError>;
     async fn update(
         &self,
         input: &UpdateProductInput,
-    ) -> Result<UpdateProductResult, Box<dyn Error>>;
-    async fn delete(&self, product_id: &ProductId) -> Result<DeleteProductResult, Box<dyn Error>>;
+    ) -> Result<UpdateProductResult, DomainError>;
+    /// Overwrites the active product with the same code, or creates one when there is none.
+    /// Either every product is stored or none is.
+    async fn upsert_by_code(
+        &self,
+        products: &[CreateProductInput],
+    ) -> Result<UpsertProductResult, DomainError>;
+    async fn delete(&self, product_id: &ProductId) -> Result<DeleteProductResult, DomainError>;
+    async fn restore(&self, product_id: &ProductId) -> Result<RestoreProductResult, DomainError>;
 }
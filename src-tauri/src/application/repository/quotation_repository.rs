@@ -0,0 +1,63 @@
+use crate::application::usecase::quotation::create_quotation::CreateQuotationInput;
+use crate::application::usecase::quotation::search_quotation::SearchQuotationInput;
+use crate::application::usecase::quotation::update_quotation::UpdateQuotationInput;
+use crate::domain::quotation::{Quotation, QuotationId, QuotationStatus};
+use crate::domain::sales_order::SalesOrderId;
+use crate::domain::error::DomainError;
+
+#[derive(Debug)]
+pub struct CreateQuotationResult {
+    quotation_id: QuotationId,
+}
+impl CreateQuotationResult {
+    pub fn new(quotation_id: QuotationId) -> Self {
+        Self { quotation_id }
+    }
+
+    pub fn quotation_id(&self) -> &QuotationId {
+        &self.quotation_id
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateQuotationResult {
+    quotation_id: QuotationId,
+}
+impl UpdateQuotationResult {
+    pub fn new(quotation_id: QuotationId) -> Self {
+        Self { quotation_id }
+    }
+
+    pub fn quotation_id(&self) -> &QuotationId {
+        &self.quotation_id
+    }
+}
+
+#[async_trait::async_trait]
+pub trait QuotationAbstructRepository: Send + Sync {
+    async fn find_by_id(&self, id: &QuotationId) -> Result<Option<Quotation>, DomainError>;
+    async fn search(&self, input: &SearchQuotationInput) -> Result<Vec<Quotation>, DomainError>;
+    async fn create(
+        &self,
+        input: &CreateQuotationInput,
+    ) -> Result<CreateQuotationResult, DomainError>;
+    async fn update(
+        &self,
+        input: &UpdateQuotationInput,
+    ) -> Result<UpdateQuotationResult, DomainError>;
+    /// Moves the quote from `current` to `next`; returns `false` when the stored status was
+    /// no longer `current`.
+    async fn update_status(
+        &self,
+        id: &QuotationId,
+        current: &QuotationStatus,
+        next: &QuotationStatus,
+    ) -> Result<bool, DomainError>;
+    /// Links the quote to the sales order created from it; returns `false` when it had
+    /// already been converted.
+    async fn mark_converted(
+        &self,
+        id: &QuotationId,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,52 @@
+use crate::application::usecase::receivable::{
+    record_payment::{PaymentAllocationInput, RecordPaymentInput},
+    search_payment::SearchPaymentInput,
+};
+use crate::domain::{
+    customer,
+    receivable::{CustomerBalance, InvoiceBalance, Payment, PaymentId},
+};
+use crate::domain::error::DomainError;
+
+#[derive(Debug)]
+pub struct RecordPaymentResult {
+    payment_id: PaymentId,
+}
+impl RecordPaymentResult {
+    pub fn new(payment_id: PaymentId) -> Self {
+        Self { payment_id }
+    }
+
+    pub fn payment_id(&self) -> &PaymentId {
+        &self.payment_id
+    }
+}
+
+#[async_trait::async_trait]
+pub trait ReceivableAbstructRepository: Send + Sync {
+    async fn find_payment_by_id(&self, id: &PaymentId) -> Result<Option<Payment>, DomainError>;
+    async fn search_payments(
+        &self,
+        input: &SearchPaymentInput,
+    ) -> Result<Vec<Payment>, DomainError>;
+    /// Stores the payment together with its allocations.
+    async fn create_payment(
+        &self,
+        input: &RecordPaymentInput,
+        allocations: &[PaymentAllocationInput],
+    ) -> Result<RecordPaymentResult, DomainError>;
+    async fn create_allocations(
+        &self,
+        payment_id: &PaymentId,
+        allocations: &[PaymentAllocationInput],
+    ) -> Result<(), DomainError>;
+    /// Balances of every invoice of the customer, or of all customers when `None`.
+    async fn search_invoice_balances(
+        &self,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<Vec<InvoiceBalance>, DomainError>;
+    async fn search_customer_balances(
+        &self,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<Vec<CustomerBalance>, DomainError>;
+}
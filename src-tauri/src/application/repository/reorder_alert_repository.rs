@@ -0,0 +1,7 @@
+use crate::domain::{product::ProductId, reorder_alert::ReorderAlert};
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait ReorderAlertAbstructRepository: Send + Sync {
+    async fn search(&self, product_id: &Option<ProductId>) -> Result<Vec<ReorderAlert>, DomainError>;
+}
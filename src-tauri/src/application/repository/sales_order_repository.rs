@@ -0,0 +1,62 @@
+use crate::application::usecase::sales_order::create_sales_order::CreateSalesOrderInput;
+use crate::application::usecase::sales_order::search_sales_order::SearchSalesOrderInput;
+use crate::application::usecase::sales_order::update_sales_order::UpdateSalesOrderInput;
+use crate::domain::sales_order::{SalesOrder, SalesOrderId};
+use crate::domain::error::DomainError;
+
+#[derive(Debug)]
+pub struct CreateSalesOrderResult {
+    sales_order_id: SalesOrderId,
+}
+impl CreateSalesOrderResult {
+    pub fn new(sales_order_id: SalesOrderId) -> Self {
+        Self { sales_order_id }
+    }
+
+    pub fn sales_order_id(&self) -> &SalesOrderId {
+        &self.sales_order_id
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateSalesOrderResult {
+    sales_order_id: SalesOrderId,
+}
+impl UpdateSalesOrderResult {
+    pub fn new(sales_order_id: SalesOrderId) -> Self {
+        Self { sales_order_id }
+    }
+
+    pub fn sales_order_id(&self) -> &SalesOrderId {
+        &self.sales_order_id
+    }
+}
+
+#[derive(Debug)]
+pub struct CancelSalesOrderResult {
+    result: bool,
+}
+impl CancelSalesOrderResult {
+    pub fn new(result: bool) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &bool {
+        &self.result
+    }
+}
+
+#[async_trait::async_trait]
+pub trait OrderAbstructRepository: Send + Sync {
+    async fn find_by_id(&self, id: &SalesOrderId) -> Result<Option<SalesOrder>, DomainError>;
+    async fn search(&self, input: &SearchSalesOrderInput) -> Result<Vec<SalesOrder>, DomainError>;
+    async fn create(
+        &self,
+        input: &CreateSalesOrderInput,
+    ) -> Result<CreateSalesOrderResult, DomainError>;
+    async fn update(
+        &self,
+        input: &UpdateSalesOrderInput,
+    ) -> Result<UpdateSalesOrderResult, DomainError>;
+    async fn cancel(&self, id: &SalesOrderId) -> Result<CancelSalesOrderResult, DomainError>;
+}
@@ -0,0 +1,32 @@
+use crate::application::usecase::stock_movement::post_stock_movement::PostStockMovementInput;
+use crate::application::usecase::stock_movement::search_stock_movement::SearchStockMovementInput;
+use crate::domain::product::ProductId;
+use crate::domain::stock_movement::{StockLevel, StockMovement, StockMovementId};
+use crate::domain::error::DomainError;
+
+#[derive(Debug)]
+pub struct PostStockMovementResult {
+    stock_movement_id: StockMovementId,
+}
+impl PostStockMovementResult {
+    pub fn new(stock_movement_id: StockMovementId) -> Self {
+        Self { stock_movement_id }
+    }
+
+    pub fn stock_movement_id(&self) -> &StockMovementId {
+        &self.stock_movement_id
+    }
+}
+
+#[async_trait::async_trait]
+pub trait StockMovementAbstructRepository: Send + Sync {
+    async fn search(
+        &self,
+        input: &SearchStockMovementInput,
+    ) -> Result<Vec<StockMovement>, DomainError>;
+    async fn create(
+        &self,
+        input: &PostStockMovementInput,
+    ) -> Result<PostStockMovementResult, DomainError>;
+    async fn find_on_hand(&self, product_id: &ProductId) -> Result<StockLevel, DomainError>;
+}
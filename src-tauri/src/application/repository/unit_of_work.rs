@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::application::repository::{
+    company_profile_repository::CompanyProfileAbstructRepository,
+    customer_repository::CustomerAbstructRepository, invoice_repository::InvoiceAbstructRepository,
+    price_list_repository::PriceListAbstructRepository,
+    product_repository::ProductAbstructRepository,
+    quotation_repository::QuotationAbstructRepository,
+    receivable_repository::ReceivableAbstructRepository,
+    sales_order_repository::OrderAbstructRepository,
+    stock_movement_repository::StockMovementAbstructRepository,
+};
+use crate::domain::error::DomainError;
+
+/// Repository operations that succeed or fail together. The repositories it hands out see
+/// each other's changes, which nobody else sees until `commit`. Dropping it without
+/// committing rolls everything back, so a usecase can simply return its error.
+#[async_trait::async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn company_profile_repository(&self) -> Arc<dyn CompanyProfileAbstructRepository>;
+    fn customer_repository(&self) -> Arc<dyn CustomerAbstructRepository>;
+    fn invoice_repository(&self) -> Arc<dyn InvoiceAbstructRepository>;
+    fn price_list_repository(&self) -> Arc<dyn PriceListAbstructRepository>;
+    fn product_repository(&self) -> Arc<dyn ProductAbstructRepository>;
+    fn quotation_repository(&self) -> Arc<dyn QuotationAbstructRepository>;
+    fn receivable_repository(&self) -> Arc<dyn ReceivableAbstructRepository>;
+    fn sales_order_repository(&self) -> Arc<dyn OrderAbstructRepository>;
+    fn stock_movement_repository(&self) -> Arc<dyn StockMovementAbstructRepository>;
+    /// Repositories handed out fail from then on.
+    async fn commit(self: Box<Self>) -> Result<(), DomainError>;
+}
+
+#[async_trait::async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, DomainError>;
+}
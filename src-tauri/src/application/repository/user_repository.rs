@@ -0,0 +1,15 @@
+use crate::application::usecase::user::{
+    create_user::CreateUserInput, sign_in_user::SignInUserInput,
+};
+use crate::domain::error::DomainError;
+use crate::domain::user::{User, UserId};
+
+#[async_trait::async_trait]
+pub trait UserAbstructRepository: Send + Sync {
+    async fn search(&self) -> Result<Vec<User>, DomainError>;
+    async fn count(&self) -> Result<i64, DomainError>;
+    /// Stores the user with a hash of the password, never the password itself.
+    async fn create(&self, input: &CreateUserInput) -> Result<UserId, DomainError>;
+    /// The user whose name and password match, if any.
+    async fn authenticate(&self, input: &SignInUserInput) -> Result<Option<User>, DomainError>;
+}
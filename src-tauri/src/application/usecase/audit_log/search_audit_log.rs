@@ -0,0 +1,99 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::audit_log_repository::AuditLogAbstructRepository,
+    domain::{
+        audit_log::{AuditEntity, AuditLog, AuditLogDate},
+        error::DomainError,
+    },
+};
+
+/// `from` and `to` are inclusive dates, compared with the UTC date of each change.
+#[derive(Debug)]
+pub struct SearchAuditLogInput {
+    offset: i64,
+    limit: i64,
+    entity_type: Option<AuditEntity>,
+    entity_id: Option<i64>,
+    from: Option<AuditLogDate>,
+    to: Option<AuditLogDate>,
+}
+impl SearchAuditLogInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        entity_type: Option<AuditEntity>,
+        entity_id: Option<i64>,
+        from: Option<AuditLogDate>,
+        to: Option<AuditLogDate>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            entity_type,
+            entity_id,
+            from,
+            to,
+        }
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn entity_type(&self) -> &Option<AuditEntity> {
+        &self.entity_type
+    }
+
+    pub fn entity_id(&self) -> &Option<i64> {
+        &self.entity_id
+    }
+
+    pub fn from(&self) -> &Option<AuditLogDate> {
+        &self.from
+    }
+
+    pub fn to(&self) -> &Option<AuditLogDate> {
+        &self.to
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchAuditLogOutput {
+    pub audit_logs: Vec<AuditLog>,
+}
+impl SearchAuditLogOutput {
+    fn new(audit_logs: Vec<AuditLog>) -> Self {
+        Self { audit_logs }
+    }
+}
+
+/// Finds recorded changes, newest first.
+pub struct SearchAuditLogUsecase {
+    repository: Arc<dyn AuditLogAbstructRepository>,
+}
+impl SearchAuditLogUsecase {
+    pub fn new(repository: Arc<dyn AuditLogAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchAuditLogInput,
+    ) -> Result<SearchAuditLogOutput, DomainError> {
+        if input.entity_id().is_some() && input.entity_type().is_none() {
+            return Err(DomainError::validation(
+                "entity_type",
+                "entity_type is required to search by entity_id",
+            ));
+        }
+        let audit_logs = self.repository.search(&input).await?;
+
+        Ok(SearchAuditLogOutput::new(audit_logs))
+    }
+}
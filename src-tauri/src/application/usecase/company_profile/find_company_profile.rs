@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::company_profile_repository::CompanyProfileAbstructRepository,
+    domain::{company_profile::CompanyProfile, error::DomainError},
+};
+
+#[derive(Debug)]
+pub struct FindCompanyProfileOutput {
+    pub company_profile: Option<CompanyProfile>,
+}
+impl FindCompanyProfileOutput {
+    pub fn new(company_profile: Option<CompanyProfile>) -> Self {
+        Self { company_profile }
+    }
+}
+
+pub struct FindCompanyProfileUsecase {
+    repository: Arc<dyn CompanyProfileAbstructRepository>,
+}
+impl FindCompanyProfileUsecase {
+    pub fn new(repository: Arc<dyn CompanyProfileAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find(&self) -> Result<FindCompanyProfileOutput, DomainError> {
+        let company_profile = self.repository.find().await?;
+
+        Ok(FindCompanyProfileOutput::new(company_profile))
+    }
+}
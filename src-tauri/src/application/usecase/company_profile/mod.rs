@@ -0,0 +1,2 @@
+pub mod find_company_profile;
+pub mod save_company_profile;
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::company_profile_repository::CompanyProfileAbstructRepository,
+    domain::{
+        company_profile::{
+            validate_registration_number, CompanyAddress, CompanyName, RegistrationNumber,
+        },
+        error::DomainError,
+    },
+};
+
+#[derive(Debug)]
+pub struct SaveCompanyProfileInput {
+    registration_number: RegistrationNumber,
+    name: CompanyName,
+    address: CompanyAddress,
+}
+impl SaveCompanyProfileInput {
+    pub fn new(
+        registration_number: RegistrationNumber,
+        name: CompanyName,
+        address: CompanyAddress,
+    ) -> Self {
+        Self {
+            registration_number,
+            name,
+            address,
+        }
+    }
+
+    pub fn registration_number(&self) -> &RegistrationNumber {
+        &self.registration_number
+    }
+
+    pub fn name(&self) -> &CompanyName {
+        &self.name
+    }
+
+    pub fn address(&self) -> &CompanyAddress {
+        &self.address
+    }
+}
+
+pub struct SaveCompanyProfileUsecase {
+    repository: Arc<dyn CompanyProfileAbstructRepository>,
+}
+impl SaveCompanyProfileUsecase {
+    pub fn new(repository: Arc<dyn CompanyProfileAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn save(&self, input: SaveCompanyProfileInput) -> Result<(), DomainError> {
+        validate_registration_number(&input.registration_number)?;
+        if input.name.trim().is_empty() {
+            return Err(DomainError::validation("name", "company name is required"));
+        }
+        if input.address.trim().is_empty() {
+            return Err(DomainError::validation(
+                "address",
+                "company address is required",
+            ));
+        }
+        self.repository.save(&input).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+
+    use crate::{
+        adapters::gateway::company_profile_repository::SqliteCompanyProfileRepository,
+        application::{
+            repository::company_profile_repository::CompanyProfileAbstructRepository,
+            usecase::company_profile::save_company_profile::{
+                SaveCompanyProfileInput, SaveCompanyProfileUsecase,
+            },
+        },
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn save_test(pool: SqlitePool) {
+        let repository = Arc::new(SqliteCompanyProfileRepository::new(pool));
+        let usecase = SaveCompanyProfileUsecase::new(repository.clone());
+
+        let input = SaveCompanyProfileInput::new(
+            String::from("T1234567890123"),
+            String::from("自社"),
+            String::from("東京都"),
+        );
+        usecase.save(input).await.unwrap();
+        let input = SaveCompanyProfileInput::new(
+            String::from("T1234567890123"),
+            String::from("自社株式会社"),
+            String::from("東京都"),
+        );
+        usecase.save(input).await.unwrap();
+        let company_profile = repository.find().await.unwrap().unwrap();
+
+        assert_eq!(company_profile.name(), "自社株式会社");
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn save_invalid_test(pool: SqlitePool) {
+        let repository = Arc::new(SqliteCompanyProfileRepository::new(pool));
+        let usecase = SaveCompanyProfileUsecase::new(repository.clone());
+
+        let input = SaveCompanyProfileInput::new(
+            String::from("1234567890123"),
+            String::from("自社"),
+            String::from("東京都"),
+        );
+        assert!(usecase.save(input).await.is_err());
+        let input = SaveCompanyProfileInput::new(
+            String::from("T1234567890123"),
+            String::from(""),
+            String::from("東京都"),
+        );
+        assert!(usecase.save(input).await.is_err());
+        assert!(repository.find().await.unwrap().is_none());
+    }
+}
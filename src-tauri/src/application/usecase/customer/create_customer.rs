@@ -1,7 +1,7 @@
-use std::error::Error;
-use std::rc::Rc;
+use std::sync::Arc;
 
-use crate::domain::customer::{Name, Postal, Address, Id};
+use crate::domain::customer::{Name, Postal, PostalCode, Address, Id};
+use crate::domain::error::DomainError;
 use crate::application::repository::customer_repository::{ CustomerAbstructRepository, CreateCustomerResult};
 
 pub struct CreateCustomerInput {
@@ -43,15 +43,16 @@ impl CreateCustomerOutput {
 
 
 pub struct CreateCustomerUsecase {
-    repository: Rc<dyn CustomerAbstructRepository>
+    repository: Arc<dyn CustomerAbstructRepository>
 }
 impl CreateCustomerUsecase {
-    pub fn new (repository:  Rc<dyn CustomerAbstructRepository>) -> Self {
+    pub fn new (repository:  Arc<dyn CustomerAbstructRepository>) -> Self {
         Self { repository }
     }
 }
 impl CreateCustomerUsecase {
-    pub async fn create(&self, input: CreateCustomerInput) -> Result<CreateCustomerOutput, Rc<dyn Error>> {
+    pub async fn create(&self, input: CreateCustomerInput) -> Result<CreateCustomerOutput, DomainError> {
+        PostalCode::new(input.postal())?;
         let result = self.repository.create(input).await?;
 
         Ok(CreateCustomerOutput::from(result))
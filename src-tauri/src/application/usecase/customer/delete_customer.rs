@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::customer_repository::{
+        CustomerAbstructRepository, DeleteCustomerResult,
+    },
+    domain::{customer::Id, error::DomainError},
+};
+
+pub type DeleteCustomerOutput = DeleteCustomerResult;
+
+pub struct DeleteCustomerUsecase {
+    repository: Arc<dyn CustomerAbstructRepository>,
+}
+impl DeleteCustomerUsecase {
+    pub fn new(repository: Arc<dyn CustomerAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Marks the customer as deleted; orders and invoices keep referring to it.
+    pub async fn delete(&self, customer_id: &Id) -> Result<DeleteCustomerOutput, DomainError> {
+        let result = self.repository.delete(customer_id).await?;
+
+        Ok(result)
+    }
+}
@@ -1,18 +1,24 @@
-use std::{rc::Rc, error::Error};
+use std::sync::Arc;
 
-use crate::{application::repository::customer_repository::CustomerAbstructRepository, domain::customer::{Customer, Id}};
+use crate::{
+    application::repository::customer_repository::CustomerAbstructRepository,
+    domain::{
+        customer::{Customer, Id},
+        error::DomainError,
+    },
+};
 
 
 pub struct FindByIDCustomerUsecase {
-    repository: Rc<dyn CustomerAbstructRepository>
+    repository: Arc<dyn CustomerAbstructRepository>
 }
 impl FindByIDCustomerUsecase {
-    pub fn new (repository: Rc<dyn CustomerAbstructRepository>) -> Self {
+    pub fn new (repository: Arc<dyn CustomerAbstructRepository>) -> Self {
         Self { repository }
     }
 }
 impl FindByIDCustomerUsecase {
-    pub async fn find_by_id(&self, customer_id: &Id) -> Result<Option<Customer>, Rc<dyn Error>> {
+    pub async fn find_by_id(&self, customer_id: &Id) -> Result<Option<Customer>, DomainError> {
         let result = self.repository.find_by_id(customer_id).await?;
 
         Ok(result)
@@ -1,3 +1,6 @@
 pub mod create_customer;
+pub mod delete_customer;
 pub mod find_by_id_customer;
+pub mod restore_customer;
+pub mod search_customer;
 pub mod update_customer;
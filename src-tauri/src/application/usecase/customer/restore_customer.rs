@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::customer_repository::{
+        CustomerAbstructRepository, RestoreCustomerResult,
+    },
+    domain::{customer::Id, error::DomainError},
+};
+
+pub type RestoreCustomerOutput = RestoreCustomerResult;
+
+pub struct RestoreCustomerUsecase {
+    repository: Arc<dyn CustomerAbstructRepository>,
+}
+impl RestoreCustomerUsecase {
+    pub fn new(repository: Arc<dyn CustomerAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn restore(&self, customer_id: &Id) -> Result<RestoreCustomerOutput, DomainError> {
+        let result = self.repository.restore(customer_id).await?;
+
+        Ok(result)
+    }
+}
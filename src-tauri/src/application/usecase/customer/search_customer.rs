@@ -0,0 +1,107 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::customer_repository::CustomerAbstructRepository,
+    domain::{
+        customer::{Address, Customer, Name, Postal},
+        error::DomainError,
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchCustomerInput {
+    offset: i64,
+    limit: i64,
+    name: Option<Name>,
+    address: Option<Address>,
+    postal: Option<Postal>,
+    include_deleted: bool,
+    after_id: Option<i64>,
+}
+impl SearchCustomerInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        name: Option<Name>,
+        address: Option<Address>,
+        postal: Option<Postal>,
+        include_deleted: Option<bool>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            name,
+            address,
+            postal,
+            include_deleted: include_deleted.unwrap_or(false),
+            after_id: None,
+        }
+    }
+
+    /// Reads only the customers after `after_id`, so that a page deep into every customer costs
+    /// no more to read than the first.
+    pub fn with_after_id(mut self, after_id: i64) -> Self {
+        self.after_id = Some(after_id);
+        self
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    /// Matches any part of the name.
+    pub fn name(&self) -> &Option<Name> {
+        &self.name
+    }
+
+    /// Matches any part of the address.
+    pub fn address(&self) -> &Option<Address> {
+        &self.address
+    }
+
+    pub fn postal(&self) -> &Option<Postal> {
+        &self.postal
+    }
+
+    /// Soft-deleted customers are left out unless this is set.
+    pub fn include_deleted(&self) -> &bool {
+        &self.include_deleted
+    }
+
+    pub fn after_id(&self) -> &Option<i64> {
+        &self.after_id
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchCustomerOutput {
+    pub customers: Vec<Customer>,
+}
+impl From<Vec<Customer>> for SearchCustomerOutput {
+    fn from(customers: Vec<Customer>) -> Self {
+        Self { customers }
+    }
+}
+
+pub struct SearchCustomerUsecase {
+    repository: Arc<dyn CustomerAbstructRepository>,
+}
+impl SearchCustomerUsecase {
+    pub fn new(repository: Arc<dyn CustomerAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchCustomerInput,
+    ) -> Result<SearchCustomerOutput, DomainError> {
+        let customers = self.repository.search(&input).await?;
+
+        Ok(SearchCustomerOutput::from(customers))
+    }
+}
@@ -1,16 +1,28 @@
-use std::{rc::Rc, error::Error};
+use std::sync::Arc;
+use time::PrimitiveDateTime;
 
-use crate::{domain::customer::{Name, Postal, Address, Id}, application::repository::customer_repository::{CustomerAbstructRepository, UpdateCustomerResult}};
+use crate::{
+    application::repository::customer_repository::{
+        CustomerAbstructRepository, UpdateCustomerResult,
+    },
+    domain::{
+        customer::{Address, Id, Name, Postal, PostalCode},
+        error::DomainError,
+    },
+};
 
 pub struct UpdateCustomerInput {
     id: Id,
     name: Option<Name>,
     postal: Option<Postal>,
     address: Option<Address>,
+    expected_updated_at: Option<PrimitiveDateTime>,
 }
 impl UpdateCustomerInput {
-    pub fn new (id: Id, name: Option<Name>, postal: Option<Postal>, address: Option<Address>) -> Self {
-        Self { id, name, postal, address }
+    /// With `expected_updated_at`, the update fails with a conflict when the customer was updated
+    /// after it was read.
+    pub fn new (id: Id, name: Option<Name>, postal: Option<Postal>, address: Option<Address>, expected_updated_at: Option<PrimitiveDateTime>) -> Self {
+        Self { id, name, postal, address, expected_updated_at }
     }
 
     pub fn id(&self) -> Id {
@@ -21,13 +33,17 @@ impl UpdateCustomerInput {
         &self.name
     }
 
-    pub fn postal(&self) -> Option<Postal> {
-        self.postal
+    pub fn postal(&self) -> &Option<Postal> {
+        &self.postal
     }
 
     pub fn address(&self) -> &Option<Address> {
         &self.address
     }
+
+    pub fn expected_updated_at(&self) -> &Option<PrimitiveDateTime> {
+        &self.expected_updated_at
+    }
 }
 
 pub struct UpdateCustomerOutput {
@@ -45,15 +61,18 @@ impl UpdateCustomerOutput {
 }
 
 pub struct UpdateCustomerUsecase {
-    repository: Rc<dyn CustomerAbstructRepository>
+    repository: Arc<dyn CustomerAbstructRepository>
 }
 impl UpdateCustomerUsecase {
-    pub fn new(repository: Rc<dyn CustomerAbstructRepository>) -> Self {
+    pub fn new(repository: Arc<dyn CustomerAbstructRepository>) -> Self {
         Self { repository }
     }
 }
 impl UpdateCustomerUsecase {
-    pub async fn update(&self, input: UpdateCustomerInput) -> Result<UpdateCustomerOutput, Rc<dyn Error>> {
+    pub async fn update(&self, input: UpdateCustomerInput) -> Result<UpdateCustomerOutput, DomainError> {
+        if let Some(postal) = input.postal() {
+            PostalCode::new(postal)?;
+        }
         let result = self.repository.update(input).await?;
 
         Ok(UpdateCustomerOutput::from(result))
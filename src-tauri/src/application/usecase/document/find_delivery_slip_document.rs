@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::{
+        company_profile_repository::CompanyProfileAbstructRepository,
+        customer_repository::CustomerAbstructRepository,
+        product_repository::ProductAbstructRepository,
+        sales_order_repository::OrderAbstructRepository,
+    },
+    domain::{
+        company_profile::CompanyProfile,
+        customer::Customer,
+        error::DomainError,
+        product::Product,
+        sales_order::{SalesOrder, SalesOrderId},
+    },
+};
+
+/// Everything printed on a delivery slip (納品書). `products` is in the same order as the
+/// sales order lines.
+#[derive(Debug)]
+pub struct FindDeliverySlipDocumentOutput {
+    pub sales_order: SalesOrder,
+    pub products: Vec<Product>,
+    pub customer: Customer,
+    pub company_profile: Option<CompanyProfile>,
+}
+
+pub struct FindDeliverySlipDocumentUsecase {
+    repository: Arc<dyn OrderAbstructRepository>,
+    product_repository: Arc<dyn ProductAbstructRepository>,
+    customer_repository: Arc<dyn CustomerAbstructRepository>,
+    company_profile_repository: Arc<dyn CompanyProfileAbstructRepository>,
+}
+impl FindDeliverySlipDocumentUsecase {
+    pub fn new(
+        repository: Arc<dyn OrderAbstructRepository>,
+        product_repository: Arc<dyn ProductAbstructRepository>,
+        customer_repository: Arc<dyn CustomerAbstructRepository>,
+        company_profile_repository: Arc<dyn CompanyProfileAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            product_repository,
+            customer_repository,
+            company_profile_repository,
+        }
+    }
+
+    pub async fn find(
+        &self,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<FindDeliverySlipDocumentOutput, DomainError> {
+        let sales_order = self
+            .repository
+            .find_by_id(sales_order_id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("sales_order", sales_order_id))?;
+        if sales_order.is_cancelled() {
+            return Err(DomainError::conflict(format!(
+                "cancelled sales order has no delivery slip: sales_order_id={}",
+                sales_order_id
+            )));
+        }
+
+        let mut products = Vec::with_capacity(sales_order.lines().len());
+        for line in sales_order.lines() {
+            let product = self
+                .product_repository
+                .find_by_id_including_deleted(line.product_id())
+                .await?
+                .ok_or_else(|| DomainError::not_found("product", line.product_id()))?;
+            products.push(product);
+        }
+        let customer = self
+            .customer_repository
+            .find_by_id_including_deleted(sales_order.customer_id())
+            .await?
+            .ok_or_else(|| {
+                DomainError::not_found("customer", sales_order.customer_id())
+            })?;
+        let company_profile = self.company_profile_repository.find().await?;
+
+        Ok(FindDeliverySlipDocumentOutput {
+            sales_order,
+            products,
+            customer,
+            company_profile,
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::{
+        customer_repository::CustomerAbstructRepository,
+        invoice_repository::InvoiceAbstructRepository,
+    },
+    domain::{
+        customer::Customer,
+        error::DomainError,
+        invoice::{Invoice, InvoiceId},
+    },
+};
+
+/// Everything printed on an invoice. The issuer comes from the invoice itself, as it was
+/// snapshotted when the invoice was issued.
+#[derive(Debug)]
+pub struct FindInvoiceDocumentOutput {
+    pub invoice: Invoice,
+    pub customer: Customer,
+}
+
+pub struct FindInvoiceDocumentUsecase {
+    repository: Arc<dyn InvoiceAbstructRepository>,
+    customer_repository: Arc<dyn CustomerAbstructRepository>,
+}
+impl FindInvoiceDocumentUsecase {
+    pub fn new(
+        repository: Arc<dyn InvoiceAbstructRepository>,
+        customer_repository: Arc<dyn CustomerAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            customer_repository,
+        }
+    }
+
+    pub async fn find(
+        &self,
+        invoice_id: &InvoiceId,
+    ) -> Result<FindInvoiceDocumentOutput, DomainError> {
+        let invoice = self
+            .repository
+            .find_by_id(invoice_id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("invoice", invoice_id))?;
+        let customer = self
+            .customer_repository
+            .find_by_id_including_deleted(invoice.customer_id())
+            .await?
+            .ok_or_else(|| DomainError::not_found("customer", invoice.customer_id()))?;
+
+        Ok(FindInvoiceDocumentOutput { invoice, customer })
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::{
+        company_profile_repository::CompanyProfileAbstructRepository,
+        customer_repository::CustomerAbstructRepository,
+        product_repository::ProductAbstructRepository,
+        quotation_repository::QuotationAbstructRepository,
+    },
+    domain::{
+        company_profile::CompanyProfile,
+        customer::Customer,
+        error::DomainError,
+        product::Product,
+        quotation::{Quotation, QuotationId},
+    },
+};
+
+/// Everything printed on a quotation (御見積書). `products` is in the same order as the
+/// quotation lines.
+#[derive(Debug)]
+pub struct FindQuotationDocumentOutput {
+    pub quotation: Quotation,
+    pub products: Vec<Product>,
+    pub customer: Customer,
+    pub company_profile: Option<CompanyProfile>,
+}
+
+pub struct FindQuotationDocumentUsecase {
+    repository: Arc<dyn QuotationAbstructRepository>,
+    product_repository: Arc<dyn ProductAbstructRepository>,
+    customer_repository: Arc<dyn CustomerAbstructRepository>,
+    company_profile_repository: Arc<dyn CompanyProfileAbstructRepository>,
+}
+impl FindQuotationDocumentUsecase {
+    pub fn new(
+        repository: Arc<dyn QuotationAbstructRepository>,
+        product_repository: Arc<dyn ProductAbstructRepository>,
+        customer_repository: Arc<dyn CustomerAbstructRepository>,
+        company_profile_repository: Arc<dyn CompanyProfileAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            product_repository,
+            customer_repository,
+            company_profile_repository,
+        }
+    }
+
+    pub async fn find(
+        &self,
+        quotation_id: &QuotationId,
+    ) -> Result<FindQuotationDocumentOutput, DomainError> {
+        let quotation = self
+            .repository
+            .find_by_id(quotation_id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("quotation", quotation_id))?;
+
+        let mut products = Vec::with_capacity(quotation.lines().len());
+        for line in quotation.lines() {
+            let product = self
+                .product_repository
+                .find_by_id_including_deleted(line.product_id())
+                .await?
+                .ok_or_else(|| DomainError::not_found("product", line.product_id()))?;
+            products.push(product);
+        }
+        let customer = self
+            .customer_repository
+            .find_by_id_including_deleted(quotation.customer_id())
+            .await?
+            .ok_or_else(|| {
+                DomainError::not_found("customer", quotation.customer_id())
+            })?;
+        let company_profile = self.company_profile_repository.find().await?;
+
+        Ok(FindQuotationDocumentOutput {
+            quotation,
+            products,
+            customer,
+            company_profile,
+        })
+    }
+}
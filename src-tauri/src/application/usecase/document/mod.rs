@@ -0,0 +1,3 @@
+pub mod find_delivery_slip_document;
+pub mod find_invoice_document;
+pub mod find_quotation_document;
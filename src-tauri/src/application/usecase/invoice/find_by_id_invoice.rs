@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::invoice_repository::InvoiceAbstructRepository,
+    domain::{
+        error::DomainError,
+        invoice::{Invoice, InvoiceId},
+    },
+};
+
+#[derive(Debug)]
+pub struct FindByIDInvoiceOutput {
+    pub invoice: Option<Invoice>,
+}
+impl FindByIDInvoiceOutput {
+    pub fn new(invoice: Option<Invoice>) -> Self {
+        Self { invoice }
+    }
+}
+
+pub struct FindByIDInvoiceUsecase {
+    repository: Arc<dyn InvoiceAbstructRepository>,
+}
+impl FindByIDInvoiceUsecase {
+    pub fn new(repository: Arc<dyn InvoiceAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find_by_id(
+        &self,
+        invoice_id: &InvoiceId,
+    ) -> Result<FindByIDInvoiceOutput, DomainError> {
+        let invoice = self.repository.find_by_id(invoice_id).await?;
+
+        Ok(FindByIDInvoiceOutput::new(invoice))
+    }
+}
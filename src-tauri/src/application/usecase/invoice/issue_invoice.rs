@@ -0,0 +1,333 @@
+use crate::{
+    application::repository::{
+        invoice_repository::IssueInvoiceResult, unit_of_work::UnitOfWorkFactory,
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        invoice::{InvoiceDate, InvoiceDraft, InvoiceIssuer},
+        tax::RoundingMode,
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct IssueInvoiceInput {
+    customer_id: customer::Id,
+    period_from: InvoiceDate,
+    period_to: InvoiceDate,
+    issued_on: InvoiceDate,
+    rounding_mode: RoundingMode,
+}
+impl IssueInvoiceInput {
+    pub fn new(
+        customer_id: customer::Id,
+        period_from: InvoiceDate,
+        period_to: InvoiceDate,
+        issued_on: InvoiceDate,
+        rounding_mode: RoundingMode,
+    ) -> Self {
+        Self {
+            customer_id,
+            period_from,
+            period_to,
+            issued_on,
+            rounding_mode,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    /// First day of the closing period (締め期間).
+    pub fn period_from(&self) -> &InvoiceDate {
+        &self.period_from
+    }
+
+    /// Closing date (締め日), inclusive.
+    pub fn period_to(&self) -> &InvoiceDate {
+        &self.period_to
+    }
+
+    pub fn issued_on(&self) -> &InvoiceDate {
+        &self.issued_on
+    }
+
+    pub fn rounding_mode(&self) -> &RoundingMode {
+        &self.rounding_mode
+    }
+}
+
+#[derive(Debug)]
+pub struct IssueInvoiceOutput {
+    result: IssueInvoiceResult,
+}
+impl IssueInvoiceOutput {
+    pub fn new(result: IssueInvoiceResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &IssueInvoiceResult {
+        &self.result
+    }
+}
+
+/// Bills the customer's delivered lines of the period. The lines are looked up and the invoice
+/// that claims them is stored in one unit of work, so a line is never billed twice.
+pub struct IssueInvoiceUsecase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+impl IssueInvoiceUsecase {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    pub async fn issue(
+        &self,
+        input: IssueInvoiceInput,
+    ) -> Result<IssueInvoiceOutput, DomainError> {
+        if input.period_from > input.period_to {
+            return Err(DomainError::validation(
+                "period_to",
+                "period_from must not be after period_to",
+            ));
+        }
+        if input.issued_on < input.period_to {
+            return Err(DomainError::validation(
+                "issued_on",
+                "issued_on must not be before period_to",
+            ));
+        }
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let repository = unit_of_work.invoice_repository();
+        // Orders delivered to a customer that was deleted afterwards are still billed.
+        let customer = unit_of_work
+            .customer_repository()
+            .find_by_id_including_deleted(&input.customer_id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("customer", input.customer_id))?;
+        let company_profile = unit_of_work
+            .company_profile_repository()
+            .find()
+            .await?
+            .ok_or_else(|| {
+                DomainError::conflict("company profile must be registered before issuing invoices")
+            })?;
+
+        let billable_lines = repository
+            .search_billable_lines(&input.customer_id, &input.period_from, &input.period_to)
+            .await?;
+        if billable_lines.is_empty() {
+            return Err(DomainError::conflict(format!(
+                "no delivered orders to invoice: customer_id={}, period={}..{}",
+                input.customer_id, input.period_from, input.period_to
+            )));
+        }
+
+        let draft = InvoiceDraft::calculate(
+            InvoiceIssuer::from(&company_profile),
+            input.customer_id,
+            customer.name().clone(),
+            input.period_from,
+            input.period_to,
+            input.issued_on,
+            input.rounding_mode,
+            billable_lines,
+        );
+        draft.validate()?;
+        let result = repository.create(&draft).await?;
+        unit_of_work.commit().await?;
+
+        Ok(IssueInvoiceOutput::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            company_profile_repository::SqliteCompanyProfileRepository,
+            customer_repository::SqliteCustomerRespository,
+            fixtures,
+            invoice_repository::SqliteInvoiceRepository,
+            product_repository::SqliteProductRepository,
+            sales_order_repository::SqliteSalesOrderRepository,
+            unit_of_work::SqliteUnitOfWorkFactory,
+        },
+        application::{
+            repository::{
+                company_profile_repository::CompanyProfileAbstructRepository,
+                customer_repository::CustomerAbstructRepository,
+                invoice_repository::InvoiceAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                sales_order_repository::OrderAbstructRepository,
+            },
+            usecase::{
+                company_profile::save_company_profile::SaveCompanyProfileInput,
+                invoice::issue_invoice::{IssueInvoiceInput, IssueInvoiceUsecase},
+                product::create_product::CreateProductInput,
+                sales_order::{
+                    cancel_sales_order::CancelSalesOrderUsecase,
+                    create_sales_order::{CreateSalesOrderInput, SalesOrderLineInput},
+                    update_sales_order::{UpdateSalesOrderInput, UpdateSalesOrderUsecase},
+                },
+            },
+        },
+        domain::{
+            sales_order::SalesOrderStatus,
+            tax::{RoundingMode, TaxRate},
+        },
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn issue_test(pool: SqlitePool) {
+        let customer_repository = Arc::new(SqliteCustomerRespository::new(pool.clone()));
+        let customer = customer_repository
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product_repository = SqliteProductRepository::new(pool.clone());
+        let standard = product_repository
+            .create(&CreateProductInput::new(
+                String::from("文具"),
+                String::from("product001"),
+                String::from("個"),
+                333,
+                10,
+                TaxRate::Standard,
+            ))
+            .await
+            .unwrap();
+        let reduced = product_repository
+            .create(&CreateProductInput::new(
+                String::from("食品"),
+                String::from("product002"),
+                String::from("個"),
+                155,
+                10,
+                TaxRate::Reduced,
+            ))
+            .await
+            .unwrap();
+        let sales_order_repository = SqliteSalesOrderRepository::new(pool.clone());
+        let order_date = Date::from_calendar_date(2023, Month::June, 10).unwrap();
+        let sales_order = sales_order_repository
+            .create(&CreateSalesOrderInput::new(
+                customer.customer_id(),
+                order_date,
+                vec![
+                    SalesOrderLineInput::new(*standard.product_id(), 1, Some(333)),
+                    SalesOrderLineInput::new(*reduced.product_id(), 1, Some(155)),
+                ],
+            ))
+            .await
+            .unwrap();
+        sales_order_repository
+            .update(&UpdateSalesOrderInput::new(
+                *sales_order.sales_order_id(),
+                None,
+                None,
+                Some(SalesOrderStatus::Delivered),
+                None,
+            ))
+            .await
+            .unwrap();
+
+        let company_profile_repository = Arc::new(SqliteCompanyProfileRepository::new(pool.clone()));
+        let repository = Arc::new(SqliteInvoiceRepository::new(pool.clone()));
+        let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
+        let usecase = IssueInvoiceUsecase::new(unit_of_work.clone());
+        let input = |rounding_mode| {
+            IssueInvoiceInput::new(
+                customer.customer_id(),
+                Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+                Date::from_calendar_date(2023, Month::June, 30).unwrap(),
+                Date::from_calendar_date(2023, Month::July, 5).unwrap(),
+                rounding_mode,
+            )
+        };
+        // Issuing requires the issuer's registration number.
+        assert!(usecase.issue(input(RoundingMode::HalfUp)).await.is_err());
+        company_profile_repository
+            .save(&SaveCompanyProfileInput::new(
+                String::from("T1234567890123"),
+                String::from("自社"),
+                String::from("東京都"),
+            ))
+            .await
+            .unwrap();
+        let error = usecase
+            .issue(IssueInvoiceInput::new(
+                customer.customer_id(),
+                Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+                Date::from_calendar_date(2023, Month::June, 30).unwrap(),
+                Date::from_calendar_date(2023, Month::June, 29).unwrap(),
+                RoundingMode::HalfUp,
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "validation");
+        // Deleting the customer does not stop billing what was delivered before.
+        customer_repository
+            .delete(&customer.customer_id())
+            .await
+            .unwrap();
+
+        let output = usecase.issue(input(RoundingMode::HalfUp)).await.unwrap();
+        let invoice = repository
+            .find_by_id(output.result().invoice_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(invoice.invoice_number(), "INV-202307-0001");
+        assert_eq!(invoice.issuer().registration_number(), "T1234567890123");
+        assert_eq!(invoice.recipient_name(), "sample.inc");
+        assert_eq!(*invoice.subtotal(), 488);
+        // 333 * 10% = 33.3 -> 33, 155 * 8% = 12.4 -> 12
+        assert_eq!(invoice.tax_summaries().len(), 2);
+        assert_eq!(*invoice.tax_amount(), 45);
+        assert_eq!(*invoice.total_amount(), 533);
+
+        // Every delivered line is already billed, so a second invoice has nothing to claim.
+        assert!(usecase.issue(input(RoundingMode::HalfUp)).await.is_err());
+
+        // The billed order keeps its lines, customer and status.
+        let sales_order_repository = Arc::new(sales_order_repository);
+        let update_usecase = UpdateSalesOrderUsecase::new(unit_of_work.clone());
+        let error = update_usecase
+            .update(UpdateSalesOrderInput::new(
+                *sales_order.sales_order_id(),
+                None,
+                None,
+                None,
+                Some(vec![SalesOrderLineInput::new(
+                    *standard.product_id(),
+                    2,
+                    Some(333),
+                )]),
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "conflict");
+        let cancel_usecase = CancelSalesOrderUsecase::new(unit_of_work);
+        let error = cancel_usecase
+            .cancel(sales_order.sales_order_id())
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), "conflict");
+        let sales_order = sales_order_repository
+            .find_by_id(sales_order.sales_order_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*sales_order.status(), SalesOrderStatus::Delivered);
+        assert!(sales_order.lines().iter().all(|line| line.is_invoiced()));
+    }
+}
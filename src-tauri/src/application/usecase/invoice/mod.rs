@@ -0,0 +1,3 @@
+pub mod find_by_id_invoice;
+pub mod issue_invoice;
+pub mod search_invoice;
@@ -0,0 +1,98 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::invoice_repository::InvoiceAbstructRepository,
+    domain::{
+        customer,
+        error::DomainError,
+        invoice::{Invoice, InvoiceDate},
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchInvoiceInput {
+    offset: i64,
+    limit: i64,
+    customer_id: Option<customer::Id>,
+    issued_on_from: Option<InvoiceDate>,
+    issued_on_to: Option<InvoiceDate>,
+    after_id: Option<i64>,
+}
+impl SearchInvoiceInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        customer_id: Option<customer::Id>,
+        issued_on_from: Option<InvoiceDate>,
+        issued_on_to: Option<InvoiceDate>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            customer_id,
+            issued_on_from,
+            issued_on_to,
+            after_id: None,
+        }
+    }
+
+    /// Reads only the invoices after `after_id`, in id order rather than by issue date, so that
+    /// a page deep into every invoice costs no more to read than the first.
+    pub fn with_after_id(mut self, after_id: i64) -> Self {
+        self.after_id = Some(after_id);
+        self
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn issued_on_from(&self) -> &Option<InvoiceDate> {
+        &self.issued_on_from
+    }
+
+    pub fn issued_on_to(&self) -> &Option<InvoiceDate> {
+        &self.issued_on_to
+    }
+
+    pub fn after_id(&self) -> &Option<i64> {
+        &self.after_id
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchInvoiceOutput {
+    pub invoices: Vec<Invoice>,
+}
+impl SearchInvoiceOutput {
+    fn new(invoices: Vec<Invoice>) -> Self {
+        Self { invoices }
+    }
+}
+
+pub struct SearchInvoiceUsecase {
+    repository: Arc<dyn InvoiceAbstructRepository>,
+}
+impl SearchInvoiceUsecase {
+    pub fn new(repository: Arc<dyn InvoiceAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchInvoiceInput,
+    ) -> Result<SearchInvoiceOutput, DomainError> {
+        let invoices = self.repository.search(&input).await?;
+
+        Ok(SearchInvoiceOutput::new(invoices))
+    }
+}
@@ -1,2 +1,13 @@
+pub mod audit_log;
+pub mod company_profile;
 pub mod customer;
+pub mod document;
+pub mod invoice;
+pub mod price_list;
 pub mod product;
+pub mod quotation;
+pub mod receivable;
+pub mod reorder_alert;
+pub mod sales_order;
+pub mod stock_movement;
+pub mod user;
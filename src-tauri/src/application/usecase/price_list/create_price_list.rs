@@ -0,0 +1,103 @@
+use crate::{
+    application::repository::price_list_repository::{
+        CreatePriceListResult, PriceListAbstructRepository,
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        price_list::{validate_price_list_entry, PriceListDate, PriceListPrice, PriceListQuantity},
+        product::ProductId,
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct CreatePriceListInput {
+    customer_id: customer::Id,
+    product_id: ProductId,
+    price: PriceListPrice,
+    valid_from: Option<PriceListDate>,
+    valid_to: Option<PriceListDate>,
+    min_quantity: PriceListQuantity,
+}
+impl CreatePriceListInput {
+    pub fn new(
+        customer_id: customer::Id,
+        product_id: ProductId,
+        price: PriceListPrice,
+        valid_from: Option<PriceListDate>,
+        valid_to: Option<PriceListDate>,
+        min_quantity: Option<PriceListQuantity>,
+    ) -> Self {
+        Self {
+            customer_id,
+            product_id,
+            price,
+            valid_from,
+            valid_to,
+            min_quantity: min_quantity.unwrap_or(1),
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn price(&self) -> &PriceListPrice {
+        &self.price
+    }
+
+    pub fn valid_from(&self) -> &Option<PriceListDate> {
+        &self.valid_from
+    }
+
+    pub fn valid_to(&self) -> &Option<PriceListDate> {
+        &self.valid_to
+    }
+
+    pub fn min_quantity(&self) -> &PriceListQuantity {
+        &self.min_quantity
+    }
+}
+
+#[derive(Debug)]
+pub struct CreatePriceListOutput {
+    result: CreatePriceListResult,
+}
+impl CreatePriceListOutput {
+    pub fn new(result: CreatePriceListResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &CreatePriceListResult {
+        &self.result
+    }
+}
+
+pub struct CreatePriceListUsecase {
+    repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl CreatePriceListUsecase {
+    pub fn new(repository: Arc<dyn PriceListAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn create(
+        &self,
+        input: CreatePriceListInput,
+    ) -> Result<CreatePriceListOutput, DomainError> {
+        validate_price_list_entry(
+            &input.price,
+            &input.valid_from,
+            &input.valid_to,
+            &input.min_quantity,
+        )?;
+        let result = self.repository.create(&input).await?;
+
+        Ok(CreatePriceListOutput::new(result))
+    }
+}
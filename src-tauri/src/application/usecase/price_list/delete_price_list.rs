@@ -0,0 +1,27 @@
+use crate::{
+    application::repository::price_list_repository::{
+        DeletePriceListResult, PriceListAbstructRepository,
+    },
+    domain::{error::DomainError, price_list::PriceListId},
+};
+use std::sync::Arc;
+
+pub type DeletePriceListOutput = DeletePriceListResult;
+
+pub struct DeletePriceListUsecase {
+    repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl DeletePriceListUsecase {
+    pub fn new(repository: Arc<dyn PriceListAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn delete(
+        &self,
+        price_list_id: &PriceListId,
+    ) -> Result<DeletePriceListOutput, DomainError> {
+        let result = self.repository.delete(price_list_id).await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::price_list_repository::PriceListAbstructRepository,
+    domain::{
+        error::DomainError,
+        price_list::{PriceListEntry, PriceListId},
+    },
+};
+
+#[derive(Debug)]
+pub struct FindByIDPriceListOutput {
+    pub price_list: Option<PriceListEntry>,
+}
+impl FindByIDPriceListOutput {
+    pub fn new(price_list: Option<PriceListEntry>) -> Self {
+        Self { price_list }
+    }
+}
+
+pub struct FindByIDPriceListUsecase {
+    repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl FindByIDPriceListUsecase {
+    pub fn new(repository: Arc<dyn PriceListAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find_by_id(
+        &self,
+        price_list_id: &PriceListId,
+    ) -> Result<FindByIDPriceListOutput, DomainError> {
+        let price_list = self.repository.find_by_id(price_list_id).await?;
+
+        Ok(FindByIDPriceListOutput::new(price_list))
+    }
+}
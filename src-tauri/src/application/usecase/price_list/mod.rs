@@ -0,0 +1,6 @@
+pub mod create_price_list;
+pub mod delete_price_list;
+pub mod find_by_id_price_list;
+pub mod resolve_price;
+pub mod search_price_list;
+pub mod update_price_list;
@@ -0,0 +1,129 @@
+use crate::{
+    application::repository::{
+        price_list_repository::PriceListAbstructRepository,
+        product_repository::ProductAbstructRepository,
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        price_list::{PriceListDate, PriceListQuantity, ResolvedPrice},
+        product::ProductId,
+    },
+};
+use std::sync::Arc;
+
+/// Price of `quantity` units of the product for the customer on `date`: the best matching
+/// price list entry, or the product's `default_price` when none applies.
+pub(crate) async fn resolve_price(
+    product_repository: &Arc<dyn ProductAbstructRepository>,
+    price_list_repository: &Arc<dyn PriceListAbstructRepository>,
+    customer_id: &customer::Id,
+    product_id: &ProductId,
+    quantity: PriceListQuantity,
+    date: &PriceListDate,
+) -> Result<ResolvedPrice, DomainError> {
+    let product = product_repository
+        .find_by_id(product_id)
+        .await?
+        .ok_or_else(|| DomainError::not_found("product", product_id))?;
+    let entries = price_list_repository
+        .find_by_customer_and_product(customer_id, product_id)
+        .await?;
+
+    Ok(ResolvedPrice::resolve(
+        *customer_id,
+        *product_id,
+        quantity,
+        date,
+        *product.default_price(),
+        &entries,
+    ))
+}
+
+#[derive(Debug)]
+pub struct ResolvePriceInput {
+    customer_id: customer::Id,
+    product_id: ProductId,
+    quantity: PriceListQuantity,
+    date: PriceListDate,
+}
+impl ResolvePriceInput {
+    pub fn new(
+        customer_id: customer::Id,
+        product_id: ProductId,
+        quantity: PriceListQuantity,
+        date: PriceListDate,
+    ) -> Self {
+        Self {
+            customer_id,
+            product_id,
+            quantity,
+            date,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn quantity(&self) -> &PriceListQuantity {
+        &self.quantity
+    }
+
+    pub fn date(&self) -> &PriceListDate {
+        &self.date
+    }
+}
+
+#[derive(Debug)]
+pub struct ResolvePriceOutput {
+    pub resolved_price: ResolvedPrice,
+}
+impl ResolvePriceOutput {
+    pub fn new(resolved_price: ResolvedPrice) -> Self {
+        Self { resolved_price }
+    }
+}
+
+pub struct ResolvePriceUsecase {
+    product_repository: Arc<dyn ProductAbstructRepository>,
+    price_list_repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl ResolvePriceUsecase {
+    pub fn new(
+        product_repository: Arc<dyn ProductAbstructRepository>,
+        price_list_repository: Arc<dyn PriceListAbstructRepository>,
+    ) -> Self {
+        Self {
+            product_repository,
+            price_list_repository,
+        }
+    }
+
+    pub async fn resolve(
+        &self,
+        input: ResolvePriceInput,
+    ) -> Result<ResolvePriceOutput, DomainError> {
+        if input.quantity <= 0 {
+            return Err(DomainError::validation(
+                "quantity",
+                format!("quantity must be positive: quantity={}", input.quantity),
+            ));
+        }
+        let resolved_price = resolve_price(
+            &self.product_repository,
+            &self.price_list_repository,
+            &input.customer_id,
+            &input.product_id,
+            input.quantity,
+            &input.date,
+        )
+        .await?;
+
+        Ok(ResolvePriceOutput::new(resolved_price))
+    }
+}
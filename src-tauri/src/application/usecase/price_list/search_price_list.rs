@@ -0,0 +1,74 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::price_list_repository::PriceListAbstructRepository,
+    domain::{customer, error::DomainError, price_list::PriceListEntry, product::ProductId},
+};
+
+#[derive(Debug)]
+pub struct SearchPriceListInput {
+    offset: i64,
+    limit: i64,
+    customer_id: Option<customer::Id>,
+    product_id: Option<ProductId>,
+}
+impl SearchPriceListInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        customer_id: Option<customer::Id>,
+        product_id: Option<ProductId>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            customer_id,
+            product_id,
+        }
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn product_id(&self) -> &Option<ProductId> {
+        &self.product_id
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchPriceListOutput {
+    pub price_lists: Vec<PriceListEntry>,
+}
+impl SearchPriceListOutput {
+    fn new(price_lists: Vec<PriceListEntry>) -> Self {
+        Self { price_lists }
+    }
+}
+
+pub struct SearchPriceListUsecase {
+    repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl SearchPriceListUsecase {
+    pub fn new(repository: Arc<dyn PriceListAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchPriceListInput,
+    ) -> Result<SearchPriceListOutput, DomainError> {
+        let price_lists = self.repository.search(&input).await?;
+
+        Ok(SearchPriceListOutput::new(price_lists))
+    }
+}
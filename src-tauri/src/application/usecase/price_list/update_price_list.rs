@@ -0,0 +1,102 @@
+use crate::{
+    application::repository::price_list_repository::{
+        PriceListAbstructRepository, UpdatePriceListResult,
+    },
+    domain::{
+        error::DomainError,
+        price_list::{
+            validate_price_list_entry, PriceListDate, PriceListId, PriceListPrice,
+            PriceListQuantity,
+        },
+    },
+};
+use std::sync::Arc;
+
+/// Replaces the terms of an entry. Customer and product are fixed; delete the entry and create
+/// another to move a price to a different pair.
+#[derive(Debug)]
+pub struct UpdatePriceListInput {
+    id: PriceListId,
+    price: PriceListPrice,
+    valid_from: Option<PriceListDate>,
+    valid_to: Option<PriceListDate>,
+    min_quantity: PriceListQuantity,
+}
+impl UpdatePriceListInput {
+    pub fn new(
+        id: PriceListId,
+        price: PriceListPrice,
+        valid_from: Option<PriceListDate>,
+        valid_to: Option<PriceListDate>,
+        min_quantity: Option<PriceListQuantity>,
+    ) -> Self {
+        Self {
+            id,
+            price,
+            valid_from,
+            valid_to,
+            min_quantity: min_quantity.unwrap_or(1),
+        }
+    }
+
+    pub fn id(&self) -> &PriceListId {
+        &self.id
+    }
+
+    pub fn price(&self) -> &PriceListPrice {
+        &self.price
+    }
+
+    pub fn valid_from(&self) -> &Option<PriceListDate> {
+        &self.valid_from
+    }
+
+    pub fn valid_to(&self) -> &Option<PriceListDate> {
+        &self.valid_to
+    }
+
+    pub fn min_quantity(&self) -> &PriceListQuantity {
+        &self.min_quantity
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdatePriceListOutput {
+    result: UpdatePriceListResult,
+}
+impl UpdatePriceListOutput {
+    pub fn new(result: UpdatePriceListResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &UpdatePriceListResult {
+        &self.result
+    }
+}
+
+pub struct UpdatePriceListUsecase {
+    repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl UpdatePriceListUsecase {
+    pub fn new(repository: Arc<dyn PriceListAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn update(
+        &self,
+        input: UpdatePriceListInput,
+    ) -> Result<UpdatePriceListOutput, DomainError> {
+        validate_price_list_entry(
+            &input.price,
+            &input.valid_from,
+            &input.valid_to,
+            &input.min_quantity,
+        )?;
+        if self.repository.find_by_id(&input.id).await?.is_none() {
+            return Err(DomainError::not_found("price_list", input.id));
+        }
+        let result = self.repository.update(&input).await?;
+
+        Ok(UpdatePriceListOutput::new(result))
+    }
+}
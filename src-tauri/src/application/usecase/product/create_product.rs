@@ -1,17 +1,40 @@
 use crate::{
     application::repository::product_repository::{CreateProductResult, ProductAbstructRepository},
-    domain::product::{
-        ProductCode, ProductDefaultPrice, ProductName, ProductStandardStockQuantity, ProductUnit,
+    domain::{
+        error::DomainError,
+        product::{validate_product, ProductId, ProductName},
+        tax::TaxRate,
     },
 };
-use std::{error::Error, rc::Rc};
+use std::sync::Arc;
+
+/// Fails with a conflict carrying the clashing product when another active product already
+/// uses `code`. `product_id` is the product being updated, which may keep its own code.
+pub(crate) async fn ensure_unique_code(
+    repository: &Arc<dyn ProductAbstructRepository>,
+    code: &str,
+    product_id: Option<&ProductId>,
+) -> Result<(), DomainError> {
+    match repository.find_by_code(code).await? {
+        Some(product) if Some(product.id()) != product_id => Err(DomainError::conflict_with(
+            format!(
+                "product code is already used: code={}, product_id={}",
+                code,
+                product.id()
+            ),
+            &product,
+        )),
+        _ => Ok(()),
+    }
+}
 
 pub struct CreateProductInput {
     name: ProductName,
-    code: ProductCode,
-    unit: ProductUnit,
-    default_price: ProductDefaultPrice,
-    standard_stock_quantity: ProductStandardStockQuantity,
+    code: String,
+    unit: String,
+    default_price: i64,
+    standard_stock_quantity: i64,
+    tax_rate: TaxRate,
 }
 impl CreateProductInput {
     pub fn new(
@@ -20,6 +43,7 @@ impl CreateProductInput {
         unit: String,
         default_price: i64,
         standard_stock_quantity: i64,
+        tax_rate: TaxRate,
     ) -> Self {
         Self {
             name,
@@ -27,6 +51,7 @@ impl CreateProductInput {
             unit,
             default_price,
             standard_stock_quantity,
+            tax_rate,
         }
     }
     pub fn name(&self) -> &str {
@@ -44,6 +69,9 @@ impl CreateProductInput {
     pub fn standard_stock_quantity(&self) -> &i64 {
         &self.standard_stock_quantity
     }
+    pub fn tax_rate(&self) -> &TaxRate {
+        &self.tax_rate
+    }
 }
 
 #[derive(Debug)]
@@ -61,20 +89,67 @@ impl CreateProductOutput {
 }
 
 pub struct CreateProductUsecase {
-    repository: Rc<dyn ProductAbstructRepository>,
+    repository: Arc<dyn ProductAbstructRepository>,
 }
 impl CreateProductUsecase {
-    pub fn new(repository: Rc<dyn ProductAbstructRepository>) -> Self {
+    pub fn new(repository: Arc<dyn ProductAbstructRepository>) -> Self {
         Self { repository }
     }
 
     pub async fn create(
         &self,
         input: CreateProductInput,
-    ) -> Result<CreateProductOutput, Rc<dyn Error>> {
+    ) -> Result<CreateProductOutput, DomainError> {
+        validate_product(
+            Some(input.code()),
+            Some(input.unit()),
+            Some(*input.default_price()),
+            Some(*input.standard_stock_quantity()),
+        )?;
+        ensure_unique_code(&self.repository, input.code(), None).await?;
         let result = self.repository.create(&input).await?;
         let output = CreateProductOutput::new(result);
 
         Ok(output)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        adapters::gateway::product_repository::SqliteProductRepository,
+        application::usecase::product::create_product::{CreateProductInput, CreateProductUsecase},
+        domain::{error::DomainError, tax::TaxRate},
+        infrastructure::database::MIGRATOR,
+    };
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+
+    fn input(name: &str, code: &str) -> CreateProductInput {
+        CreateProductInput::new(
+            String::from(name),
+            String::from(code),
+            String::from("個"),
+            2000,
+            10,
+            TaxRate::Standard,
+        )
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn duplicate_code_test(pool: SqlitePool) {
+        let repository = Arc::new(SqliteProductRepository::new(pool));
+        let usecase = CreateProductUsecase::new(repository);
+        let output = usecase.create(input("商品1", "product001")).await.unwrap();
+
+        let error = usecase.create(input("商品2", "product001")).await.unwrap_err();
+        match error {
+            DomainError::Conflict { current, .. } => {
+                let current = current.unwrap();
+                assert_eq!(current["id"], *output.result().product_id());
+                assert_eq!(current["name"], "商品1");
+            }
+            _ => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
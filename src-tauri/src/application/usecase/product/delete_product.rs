@@ -1,17 +1,20 @@
-use std::{rc::Rc, error::Error};
-use crate::{application::repository::product_repository::{ProductAbstructRepository, DeleteProductResult}, domain::product::ProductId};
+use std::sync::Arc;
+use crate::{
+    application::repository::product_repository::{DeleteProductResult, ProductAbstructRepository},
+    domain::{error::DomainError, product::ProductId},
+};
 
 pub type DeleteProductOutput = DeleteProductResult;
 
 pub struct DeleteProductUsecase {
-    repository: Rc<dyn ProductAbstructRepository>,
+    repository: Arc<dyn ProductAbstructRepository>,
 }
 impl DeleteProductUsecase {
-    pub fn new (repository: Rc<dyn ProductAbstructRepository>) -> Self {
+    pub fn new (repository: Arc<dyn ProductAbstructRepository>) -> Self {
         Self { repository }
     }
 
-    pub async fn delete (&self, product_id: &ProductId) -> Result<DeleteProductOutput, Rc<dyn Error>> {
+    pub async fn delete (&self, product_id: &ProductId) -> Result<DeleteProductOutput, DomainError> {
         let delete_result = self.repository.delete(product_id).await?;
 
         Ok(delete_result)
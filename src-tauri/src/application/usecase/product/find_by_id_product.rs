@@ -1,8 +1,11 @@
-use std::{error::Error, rc::Rc};
+use std::sync::Arc;
 
 use crate::{
     application::repository::product_repository::ProductAbstructRepository,
-    domain::product::{Product, ProductId},
+    domain::{
+        error::DomainError,
+        product::{Product, ProductId},
+    },
 };
 
 #[derive(Debug)]
@@ -16,18 +19,18 @@ impl FindByIDProductOutput {
 }
 
 pub struct FindByIDProductUsecase {
-    repository: Rc<dyn ProductAbstructRepository>,
+    repository: Arc<dyn ProductAbstructRepository>,
 }
 impl FindByIDProductUsecase {
-    pub fn new(repository: Rc<dyn ProductAbstructRepository>) -> Self {
+    pub fn new(repository: Arc<dyn ProductAbstructRepository>) -> Self {
         Self { repository }
     }
 
     pub async fn find_by_id(
         &self,
         product_id: &ProductId,
-    ) -> Result<FindByIDProductOutput, Box<dyn Error>> {
-        let product = self.repository.find_by_id(&product_id).await?;
+    ) -> Result<FindByIDProductOutput, DomainError> {
+        let product = self.repository.find_by_id(product_id).await?;
 
         let output = FindByIDProductOutput::new(product);
 
@@ -39,7 +42,7 @@ impl FindByIDProductUsecase {
 mod tests {
 
     use sqlx::SqlitePool;
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     use crate::{
         adapters::gateway::product_repository::SqliteProductRepository,
@@ -74,9 +77,9 @@ mod tests {
         .execute(&mut conn)
         .await.unwrap();
 
-        let usecase = FindByIDProductUsecase::new(Rc::new(repository));
+        let usecase = FindByIDProductUsecase::new(Arc::new(repository));
         let outputs = usecase.find_by_id(&result.last_insert_rowid()).await.unwrap();
 
-        assert_eq!(outputs.product.is_some(), true);
+        assert!(outputs.product.is_some());
     }
 }
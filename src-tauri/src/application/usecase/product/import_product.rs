@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::{
+    application::{
+        repository::product_repository::ProductAbstructRepository,
+        usecase::product::create_product::CreateProductInput,
+    },
+    domain::{
+        error::{DomainError, FieldError},
+        product::{validate_product, ProductId},
+    },
+};
+
+/// A product read from row `row` of an import file. `errors` holds the fields that could not be
+/// read, such as a price that is not a number.
+pub struct ImportProductRow {
+    row: i64,
+    product: CreateProductInput,
+    errors: Vec<FieldError>,
+}
+impl ImportProductRow {
+    pub fn new(row: i64, product: CreateProductInput, errors: Vec<FieldError>) -> Self {
+        Self {
+            row,
+            product,
+            errors,
+        }
+    }
+
+    pub fn row(&self) -> &i64 {
+        &self.row
+    }
+
+    pub fn product(&self) -> &CreateProductInput {
+        &self.product
+    }
+
+    pub fn errors(&self) -> &Vec<FieldError> {
+        &self.errors
+    }
+}
+
+pub struct ImportProductInput {
+    rows: Vec<ImportProductRow>,
+    dry_run: bool,
+}
+impl ImportProductInput {
+    pub fn new(rows: Vec<ImportProductRow>, dry_run: bool) -> Self {
+        Self { rows, dry_run }
+    }
+
+    pub fn rows(&self) -> &Vec<ImportProductRow> {
+        &self.rows
+    }
+
+    pub fn dry_run(&self) -> &bool {
+        &self.dry_run
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportProductAction {
+    Create,
+    /// Overwrites the active product with the same code.
+    Update,
+}
+
+#[derive(Debug)]
+pub struct ImportProductRowResult {
+    row: i64,
+    code: String,
+    action: ImportProductAction,
+    product_id: Option<ProductId>,
+    errors: Vec<FieldError>,
+}
+impl ImportProductRowResult {
+    pub fn row(&self) -> &i64 {
+        &self.row
+    }
+
+    pub fn code(&self) -> &String {
+        &self.code
+    }
+
+    pub fn action(&self) -> &ImportProductAction {
+        &self.action
+    }
+
+    /// The product that is overwritten.
+    pub fn product_id(&self) -> &Option<ProductId> {
+        &self.product_id
+    }
+
+    pub fn errors(&self) -> &Vec<FieldError> {
+        &self.errors
+    }
+}
+
+/// `created` and `updated` count what was stored, or what would be when nothing was applied.
+#[derive(Debug)]
+pub struct ImportProductOutput {
+    pub rows: Vec<ImportProductRowResult>,
+    pub applied: bool,
+    pub created: i64,
+    pub updated: i64,
+}
+
+/// Imports products by code: new codes are created and known ones overwrite the product. A dry
+/// run only reports what would happen, and nothing is stored while any row has errors.
+pub struct ImportProductUsecase {
+    repository: Arc<dyn ProductAbstructRepository>,
+}
+impl ImportProductUsecase {
+    pub fn new(repository: Arc<dyn ProductAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    async fn check_row(
+        &self,
+        row: &ImportProductRow,
+        first_rows: &mut HashMap<String, i64>,
+    ) -> Result<ImportProductRowResult, DomainError> {
+        let product = row.product();
+        let mut errors = row.errors().clone();
+        if product.name().trim().is_empty() {
+            errors.push(FieldError::new("name", "product name is required"));
+        }
+        if let Err(error) = validate_product(
+            Some(product.code()),
+            Some(product.unit()),
+            Some(*product.default_price()),
+            Some(*product.standard_stock_quantity()),
+        ) {
+            errors.extend(error.fields().iter().cloned());
+        }
+        match first_rows.get(product.code()) {
+            Some(first_row) => errors.push(FieldError::new(
+                "code",
+                format!("product code is duplicated in the file: row={}", first_row),
+            )),
+            None => {
+                first_rows.insert(product.code().to_string(), *row.row());
+            }
+        }
+        let product_id = self
+            .repository
+            .find_by_code(product.code())
+            .await?
+            .map(|current| *current.id());
+
+        Ok(ImportProductRowResult {
+            row: *row.row(),
+            code: product.code().to_string(),
+            action: match product_id {
+                Some(_) => ImportProductAction::Update,
+                None => ImportProductAction::Create,
+            },
+            product_id,
+            errors,
+        })
+    }
+
+    pub async fn import(
+        &self,
+        input: ImportProductInput,
+    ) -> Result<ImportProductOutput, DomainError> {
+        let mut first_rows = HashMap::new();
+        let mut rows = Vec::with_capacity(input.rows().len());
+        for row in input.rows() {
+            rows.push(self.check_row(row, &mut first_rows).await?);
+        }
+        let created = rows
+            .iter()
+            .filter(|row| row.action == ImportProductAction::Create)
+            .count() as i64;
+        let updated = rows.len() as i64 - created;
+
+        let has_errors = rows.iter().any(|row| !row.errors.is_empty());
+        if *input.dry_run() || has_errors || rows.is_empty() {
+            return Ok(ImportProductOutput {
+                rows,
+                applied: false,
+                created,
+                updated,
+            });
+        }
+
+        let products = input
+            .rows
+            .into_iter()
+            .map(|row| row.product)
+            .collect::<Vec<_>>();
+        let result = self.repository.upsert_by_code(&products).await?;
+
+        Ok(ImportProductOutput {
+            rows,
+            applied: true,
+            created: *result.created(),
+            updated: *result.updated(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use sqlx::SqlitePool;
+
+    use crate::{
+        adapters::gateway::product_repository::SqliteProductRepository,
+        application::{
+            repository::product_repository::ProductAbstructRepository,
+            usecase::product::{
+                create_product::CreateProductInput,
+                import_product::{
+                    ImportProductAction, ImportProductInput, ImportProductRow, ImportProductUsecase,
+                },
+            },
+        },
+        domain::tax::TaxRate,
+        infrastructure::database::MIGRATOR,
+    };
+
+    fn row(row: i64, name: &str, code: &str, default_price: i64) -> ImportProductRow {
+        ImportProductRow::new(
+            row,
+            CreateProductInput::new(
+                String::from(name),
+                String::from(code),
+                String::from("個"),
+                default_price,
+                10,
+                TaxRate::Standard,
+            ),
+            Vec::new(),
+        )
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn import_test(pool: SqlitePool) {
+        let repository = Arc::new(SqliteProductRepository::new(pool));
+        repository
+            .create(row(0, "商品1", "product001", 2000).product())
+            .await
+            .unwrap();
+        let usecase = ImportProductUsecase::new(repository.clone());
+
+        let output = usecase
+            .import(ImportProductInput::new(
+                vec![
+                    row(2, "商品1改", "product001", 2500),
+                    row(3, "商品2", "product002", -1),
+                    row(4, "商品3", "product002", 3000),
+                ],
+                false,
+            ))
+            .await
+            .unwrap();
+        assert!(!output.applied);
+        assert_eq!(*output.rows[0].action(), ImportProductAction::Update);
+        assert!(output.rows[0].errors().is_empty());
+        assert_eq!(output.rows[1].errors()[0].field(), "default_price");
+        assert_eq!(output.rows[2].errors()[0].field(), "code");
+        assert!(repository
+            .find_by_code("product002")
+            .await
+            .unwrap()
+            .is_none());
+
+        let input = || {
+            ImportProductInput::new(
+                vec![
+                    row(2, "商品1改", "product001", 2500),
+                    row(3, "商品2", "product002", 3000),
+                ],
+                true,
+            )
+        };
+        let output = usecase.import(input()).await.unwrap();
+        assert!(!output.applied);
+        assert_eq!((output.created, output.updated), (1, 1));
+        assert!(repository
+            .find_by_code("product002")
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut input = input();
+        input.dry_run = false;
+        let output = usecase.import(input).await.unwrap();
+
+        assert!(output.applied);
+        assert_eq!((output.created, output.updated), (1, 1));
+        let product = repository
+            .find_by_code("product001")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*product.default_price(), 2500);
+        assert!(repository
+            .find_by_code("product002")
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
@@ -1,5 +1,7 @@
 pub mod create_product;
 pub mod delete_product;
 pub mod find_by_id_product;
+pub mod import_product;
+pub mod restore_product;
 pub mod search_product;
 pub mod update_product;
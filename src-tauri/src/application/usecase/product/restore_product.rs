@@ -0,0 +1,27 @@
+use crate::{
+    application::repository::product_repository::{
+        ProductAbstructRepository, RestoreProductResult,
+    },
+    domain::{error::DomainError, product::ProductId},
+};
+use std::sync::Arc;
+
+pub type RestoreProductOutput = RestoreProductResult;
+
+pub struct RestoreProductUsecase {
+    repository: Arc<dyn ProductAbstructRepository>,
+}
+impl RestoreProductUsecase {
+    pub fn new(repository: Arc<dyn ProductAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn restore(
+        &self,
+        product_id: &ProductId,
+    ) -> Result<RestoreProductOutput, DomainError> {
+        let result = self.repository.restore(product_id).await?;
+
+        Ok(result)
+    }
+}
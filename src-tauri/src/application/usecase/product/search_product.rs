@@ -1,40 +1,156 @@
-use serde::Serialize;
-use std::{error::Error, rc::Rc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 use crate::{
     application::repository::product_repository::ProductAbstructRepository,
-    domain::product::Product,
+    domain::{error::DomainError, product::Product},
 };
 
+/// How a text condition is compared with the stored value.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TextMatch {
+    /// The value contains the text.
+    Partial,
+    /// The value starts with the text.
+    Prefix,
+    Exact,
+}
+impl Default for TextMatch {
+    fn default() -> Self {
+        TextMatch::Partial
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductSortKey {
+    Id,
+    Name,
+    Code,
+    DefaultPrice,
+    StandardStockQuantity,
+    CreatedAt,
+    UpdatedAt,
+}
+impl Default for ProductSortKey {
+    fn default() -> Self {
+        ProductSortKey::Id
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+impl Default for SortDirection {
+    fn default() -> Self {
+        SortDirection::Asc
+    }
+}
+
+/// An inclusive range; either end may be left open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NumberRange {
+    min: Option<i64>,
+    max: Option<i64>,
+}
+impl NumberRange {
+    pub fn new(min: Option<i64>, max: Option<i64>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn min(&self) -> &Option<i64> {
+        &self.min
+    }
+
+    pub fn max(&self) -> &Option<i64> {
+        &self.max
+    }
+
+    fn validate(&self, field: &str) -> Result<(), DomainError> {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if min > max => Err(DomainError::validation(
+                field,
+                format!("minimum must not exceed maximum: min={}, max={}", min, max),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SearchProductInput {
     offset: i64,
     limit: i64,
     name: Option<String>,
     code: Option<String>,
+    name_match: TextMatch,
+    code_match: TextMatch,
+    default_price: NumberRange,
+    standard_stock_quantity: NumberRange,
+    sort_key: ProductSortKey,
+    sort_direction: SortDirection,
+    include_deleted: bool,
+    after_id: Option<i64>,
 }
 
 impl SearchProductInput {
+    /// Names and codes match partially, and products come in the order they were created.
     pub fn new(
         offset: Option<i64>,
         limit: Option<i64>,
         name: Option<String>,
         code: Option<String>,
+        include_deleted: Option<bool>,
     ) -> Self {
         Self {
-            offset: match offset {
-                None => 0,
-                Some(offset) => offset,
-            },
-            limit: match limit {
-                None => 100,
-                Some(limit) => limit,
-            },
+            offset: offset.unwrap_or_default(),
+            limit: limit.unwrap_or(100),
             name,
             code,
+            name_match: TextMatch::default(),
+            code_match: TextMatch::default(),
+            default_price: NumberRange::default(),
+            standard_stock_quantity: NumberRange::default(),
+            sort_key: ProductSortKey::default(),
+            sort_direction: SortDirection::default(),
+            include_deleted: include_deleted.unwrap_or(false),
+            after_id: None,
         }
     }
 
+    pub fn with_text_match(mut self, name_match: TextMatch, code_match: TextMatch) -> Self {
+        self.name_match = name_match;
+        self.code_match = code_match;
+        self
+    }
+
+    pub fn with_default_price(mut self, default_price: NumberRange) -> Self {
+        self.default_price = default_price;
+        self
+    }
+
+    pub fn with_standard_stock_quantity(mut self, standard_stock_quantity: NumberRange) -> Self {
+        self.standard_stock_quantity = standard_stock_quantity;
+        self
+    }
+
+    pub fn with_sort(mut self, sort_key: ProductSortKey, sort_direction: SortDirection) -> Self {
+        self.sort_key = sort_key;
+        self.sort_direction = sort_direction;
+        self
+    }
+
+    /// Reads only the products after `after_id`, in id order whatever the sort, so that a page
+    /// deep into every product costs no more to read than the first.
+    pub fn with_after_id(mut self, after_id: i64) -> Self {
+        self.after_id = Some(after_id);
+        self
+    }
+
     pub fn offset(&self) -> &i64 {
         &self.offset
     }
@@ -50,33 +166,75 @@ impl SearchProductInput {
     pub fn code(&self) -> &Option<String> {
         &self.code
     }
+
+    pub fn name_match(&self) -> &TextMatch {
+        &self.name_match
+    }
+
+    pub fn code_match(&self) -> &TextMatch {
+        &self.code_match
+    }
+
+    pub fn default_price(&self) -> &NumberRange {
+        &self.default_price
+    }
+
+    pub fn standard_stock_quantity(&self) -> &NumberRange {
+        &self.standard_stock_quantity
+    }
+
+    /// Ties are broken by id in the same direction, so that pages do not overlap.
+    pub fn sort_key(&self) -> &ProductSortKey {
+        &self.sort_key
+    }
+
+    pub fn sort_direction(&self) -> &SortDirection {
+        &self.sort_direction
+    }
+
+    /// Soft-deleted products are left out unless this is set.
+    pub fn include_deleted(&self) -> &bool {
+        &self.include_deleted
+    }
+
+    pub fn after_id(&self) -> &Option<i64> {
+        &self.after_id
+    }
 }
 
 #[derive(Serialize, Debug)]
 pub struct SearchProductOutput {
     pub products: Vec<Product>,
+    /// Every product matching the conditions, regardless of `offset` and `limit`.
+    pub total: i64,
 }
 impl SearchProductOutput {
-    fn new(products: Vec<Product>) -> Self {
-        Self { products }
+    fn new(products: Vec<Product>, total: i64) -> Self {
+        Self { products, total }
     }
 }
 
 pub struct SearchProductUsecase {
-    repository: Rc<dyn ProductAbstructRepository>,
+    repository: Arc<dyn ProductAbstructRepository>,
 }
 
 impl SearchProductUsecase {
-    pub fn new(repository: Rc<dyn ProductAbstructRepository>) -> Self {
+    pub fn new(repository: Arc<dyn ProductAbstructRepository>) -> Self {
         Self { repository }
     }
 
     pub async fn search(
         &self,
         input: SearchProductInput,
-    ) -> Result<SearchProductOutput, Box<dyn Error>> {
+    ) -> Result<SearchProductOutput, DomainError> {
+        input.default_price().validate("default_price")?;
+        input
+            .standard_stock_quantity()
+            .validate("standard_stock_quantity")?;
+
         let products = self.repository.search(&input).await?;
-        let output = SearchProductOutput::new(products);
+        let total = self.repository.count(&input).await?;
+        let output = SearchProductOutput::new(products, total);
         Ok(output)
     }
 }
@@ -85,11 +243,14 @@ impl SearchProductUsecase {
 mod tests {
 
     use sqlx::SqlitePool;
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     use crate::{
         adapters::gateway::product_repository::SqliteProductRepository,
-        application::usecase::product::search_product::{SearchProductInput, SearchProductUsecase},
+        application::usecase::product::search_product::{
+            NumberRange, SearchProductInput, SearchProductUsecase,
+        },
+        domain::error::DomainError,
         infrastructure::database::MIGRATOR,
     };
 
@@ -117,16 +278,26 @@ mod tests {
         .execute(&mut conn)
         .await.unwrap();
 
-        let usecase = SearchProductUsecase::new(Rc::new(repository));
+        let usecase = SearchProductUsecase::new(Arc::new(repository));
 
         let offset: Option<i64> = None;
         let limit: Option<i64> = None;
         let name: Option<String> = Some(String::from("商品1"));
         let code: Option<String> = None;
 
-        let input = SearchProductInput::new(offset, limit, name, code);
+        let input = SearchProductInput::new(offset, limit, name, code, None);
         let outputs = usecase.search(input).await.unwrap();
 
         assert_eq!(outputs.products.len(), 1);
+        assert_eq!(outputs.total, 1);
+
+        let input = SearchProductInput::new(None, None, None, None, None)
+            .with_default_price(NumberRange::new(Some(3000), Some(1000)));
+        match usecase.search(input).await {
+            Err(DomainError::Validation { fields, .. }) => {
+                assert_eq!(fields[0].field(), "default_price");
+            }
+            _ => panic!(),
+        }
     }
 }
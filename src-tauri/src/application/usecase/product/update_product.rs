@@ -1,20 +1,27 @@
 use crate::{
-    application::repository::product_repository::{ProductAbstructRepository, UpdateProductResult},
-    domain::product::{
-        ProductCode, ProductDefaultPrice, ProductId, ProductName, ProductStandardStockQuantity,
-        ProductUnit,
+    application::{
+        repository::product_repository::{ProductAbstructRepository, UpdateProductResult},
+        usecase::product::create_product::ensure_unique_code,
+    },
+    domain::{
+        error::DomainError,
+        product::{validate_product, ProductId, ProductName},
+        tax::TaxRate,
     },
 };
-use std::{error::Error, rc::Rc};
+use std::sync::Arc;
+use time::PrimitiveDateTime;
 
 #[derive(Debug)]
 pub struct UpdateProductInput {
     id: ProductId,
     name: Option<ProductName>,
-    code: Option<ProductCode>,
-    unit: Option<ProductUnit>,
-    default_price: Option<ProductDefaultPrice>,
-    standard_stock_quantity: Option<ProductStandardStockQuantity>,
+    code: Option<String>,
+    unit: Option<String>,
+    default_price: Option<i64>,
+    standard_stock_quantity: Option<i64>,
+    tax_rate: Option<TaxRate>,
+    expected_updated_at: Option<PrimitiveDateTime>,
 }
 impl UpdateProductInput {
     pub fn new(
@@ -24,6 +31,7 @@ impl UpdateProductInput {
         unit: Option<String>,
         default_price: Option<i64>,
         standard_stock_quantity: Option<i64>,
+        tax_rate: Option<TaxRate>,
     ) -> Self {
         Self {
             id,
@@ -32,9 +40,17 @@ impl UpdateProductInput {
             unit,
             default_price,
             standard_stock_quantity,
+            tax_rate,
+            expected_updated_at: None,
         }
     }
 
+    /// Makes the update fail with a conflict when the product was updated after it was read.
+    pub fn with_expected_updated_at(mut self, expected_updated_at: Option<PrimitiveDateTime>) -> Self {
+        self.expected_updated_at = expected_updated_at;
+        self
+    }
+
     pub fn id(&self) -> &ProductId {
         &self.id
     }
@@ -43,21 +59,30 @@ impl UpdateProductInput {
         &self.name
     }
 
-    pub fn code(&self) -> &Option<ProductCode> {
+    pub fn code(&self) -> &Option<String> {
         &self.code
     }
 
-    pub fn unit(&self) -> &Option<ProductUnit> {
+    pub fn unit(&self) -> &Option<String> {
         &self.unit
     }
 
-    pub fn default_price(&self) -> &Option<ProductDefaultPrice> {
+    pub fn default_price(&self) -> &Option<i64> {
         &self.default_price
     }
 
-    pub fn standard_stock_quantity(&self) -> &Option<ProductStandardStockQuantity> {
+    pub fn standard_stock_quantity(&self) -> &Option<i64> {
         &self.standard_stock_quantity
     }
+
+    pub fn tax_rate(&self) -> &Option<TaxRate> {
+        &self.tax_rate
+    }
+
+    /// The `updated_at` of the product as it was read before editing.
+    pub fn expected_updated_at(&self) -> &Option<PrimitiveDateTime> {
+        &self.expected_updated_at
+    }
 }
 
 #[derive(Debug)]
@@ -75,17 +100,26 @@ impl UpdateProductOutput {
 }
 
 pub struct UpdateProductUsecase {
-    repository: Rc<dyn ProductAbstructRepository>,
+    repository: Arc<dyn ProductAbstructRepository>,
 }
 impl UpdateProductUsecase {
-    pub fn new(repository: Rc<dyn ProductAbstructRepository>) -> Self {
+    pub fn new(repository: Arc<dyn ProductAbstructRepository>) -> Self {
         Self { repository }
     }
 
     pub async fn update(
         &self,
         input: UpdateProductInput,
-    ) -> Result<UpdateProductOutput, Box<dyn Error>> {
+    ) -> Result<UpdateProductOutput, DomainError> {
+        validate_product(
+            input.code().as_deref(),
+            input.unit().as_deref(),
+            *input.default_price(),
+            *input.standard_stock_quantity(),
+        )?;
+        if let Some(code) = input.code() {
+            ensure_unique_code(&self.repository, code, Some(input.id())).await?;
+        }
         let update_product_result = self.repository.update(&input).await?;
         let result = UpdateProductOutput::new(update_product_result);
 
@@ -101,12 +135,12 @@ mod tests {
         infrastructure::database::MIGRATOR,
     };
     use sqlx::SqlitePool;
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     #[sqlx::test(migrator = "MIGRATOR")]
     async fn update_test(pool: SqlitePool) {
         let mut conn = pool.acquire().await.unwrap();
-        let repository = Rc::new(SqliteProductRepository::new(pool));
+        let repository = Arc::new(SqliteProductRepository::new(pool));
         let usecase = UpdateProductUsecase::new(repository.clone());
 
         sqlx::query(
@@ -136,6 +170,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
 
         let result = usecase.update(input).await.unwrap();
@@ -0,0 +1,99 @@
+use crate::{
+    application::repository::quotation_repository::QuotationAbstructRepository,
+    domain::{
+        error::DomainError,
+        quotation::{QuotationDate, QuotationId, QuotationStatus},
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct ChangeQuotationStatusInput {
+    id: QuotationId,
+    status: QuotationStatus,
+    changed_on: QuotationDate,
+}
+impl ChangeQuotationStatusInput {
+    pub fn new(id: QuotationId, status: QuotationStatus, changed_on: QuotationDate) -> Self {
+        Self {
+            id,
+            status,
+            changed_on,
+        }
+    }
+
+    pub fn id(&self) -> &QuotationId {
+        &self.id
+    }
+
+    pub fn status(&self) -> &QuotationStatus {
+        &self.status
+    }
+
+    /// The day the customer answered; an answer after `valid_until` cannot accept the quote.
+    pub fn changed_on(&self) -> &QuotationDate {
+        &self.changed_on
+    }
+}
+
+#[derive(Debug)]
+pub struct ChangeQuotationStatusOutput {
+    quotation_id: QuotationId,
+}
+impl ChangeQuotationStatusOutput {
+    pub fn new(quotation_id: QuotationId) -> Self {
+        Self { quotation_id }
+    }
+
+    pub fn quotation_id(&self) -> &QuotationId {
+        &self.quotation_id
+    }
+}
+
+pub struct ChangeQuotationStatusUsecase {
+    repository: Arc<dyn QuotationAbstructRepository>,
+}
+impl ChangeQuotationStatusUsecase {
+    pub fn new(repository: Arc<dyn QuotationAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn change(
+        &self,
+        input: ChangeQuotationStatusInput,
+    ) -> Result<ChangeQuotationStatusOutput, DomainError> {
+        let quotation = self
+            .repository
+            .find_by_id(&input.id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("quotation", input.id))?;
+        if !quotation.status().can_transition_to(&input.status) {
+            return Err(DomainError::conflict(format!(
+                "quotation status cannot change from {} to {}: id={}",
+                quotation.status().as_str(),
+                input.status.as_str(),
+                input.id
+            )));
+        }
+        if input.status == QuotationStatus::Accepted && quotation.is_expired_on(&input.changed_on) {
+            return Err(DomainError::conflict(format!(
+                "quotation expired on {}: id={}",
+                quotation.valid_until(),
+                input.id
+            )));
+        }
+
+        let updated = self
+            .repository
+            .update_status(&input.id, quotation.status(), &input.status)
+            .await?;
+        if !updated {
+            return Err(DomainError::conflict(format!(
+                "quotation was changed concurrently: id={}",
+                input.id
+            )));
+        }
+
+        Ok(ChangeQuotationStatusOutput::new(input.id))
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    application::{
+        repository::{
+            sales_order_repository::CreateSalesOrderResult, unit_of_work::UnitOfWorkFactory,
+        },
+        usecase::sales_order::create_sales_order::{
+            ensure_active_customer, CreateSalesOrderInput, SalesOrderLineInput,
+        },
+    },
+    domain::{
+        error::DomainError,
+        quotation::{QuotationId, QuotationStatus},
+        sales_order::SalesOrderDate,
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct ConvertQuotationInput {
+    id: QuotationId,
+    order_date: SalesOrderDate,
+}
+impl ConvertQuotationInput {
+    pub fn new(id: QuotationId, order_date: SalesOrderDate) -> Self {
+        Self { id, order_date }
+    }
+
+    pub fn id(&self) -> &QuotationId {
+        &self.id
+    }
+
+    pub fn order_date(&self) -> &SalesOrderDate {
+        &self.order_date
+    }
+}
+
+#[derive(Debug)]
+pub struct ConvertQuotationOutput {
+    result: CreateSalesOrderResult,
+}
+impl ConvertQuotationOutput {
+    pub fn new(result: CreateSalesOrderResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &CreateSalesOrderResult {
+        &self.result
+    }
+}
+
+/// Turns an accepted quotation into a sales order with the same customer, lines and quoted
+/// prices. A quotation converts at most once; the order and the quotation's link to it are
+/// written in one unit of work.
+pub struct ConvertQuotationUsecase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+impl ConvertQuotationUsecase {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    pub async fn convert(
+        &self,
+        input: ConvertQuotationInput,
+    ) -> Result<ConvertQuotationOutput, DomainError> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let repository = unit_of_work.quotation_repository();
+        let sales_order_repository = unit_of_work.sales_order_repository();
+        let quotation = repository
+            .find_by_id(&input.id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("quotation", input.id))?;
+        if *quotation.status() != QuotationStatus::Accepted {
+            return Err(DomainError::conflict(format!(
+                "only accepted quotations can be converted: id={}, status={}",
+                input.id,
+                quotation.status().as_str()
+            )));
+        }
+        if let Some(sales_order_id) = quotation.sales_order_id() {
+            return Err(DomainError::conflict(format!(
+                "quotation is already converted: id={}, sales_order_id={}",
+                input.id, sales_order_id
+            )));
+        }
+        ensure_active_customer(&unit_of_work.customer_repository(), quotation.customer_id())
+            .await?;
+
+        let lines = quotation
+            .lines()
+            .iter()
+            .map(|line| {
+                SalesOrderLineInput::new(
+                    *line.product_id(),
+                    *line.quantity(),
+                    Some(*line.unit_price()),
+                )
+            })
+            .collect();
+        let sales_order_input =
+            CreateSalesOrderInput::new(*quotation.customer_id(), input.order_date, lines);
+        let result = sales_order_repository.create(&sales_order_input).await?;
+        let converted = repository
+            .mark_converted(&input.id, result.sales_order_id())
+            .await?;
+        if !converted {
+            // Dropping the unit of work takes the new order back.
+            return Err(DomainError::conflict(format!(
+                "quotation is already converted: id={}",
+                input.id
+            )));
+        }
+        unit_of_work.commit().await?;
+
+        Ok(ConvertQuotationOutput::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository,
+            fixtures,
+            price_list_repository::SqlitePriceListRepository,
+            product_repository::SqliteProductRepository,
+            quotation_repository::SqliteQuotationRepository,
+            sales_order_repository::SqliteSalesOrderRepository,
+            unit_of_work::SqliteUnitOfWorkFactory,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                quotation_repository::QuotationAbstructRepository,
+                sales_order_repository::OrderAbstructRepository,
+            },
+            usecase::{
+                quotation::{
+                    change_quotation_status::{
+                        ChangeQuotationStatusInput, ChangeQuotationStatusUsecase,
+                    },
+                    convert_quotation::{ConvertQuotationInput, ConvertQuotationUsecase},
+                    create_quotation::{
+                        CreateQuotationInput, CreateQuotationUsecase, QuotationLineInput,
+                    },
+                },
+            },
+        },
+        domain::quotation::QuotationStatus,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn convert_test(pool: SqlitePool) {
+        let customer_repository = Arc::new(SqliteCustomerRespository::new(pool.clone()));
+        let customer = customer_repository
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product_repository = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let product = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let price_list_repository = Arc::new(SqlitePriceListRepository::new(pool.clone()));
+        let repository = Arc::new(SqliteQuotationRepository::new(pool.clone()));
+        let sales_order_repository = Arc::new(SqliteSalesOrderRepository::new(pool.clone()));
+        let quoted_on = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+        let valid_until = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+
+        let output = CreateQuotationUsecase::new(
+            repository.clone(),
+            customer_repository,
+            product_repository,
+            price_list_repository,
+        )
+        .create(CreateQuotationInput::new(
+            customer.customer_id(),
+            quoted_on,
+            valid_until,
+            vec![
+                QuotationLineInput::new(*product.product_id(), 3, None),
+                QuotationLineInput::new(*product.product_id(), 2, Some(1800)),
+            ],
+        ))
+        .await
+        .unwrap();
+        let quotation_id = *output.result().quotation_id();
+        let usecase = ConvertQuotationUsecase::new(Arc::new(SqliteUnitOfWorkFactory::new(
+            pool.clone(),
+        )));
+        let order_date = Date::from_calendar_date(2023, Month::June, 20).unwrap();
+
+        assert!(usecase
+            .convert(ConvertQuotationInput::new(quotation_id, order_date))
+            .await
+            .is_err());
+
+        let change_usecase = ChangeQuotationStatusUsecase::new(repository.clone());
+        for status in [QuotationStatus::Sent, QuotationStatus::Accepted] {
+            let input = ChangeQuotationStatusInput::new(quotation_id, status, order_date);
+            change_usecase.change(input).await.unwrap();
+        }
+        let output = usecase
+            .convert(ConvertQuotationInput::new(quotation_id, order_date))
+            .await
+            .unwrap();
+        let sales_order = sales_order_repository
+            .find_by_id(output.result().sales_order_id())
+            .await
+            .unwrap()
+            .unwrap();
+        let quotation = repository.find_by_id(&quotation_id).await.unwrap().unwrap();
+
+        assert_eq!(*sales_order.customer_id(), customer.customer_id());
+        assert_eq!(*sales_order.order_date(), order_date);
+        assert_eq!(*sales_order.lines()[0].unit_price(), 2000);
+        assert_eq!(*sales_order.lines()[1].unit_price(), 1800);
+        assert_eq!(sales_order.total_amount(), quotation.total_amount());
+        assert_eq!(*quotation.sales_order_id(), Some(*sales_order.id()));
+        assert!(usecase
+            .convert(ConvertQuotationInput::new(quotation_id, order_date))
+            .await
+            .is_err());
+        let sales_orders: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM t_sales_orders")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sales_orders, 1);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn accept_expired_test(pool: SqlitePool) {
+        let customer_repository = Arc::new(SqliteCustomerRespository::new(pool.clone()));
+        let customer = customer_repository
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product_repository = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let product = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let price_list_repository = Arc::new(SqlitePriceListRepository::new(pool.clone()));
+        let repository = Arc::new(SqliteQuotationRepository::new(pool));
+        let valid_until = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+        let output = CreateQuotationUsecase::new(
+            repository.clone(),
+            customer_repository,
+            product_repository,
+            price_list_repository,
+        )
+        .create(CreateQuotationInput::new(
+            customer.customer_id(),
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            valid_until,
+            vec![QuotationLineInput::new(*product.product_id(), 1, None)],
+        ))
+        .await
+        .unwrap();
+        let quotation_id = *output.result().quotation_id();
+        let usecase = ChangeQuotationStatusUsecase::new(repository);
+        let answered_on = Date::from_calendar_date(2023, Month::July, 1).unwrap();
+
+        usecase
+            .change(ChangeQuotationStatusInput::new(
+                quotation_id,
+                QuotationStatus::Sent,
+                valid_until,
+            ))
+            .await
+            .unwrap();
+        let input =
+            ChangeQuotationStatusInput::new(quotation_id, QuotationStatus::Accepted, answered_on);
+
+        assert!(usecase.change(input).await.is_err());
+    }
+}
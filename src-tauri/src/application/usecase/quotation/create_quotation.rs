@@ -0,0 +1,132 @@
+use crate::{
+    application::{
+        repository::{
+            customer_repository::CustomerAbstructRepository,
+            price_list_repository::PriceListAbstructRepository,
+            product_repository::ProductAbstructRepository,
+            quotation_repository::{CreateQuotationResult, QuotationAbstructRepository},
+        },
+        usecase::sales_order::create_sales_order::{
+            ensure_active_customer, resolve_lines, SalesOrderLineInput,
+        },
+    },
+    domain::{customer, error::DomainError, quotation::QuotationDate},
+};
+use std::sync::Arc;
+
+/// Quote lines are entered like order lines, so that an accepted quote converts as is.
+pub type QuotationLineInput = SalesOrderLineInput;
+
+/// Rejects a validity date earlier than the quote itself.
+pub(crate) fn validate_period(
+    quoted_on: &QuotationDate,
+    valid_until: &QuotationDate,
+) -> Result<(), DomainError> {
+    if valid_until < quoted_on {
+        return Err(DomainError::validation(
+            "valid_until",
+            format!(
+                "valid_until must not be before quoted_on: quoted_on={}, valid_until={}",
+                quoted_on, valid_until
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct CreateQuotationInput {
+    customer_id: customer::Id,
+    quoted_on: QuotationDate,
+    valid_until: QuotationDate,
+    lines: Vec<QuotationLineInput>,
+}
+impl CreateQuotationInput {
+    pub fn new(
+        customer_id: customer::Id,
+        quoted_on: QuotationDate,
+        valid_until: QuotationDate,
+        lines: Vec<QuotationLineInput>,
+    ) -> Self {
+        Self {
+            customer_id,
+            quoted_on,
+            valid_until,
+            lines,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn quoted_on(&self) -> &QuotationDate {
+        &self.quoted_on
+    }
+
+    pub fn valid_until(&self) -> &QuotationDate {
+        &self.valid_until
+    }
+
+    pub fn lines(&self) -> &Vec<QuotationLineInput> {
+        &self.lines
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateQuotationOutput {
+    result: CreateQuotationResult,
+}
+impl CreateQuotationOutput {
+    pub fn new(result: CreateQuotationResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &CreateQuotationResult {
+        &self.result
+    }
+}
+
+pub struct CreateQuotationUsecase {
+    repository: Arc<dyn QuotationAbstructRepository>,
+    customer_repository: Arc<dyn CustomerAbstructRepository>,
+    product_repository: Arc<dyn ProductAbstructRepository>,
+    price_list_repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl CreateQuotationUsecase {
+    pub fn new(
+        repository: Arc<dyn QuotationAbstructRepository>,
+        customer_repository: Arc<dyn CustomerAbstructRepository>,
+        product_repository: Arc<dyn ProductAbstructRepository>,
+        price_list_repository: Arc<dyn PriceListAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            customer_repository,
+            product_repository,
+            price_list_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        input: CreateQuotationInput,
+    ) -> Result<CreateQuotationOutput, DomainError> {
+        validate_period(&input.quoted_on, &input.valid_until)?;
+        ensure_active_customer(&self.customer_repository, &input.customer_id).await?;
+        let lines = resolve_lines(
+            &self.product_repository,
+            &self.price_list_repository,
+            &input.customer_id,
+            &input.quoted_on,
+            &input.lines,
+        )
+        .await?;
+        let input =
+            CreateQuotationInput::new(input.customer_id, input.quoted_on, input.valid_until, lines);
+        let result = self.repository.create(&input).await?;
+
+        Ok(CreateQuotationOutput::new(result))
+    }
+}
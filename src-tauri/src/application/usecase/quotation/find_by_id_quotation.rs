@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::quotation_repository::QuotationAbstructRepository,
+    domain::{
+        error::DomainError,
+        quotation::{Quotation, QuotationId},
+    },
+};
+
+#[derive(Debug)]
+pub struct FindByIDQuotationOutput {
+    pub quotation: Option<Quotation>,
+}
+impl FindByIDQuotationOutput {
+    pub fn new(quotation: Option<Quotation>) -> Self {
+        Self { quotation }
+    }
+}
+
+pub struct FindByIDQuotationUsecase {
+    repository: Arc<dyn QuotationAbstructRepository>,
+}
+impl FindByIDQuotationUsecase {
+    pub fn new(repository: Arc<dyn QuotationAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find_by_id(
+        &self,
+        quotation_id: &QuotationId,
+    ) -> Result<FindByIDQuotationOutput, DomainError> {
+        let quotation = self.repository.find_by_id(quotation_id).await?;
+
+        Ok(FindByIDQuotationOutput::new(quotation))
+    }
+}
@@ -0,0 +1,6 @@
+pub mod change_quotation_status;
+pub mod convert_quotation;
+pub mod create_quotation;
+pub mod find_by_id_quotation;
+pub mod search_quotation;
+pub mod update_quotation;
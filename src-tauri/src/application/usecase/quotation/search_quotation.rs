@@ -0,0 +1,92 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::quotation_repository::QuotationAbstructRepository,
+    domain::{
+        customer,
+        error::DomainError,
+        quotation::{Quotation, QuotationDate, QuotationStatus},
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchQuotationInput {
+    offset: i64,
+    limit: i64,
+    customer_id: Option<customer::Id>,
+    status: Option<QuotationStatus>,
+    quoted_on_from: Option<QuotationDate>,
+    quoted_on_to: Option<QuotationDate>,
+}
+impl SearchQuotationInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        customer_id: Option<customer::Id>,
+        status: Option<QuotationStatus>,
+        quoted_on_from: Option<QuotationDate>,
+        quoted_on_to: Option<QuotationDate>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            customer_id,
+            status,
+            quoted_on_from,
+            quoted_on_to,
+        }
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn status(&self) -> &Option<QuotationStatus> {
+        &self.status
+    }
+
+    pub fn quoted_on_from(&self) -> &Option<QuotationDate> {
+        &self.quoted_on_from
+    }
+
+    pub fn quoted_on_to(&self) -> &Option<QuotationDate> {
+        &self.quoted_on_to
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchQuotationOutput {
+    pub quotations: Vec<Quotation>,
+}
+impl SearchQuotationOutput {
+    fn new(quotations: Vec<Quotation>) -> Self {
+        Self { quotations }
+    }
+}
+
+pub struct SearchQuotationUsecase {
+    repository: Arc<dyn QuotationAbstructRepository>,
+}
+impl SearchQuotationUsecase {
+    pub fn new(repository: Arc<dyn QuotationAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchQuotationInput,
+    ) -> Result<SearchQuotationOutput, DomainError> {
+        let quotations = self.repository.search(&input).await?;
+
+        Ok(SearchQuotationOutput::new(quotations))
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    application::{
+        repository::{
+            customer_repository::CustomerAbstructRepository,
+            price_list_repository::PriceListAbstructRepository,
+            product_repository::ProductAbstructRepository,
+            quotation_repository::{QuotationAbstructRepository, UpdateQuotationResult},
+        },
+        usecase::{
+            quotation::create_quotation::{validate_period, QuotationLineInput},
+            sales_order::create_sales_order::{ensure_active_customer, resolve_lines},
+        },
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        quotation::{QuotationDate, QuotationId},
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct UpdateQuotationInput {
+    id: QuotationId,
+    customer_id: Option<customer::Id>,
+    quoted_on: Option<QuotationDate>,
+    valid_until: Option<QuotationDate>,
+    lines: Option<Vec<QuotationLineInput>>,
+}
+impl UpdateQuotationInput {
+    pub fn new(
+        id: QuotationId,
+        customer_id: Option<customer::Id>,
+        quoted_on: Option<QuotationDate>,
+        valid_until: Option<QuotationDate>,
+        lines: Option<Vec<QuotationLineInput>>,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            quoted_on,
+            valid_until,
+            lines,
+        }
+    }
+
+    pub fn id(&self) -> &QuotationId {
+        &self.id
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn quoted_on(&self) -> &Option<QuotationDate> {
+        &self.quoted_on
+    }
+
+    pub fn valid_until(&self) -> &Option<QuotationDate> {
+        &self.valid_until
+    }
+
+    pub fn lines(&self) -> &Option<Vec<QuotationLineInput>> {
+        &self.lines
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateQuotationOutput {
+    result: UpdateQuotationResult,
+}
+impl UpdateQuotationOutput {
+    pub fn new(result: UpdateQuotationResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &UpdateQuotationResult {
+        &self.result
+    }
+}
+
+pub struct UpdateQuotationUsecase {
+    repository: Arc<dyn QuotationAbstructRepository>,
+    customer_repository: Arc<dyn CustomerAbstructRepository>,
+    product_repository: Arc<dyn ProductAbstructRepository>,
+    price_list_repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl UpdateQuotationUsecase {
+    pub fn new(
+        repository: Arc<dyn QuotationAbstructRepository>,
+        customer_repository: Arc<dyn CustomerAbstructRepository>,
+        product_repository: Arc<dyn ProductAbstructRepository>,
+        price_list_repository: Arc<dyn PriceListAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            customer_repository,
+            product_repository,
+            price_list_repository,
+        }
+    }
+
+    pub async fn update(
+        &self,
+        input: UpdateQuotationInput,
+    ) -> Result<UpdateQuotationOutput, DomainError> {
+        let quotation = self
+            .repository
+            .find_by_id(&input.id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("quotation", input.id))?;
+        if !quotation.is_editable() {
+            return Err(DomainError::conflict(format!(
+                "only draft quotations can be edited: id={}, status={}",
+                input.id,
+                quotation.status().as_str()
+            )));
+        }
+        validate_period(
+            input.quoted_on.as_ref().unwrap_or(quotation.quoted_on()),
+            input
+                .valid_until
+                .as_ref()
+                .unwrap_or(quotation.valid_until()),
+        )?;
+        if let Some(customer_id) = &input.customer_id {
+            ensure_active_customer(&self.customer_repository, customer_id).await?;
+        }
+
+        let lines = match &input.lines {
+            Some(lines) => Some(
+                resolve_lines(
+                    &self.product_repository,
+                    &self.price_list_repository,
+                    input
+                        .customer_id
+                        .as_ref()
+                        .unwrap_or(quotation.customer_id()),
+                    input.quoted_on.as_ref().unwrap_or(quotation.quoted_on()),
+                    lines,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let input = UpdateQuotationInput::new(
+            input.id,
+            input.customer_id,
+            input.quoted_on,
+            input.valid_until,
+            lines,
+        );
+        let result = self.repository.update(&input).await?;
+
+        Ok(UpdateQuotationOutput::new(result))
+    }
+}
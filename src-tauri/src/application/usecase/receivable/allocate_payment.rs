@@ -0,0 +1,91 @@
+use crate::{
+    application::{
+        repository::unit_of_work::UnitOfWorkFactory,
+        usecase::receivable::record_payment::{resolve_allocations, PaymentAllocationInput},
+    },
+    domain::{error::DomainError, receivable::PaymentId},
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct AllocatePaymentInput {
+    payment_id: PaymentId,
+    allocations: Option<Vec<PaymentAllocationInput>>,
+}
+impl AllocatePaymentInput {
+    pub fn new(payment_id: PaymentId, allocations: Option<Vec<PaymentAllocationInput>>) -> Self {
+        Self {
+            payment_id,
+            allocations,
+        }
+    }
+
+    pub fn payment_id(&self) -> &PaymentId {
+        &self.payment_id
+    }
+
+    /// `None` applies the unallocated amount to the oldest open invoices.
+    pub fn allocations(&self) -> &Option<Vec<PaymentAllocationInput>> {
+        &self.allocations
+    }
+}
+
+#[derive(Debug)]
+pub struct AllocatePaymentOutput {
+    payment_id: PaymentId,
+}
+impl AllocatePaymentOutput {
+    pub fn new(payment_id: PaymentId) -> Self {
+        Self { payment_id }
+    }
+
+    pub fn payment_id(&self) -> &PaymentId {
+        &self.payment_id
+    }
+}
+
+/// Applies the unallocated part of an earlier payment, typically an over-payment, to invoices
+/// issued since. The balances are read and the allocations stored in one unit of work, so two
+/// allocations at once cannot spend the same amount twice.
+pub struct AllocatePaymentUsecase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+impl AllocatePaymentUsecase {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    pub async fn allocate(
+        &self,
+        input: AllocatePaymentInput,
+    ) -> Result<AllocatePaymentOutput, DomainError> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let repository = unit_of_work.receivable_repository();
+        let payment = repository
+            .find_payment_by_id(&input.payment_id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("payment", input.payment_id))?;
+        let balances = repository
+            .search_invoice_balances(&Some(*payment.customer_id()))
+            .await?;
+        let allocations = resolve_allocations(
+            payment.customer_id(),
+            payment.unallocated_amount(),
+            &balances,
+            &input.allocations,
+        )?;
+        if allocations.is_empty() {
+            return Err(DomainError::conflict(format!(
+                "nothing to allocate: payment_id={}, unallocated={}",
+                input.payment_id,
+                payment.unallocated_amount()
+            )));
+        }
+        repository
+            .create_allocations(&input.payment_id, &allocations)
+            .await?;
+        unit_of_work.commit().await?;
+
+        Ok(AllocatePaymentOutput::new(input.payment_id))
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::receivable_repository::ReceivableAbstructRepository,
+    domain::{
+        error::DomainError,
+        receivable::{Payment, PaymentId},
+    },
+};
+
+#[derive(Debug)]
+pub struct FindByIDPaymentOutput {
+    pub payment: Option<Payment>,
+}
+impl FindByIDPaymentOutput {
+    pub fn new(payment: Option<Payment>) -> Self {
+        Self { payment }
+    }
+}
+
+pub struct FindByIDPaymentUsecase {
+    repository: Arc<dyn ReceivableAbstructRepository>,
+}
+impl FindByIDPaymentUsecase {
+    pub fn new(repository: Arc<dyn ReceivableAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find_by_id(
+        &self,
+        payment_id: &PaymentId,
+    ) -> Result<FindByIDPaymentOutput, DomainError> {
+        let payment = self.repository.find_payment_by_id(payment_id).await?;
+
+        Ok(FindByIDPaymentOutput::new(payment))
+    }
+}
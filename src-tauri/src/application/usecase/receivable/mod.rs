@@ -0,0 +1,7 @@
+pub mod allocate_payment;
+pub mod find_by_id_payment;
+pub mod record_payment;
+pub mod search_aging_report;
+pub mod search_customer_balance;
+pub mod search_invoice_balance;
+pub mod search_payment;
@@ -0,0 +1,178 @@
+use crate::{
+    application::repository::{
+        receivable_repository::RecordPaymentResult, unit_of_work::UnitOfWorkFactory,
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        invoice::InvoiceId,
+        receivable::{
+            allocate_oldest_first, validate_allocations, BankFee, InvoiceBalance, PaymentAmount,
+            PaymentDate, PaymentMethod, PaymentNote,
+        },
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct PaymentAllocationInput {
+    invoice_id: InvoiceId,
+    amount: PaymentAmount,
+}
+impl PaymentAllocationInput {
+    pub fn new(invoice_id: InvoiceId, amount: PaymentAmount) -> Self {
+        Self { invoice_id, amount }
+    }
+
+    pub fn invoice_id(&self) -> &InvoiceId {
+        &self.invoice_id
+    }
+
+    pub fn amount(&self) -> &PaymentAmount {
+        &self.amount
+    }
+}
+
+/// Uses the allocations given, or spreads `available` over the open invoices oldest first
+/// when there are none, and checks the result against the invoice balances.
+pub(crate) fn resolve_allocations(
+    customer_id: &customer::Id,
+    available: PaymentAmount,
+    balances: &[InvoiceBalance],
+    allocations: &Option<Vec<PaymentAllocationInput>>,
+) -> Result<Vec<PaymentAllocationInput>, DomainError> {
+    let allocations: Vec<(InvoiceId, PaymentAmount)> = match allocations {
+        Some(allocations) => allocations
+            .iter()
+            .map(|allocation| (allocation.invoice_id, allocation.amount))
+            .collect(),
+        None => allocate_oldest_first(available, balances),
+    };
+    validate_allocations(customer_id, available, balances, &allocations)?;
+
+    Ok(allocations
+        .into_iter()
+        .map(|(invoice_id, amount)| PaymentAllocationInput::new(invoice_id, amount))
+        .collect())
+}
+
+#[derive(Debug)]
+pub struct RecordPaymentInput {
+    customer_id: customer::Id,
+    paid_on: PaymentDate,
+    amount: PaymentAmount,
+    method: PaymentMethod,
+    bank_fee: BankFee,
+    note: Option<PaymentNote>,
+    allocations: Option<Vec<PaymentAllocationInput>>,
+}
+impl RecordPaymentInput {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        customer_id: customer::Id,
+        paid_on: PaymentDate,
+        amount: PaymentAmount,
+        method: PaymentMethod,
+        bank_fee: BankFee,
+        note: Option<PaymentNote>,
+        allocations: Option<Vec<PaymentAllocationInput>>,
+    ) -> Self {
+        Self {
+            customer_id,
+            paid_on,
+            amount,
+            method,
+            bank_fee,
+            note,
+            allocations,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn paid_on(&self) -> &PaymentDate {
+        &self.paid_on
+    }
+
+    pub fn amount(&self) -> &PaymentAmount {
+        &self.amount
+    }
+
+    pub fn method(&self) -> &PaymentMethod {
+        &self.method
+    }
+
+    pub fn bank_fee(&self) -> &BankFee {
+        &self.bank_fee
+    }
+
+    pub fn note(&self) -> &Option<PaymentNote> {
+        &self.note
+    }
+
+    /// `None` allocates the payment to the oldest open invoices automatically.
+    pub fn allocations(&self) -> &Option<Vec<PaymentAllocationInput>> {
+        &self.allocations
+    }
+}
+
+#[derive(Debug)]
+pub struct RecordPaymentOutput {
+    result: RecordPaymentResult,
+}
+impl RecordPaymentOutput {
+    pub fn new(result: RecordPaymentResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &RecordPaymentResult {
+        &self.result
+    }
+}
+
+/// Stores a payment with its allocations, checked against the invoice balances read in the same
+/// unit of work.
+pub struct RecordPaymentUsecase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+impl RecordPaymentUsecase {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    pub async fn record(
+        &self,
+        input: RecordPaymentInput,
+    ) -> Result<RecordPaymentOutput, DomainError> {
+        if input.amount <= 0 {
+            return Err(DomainError::validation(
+                "amount",
+                format!("payment amount must be positive: amount={}", input.amount),
+            ));
+        }
+        if input.bank_fee < 0 {
+            return Err(DomainError::validation(
+                "bank_fee",
+                format!("bank fee must not be negative: bank_fee={}", input.bank_fee),
+            ));
+        }
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let repository = unit_of_work.receivable_repository();
+        let balances = repository
+            .search_invoice_balances(&Some(input.customer_id))
+            .await?;
+        let allocations = resolve_allocations(
+            &input.customer_id,
+            input.amount + input.bank_fee,
+            &balances,
+            &input.allocations,
+        )?;
+        let result = repository.create_payment(&input, &allocations).await?;
+        unit_of_work.commit().await?;
+
+        Ok(RecordPaymentOutput::new(result))
+    }
+}
@@ -0,0 +1,62 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::receivable_repository::ReceivableAbstructRepository,
+    domain::{
+        customer,
+        error::DomainError,
+        receivable::{AgingReport, PaymentDate},
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchAgingReportInput {
+    as_of: PaymentDate,
+    customer_id: Option<customer::Id>,
+}
+impl SearchAgingReportInput {
+    pub fn new(as_of: PaymentDate, customer_id: Option<customer::Id>) -> Self {
+        Self { as_of, customer_id }
+    }
+
+    pub fn as_of(&self) -> &PaymentDate {
+        &self.as_of
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchAgingReportOutput {
+    pub aging_reports: Vec<AgingReport>,
+}
+impl SearchAgingReportOutput {
+    fn new(aging_reports: Vec<AgingReport>) -> Self {
+        Self { aging_reports }
+    }
+}
+
+pub struct SearchAgingReportUsecase {
+    repository: Arc<dyn ReceivableAbstructRepository>,
+}
+impl SearchAgingReportUsecase {
+    pub fn new(repository: Arc<dyn ReceivableAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchAgingReportInput,
+    ) -> Result<SearchAgingReportOutput, DomainError> {
+        let balances = self
+            .repository
+            .search_invoice_balances(&input.customer_id)
+            .await?;
+        let aging_reports = AgingReport::calculate(&input.as_of, &balances);
+
+        Ok(SearchAgingReportOutput::new(aging_reports))
+    }
+}
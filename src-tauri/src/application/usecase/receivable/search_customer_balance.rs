@@ -0,0 +1,38 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::receivable_repository::ReceivableAbstructRepository,
+    domain::{customer, error::DomainError, receivable::CustomerBalance},
+};
+
+#[derive(Serialize, Debug)]
+pub struct SearchCustomerBalanceOutput {
+    pub customer_balances: Vec<CustomerBalance>,
+}
+impl SearchCustomerBalanceOutput {
+    fn new(customer_balances: Vec<CustomerBalance>) -> Self {
+        Self { customer_balances }
+    }
+}
+
+pub struct SearchCustomerBalanceUsecase {
+    repository: Arc<dyn ReceivableAbstructRepository>,
+}
+impl SearchCustomerBalanceUsecase {
+    pub fn new(repository: Arc<dyn ReceivableAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        customer_id: &Option<customer::Id>,
+    ) -> Result<SearchCustomerBalanceOutput, DomainError> {
+        let customer_balances = self
+            .repository
+            .search_customer_balances(customer_id)
+            .await?;
+
+        Ok(SearchCustomerBalanceOutput::new(customer_balances))
+    }
+}
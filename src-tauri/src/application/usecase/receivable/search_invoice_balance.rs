@@ -0,0 +1,63 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::receivable_repository::ReceivableAbstructRepository,
+    domain::{customer, error::DomainError, receivable::InvoiceBalance},
+};
+
+#[derive(Debug)]
+pub struct SearchInvoiceBalanceInput {
+    customer_id: Option<customer::Id>,
+    only_outstanding: bool,
+}
+impl SearchInvoiceBalanceInput {
+    pub fn new(customer_id: Option<customer::Id>, only_outstanding: Option<bool>) -> Self {
+        Self {
+            customer_id,
+            only_outstanding: only_outstanding.unwrap_or(true),
+        }
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn only_outstanding(&self) -> &bool {
+        &self.only_outstanding
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchInvoiceBalanceOutput {
+    pub invoice_balances: Vec<InvoiceBalance>,
+}
+impl SearchInvoiceBalanceOutput {
+    fn new(invoice_balances: Vec<InvoiceBalance>) -> Self {
+        Self { invoice_balances }
+    }
+}
+
+pub struct SearchInvoiceBalanceUsecase {
+    repository: Arc<dyn ReceivableAbstructRepository>,
+}
+impl SearchInvoiceBalanceUsecase {
+    pub fn new(repository: Arc<dyn ReceivableAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchInvoiceBalanceInput,
+    ) -> Result<SearchInvoiceBalanceOutput, DomainError> {
+        let mut invoice_balances = self
+            .repository
+            .search_invoice_balances(&input.customer_id)
+            .await?;
+        if input.only_outstanding {
+            invoice_balances.retain(|balance| *balance.outstanding_amount() > 0);
+        }
+
+        Ok(SearchInvoiceBalanceOutput::new(invoice_balances))
+    }
+}
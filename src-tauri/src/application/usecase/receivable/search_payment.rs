@@ -0,0 +1,85 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::receivable_repository::ReceivableAbstructRepository,
+    domain::{
+        customer,
+        error::DomainError,
+        receivable::{Payment, PaymentDate},
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchPaymentInput {
+    offset: i64,
+    limit: i64,
+    customer_id: Option<customer::Id>,
+    paid_on_from: Option<PaymentDate>,
+    paid_on_to: Option<PaymentDate>,
+}
+impl SearchPaymentInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        customer_id: Option<customer::Id>,
+        paid_on_from: Option<PaymentDate>,
+        paid_on_to: Option<PaymentDate>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            customer_id,
+            paid_on_from,
+            paid_on_to,
+        }
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn paid_on_from(&self) -> &Option<PaymentDate> {
+        &self.paid_on_from
+    }
+
+    pub fn paid_on_to(&self) -> &Option<PaymentDate> {
+        &self.paid_on_to
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchPaymentOutput {
+    pub payments: Vec<Payment>,
+}
+impl SearchPaymentOutput {
+    fn new(payments: Vec<Payment>) -> Self {
+        Self { payments }
+    }
+}
+
+pub struct SearchPaymentUsecase {
+    repository: Arc<dyn ReceivableAbstructRepository>,
+}
+impl SearchPaymentUsecase {
+    pub fn new(repository: Arc<dyn ReceivableAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchPaymentInput,
+    ) -> Result<SearchPaymentOutput, DomainError> {
+        let payments = self.repository.search_payments(&input).await?;
+
+        Ok(SearchPaymentOutput::new(payments))
+    }
+}
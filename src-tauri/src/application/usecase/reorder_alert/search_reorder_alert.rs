@@ -0,0 +1,95 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::reorder_alert_repository::ReorderAlertAbstructRepository,
+    domain::{error::DomainError, product::ProductId, reorder_alert::ReorderAlert},
+};
+
+#[derive(Debug)]
+pub struct SearchReorderAlertInput {
+    product_id: Option<ProductId>,
+}
+impl SearchReorderAlertInput {
+    pub fn new(product_id: Option<ProductId>) -> Self {
+        Self { product_id }
+    }
+
+    pub fn product_id(&self) -> &Option<ProductId> {
+        &self.product_id
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchReorderAlertOutput {
+    pub reorder_alerts: Vec<ReorderAlert>,
+}
+impl SearchReorderAlertOutput {
+    fn new(reorder_alerts: Vec<ReorderAlert>) -> Self {
+        Self { reorder_alerts }
+    }
+}
+
+pub struct SearchReorderAlertUsecase {
+    repository: Arc<dyn ReorderAlertAbstructRepository>,
+}
+impl SearchReorderAlertUsecase {
+    pub fn new(repository: Arc<dyn ReorderAlertAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchReorderAlertInput,
+    ) -> Result<SearchReorderAlertOutput, DomainError> {
+        let reorder_alerts = self.repository.search(input.product_id()).await?;
+
+        Ok(SearchReorderAlertOutput::new(reorder_alerts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+
+    use crate::{
+        adapters::gateway::reorder_alert_repository::SqliteReorderAlertRepository,
+        application::usecase::reorder_alert::search_reorder_alert::{
+            SearchReorderAlertInput, SearchReorderAlertUsecase,
+        },
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn search_test(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        sqlx::query(
+            "INSERT INTO
+                m_products (id, name, code, unit, default_price, standard_stock_quantity)
+                VALUES
+                    (1, \"商品1\", \"product001\", \"個\", 2000, 10),
+                    (2, \"商品2\", \"product002\", \"個\", 3000, 5)",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO
+                t_stock_movements (product_id, movement_type, quantity, moved_on)
+                VALUES
+                    (1, \"receipt\", 4, \"2023-06-01\"),
+                    (2, \"receipt\", 5, \"2023-06-01\")",
+        )
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+        let usecase = SearchReorderAlertUsecase::new(Arc::new(SqliteReorderAlertRepository::new(pool)));
+        let output = usecase.search(SearchReorderAlertInput::new(None)).await.unwrap();
+
+        assert_eq!(output.reorder_alerts.len(), 1);
+        assert_eq!(*output.reorder_alerts[0].product_id(), 1);
+        assert_eq!(*output.reorder_alerts[0].shortfall(), 6);
+    }
+}
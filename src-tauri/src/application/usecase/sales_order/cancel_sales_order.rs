@@ -0,0 +1,60 @@
+use crate::{
+    application::{
+        repository::{
+            sales_order_repository::CancelSalesOrderResult, unit_of_work::UnitOfWorkFactory,
+        },
+        usecase::sales_order::update_sales_order::post_stock_changes,
+    },
+    domain::{error::DomainError, sales_order::SalesOrderId},
+};
+use std::sync::Arc;
+
+pub type CancelSalesOrderOutput = CancelSalesOrderResult;
+
+/// Cancels an order that is not invoiced yet, returning to stock whatever it had shipped in the
+/// same unit of work.
+pub struct CancelSalesOrderUsecase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+impl CancelSalesOrderUsecase {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    pub async fn cancel(
+        &self,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<CancelSalesOrderOutput, DomainError> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let repository = unit_of_work.sales_order_repository();
+        let sales_order = match repository.find_by_id(sales_order_id).await? {
+            Some(sales_order) => sales_order,
+            None => return Ok(CancelSalesOrderResult::new(false)),
+        };
+        if sales_order.is_invoiced() {
+            return Err(DomainError::conflict_with(
+                format!(
+                    "an invoiced sales order cannot be cancelled: id={}",
+                    sales_order_id
+                ),
+                &sales_order,
+            ));
+        }
+        let result = repository.cancel(sales_order_id).await?;
+        if *result.result() {
+            let cancelled = repository
+                .find_by_id(sales_order_id)
+                .await?
+                .ok_or_else(|| DomainError::not_found("sales_order", *sales_order_id))?;
+            post_stock_changes(
+                &unit_of_work.stock_movement_repository(),
+                &sales_order,
+                &cancelled,
+            )
+            .await?;
+        }
+        unit_of_work.commit().await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    application::{
+        repository::{
+            customer_repository::CustomerAbstructRepository,
+            price_list_repository::PriceListAbstructRepository,
+            product_repository::ProductAbstructRepository,
+            sales_order_repository::{CreateSalesOrderResult, OrderAbstructRepository},
+        },
+        usecase::price_list::resolve_price::resolve_price,
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        product::ProductId,
+        sales_order::{SalesOrderDate, SalesOrderQuantity, SalesOrderUnitPrice},
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SalesOrderLineInput {
+    product_id: ProductId,
+    quantity: SalesOrderQuantity,
+    unit_price: Option<SalesOrderUnitPrice>,
+}
+impl SalesOrderLineInput {
+    pub fn new(
+        product_id: ProductId,
+        quantity: SalesOrderQuantity,
+        unit_price: Option<SalesOrderUnitPrice>,
+    ) -> Self {
+        Self {
+            product_id,
+            quantity,
+            unit_price,
+        }
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn quantity(&self) -> &SalesOrderQuantity {
+        &self.quantity
+    }
+
+    pub fn unit_price(&self) -> &Option<SalesOrderUnitPrice> {
+        &self.unit_price
+    }
+}
+
+/// Fails with not found unless the customer exists and is not deleted. Orders and quotations
+/// are not taken for deleted customers, though what they already ordered can still be billed.
+pub(crate) async fn ensure_active_customer(
+    customer_repository: &Arc<dyn CustomerAbstructRepository>,
+    customer_id: &customer::Id,
+) -> Result<(), DomainError> {
+    customer_repository
+        .find_by_id(customer_id)
+        .await?
+        .ok_or_else(|| DomainError::not_found("customer", customer_id))?;
+
+    Ok(())
+}
+
+/// Fills in the unit price of every line that has none with the customer's price list entry
+/// for `date`, falling back to the product's `default_price`.
+pub(crate) async fn resolve_lines(
+    product_repository: &Arc<dyn ProductAbstructRepository>,
+    price_list_repository: &Arc<dyn PriceListAbstructRepository>,
+    customer_id: &customer::Id,
+    date: &SalesOrderDate,
+    lines: &[SalesOrderLineInput],
+) -> Result<Vec<SalesOrderLineInput>, DomainError> {
+    if lines.is_empty() {
+        return Err(DomainError::validation(
+            "lines",
+            "at least one line is required",
+        ));
+    }
+
+    let mut resolved = Vec::with_capacity(lines.len());
+    for line in lines {
+        if line.quantity <= 0 {
+            return Err(DomainError::validation(
+                "quantity",
+                format!("quantity must be positive: product_id={}", line.product_id),
+            ));
+        }
+        let unit_price = match line.unit_price {
+            Some(unit_price) => {
+                product_repository
+                    .find_by_id(&line.product_id)
+                    .await?
+                    .ok_or_else(|| DomainError::not_found("product", line.product_id))?;
+                unit_price
+            }
+            None => *resolve_price(
+                product_repository,
+                price_list_repository,
+                customer_id,
+                &line.product_id,
+                line.quantity,
+                date,
+            )
+            .await?
+            .price(),
+        };
+        resolved.push(SalesOrderLineInput::new(
+            line.product_id,
+            line.quantity,
+            Some(unit_price),
+        ));
+    }
+
+    Ok(resolved)
+}
+
+#[derive(Debug)]
+pub struct CreateSalesOrderInput {
+    customer_id: customer::Id,
+    order_date: SalesOrderDate,
+    lines: Vec<SalesOrderLineInput>,
+}
+impl CreateSalesOrderInput {
+    pub fn new(
+        customer_id: customer::Id,
+        order_date: SalesOrderDate,
+        lines: Vec<SalesOrderLineInput>,
+    ) -> Self {
+        Self {
+            customer_id,
+            order_date,
+            lines,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn order_date(&self) -> &SalesOrderDate {
+        &self.order_date
+    }
+
+    pub fn lines(&self) -> &Vec<SalesOrderLineInput> {
+        &self.lines
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateSalesOrderOutput {
+    result: CreateSalesOrderResult,
+}
+impl CreateSalesOrderOutput {
+    pub fn new(result: CreateSalesOrderResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &CreateSalesOrderResult {
+        &self.result
+    }
+}
+
+pub struct CreateSalesOrderUsecase {
+    repository: Arc<dyn OrderAbstructRepository>,
+    customer_repository: Arc<dyn CustomerAbstructRepository>,
+    product_repository: Arc<dyn ProductAbstructRepository>,
+    price_list_repository: Arc<dyn PriceListAbstructRepository>,
+}
+impl CreateSalesOrderUsecase {
+    pub fn new(
+        repository: Arc<dyn OrderAbstructRepository>,
+        customer_repository: Arc<dyn CustomerAbstructRepository>,
+        product_repository: Arc<dyn ProductAbstructRepository>,
+        price_list_repository: Arc<dyn PriceListAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            customer_repository,
+            product_repository,
+            price_list_repository,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        input: CreateSalesOrderInput,
+    ) -> Result<CreateSalesOrderOutput, DomainError> {
+        ensure_active_customer(&self.customer_repository, &input.customer_id).await?;
+        let lines = resolve_lines(
+            &self.product_repository,
+            &self.price_list_repository,
+            &input.customer_id,
+            &input.order_date,
+            &input.lines,
+        )
+        .await?;
+        let input = CreateSalesOrderInput::new(input.customer_id, input.order_date, lines);
+        let result = self.repository.create(&input).await?;
+
+        Ok(CreateSalesOrderOutput::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository, fixtures,
+            price_list_repository::SqlitePriceListRepository,
+            product_repository::SqliteProductRepository,
+            sales_order_repository::SqliteSalesOrderRepository,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                price_list_repository::PriceListAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                sales_order_repository::OrderAbstructRepository,
+            },
+            usecase::{
+                price_list::create_price_list::CreatePriceListInput,
+                sales_order::create_sales_order::{
+                    CreateSalesOrderInput, CreateSalesOrderUsecase, SalesOrderLineInput,
+                },
+            },
+        },
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let customer_repository = Arc::new(SqliteCustomerRespository::new(pool.clone()));
+        let customer = customer_repository
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product_repository = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let product = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let price_list_repository = Arc::new(SqlitePriceListRepository::new(pool.clone()));
+        let repository = Arc::new(SqliteSalesOrderRepository::new(pool));
+        let usecase = CreateSalesOrderUsecase::new(
+            repository.clone(),
+            customer_repository.clone(),
+            product_repository,
+            price_list_repository.clone(),
+        );
+
+        let input = CreateSalesOrderInput::new(
+            customer.customer_id(),
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            vec![
+                SalesOrderLineInput::new(*product.product_id(), 3, None),
+                SalesOrderLineInput::new(*product.product_id(), 1, Some(1500)),
+            ],
+        );
+        let output = usecase.create(input).await.unwrap();
+        let sales_order = repository
+            .find_by_id(output.result().sales_order_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(*sales_order.lines()[0].unit_price(), 2000);
+        assert_eq!(*sales_order.lines()[1].unit_price(), 1500);
+        assert_eq!(sales_order.total_amount(), 7500);
+
+        customer_repository
+            .delete(&customer.customer_id())
+            .await
+            .unwrap();
+        let input = CreateSalesOrderInput::new(
+            customer.customer_id(),
+            Date::from_calendar_date(2023, Month::June, 2).unwrap(),
+            vec![SalesOrderLineInput::new(*product.product_id(), 1, None)],
+        );
+        let error = usecase.create(input).await.unwrap_err();
+        assert_eq!(error.code(), "not_found");
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn price_list_test(pool: SqlitePool) {
+        let customer_repository = Arc::new(SqliteCustomerRespository::new(pool.clone()));
+        let customer = customer_repository
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product_repository = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let product = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let price_list_repository = Arc::new(SqlitePriceListRepository::new(pool.clone()));
+        price_list_repository
+            .create(&CreatePriceListInput::new(
+                customer.customer_id(),
+                *product.product_id(),
+                1600,
+                None,
+                None,
+                Some(5),
+            ))
+            .await
+            .unwrap();
+        let repository = Arc::new(SqliteSalesOrderRepository::new(pool));
+        let usecase = CreateSalesOrderUsecase::new(
+            repository.clone(),
+            customer_repository,
+            product_repository,
+            price_list_repository,
+        );
+
+        let input = CreateSalesOrderInput::new(
+            customer.customer_id(),
+            Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+            vec![
+                SalesOrderLineInput::new(*product.product_id(), 5, None),
+                SalesOrderLineInput::new(*product.product_id(), 1, None),
+            ],
+        );
+        let output = usecase.create(input).await.unwrap();
+        let sales_order = repository
+            .find_by_id(output.result().sales_order_id())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(*sales_order.lines()[0].unit_price(), 1600);
+        assert_eq!(*sales_order.lines()[1].unit_price(), 2000);
+    }
+}
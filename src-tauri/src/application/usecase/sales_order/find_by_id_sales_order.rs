@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::sales_order_repository::OrderAbstructRepository,
+    domain::{
+        error::DomainError,
+        sales_order::{SalesOrder, SalesOrderId},
+    },
+};
+
+#[derive(Debug)]
+pub struct FindByIDSalesOrderOutput {
+    pub sales_order: Option<SalesOrder>,
+}
+impl FindByIDSalesOrderOutput {
+    pub fn new(sales_order: Option<SalesOrder>) -> Self {
+        Self { sales_order }
+    }
+}
+
+pub struct FindByIDSalesOrderUsecase {
+    repository: Arc<dyn OrderAbstructRepository>,
+}
+impl FindByIDSalesOrderUsecase {
+    pub fn new(repository: Arc<dyn OrderAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find_by_id(
+        &self,
+        sales_order_id: &SalesOrderId,
+    ) -> Result<FindByIDSalesOrderOutput, DomainError> {
+        let sales_order = self.repository.find_by_id(sales_order_id).await?;
+
+        Ok(FindByIDSalesOrderOutput::new(sales_order))
+    }
+}
@@ -0,0 +1,5 @@
+pub mod cancel_sales_order;
+pub mod create_sales_order;
+pub mod find_by_id_sales_order;
+pub mod search_sales_order;
+pub mod update_sales_order;
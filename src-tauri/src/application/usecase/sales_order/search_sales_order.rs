@@ -0,0 +1,105 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::sales_order_repository::OrderAbstructRepository,
+    domain::{
+        customer,
+        error::DomainError,
+        sales_order::{SalesOrder, SalesOrderDate, SalesOrderStatus},
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchSalesOrderInput {
+    offset: i64,
+    limit: i64,
+    customer_id: Option<customer::Id>,
+    status: Option<SalesOrderStatus>,
+    order_date_from: Option<SalesOrderDate>,
+    order_date_to: Option<SalesOrderDate>,
+    after_id: Option<i64>,
+}
+impl SearchSalesOrderInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        customer_id: Option<customer::Id>,
+        status: Option<SalesOrderStatus>,
+        order_date_from: Option<SalesOrderDate>,
+        order_date_to: Option<SalesOrderDate>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            customer_id,
+            status,
+            order_date_from,
+            order_date_to,
+            after_id: None,
+        }
+    }
+
+    /// Reads only the sales orders after `after_id`, in id order rather than by order date, so
+    /// that a page deep into every sales order costs no more to read than the first.
+    pub fn with_after_id(mut self, after_id: i64) -> Self {
+        self.after_id = Some(after_id);
+        self
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn status(&self) -> &Option<SalesOrderStatus> {
+        &self.status
+    }
+
+    pub fn order_date_from(&self) -> &Option<SalesOrderDate> {
+        &self.order_date_from
+    }
+
+    pub fn order_date_to(&self) -> &Option<SalesOrderDate> {
+        &self.order_date_to
+    }
+
+    pub fn after_id(&self) -> &Option<i64> {
+        &self.after_id
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchSalesOrderOutput {
+    pub sales_orders: Vec<SalesOrder>,
+}
+impl SearchSalesOrderOutput {
+    fn new(sales_orders: Vec<SalesOrder>) -> Self {
+        Self { sales_orders }
+    }
+}
+
+pub struct SearchSalesOrderUsecase {
+    repository: Arc<dyn OrderAbstructRepository>,
+}
+impl SearchSalesOrderUsecase {
+    pub fn new(repository: Arc<dyn OrderAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchSalesOrderInput,
+    ) -> Result<SearchSalesOrderOutput, DomainError> {
+        let sales_orders = self.repository.search(&input).await?;
+
+        Ok(SearchSalesOrderOutput::new(sales_orders))
+    }
+}
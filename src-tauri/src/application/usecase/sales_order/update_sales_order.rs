@@ -0,0 +1,367 @@
+use crate::{
+    application::{
+        repository::{
+            sales_order_repository::UpdateSalesOrderResult,
+            stock_movement_repository::StockMovementAbstructRepository,
+            unit_of_work::UnitOfWorkFactory,
+        },
+        usecase::{
+            sales_order::create_sales_order::{
+                ensure_active_customer, resolve_lines, SalesOrderLineInput,
+            },
+            stock_movement::post_stock_movement::PostStockMovementInput,
+        },
+    },
+    domain::{
+        customer,
+        error::DomainError,
+        product::ProductId,
+        sales_order::{SalesOrder, SalesOrderDate, SalesOrderId, SalesOrderStatus},
+        stock_movement::{StockMovementQuantity, StockMovementType},
+    },
+};
+use std::{collections::BTreeSet, sync::Arc};
+
+/// Posts the shipments and returns that take stock from what `before` had shipped to what
+/// `after` has, and gives back the change per product.
+pub(crate) async fn post_stock_changes(
+    repository: &Arc<dyn StockMovementAbstructRepository>,
+    before: &SalesOrder,
+    after: &SalesOrder,
+) -> Result<Vec<(ProductId, StockMovementQuantity)>, DomainError> {
+    let shipped_before = before.shipped_quantities();
+    let shipped_after = after.shipped_quantities();
+    let product_ids: BTreeSet<_> = shipped_before
+        .keys()
+        .chain(shipped_after.keys())
+        .copied()
+        .collect();
+
+    let mut stock_changes = Vec::new();
+    for product_id in product_ids {
+        let change = shipped_before.get(&product_id).unwrap_or(&0)
+            - shipped_after.get(&product_id).unwrap_or(&0);
+        if change == 0 {
+            continue;
+        }
+        let movement_type = if change < 0 {
+            StockMovementType::Shipment
+        } else {
+            StockMovementType::Return
+        };
+        let input = PostStockMovementInput::new(
+            product_id,
+            movement_type,
+            change.abs(),
+            *after.order_date(),
+            Some(format!("sales_order_id={}", after.id())),
+        );
+        repository.create(&input).await?;
+        stock_changes.push((product_id, change));
+    }
+
+    Ok(stock_changes)
+}
+
+#[derive(Debug)]
+pub struct UpdateSalesOrderInput {
+    id: SalesOrderId,
+    customer_id: Option<customer::Id>,
+    order_date: Option<SalesOrderDate>,
+    status: Option<SalesOrderStatus>,
+    lines: Option<Vec<SalesOrderLineInput>>,
+}
+impl UpdateSalesOrderInput {
+    pub fn new(
+        id: SalesOrderId,
+        customer_id: Option<customer::Id>,
+        order_date: Option<SalesOrderDate>,
+        status: Option<SalesOrderStatus>,
+        lines: Option<Vec<SalesOrderLineInput>>,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            order_date,
+            status,
+            lines,
+        }
+    }
+
+    pub fn id(&self) -> &SalesOrderId {
+        &self.id
+    }
+
+    pub fn customer_id(&self) -> &Option<customer::Id> {
+        &self.customer_id
+    }
+
+    pub fn order_date(&self) -> &Option<SalesOrderDate> {
+        &self.order_date
+    }
+
+    pub fn status(&self) -> &Option<SalesOrderStatus> {
+        &self.status
+    }
+
+    pub fn lines(&self) -> &Option<Vec<SalesOrderLineInput>> {
+        &self.lines
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateSalesOrderOutput {
+    result: UpdateSalesOrderResult,
+    stock_changes: Vec<(ProductId, StockMovementQuantity)>,
+}
+impl UpdateSalesOrderOutput {
+    pub fn new(
+        result: UpdateSalesOrderResult,
+        stock_changes: Vec<(ProductId, StockMovementQuantity)>,
+    ) -> Self {
+        Self {
+            result,
+            stock_changes,
+        }
+    }
+
+    pub fn result(&self) -> &UpdateSalesOrderResult {
+        &self.result
+    }
+
+    /// Signed change of the on-hand quantity of every product whose stock the update moved.
+    pub fn stock_changes(&self) -> &Vec<(ProductId, StockMovementQuantity)> {
+        &self.stock_changes
+    }
+}
+
+/// Changes an order and moves stock to match in one unit of work: delivering ships the lines,
+/// and changing the lines or status of a delivered order ships or returns the difference.
+pub struct UpdateSalesOrderUsecase {
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+impl UpdateSalesOrderUsecase {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWorkFactory>) -> Self {
+        Self { unit_of_work }
+    }
+
+    pub async fn update(
+        &self,
+        input: UpdateSalesOrderInput,
+    ) -> Result<UpdateSalesOrderOutput, DomainError> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let repository = unit_of_work.sales_order_repository();
+        let sales_order = repository
+            .find_by_id(&input.id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("sales_order", input.id))?;
+        if sales_order.is_cancelled() {
+            return Err(DomainError::conflict(format!(
+                "sales order is cancelled: id={}",
+                input.id
+            )));
+        }
+        if sales_order.is_invoiced()
+            && (input.customer_id.is_some() || input.status.is_some() || input.lines.is_some())
+        {
+            return Err(DomainError::conflict_with(
+                format!(
+                    "the lines, customer and status of an invoiced sales order cannot be changed: id={}",
+                    input.id
+                ),
+                &sales_order,
+            ));
+        }
+        if input.status == Some(SalesOrderStatus::Cancelled) {
+            return Err(DomainError::validation(
+                "status",
+                "use cancel to cancel a sales order",
+            ));
+        }
+        if let Some(customer_id) = &input.customer_id {
+            ensure_active_customer(&unit_of_work.customer_repository(), customer_id).await?;
+        }
+
+        let lines = match &input.lines {
+            Some(lines) => Some(
+                resolve_lines(
+                    &unit_of_work.product_repository(),
+                    &unit_of_work.price_list_repository(),
+                    input
+                        .customer_id
+                        .as_ref()
+                        .unwrap_or(sales_order.customer_id()),
+                    input
+                        .order_date
+                        .as_ref()
+                        .unwrap_or(sales_order.order_date()),
+                    lines,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let input = UpdateSalesOrderInput::new(
+            input.id,
+            input.customer_id,
+            input.order_date,
+            input.status,
+            lines,
+        );
+        let result = repository.update(&input).await?;
+        let updated = repository
+            .find_by_id(&input.id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("sales_order", input.id))?;
+        let stock_changes = post_stock_changes(
+            &unit_of_work.stock_movement_repository(),
+            &sales_order,
+            &updated,
+        )
+        .await?;
+        unit_of_work.commit().await?;
+
+        Ok(UpdateSalesOrderOutput::new(result, stock_changes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            customer_repository::SqliteCustomerRespository, fixtures,
+            product_repository::SqliteProductRepository,
+            sales_order_repository::SqliteSalesOrderRepository,
+            stock_movement_repository::SqliteStockMovementRepository,
+            unit_of_work::SqliteUnitOfWorkFactory,
+        },
+        application::{
+            repository::{
+                customer_repository::CustomerAbstructRepository,
+                product_repository::ProductAbstructRepository,
+                sales_order_repository::OrderAbstructRepository,
+                stock_movement_repository::StockMovementAbstructRepository,
+            },
+            usecase::sales_order::{
+                cancel_sales_order::CancelSalesOrderUsecase,
+                create_sales_order::{CreateSalesOrderInput, SalesOrderLineInput},
+                update_sales_order::{UpdateSalesOrderInput, UpdateSalesOrderUsecase},
+            },
+        },
+        domain::sales_order::SalesOrderStatus,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn stock_test(pool: SqlitePool) {
+        let customer = SqliteCustomerRespository::new(pool.clone())
+            .create(fixtures::customer_input())
+            .await
+            .unwrap();
+        let product = SqliteProductRepository::new(pool.clone())
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let product_id = *product.product_id();
+        let result = SqliteSalesOrderRepository::new(pool.clone())
+            .create(&CreateSalesOrderInput::new(
+                customer.customer_id(),
+                Date::from_calendar_date(2023, Month::June, 1).unwrap(),
+                vec![
+                    SalesOrderLineInput::new(product_id, 3, Some(2000)),
+                    SalesOrderLineInput::new(product_id, 2, Some(1800)),
+                ],
+            ))
+            .await
+            .unwrap();
+        let sales_order_id = *result.sales_order_id();
+        let stock_movement_repository = SqliteStockMovementRepository::new(pool.clone());
+        let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
+        let usecase = UpdateSalesOrderUsecase::new(unit_of_work.clone());
+
+        // Delivering ships every line.
+        let output = usecase
+            .update(UpdateSalesOrderInput::new(
+                sales_order_id,
+                None,
+                None,
+                Some(SalesOrderStatus::Delivered),
+                None,
+            ))
+            .await
+            .unwrap();
+        let stock_level = stock_movement_repository
+            .find_on_hand(&product_id)
+            .await
+            .unwrap();
+
+        assert_eq!(*output.stock_changes(), vec![(product_id, -5)]);
+        assert_eq!(*stock_level.on_hand_quantity(), -5);
+
+        // Changing the lines of a delivered order returns the difference.
+        let output = usecase
+            .update(UpdateSalesOrderInput::new(
+                sales_order_id,
+                None,
+                None,
+                None,
+                Some(vec![SalesOrderLineInput::new(product_id, 4, Some(2000))]),
+            ))
+            .await
+            .unwrap();
+        let stock_level = stock_movement_repository
+            .find_on_hand(&product_id)
+            .await
+            .unwrap();
+
+        assert_eq!(*output.stock_changes(), vec![(product_id, 1)]);
+        assert_eq!(*stock_level.on_hand_quantity(), -4);
+
+        // A line for an unknown product fails the update and leaves stock alone.
+        let error = usecase
+            .update(UpdateSalesOrderInput::new(
+                sales_order_id,
+                None,
+                None,
+                None,
+                Some(vec![
+                    SalesOrderLineInput::new(product_id, 1, Some(2000)),
+                    SalesOrderLineInput::new(product_id + 1, 1, None),
+                ]),
+            ))
+            .await
+            .unwrap_err();
+        let stock_level = stock_movement_repository
+            .find_on_hand(&product_id)
+            .await
+            .unwrap();
+
+        assert_eq!(error.code(), "not_found");
+        assert_eq!(*stock_level.on_hand_quantity(), -4);
+
+        // Cancelling returns what the order had shipped.
+        let cancelled = CancelSalesOrderUsecase::new(unit_of_work)
+            .cancel(&sales_order_id)
+            .await
+            .unwrap();
+        let stock_level = stock_movement_repository
+            .find_on_hand(&product_id)
+            .await
+            .unwrap();
+        let movements: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM t_stock_movements WHERE note = 'sales_order_id=' || ?",
+        )
+        .bind(sales_order_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        assert!(*cancelled.result());
+        assert_eq!(*stock_level.on_hand_quantity(), 0);
+        assert_eq!(movements, 3);
+    }
+}
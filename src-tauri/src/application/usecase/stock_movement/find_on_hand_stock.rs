@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::stock_movement_repository::StockMovementAbstructRepository,
+    domain::{error::DomainError, product::ProductId, stock_movement::StockLevel},
+};
+
+#[derive(Debug)]
+pub struct FindOnHandStockOutput {
+    pub stock_level: StockLevel,
+}
+impl FindOnHandStockOutput {
+    pub fn new(stock_level: StockLevel) -> Self {
+        Self { stock_level }
+    }
+}
+
+pub struct FindOnHandStockUsecase {
+    repository: Arc<dyn StockMovementAbstructRepository>,
+}
+impl FindOnHandStockUsecase {
+    pub fn new(repository: Arc<dyn StockMovementAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn find_on_hand(
+        &self,
+        product_id: &ProductId,
+    ) -> Result<FindOnHandStockOutput, DomainError> {
+        let stock_level = self.repository.find_on_hand(product_id).await?;
+
+        Ok(FindOnHandStockOutput::new(stock_level))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod find_on_hand_stock;
+pub mod post_stock_movement;
+pub mod search_stock_movement;
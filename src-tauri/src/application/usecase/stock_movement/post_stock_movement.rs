@@ -0,0 +1,168 @@
+use crate::{
+    application::repository::{
+        product_repository::ProductAbstructRepository,
+        stock_movement_repository::{PostStockMovementResult, StockMovementAbstructRepository},
+    },
+    domain::{
+        error::DomainError,
+        product::ProductId,
+        stock_movement::{
+            StockMovementDate, StockMovementNote, StockMovementQuantity, StockMovementType,
+        },
+    },
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct PostStockMovementInput {
+    product_id: ProductId,
+    movement_type: StockMovementType,
+    quantity: StockMovementQuantity,
+    moved_on: StockMovementDate,
+    note: Option<StockMovementNote>,
+}
+impl PostStockMovementInput {
+    pub fn new(
+        product_id: ProductId,
+        movement_type: StockMovementType,
+        quantity: StockMovementQuantity,
+        moved_on: StockMovementDate,
+        note: Option<StockMovementNote>,
+    ) -> Self {
+        Self {
+            product_id,
+            movement_type,
+            quantity,
+            moved_on,
+            note,
+        }
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn movement_type(&self) -> &StockMovementType {
+        &self.movement_type
+    }
+
+    /// Quantity as entered; see [`StockMovementType::signed_quantity`] for the stored value.
+    pub fn quantity(&self) -> &StockMovementQuantity {
+        &self.quantity
+    }
+
+    pub fn moved_on(&self) -> &StockMovementDate {
+        &self.moved_on
+    }
+
+    pub fn note(&self) -> &Option<StockMovementNote> {
+        &self.note
+    }
+}
+
+#[derive(Debug)]
+pub struct PostStockMovementOutput {
+    result: PostStockMovementResult,
+}
+impl PostStockMovementOutput {
+    pub fn new(result: PostStockMovementResult) -> Self {
+        Self { result }
+    }
+
+    pub fn result(&self) -> &PostStockMovementResult {
+        &self.result
+    }
+}
+
+pub struct PostStockMovementUsecase {
+    repository: Arc<dyn StockMovementAbstructRepository>,
+    product_repository: Arc<dyn ProductAbstructRepository>,
+}
+impl PostStockMovementUsecase {
+    pub fn new(
+        repository: Arc<dyn StockMovementAbstructRepository>,
+        product_repository: Arc<dyn ProductAbstructRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            product_repository,
+        }
+    }
+
+    pub async fn post(
+        &self,
+        input: PostStockMovementInput,
+    ) -> Result<PostStockMovementOutput, DomainError> {
+        input.movement_type.validate_quantity(input.quantity)?;
+        if self.product_repository.find_by_id(&input.product_id).await?.is_none() {
+            return Err(DomainError::not_found("product", input.product_id));
+        }
+        let result = self.repository.create(&input).await?;
+
+        Ok(PostStockMovementOutput::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::gateway::{
+            fixtures,
+            product_repository::SqliteProductRepository,
+            stock_movement_repository::SqliteStockMovementRepository,
+        },
+        application::{
+            repository::{
+                product_repository::ProductAbstructRepository,
+                stock_movement_repository::StockMovementAbstructRepository,
+            },
+            usecase::{
+                stock_movement::post_stock_movement::{PostStockMovementInput, PostStockMovementUsecase},
+            },
+        },
+        domain::stock_movement::StockMovementType,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn post_test(pool: SqlitePool) {
+        let product_repository = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let product = product_repository
+            .create(&fixtures::product_input("product001"))
+            .await
+            .unwrap();
+        let repository = Arc::new(SqliteStockMovementRepository::new(pool));
+        let usecase = PostStockMovementUsecase::new(repository.clone(), product_repository);
+        let moved_on = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+
+        let movements = vec![
+            (StockMovementType::Receipt, 20),
+            (StockMovementType::Shipment, 8),
+            (StockMovementType::Return, 1),
+            (StockMovementType::Adjustment, -3),
+        ];
+        for (movement_type, quantity) in movements {
+            let input = PostStockMovementInput::new(*product.product_id(), movement_type, quantity, moved_on, None);
+            usecase.post(input).await.unwrap();
+        }
+        let stock_level = repository.find_on_hand(product.product_id()).await.unwrap();
+
+        assert_eq!(*stock_level.on_hand_quantity(), 10);
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn post_negative_shipment_test(pool: SqlitePool) {
+        let product_repository = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let repository = Arc::new(SqliteStockMovementRepository::new(pool));
+        let usecase = PostStockMovementUsecase::new(repository, product_repository);
+        let moved_on = Date::from_calendar_date(2023, Month::June, 1).unwrap();
+
+        let input = PostStockMovementInput::new(1, StockMovementType::Shipment, -1, moved_on, None);
+
+        assert!(usecase.post(input).await.is_err());
+    }
+}
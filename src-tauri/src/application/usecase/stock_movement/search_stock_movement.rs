@@ -0,0 +1,92 @@
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::{
+    application::repository::stock_movement_repository::StockMovementAbstructRepository,
+    domain::{
+        error::DomainError,
+        product::ProductId,
+        stock_movement::{StockMovement, StockMovementDate, StockMovementType},
+    },
+};
+
+#[derive(Debug)]
+pub struct SearchStockMovementInput {
+    offset: i64,
+    limit: i64,
+    product_id: Option<ProductId>,
+    movement_type: Option<StockMovementType>,
+    moved_on_from: Option<StockMovementDate>,
+    moved_on_to: Option<StockMovementDate>,
+}
+impl SearchStockMovementInput {
+    pub fn new(
+        offset: Option<i64>,
+        limit: Option<i64>,
+        product_id: Option<ProductId>,
+        movement_type: Option<StockMovementType>,
+        moved_on_from: Option<StockMovementDate>,
+        moved_on_to: Option<StockMovementDate>,
+    ) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(100),
+            product_id,
+            movement_type,
+            moved_on_from,
+            moved_on_to,
+        }
+    }
+
+    pub fn offset(&self) -> &i64 {
+        &self.offset
+    }
+
+    pub fn limit(&self) -> &i64 {
+        &self.limit
+    }
+
+    pub fn product_id(&self) -> &Option<ProductId> {
+        &self.product_id
+    }
+
+    pub fn movement_type(&self) -> &Option<StockMovementType> {
+        &self.movement_type
+    }
+
+    pub fn moved_on_from(&self) -> &Option<StockMovementDate> {
+        &self.moved_on_from
+    }
+
+    pub fn moved_on_to(&self) -> &Option<StockMovementDate> {
+        &self.moved_on_to
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SearchStockMovementOutput {
+    pub stock_movements: Vec<StockMovement>,
+}
+impl SearchStockMovementOutput {
+    fn new(stock_movements: Vec<StockMovement>) -> Self {
+        Self { stock_movements }
+    }
+}
+
+pub struct SearchStockMovementUsecase {
+    repository: Arc<dyn StockMovementAbstructRepository>,
+}
+impl SearchStockMovementUsecase {
+    pub fn new(repository: Arc<dyn StockMovementAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(
+        &self,
+        input: SearchStockMovementInput,
+    ) -> Result<SearchStockMovementOutput, DomainError> {
+        let stock_movements = self.repository.search(&input).await?;
+
+        Ok(SearchStockMovementOutput::new(stock_movements))
+    }
+}
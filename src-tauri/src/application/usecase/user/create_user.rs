@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::user_repository::UserAbstructRepository,
+    domain::{
+        error::DomainError,
+        user::{Role, UserId, UserName},
+    },
+};
+
+/// Passwords shorter than this are rejected.
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+#[derive(Debug)]
+pub struct CreateUserInput {
+    name: UserName,
+    password: String,
+    role: Role,
+}
+impl CreateUserInput {
+    pub fn new(name: UserName, password: String, role: Role) -> Self {
+        Self {
+            name,
+            password,
+            role,
+        }
+    }
+
+    pub fn name(&self) -> &UserName {
+        &self.name
+    }
+
+    pub fn password(&self) -> &String {
+        &self.password
+    }
+
+    pub fn role(&self) -> &Role {
+        &self.role
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateUserOutput {
+    pub user_id: UserId,
+}
+impl CreateUserOutput {
+    fn new(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
+
+pub struct CreateUserUsecase {
+    repository: Arc<dyn UserAbstructRepository>,
+}
+impl CreateUserUsecase {
+    pub fn new(repository: Arc<dyn UserAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// The first user must be an admin, so that someone can manage the others.
+    pub async fn create(&self, input: CreateUserInput) -> Result<CreateUserOutput, DomainError> {
+        if input.name.trim().is_empty() {
+            return Err(DomainError::validation("name", "user name is required"));
+        }
+        if input.name.chars().count() > 100 {
+            return Err(DomainError::validation("name", "user name is too long"));
+        }
+        if input.password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(DomainError::validation(
+                "password",
+                format!(
+                    "password must be at least {} characters",
+                    MIN_PASSWORD_LENGTH
+                ),
+            ));
+        }
+        if input.role != Role::Admin && self.repository.count().await? == 0 {
+            return Err(DomainError::validation(
+                "role",
+                "the first user must be an admin",
+            ));
+        }
+        let user_id = self.repository.create(&input).await?;
+
+        Ok(CreateUserOutput::new(user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use std::sync::Arc;
+
+    use crate::{
+        adapters::gateway::user_repository::SqliteUserRepository,
+        application::usecase::user::create_user::{CreateUserInput, CreateUserUsecase},
+        domain::user::Role,
+        infrastructure::database::MIGRATOR,
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn create_test(pool: SqlitePool) {
+        let usecase = CreateUserUsecase::new(Arc::new(SqliteUserRepository::new(pool)));
+
+        let input = CreateUserInput::new("tanaka".to_string(), "short".to_string(), Role::Admin);
+        let error = usecase.create(input).await.unwrap_err();
+        assert_eq!(error.fields()[0].field(), "password");
+
+        let input =
+            CreateUserInput::new("tanaka".to_string(), "password1".to_string(), Role::Sales);
+        let error = usecase.create(input).await.unwrap_err();
+        assert_eq!(error.fields()[0].field(), "role");
+
+        let input =
+            CreateUserInput::new("tanaka".to_string(), "password1".to_string(), Role::Admin);
+        usecase.create(input).await.unwrap();
+        let input =
+            CreateUserInput::new("suzuki".to_string(), "password2".to_string(), Role::Viewer);
+        usecase.create(input).await.unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_user;
+pub mod search_user;
+pub mod sign_in_user;
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::user_repository::UserAbstructRepository,
+    domain::{error::DomainError, user::User},
+};
+
+#[derive(Debug)]
+pub struct SearchUserOutput {
+    pub users: Vec<User>,
+}
+impl SearchUserOutput {
+    fn new(users: Vec<User>) -> Self {
+        Self { users }
+    }
+}
+
+pub struct SearchUserUsecase {
+    repository: Arc<dyn UserAbstructRepository>,
+}
+impl SearchUserUsecase {
+    pub fn new(repository: Arc<dyn UserAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn search(&self) -> Result<SearchUserOutput, DomainError> {
+        let users = self.repository.search().await?;
+
+        Ok(SearchUserOutput::new(users))
+    }
+
+    /// Until the first user is created, nobody can sign in.
+    pub async fn count(&self) -> Result<i64, DomainError> {
+        self.repository.count().await
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::{
+    application::repository::user_repository::UserAbstructRepository,
+    domain::{
+        error::DomainError,
+        user::{User, UserName},
+    },
+};
+
+#[derive(Debug)]
+pub struct SignInUserInput {
+    name: UserName,
+    password: String,
+}
+impl SignInUserInput {
+    pub fn new(name: UserName, password: String) -> Self {
+        Self { name, password }
+    }
+
+    pub fn name(&self) -> &UserName {
+        &self.name
+    }
+
+    pub fn password(&self) -> &String {
+        &self.password
+    }
+}
+
+#[derive(Debug)]
+pub struct SignInUserOutput {
+    pub user: User,
+}
+impl SignInUserOutput {
+    fn new(user: User) -> Self {
+        Self { user }
+    }
+}
+
+pub struct SignInUserUsecase {
+    repository: Arc<dyn UserAbstructRepository>,
+}
+impl SignInUserUsecase {
+    pub fn new(repository: Arc<dyn UserAbstructRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Does not tell an unknown name from a wrong password.
+    pub async fn sign_in(&self, input: SignInUserInput) -> Result<SignInUserOutput, DomainError> {
+        let user = self.repository.authenticate(&input).await?.ok_or_else(|| {
+            DomainError::validation("password", "user name or password is incorrect")
+        })?;
+
+        Ok(SignInUserOutput::new(user))
+    }
+}
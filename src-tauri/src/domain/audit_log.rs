@@ -0,0 +1,168 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime};
+
+pub type AuditLogId = i64;
+pub type AuditLogDate = Date;
+
+/// The kinds of records whose changes are kept in the audit log.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntity {
+    Product,
+    Customer,
+    PriceList,
+    SalesOrder,
+    SalesOrderLine,
+    Invoice,
+    InvoiceLine,
+    Payment,
+    PaymentAllocation,
+    Quotation,
+    QuotationLine,
+    StockMovement,
+}
+impl AuditEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEntity::Product => "product",
+            AuditEntity::Customer => "customer",
+            AuditEntity::PriceList => "price_list",
+            AuditEntity::SalesOrder => "sales_order",
+            AuditEntity::SalesOrderLine => "sales_order_line",
+            AuditEntity::Invoice => "invoice",
+            AuditEntity::InvoiceLine => "invoice_line",
+            AuditEntity::Payment => "payment",
+            AuditEntity::PaymentAllocation => "payment_allocation",
+            AuditEntity::Quotation => "quotation",
+            AuditEntity::QuotationLine => "quotation_line",
+            AuditEntity::StockMovement => "stock_movement",
+        }
+    }
+}
+impl FromStr for AuditEntity {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "product" => Ok(AuditEntity::Product),
+            "customer" => Ok(AuditEntity::Customer),
+            "price_list" => Ok(AuditEntity::PriceList),
+            "sales_order" => Ok(AuditEntity::SalesOrder),
+            "sales_order_line" => Ok(AuditEntity::SalesOrderLine),
+            "invoice" => Ok(AuditEntity::Invoice),
+            "invoice_line" => Ok(AuditEntity::InvoiceLine),
+            "payment" => Ok(AuditEntity::Payment),
+            "payment_allocation" => Ok(AuditEntity::PaymentAllocation),
+            "quotation" => Ok(AuditEntity::Quotation),
+            "quotation_line" => Ok(AuditEntity::QuotationLine),
+            "stock_movement" => Ok(AuditEntity::StockMovement),
+            _ => Err(format!("unknown audit entity: {}", value)),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    Create,
+    Update,
+    /// A soft delete, or the removal of a record that has no soft delete.
+    Delete,
+    Restore,
+}
+impl AuditOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditOperation::Create => "create",
+            AuditOperation::Update => "update",
+            AuditOperation::Delete => "delete",
+            AuditOperation::Restore => "restore",
+        }
+    }
+}
+impl FromStr for AuditOperation {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "create" => Ok(AuditOperation::Create),
+            "update" => Ok(AuditOperation::Update),
+            "delete" => Ok(AuditOperation::Delete),
+            "restore" => Ok(AuditOperation::Restore),
+            _ => Err(format!("unknown audit operation: {}", value)),
+        }
+    }
+}
+
+/// A change to one record. `before` is absent for creations and `after` for removals; both
+/// hold the record's columns as they were stored.
+#[derive(Serialize, Debug)]
+pub struct AuditLog {
+    id: AuditLogId,
+    entity_type: AuditEntity,
+    entity_id: i64,
+    operation: AuditOperation,
+    before: Option<serde_json::Value>,
+    after: Option<serde_json::Value>,
+    actor: Option<String>,
+    created_at: PrimitiveDateTime,
+}
+impl AuditLog {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: AuditLogId,
+        entity_type: AuditEntity,
+        entity_id: i64,
+        operation: AuditOperation,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+        actor: Option<String>,
+        created_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            entity_type,
+            entity_id,
+            operation,
+            before,
+            after,
+            actor,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &AuditLogId {
+        &self.id
+    }
+
+    pub fn entity_type(&self) -> &AuditEntity {
+        &self.entity_type
+    }
+
+    pub fn entity_id(&self) -> &i64 {
+        &self.entity_id
+    }
+
+    pub fn operation(&self) -> &AuditOperation {
+        &self.operation
+    }
+
+    pub fn before(&self) -> &Option<serde_json::Value> {
+        &self.before
+    }
+
+    pub fn after(&self) -> &Option<serde_json::Value> {
+        &self.after
+    }
+
+    /// The user the application acted for, when one was known.
+    pub fn actor(&self) -> &Option<String> {
+        &self.actor
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+}
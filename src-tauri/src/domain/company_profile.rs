@@ -0,0 +1,82 @@
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::domain::error::DomainError;
+
+/// 適格請求書発行事業者登録番号: `T` followed by 13 digits.
+pub type RegistrationNumber = String;
+pub type CompanyName = String;
+pub type CompanyAddress = String;
+
+/// The business issuing invoices. There is exactly one per database.
+#[derive(Serialize, Debug, Clone)]
+pub struct CompanyProfile {
+    registration_number: RegistrationNumber,
+    name: CompanyName,
+    address: CompanyAddress,
+    updated_at: PrimitiveDateTime,
+}
+impl CompanyProfile {
+    pub fn new(
+        registration_number: RegistrationNumber,
+        name: CompanyName,
+        address: CompanyAddress,
+        updated_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            registration_number,
+            name,
+            address,
+            updated_at,
+        }
+    }
+
+    pub fn registration_number(&self) -> &RegistrationNumber {
+        &self.registration_number
+    }
+
+    pub fn name(&self) -> &CompanyName {
+        &self.name
+    }
+
+    pub fn address(&self) -> &CompanyAddress {
+        &self.address
+    }
+
+    pub fn updated_at(&self) -> &PrimitiveDateTime {
+        &self.updated_at
+    }
+}
+
+pub fn validate_registration_number(registration_number: &str) -> Result<(), DomainError> {
+    let is_valid = match registration_number.strip_prefix('T') {
+        Some(digits) => digits.len() == 13 && digits.chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    };
+    if !is_valid {
+        return Err(DomainError::validation(
+            "registration_number",
+            format!(
+                "registration number must be T followed by 13 digits: {}",
+                registration_number
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::company_profile::validate_registration_number;
+
+    #[test]
+    fn validate_registration_number_test() {
+        assert!(validate_registration_number("T1234567890123").is_ok());
+        assert!(validate_registration_number("1234567890123").is_err());
+        assert!(validate_registration_number("T123456789012").is_err());
+        assert!(validate_registration_number("T12345678901234").is_err());
+        assert!(validate_registration_number("T12345678901a3").is_err());
+        assert!(validate_registration_number("").is_err());
+    }
+}
@@ -1,11 +1,31 @@
 use serde::{Serialize, Deserialize};
 use time::PrimitiveDateTime;
 
+use crate::domain::error::DomainError;
+
 pub type Id = i64;
 pub type Name = String;
-pub type Postal = i64;
+pub type Postal = String;
 pub type Address = String;
 
+/// A Japanese postal code: seven digits without a hyphen. It is kept as text so that the
+/// leading zeros of codes such as 0600000 survive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostalCode(Postal);
+impl PostalCode {
+    pub fn new(postal: &str) -> Result<Self, DomainError> {
+        if postal.len() != 7 || !postal.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(DomainError::validation("postal", format!("postal code must be 7 digits: postal={}", postal)));
+        }
+
+        Ok(Self(postal.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 #[derive(Deserialize ,Serialize, Debug)]
 pub struct Customer {
     id: Id,
@@ -64,4 +84,17 @@ impl Customer {
     pub fn deleted_at(&self) -> &Option<PrimitiveDateTime> {
         &self.deleted_at
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::customer::PostalCode;
+
+    #[test]
+    fn postal_code_test() {
+        assert_eq!(PostalCode::new("0600000").unwrap().as_str(), "0600000");
+        assert!(PostalCode::new("060000").is_err());
+        assert!(PostalCode::new("060-0000").is_err());
+        assert!(PostalCode::new("０６００００００").is_err());
+    }
+}
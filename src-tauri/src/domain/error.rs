@@ -1,4 +1,159 @@
+use std::fmt;
+
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    field: String,
+    message: String,
+}
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn field(&self) -> &String {
+        &self.field
+    }
+
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+/// Errors the application reports to its callers. Each variant has a stable `code` so that
+/// the frontend can react without parsing messages.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "code")]
 pub enum DomainError {
-    Validation,
-    Unexpected,
+    /// The input was rejected; `fields` names the offending inputs.
+    Validation {
+        message: String,
+        fields: Vec<FieldError>,
+    },
+    NotFound {
+        entity: String,
+        id: String,
+    },
+    /// The operation clashes with the stored state. `current` carries the record it clashed
+    /// with, when there is one worth showing.
+    Conflict {
+        message: String,
+        current: Option<serde_json::Value>,
+    },
+    /// The signed-in user, or the lack of one, does not allow the operation.
+    Forbidden {
+        message: String,
+    },
+    DatabaseUnavailable {
+        message: String,
+    },
+    Unexpected {
+        message: String,
+    },
+}
+impl DomainError {
+    pub fn validation(field: impl Into<String>, message: impl Into<String>) -> Self {
+        let message = message.into();
+        DomainError::Validation {
+            fields: vec![FieldError::new(field, message.clone())],
+            message,
+        }
+    }
+
+    pub fn not_found(entity: impl Into<String>, id: impl ToString) -> Self {
+        DomainError::NotFound {
+            entity: entity.into(),
+            id: id.to_string(),
+        }
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        DomainError::Conflict {
+            message: message.into(),
+            current: None,
+        }
+    }
+
+    /// A conflict that carries the stored record, so that the caller can show what it
+    /// clashed with.
+    pub fn conflict_with<T: Serialize>(message: impl Into<String>, current: &T) -> Self {
+        DomainError::Conflict {
+            message: message.into(),
+            current: serde_json::to_value(current).ok(),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        DomainError::Forbidden {
+            message: message.into(),
+        }
+    }
+
+    pub fn database_unavailable(message: impl ToString) -> Self {
+        DomainError::DatabaseUnavailable {
+            message: message.to_string(),
+        }
+    }
+
+    pub fn unexpected(message: impl ToString) -> Self {
+        DomainError::Unexpected {
+            message: message.to_string(),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomainError::Validation { .. } => "validation",
+            DomainError::NotFound { .. } => "not_found",
+            DomainError::Conflict { .. } => "conflict",
+            DomainError::Forbidden { .. } => "forbidden",
+            DomainError::DatabaseUnavailable { .. } => "database_unavailable",
+            DomainError::Unexpected { .. } => "unexpected",
+        }
+    }
+
+    pub fn fields(&self) -> &[FieldError] {
+        match self {
+            DomainError::Validation { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+}
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainError::Validation { message, .. } => write!(f, "{}", message),
+            DomainError::NotFound { entity, id } => write!(f, "{} not found: id={}", entity, id),
+            DomainError::Conflict { message, .. } => write!(f, "{}", message),
+            DomainError::Forbidden { message } => write!(f, "{}", message),
+            DomainError::DatabaseUnavailable { message } => {
+                write!(f, "database unavailable: {}", message)
+            }
+            DomainError::Unexpected { message } => write!(f, "{}", message),
+        }
+    }
+}
+impl std::error::Error for DomainError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::error::DomainError;
+
+    #[test]
+    fn serialize_test() {
+        let error = DomainError::validation("price", "price must not be negative");
+        let value = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(value["code"], "validation");
+        assert_eq!(value["fields"][0]["field"], "price");
+        assert_eq!(
+            DomainError::not_found("product", 1).to_string(),
+            "product not found: id=1"
+        );
+        assert_eq!(DomainError::forbidden("sign in first").code(), "forbidden");
+    }
 }
@@ -0,0 +1,565 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{
+    company_profile::{
+        validate_registration_number, CompanyAddress, CompanyName, CompanyProfile,
+        RegistrationNumber,
+    },
+    customer,
+    error::{DomainError, FieldError},
+    product::{ProductId, ProductName},
+    sales_order::{SalesOrderId, SalesOrderLineId, SalesOrderQuantity, SalesOrderUnitPrice},
+    tax::{calculate_tax, RoundingMode, TaxRate},
+};
+
+pub type InvoiceId = i64;
+pub type InvoiceNumber = String;
+pub type InvoiceDate = Date;
+
+/// A delivered sales order line that has not been invoiced yet.
+#[derive(Debug)]
+pub struct BillableLine {
+    sales_order_id: SalesOrderId,
+    sales_order_line_id: SalesOrderLineId,
+    product_id: ProductId,
+    product_name: ProductName,
+    quantity: SalesOrderQuantity,
+    unit_price: SalesOrderUnitPrice,
+    tax_rate: TaxRate,
+}
+impl BillableLine {
+    pub fn new(
+        sales_order_id: SalesOrderId,
+        sales_order_line_id: SalesOrderLineId,
+        product_id: ProductId,
+        product_name: ProductName,
+        quantity: SalesOrderQuantity,
+        unit_price: SalesOrderUnitPrice,
+        tax_rate: TaxRate,
+    ) -> Self {
+        Self {
+            sales_order_id,
+            sales_order_line_id,
+            product_id,
+            product_name,
+            quantity,
+            unit_price,
+            tax_rate,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InvoiceLine {
+    sales_order_id: SalesOrderId,
+    sales_order_line_id: SalesOrderLineId,
+    product_id: ProductId,
+    product_name: ProductName,
+    quantity: SalesOrderQuantity,
+    unit_price: SalesOrderUnitPrice,
+    amount: i64,
+    tax_rate: TaxRate,
+}
+impl InvoiceLine {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sales_order_id: SalesOrderId,
+        sales_order_line_id: SalesOrderLineId,
+        product_id: ProductId,
+        product_name: ProductName,
+        quantity: SalesOrderQuantity,
+        unit_price: SalesOrderUnitPrice,
+        amount: i64,
+        tax_rate: TaxRate,
+    ) -> Self {
+        Self {
+            sales_order_id,
+            sales_order_line_id,
+            product_id,
+            product_name,
+            quantity,
+            unit_price,
+            amount,
+            tax_rate,
+        }
+    }
+
+    /// Prices a billable line. Tax is not computed per line; see [`TaxSummary`].
+    pub fn calculate(line: BillableLine) -> Self {
+        let amount = line.quantity * line.unit_price;
+
+        Self {
+            sales_order_id: line.sales_order_id,
+            sales_order_line_id: line.sales_order_line_id,
+            product_id: line.product_id,
+            product_name: line.product_name,
+            quantity: line.quantity,
+            unit_price: line.unit_price,
+            amount,
+            tax_rate: line.tax_rate,
+        }
+    }
+
+    pub fn sales_order_id(&self) -> &SalesOrderId {
+        &self.sales_order_id
+    }
+
+    pub fn sales_order_line_id(&self) -> &SalesOrderLineId {
+        &self.sales_order_line_id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn product_name(&self) -> &str {
+        &self.product_name
+    }
+
+    pub fn quantity(&self) -> &SalesOrderQuantity {
+        &self.quantity
+    }
+
+    pub fn unit_price(&self) -> &SalesOrderUnitPrice {
+        &self.unit_price
+    }
+
+    pub fn amount(&self) -> &i64 {
+        &self.amount
+    }
+
+    pub fn tax_rate(&self) -> &TaxRate {
+        &self.tax_rate
+    }
+}
+
+/// Taxable amount and tax for one tax rate. Under the qualified invoice system tax is rounded
+/// once per rate per invoice, never per line.
+#[derive(Serialize, Debug, Clone)]
+pub struct TaxSummary {
+    tax_rate: TaxRate,
+    taxable_amount: i64,
+    tax_amount: i64,
+}
+impl TaxSummary {
+    pub fn new(tax_rate: TaxRate, taxable_amount: i64, tax_amount: i64) -> Self {
+        Self {
+            tax_rate,
+            taxable_amount,
+            tax_amount,
+        }
+    }
+
+    /// Totals `(tax rate, amount)` pairs by tax rate, standard rate first.
+    pub fn calculate(
+        amounts: impl IntoIterator<Item = (TaxRate, i64)>,
+        rounding_mode: RoundingMode,
+    ) -> Vec<Self> {
+        let mut taxable_amounts: BTreeMap<TaxRate, i64> = BTreeMap::new();
+        for (tax_rate, amount) in amounts {
+            *taxable_amounts.entry(tax_rate).or_insert(0) += amount;
+        }
+
+        taxable_amounts
+            .into_iter()
+            .map(|(tax_rate, taxable_amount)| {
+                let tax_amount = calculate_tax(taxable_amount, tax_rate, rounding_mode);
+                Self::new(tax_rate, taxable_amount, tax_amount)
+            })
+            .collect()
+    }
+
+    pub fn tax_rate(&self) -> &TaxRate {
+        &self.tax_rate
+    }
+
+    pub fn taxable_amount(&self) -> &i64 {
+        &self.taxable_amount
+    }
+
+    pub fn tax_amount(&self) -> &i64 {
+        &self.tax_amount
+    }
+}
+
+/// Issuer details copied onto each invoice, so later edits to the company profile do not
+/// alter documents already issued.
+#[derive(Serialize, Debug, Clone)]
+pub struct InvoiceIssuer {
+    registration_number: RegistrationNumber,
+    name: CompanyName,
+    address: CompanyAddress,
+}
+impl InvoiceIssuer {
+    pub fn new(
+        registration_number: RegistrationNumber,
+        name: CompanyName,
+        address: CompanyAddress,
+    ) -> Self {
+        Self {
+            registration_number,
+            name,
+            address,
+        }
+    }
+
+    pub fn registration_number(&self) -> &RegistrationNumber {
+        &self.registration_number
+    }
+
+    pub fn name(&self) -> &CompanyName {
+        &self.name
+    }
+
+    pub fn address(&self) -> &CompanyAddress {
+        &self.address
+    }
+}
+impl From<&CompanyProfile> for InvoiceIssuer {
+    fn from(company_profile: &CompanyProfile) -> Self {
+        Self::new(
+            company_profile.registration_number().clone(),
+            company_profile.name().clone(),
+            company_profile.address().clone(),
+        )
+    }
+}
+
+/// An invoice that has been calculated but not issued, so it has no number yet.
+#[derive(Serialize, Debug)]
+pub struct InvoiceDraft {
+    issuer: InvoiceIssuer,
+    customer_id: customer::Id,
+    recipient_name: customer::Name,
+    period_from: InvoiceDate,
+    period_to: InvoiceDate,
+    issued_on: InvoiceDate,
+    rounding_mode: RoundingMode,
+    lines: Vec<InvoiceLine>,
+    tax_summaries: Vec<TaxSummary>,
+}
+impl InvoiceDraft {
+    #[allow(clippy::too_many_arguments)]
+    pub fn calculate(
+        issuer: InvoiceIssuer,
+        customer_id: customer::Id,
+        recipient_name: customer::Name,
+        period_from: InvoiceDate,
+        period_to: InvoiceDate,
+        issued_on: InvoiceDate,
+        rounding_mode: RoundingMode,
+        billable_lines: Vec<BillableLine>,
+    ) -> Self {
+        let lines: Vec<InvoiceLine> = billable_lines
+            .into_iter()
+            .map(InvoiceLine::calculate)
+            .collect();
+        let tax_summaries = TaxSummary::calculate(
+            lines.iter().map(|line| (line.tax_rate, line.amount)),
+            rounding_mode,
+        );
+
+        Self {
+            issuer,
+            customer_id,
+            recipient_name,
+            period_from,
+            period_to,
+            issued_on,
+            rounding_mode,
+            lines,
+            tax_summaries,
+        }
+    }
+
+    /// Checks the fields a qualified invoice (適格請求書) must carry.
+    pub fn validate(&self) -> Result<(), DomainError> {
+        validate_registration_number(&self.issuer.registration_number)?;
+        let mut missing_fields = Vec::new();
+        if self.issuer.name.trim().is_empty() {
+            missing_fields.push("issuer_name");
+        }
+        if self.issuer.address.trim().is_empty() {
+            missing_fields.push("issuer_address");
+        }
+        if self.recipient_name.trim().is_empty() {
+            missing_fields.push("recipient_name");
+        }
+        if self.lines.is_empty() {
+            missing_fields.push("lines");
+        }
+        if self
+            .lines
+            .iter()
+            .any(|line| line.product_name.trim().is_empty())
+        {
+            missing_fields.push("product_name");
+        }
+        if !missing_fields.is_empty() {
+            return Err(DomainError::Validation {
+                message: format!(
+                    "invoice is missing required fields: {}",
+                    missing_fields.join(", ")
+                ),
+                fields: missing_fields
+                    .iter()
+                    .map(|field| FieldError::new(*field, "required"))
+                    .collect(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn issuer(&self) -> &InvoiceIssuer {
+        &self.issuer
+    }
+
+    pub fn recipient_name(&self) -> &customer::Name {
+        &self.recipient_name
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn period_from(&self) -> &InvoiceDate {
+        &self.period_from
+    }
+
+    pub fn period_to(&self) -> &InvoiceDate {
+        &self.period_to
+    }
+
+    pub fn issued_on(&self) -> &InvoiceDate {
+        &self.issued_on
+    }
+
+    pub fn rounding_mode(&self) -> &RoundingMode {
+        &self.rounding_mode
+    }
+
+    pub fn lines(&self) -> &Vec<InvoiceLine> {
+        &self.lines
+    }
+
+    pub fn tax_summaries(&self) -> &Vec<TaxSummary> {
+        &self.tax_summaries
+    }
+
+    pub fn subtotal(&self) -> i64 {
+        self.tax_summaries
+            .iter()
+            .map(|summary| summary.taxable_amount)
+            .sum()
+    }
+
+    pub fn tax_amount(&self) -> i64 {
+        self.tax_summaries
+            .iter()
+            .map(|summary| summary.tax_amount)
+            .sum()
+    }
+
+    pub fn total_amount(&self) -> i64 {
+        self.subtotal() + self.tax_amount()
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct Invoice {
+    id: InvoiceId,
+    invoice_number: InvoiceNumber,
+    issuer: InvoiceIssuer,
+    customer_id: customer::Id,
+    recipient_name: customer::Name,
+    period_from: InvoiceDate,
+    period_to: InvoiceDate,
+    issued_on: InvoiceDate,
+    rounding_mode: RoundingMode,
+    subtotal: i64,
+    tax_amount: i64,
+    total_amount: i64,
+    lines: Vec<InvoiceLine>,
+    tax_summaries: Vec<TaxSummary>,
+    created_at: PrimitiveDateTime,
+}
+impl Invoice {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InvoiceId,
+        invoice_number: InvoiceNumber,
+        issuer: InvoiceIssuer,
+        customer_id: customer::Id,
+        recipient_name: customer::Name,
+        period_from: InvoiceDate,
+        period_to: InvoiceDate,
+        issued_on: InvoiceDate,
+        rounding_mode: RoundingMode,
+        subtotal: i64,
+        tax_amount: i64,
+        total_amount: i64,
+        lines: Vec<InvoiceLine>,
+        tax_summaries: Vec<TaxSummary>,
+        created_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            invoice_number,
+            issuer,
+            customer_id,
+            recipient_name,
+            period_from,
+            period_to,
+            issued_on,
+            rounding_mode,
+            subtotal,
+            tax_amount,
+            total_amount,
+            lines,
+            tax_summaries,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &InvoiceId {
+        &self.id
+    }
+
+    pub fn invoice_number(&self) -> &str {
+        &self.invoice_number
+    }
+
+    pub fn issuer(&self) -> &InvoiceIssuer {
+        &self.issuer
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn recipient_name(&self) -> &customer::Name {
+        &self.recipient_name
+    }
+
+    pub fn period_from(&self) -> &InvoiceDate {
+        &self.period_from
+    }
+
+    pub fn period_to(&self) -> &InvoiceDate {
+        &self.period_to
+    }
+
+    pub fn issued_on(&self) -> &InvoiceDate {
+        &self.issued_on
+    }
+
+    pub fn rounding_mode(&self) -> &RoundingMode {
+        &self.rounding_mode
+    }
+
+    pub fn subtotal(&self) -> &i64 {
+        &self.subtotal
+    }
+
+    pub fn tax_amount(&self) -> &i64 {
+        &self.tax_amount
+    }
+
+    pub fn total_amount(&self) -> &i64 {
+        &self.total_amount
+    }
+
+    pub fn lines(&self) -> &Vec<InvoiceLine> {
+        &self.lines
+    }
+
+    pub fn tax_summaries(&self) -> &Vec<TaxSummary> {
+        &self.tax_summaries
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+}
+
+/// Invoice numbers are `INV-YYYYMM-NNNN`, sequential within the month of issue.
+pub fn invoice_number(issued_on: &InvoiceDate, sequence: i64) -> InvoiceNumber {
+    format!("{}{:04}", invoice_number_prefix(issued_on), sequence)
+}
+
+pub fn invoice_number_prefix(issued_on: &InvoiceDate) -> String {
+    format!(
+        "INV-{:04}{:02}-",
+        issued_on.year(),
+        u8::from(issued_on.month())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use crate::domain::{
+        invoice::{BillableLine, InvoiceDraft, InvoiceIssuer},
+        tax::{RoundingMode, TaxRate},
+    };
+
+    fn draft(issuer: InvoiceIssuer, recipient_name: &str) -> InvoiceDraft {
+        let date = Date::from_calendar_date(2023, Month::June, 30).unwrap();
+        let lines = vec![
+            BillableLine::new(1, 1, 1, String::from("文具"), 1, 105, TaxRate::Standard),
+            BillableLine::new(1, 2, 1, String::from("文具"), 1, 105, TaxRate::Standard),
+            BillableLine::new(1, 3, 2, String::from("食品"), 1, 155, TaxRate::Reduced),
+        ];
+
+        InvoiceDraft::calculate(
+            issuer,
+            1,
+            String::from(recipient_name),
+            date,
+            date,
+            date,
+            RoundingMode::RoundDown,
+            lines,
+        )
+    }
+
+    fn issuer() -> InvoiceIssuer {
+        InvoiceIssuer::new(
+            String::from("T1234567890123"),
+            String::from("自社"),
+            String::from("東京都"),
+        )
+    }
+
+    #[test]
+    fn tax_summaries_test() {
+        let draft = draft(issuer(), "sample.inc");
+        let summaries = draft.tax_summaries();
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(*summaries[0].tax_rate(), TaxRate::Standard);
+        assert_eq!(*summaries[0].taxable_amount(), 210);
+        // Rounded once per rate: 210 * 10% = 21, where per-line rounding would give 10 + 10.
+        assert_eq!(*summaries[0].tax_amount(), 21);
+        assert_eq!(*summaries[1].tax_rate(), TaxRate::Reduced);
+        assert_eq!(*summaries[1].tax_amount(), 12);
+        assert_eq!(draft.total_amount(), 365 + 33);
+    }
+
+    #[test]
+    fn validate_test() {
+        assert!(draft(issuer(), "sample.inc").validate().is_ok());
+        assert!(draft(issuer(), " ").validate().is_err());
+
+        let issuer = InvoiceIssuer::new(
+            String::from("1234567890123"),
+            String::from("自社"),
+            String::from("東京都"),
+        );
+        assert!(draft(issuer, "sample.inc").validate().is_err());
+    }
+}
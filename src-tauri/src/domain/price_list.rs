@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{customer, error::DomainError, product::ProductId};
+
+pub type PriceListId = i64;
+pub type PriceListPrice = i64;
+pub type PriceListDate = Date;
+pub type PriceListQuantity = i64;
+
+/// A negotiated price for one customer and product. It applies from `min_quantity` units
+/// upward and, when given, only between `valid_from` and `valid_to` inclusive.
+#[derive(Serialize, Debug)]
+pub struct PriceListEntry {
+    id: PriceListId,
+    customer_id: customer::Id,
+    product_id: ProductId,
+    price: PriceListPrice,
+    valid_from: Option<PriceListDate>,
+    valid_to: Option<PriceListDate>,
+    min_quantity: PriceListQuantity,
+    created_at: PrimitiveDateTime,
+    updated_at: PrimitiveDateTime,
+}
+impl PriceListEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: PriceListId,
+        customer_id: customer::Id,
+        product_id: ProductId,
+        price: PriceListPrice,
+        valid_from: Option<PriceListDate>,
+        valid_to: Option<PriceListDate>,
+        min_quantity: PriceListQuantity,
+        created_at: PrimitiveDateTime,
+        updated_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            product_id,
+            price,
+            valid_from,
+            valid_to,
+            min_quantity,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> &PriceListId {
+        &self.id
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn price(&self) -> &PriceListPrice {
+        &self.price
+    }
+
+    pub fn valid_from(&self) -> &Option<PriceListDate> {
+        &self.valid_from
+    }
+
+    pub fn valid_to(&self) -> &Option<PriceListDate> {
+        &self.valid_to
+    }
+
+    pub fn min_quantity(&self) -> &PriceListQuantity {
+        &self.min_quantity
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &PrimitiveDateTime {
+        &self.updated_at
+    }
+
+    pub fn applies_to(&self, date: &PriceListDate, quantity: PriceListQuantity) -> bool {
+        quantity >= self.min_quantity
+            && self
+                .valid_from
+                .map_or(true, |valid_from| *date >= valid_from)
+            && self.valid_to.map_or(true, |valid_to| *date <= valid_to)
+    }
+}
+
+/// Checks the fields shared by creating and updating an entry.
+pub fn validate_price_list_entry(
+    price: &PriceListPrice,
+    valid_from: &Option<PriceListDate>,
+    valid_to: &Option<PriceListDate>,
+    min_quantity: &PriceListQuantity,
+) -> Result<(), DomainError> {
+    if *price < 0 {
+        return Err(DomainError::validation(
+            "price",
+            format!("price must not be negative: price={}", price),
+        ));
+    }
+    if *min_quantity < 1 {
+        return Err(DomainError::validation(
+            "min_quantity",
+            format!("min_quantity must be at least 1: min_quantity={}", min_quantity),
+        ));
+    }
+    if let (Some(valid_from), Some(valid_to)) = (valid_from, valid_to) {
+        if valid_to < valid_from {
+            return Err(DomainError::validation(
+                "valid_to",
+                format!(
+                    "valid_to must not be before valid_from: valid_from={}, valid_to={}",
+                    valid_from, valid_to
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum PriceSource {
+    PriceList { price_list_id: PriceListId },
+    DefaultPrice,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct ResolvedPrice {
+    customer_id: customer::Id,
+    product_id: ProductId,
+    quantity: PriceListQuantity,
+    price: PriceListPrice,
+    source: PriceSource,
+}
+impl ResolvedPrice {
+    /// Picks the entry that applies on `date` for `quantity`, preferring the highest volume
+    /// tier, then the most recent validity period. Falls back to `default_price`.
+    pub fn resolve(
+        customer_id: customer::Id,
+        product_id: ProductId,
+        quantity: PriceListQuantity,
+        date: &PriceListDate,
+        default_price: PriceListPrice,
+        entries: &[PriceListEntry],
+    ) -> Self {
+        let entry = entries
+            .iter()
+            .filter(|entry| {
+                entry.customer_id == customer_id
+                    && entry.product_id == product_id
+                    && entry.applies_to(date, quantity)
+            })
+            .max_by_key(|entry| (entry.min_quantity, entry.valid_from, entry.id));
+
+        match entry {
+            Some(entry) => Self {
+                customer_id,
+                product_id,
+                quantity,
+                price: entry.price,
+                source: PriceSource::PriceList {
+                    price_list_id: entry.id,
+                },
+            },
+            None => Self {
+                customer_id,
+                product_id,
+                quantity,
+                price: default_price,
+                source: PriceSource::DefaultPrice,
+            },
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn quantity(&self) -> &PriceListQuantity {
+        &self.quantity
+    }
+
+    pub fn price(&self) -> &PriceListPrice {
+        &self.price
+    }
+
+    pub fn source(&self) -> &PriceSource {
+        &self.source
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use crate::domain::price_list::{
+        validate_price_list_entry, PriceListEntry, PriceSource, ResolvedPrice,
+    };
+
+    fn entry(
+        id: i64,
+        price: i64,
+        valid_from: Option<Date>,
+        valid_to: Option<Date>,
+        min_quantity: i64,
+    ) -> PriceListEntry {
+        let timestamp = PrimitiveDateTime::new(
+            Date::from_calendar_date(2023, Month::January, 1).unwrap(),
+            Time::MIDNIGHT,
+        );
+
+        PriceListEntry::new(
+            id,
+            1,
+            1,
+            price,
+            valid_from,
+            valid_to,
+            min_quantity,
+            timestamp,
+            timestamp,
+        )
+    }
+
+    #[test]
+    fn resolve_test() {
+        let entries = vec![
+            entry(1, 1800, None, None, 1),
+            entry(2, 1500, None, None, 10),
+            entry(
+                3,
+                1700,
+                Some(Date::from_calendar_date(2023, Month::June, 1).unwrap()),
+                Some(Date::from_calendar_date(2023, Month::June, 30).unwrap()),
+                1,
+            ),
+        ];
+        let may = Date::from_calendar_date(2023, Month::May, 15).unwrap();
+        let june = Date::from_calendar_date(2023, Month::June, 15).unwrap();
+
+        assert_eq!(
+            *ResolvedPrice::resolve(1, 1, 1, &may, 2000, &entries).price(),
+            1800
+        );
+        assert_eq!(
+            *ResolvedPrice::resolve(1, 1, 1, &june, 2000, &entries).price(),
+            1700
+        );
+        assert_eq!(
+            *ResolvedPrice::resolve(1, 1, 10, &june, 2000, &entries).price(),
+            1500
+        );
+
+        let resolved = ResolvedPrice::resolve(2, 1, 1, &june, 2000, &entries);
+        assert_eq!(*resolved.price(), 2000);
+        assert_eq!(*resolved.source(), PriceSource::DefaultPrice);
+    }
+
+    #[test]
+    fn validate_test() {
+        let june = Some(Date::from_calendar_date(2023, Month::June, 1).unwrap());
+        let may = Some(Date::from_calendar_date(2023, Month::May, 1).unwrap());
+
+        assert!(validate_price_list_entry(&1000, &may, &june, &1).is_ok());
+        assert!(validate_price_list_entry(&-1, &None, &None, &1).is_err());
+        assert!(validate_price_list_entry(&1000, &None, &None, &0).is_err());
+        assert!(validate_price_list_entry(&1000, &june, &may, &1).is_err());
+    }
+}
@@ -1,33 +1,171 @@
 use serde::Serialize;
 use time::PrimitiveDateTime;
 
+use crate::domain::{
+    error::{DomainError, FieldError},
+    tax::TaxRate,
+};
+
 pub type ProductId = i64;
 pub type ProductName = String;
-pub type ProductCode = String;
-pub type ProductUnit = String;
-pub type ProductDefaultPrice = i64;
-pub type ProductStandardStockQuantity = i64;
+
+/// A product code that is not blank, at most as long as the `VARCHAR(100)` column. Any
+/// characters are allowed, so that codes saved before the check still pass it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductCode(String);
+impl ProductCode {
+    const MAX_LENGTH: usize = 100;
+
+    pub fn new(code: &str) -> Result<Self, DomainError> {
+        let is_valid = !code.trim().is_empty() && code.chars().count() <= Self::MAX_LENGTH;
+        if !is_valid {
+            return Err(DomainError::validation(
+                "code",
+                format!(
+                    "code must be 1 to {} characters: code={}",
+                    Self::MAX_LENGTH,
+                    code
+                ),
+            ));
+        }
+
+        Ok(Self(code.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A unit such as `個` or `box`, at most as long as the `VARCHAR(5)` column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductUnit(String);
+impl ProductUnit {
+    const MAX_LENGTH: usize = 5;
+
+    pub fn new(unit: &str) -> Result<Self, DomainError> {
+        let length = unit.trim().chars().count();
+        if length == 0 || length > Self::MAX_LENGTH {
+            return Err(DomainError::validation(
+                "unit",
+                format!(
+                    "unit must be 1 to {} characters: unit={}",
+                    Self::MAX_LENGTH,
+                    unit
+                ),
+            ));
+        }
+
+        Ok(Self(unit.trim().to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProductDefaultPrice(i64);
+impl ProductDefaultPrice {
+    pub fn new(default_price: i64) -> Result<Self, DomainError> {
+        if default_price < 0 {
+            return Err(DomainError::validation(
+                "default_price",
+                format!(
+                    "default_price must not be negative: default_price={}",
+                    default_price
+                ),
+            ));
+        }
+
+        Ok(Self(default_price))
+    }
+
+    pub fn value(&self) -> &i64 {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProductStandardStockQuantity(i64);
+impl ProductStandardStockQuantity {
+    pub fn new(standard_stock_quantity: i64) -> Result<Self, DomainError> {
+        if standard_stock_quantity < 0 {
+            return Err(DomainError::validation(
+                "standard_stock_quantity",
+                format!(
+                    "standard_stock_quantity must not be negative: standard_stock_quantity={}",
+                    standard_stock_quantity
+                ),
+            ));
+        }
+
+        Ok(Self(standard_stock_quantity))
+    }
+
+    pub fn value(&self) -> &i64 {
+        &self.0
+    }
+}
+
+/// Checks the given product fields, reporting every invalid one at once. Fields left out
+/// with `None` are not checked.
+pub fn validate_product(
+    code: Option<&str>,
+    unit: Option<&str>,
+    default_price: Option<i64>,
+    standard_stock_quantity: Option<i64>,
+) -> Result<(), DomainError> {
+    let results = [
+        code.map(|code| ProductCode::new(code).map(|_| ())),
+        unit.map(|unit| ProductUnit::new(unit).map(|_| ())),
+        default_price.map(|price| ProductDefaultPrice::new(price).map(|_| ())),
+        standard_stock_quantity
+            .map(|quantity| ProductStandardStockQuantity::new(quantity).map(|_| ())),
+    ];
+    let fields: Vec<FieldError> = results
+        .iter()
+        .flatten()
+        .filter_map(|result| result.as_ref().err())
+        .flat_map(|error| error.fields().to_vec())
+        .collect();
+    if fields.is_empty() {
+        return Ok(());
+    }
+
+    Err(DomainError::Validation {
+        message: fields
+            .iter()
+            .map(|field| field.message().as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+        fields,
+    })
+}
 
 #[derive(Serialize, Debug)]
 pub struct Product {
     id: ProductId,
     name: ProductName,
-    code: ProductCode,
-    unit: ProductUnit,
-    default_price: ProductDefaultPrice,
-    standard_stock_quantity: ProductStandardStockQuantity,
+    code: String,
+    unit: String,
+    default_price: i64,
+    standard_stock_quantity: i64,
+    tax_rate: TaxRate,
     created_at: PrimitiveDateTime,
     updated_at: PrimitiveDateTime,
     deleted_at: Option<PrimitiveDateTime>,
 }
 impl Product {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: ProductId,
         name: ProductName,
-        code: ProductCode,
-        unit: ProductUnit,
-        default_price: ProductDefaultPrice,
-        standard_stock_quantity: ProductStandardStockQuantity,
+        code: String,
+        unit: String,
+        default_price: i64,
+        standard_stock_quantity: i64,
+        tax_rate: TaxRate,
         created_at: PrimitiveDateTime,
         updated_at: PrimitiveDateTime,
         deleted_at: Option<PrimitiveDateTime>,
@@ -39,6 +177,7 @@ impl Product {
             unit,
             default_price,
             standard_stock_quantity,
+            tax_rate,
             created_at,
             updated_at,
             deleted_at,
@@ -69,6 +208,10 @@ impl Product {
         &self.standard_stock_quantity
     }
 
+    pub fn tax_rate(&self) -> &TaxRate {
+        &self.tax_rate
+    }
+
     pub fn created_at(&self) -> &PrimitiveDateTime {
         &self.created_at
     }
@@ -81,3 +224,39 @@ impl Product {
         &self.deleted_at
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::product::{validate_product, ProductCode, ProductUnit};
+
+    #[test]
+    fn value_object_test() {
+        assert!(ProductCode::new("product-001").is_ok());
+        assert!(ProductCode::new("").is_err());
+        assert!(ProductCode::new("商品001").is_ok());
+        assert!(ProductCode::new(&"a".repeat(100)).is_ok());
+        assert!(ProductCode::new(&"a".repeat(101)).is_err());
+        assert!(ProductCode::new(" ").is_err());
+        assert_eq!(ProductUnit::new("ケース").unwrap().as_str(), "ケース");
+        assert!(ProductUnit::new("カートン箱").is_ok());
+        assert!(ProductUnit::new("ダンボール箱").is_err());
+    }
+
+    #[test]
+    fn validate_product_test() {
+        assert!(validate_product(Some("product001"), Some("個"), Some(0), Some(0)).is_ok());
+        assert!(validate_product(None, None, None, None).is_ok());
+
+        let error = validate_product(Some("product001"), Some(""), Some(-1), Some(-1)).unwrap_err();
+        let fields: Vec<&str> = error
+            .fields()
+            .iter()
+            .map(|field| field.field().as_str())
+            .collect();
+
+        assert_eq!(
+            fields,
+            vec!["unit", "default_price", "standard_stock_quantity"]
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{customer, product::ProductId, sales_order::SalesOrderId};
+
+pub type QuotationId = i64;
+pub type QuotationLineId = i64;
+pub type QuotationDate = Date;
+pub type QuotationQuantity = i64;
+pub type QuotationUnitPrice = i64;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotationStatus {
+    Draft,
+    Sent,
+    Accepted,
+    Rejected,
+    Expired,
+}
+impl QuotationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuotationStatus::Draft => "draft",
+            QuotationStatus::Sent => "sent",
+            QuotationStatus::Accepted => "accepted",
+            QuotationStatus::Rejected => "rejected",
+            QuotationStatus::Expired => "expired",
+        }
+    }
+
+    /// A quote is prepared as a draft, sent to the customer and then answered or left to
+    /// expire. Accepted, rejected and expired quotes are final.
+    pub fn can_transition_to(&self, next: &QuotationStatus) -> bool {
+        matches!(
+            (self, next),
+            (QuotationStatus::Draft, QuotationStatus::Sent)
+                | (QuotationStatus::Draft, QuotationStatus::Expired)
+                | (QuotationStatus::Sent, QuotationStatus::Accepted)
+                | (QuotationStatus::Sent, QuotationStatus::Rejected)
+                | (QuotationStatus::Sent, QuotationStatus::Expired)
+        )
+    }
+}
+impl FromStr for QuotationStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "draft" => Ok(QuotationStatus::Draft),
+            "sent" => Ok(QuotationStatus::Sent),
+            "accepted" => Ok(QuotationStatus::Accepted),
+            "rejected" => Ok(QuotationStatus::Rejected),
+            "expired" => Ok(QuotationStatus::Expired),
+            _ => Err(format!("unknown quotation status: {}", value)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct QuotationLine {
+    id: QuotationLineId,
+    product_id: ProductId,
+    quantity: QuotationQuantity,
+    unit_price: QuotationUnitPrice,
+}
+impl QuotationLine {
+    pub fn new(
+        id: QuotationLineId,
+        product_id: ProductId,
+        quantity: QuotationQuantity,
+        unit_price: QuotationUnitPrice,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            quantity,
+            unit_price,
+        }
+    }
+
+    pub fn id(&self) -> &QuotationLineId {
+        &self.id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn quantity(&self) -> &QuotationQuantity {
+        &self.quantity
+    }
+
+    pub fn unit_price(&self) -> &QuotationUnitPrice {
+        &self.unit_price
+    }
+
+    pub fn amount(&self) -> i64 {
+        self.quantity * self.unit_price
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct Quotation {
+    id: QuotationId,
+    customer_id: customer::Id,
+    quoted_on: QuotationDate,
+    valid_until: QuotationDate,
+    status: QuotationStatus,
+    lines: Vec<QuotationLine>,
+    sales_order_id: Option<SalesOrderId>,
+    created_at: PrimitiveDateTime,
+    updated_at: PrimitiveDateTime,
+}
+impl Quotation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: QuotationId,
+        customer_id: customer::Id,
+        quoted_on: QuotationDate,
+        valid_until: QuotationDate,
+        status: QuotationStatus,
+        lines: Vec<QuotationLine>,
+        sales_order_id: Option<SalesOrderId>,
+        created_at: PrimitiveDateTime,
+        updated_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            quoted_on,
+            valid_until,
+            status,
+            lines,
+            sales_order_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> &QuotationId {
+        &self.id
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn quoted_on(&self) -> &QuotationDate {
+        &self.quoted_on
+    }
+
+    pub fn valid_until(&self) -> &QuotationDate {
+        &self.valid_until
+    }
+
+    pub fn status(&self) -> &QuotationStatus {
+        &self.status
+    }
+
+    pub fn lines(&self) -> &Vec<QuotationLine> {
+        &self.lines
+    }
+
+    /// The sales order this quote was converted into, if any.
+    pub fn sales_order_id(&self) -> &Option<SalesOrderId> {
+        &self.sales_order_id
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &PrimitiveDateTime {
+        &self.updated_at
+    }
+
+    pub fn total_amount(&self) -> i64 {
+        self.lines.iter().map(|line| line.amount()).sum()
+    }
+
+    /// Only drafts can be edited; once sent, the quote is what the customer has seen.
+    pub fn is_editable(&self) -> bool {
+        self.status == QuotationStatus::Draft
+    }
+
+    pub fn is_expired_on(&self, date: &QuotationDate) -> bool {
+        *date > self.valid_until
+    }
+
+    pub fn is_converted(&self) -> bool {
+        self.sales_order_id.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::quotation::QuotationStatus;
+
+    #[test]
+    fn can_transition_to_test() {
+        assert!(QuotationStatus::Draft.can_transition_to(&QuotationStatus::Sent));
+        assert!(QuotationStatus::Sent.can_transition_to(&QuotationStatus::Accepted));
+        assert!(QuotationStatus::Sent.can_transition_to(&QuotationStatus::Expired));
+        assert!(!QuotationStatus::Draft.can_transition_to(&QuotationStatus::Accepted));
+        assert!(!QuotationStatus::Accepted.can_transition_to(&QuotationStatus::Rejected));
+        assert!(!QuotationStatus::Expired.can_transition_to(&QuotationStatus::Sent));
+    }
+}
@@ -0,0 +1,569 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{
+    customer,
+    error::DomainError,
+    invoice::{InvoiceDate, InvoiceId, InvoiceNumber},
+};
+
+pub type PaymentId = i64;
+pub type PaymentAllocationId = i64;
+pub type PaymentDate = Date;
+pub type PaymentAmount = i64;
+pub type BankFee = i64;
+pub type PaymentNote = String;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethod {
+    BankTransfer,
+    Cash,
+    Bill,
+    Offset,
+    Other,
+}
+impl PaymentMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentMethod::BankTransfer => "bank_transfer",
+            PaymentMethod::Cash => "cash",
+            PaymentMethod::Bill => "bill",
+            PaymentMethod::Offset => "offset",
+            PaymentMethod::Other => "other",
+        }
+    }
+}
+impl FromStr for PaymentMethod {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bank_transfer" => Ok(PaymentMethod::BankTransfer),
+            "cash" => Ok(PaymentMethod::Cash),
+            "bill" => Ok(PaymentMethod::Bill),
+            "offset" => Ok(PaymentMethod::Offset),
+            "other" => Ok(PaymentMethod::Other),
+            _ => Err(format!("unknown payment method: {}", value)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PaymentAllocation {
+    id: PaymentAllocationId,
+    invoice_id: InvoiceId,
+    amount: PaymentAmount,
+}
+impl PaymentAllocation {
+    pub fn new(id: PaymentAllocationId, invoice_id: InvoiceId, amount: PaymentAmount) -> Self {
+        Self {
+            id,
+            invoice_id,
+            amount,
+        }
+    }
+
+    pub fn id(&self) -> &PaymentAllocationId {
+        &self.id
+    }
+
+    pub fn invoice_id(&self) -> &InvoiceId {
+        &self.invoice_id
+    }
+
+    pub fn amount(&self) -> &PaymentAmount {
+        &self.amount
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct Payment {
+    id: PaymentId,
+    customer_id: customer::Id,
+    paid_on: PaymentDate,
+    amount: PaymentAmount,
+    method: PaymentMethod,
+    bank_fee: BankFee,
+    note: Option<PaymentNote>,
+    allocations: Vec<PaymentAllocation>,
+    created_at: PrimitiveDateTime,
+}
+impl Payment {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: PaymentId,
+        customer_id: customer::Id,
+        paid_on: PaymentDate,
+        amount: PaymentAmount,
+        method: PaymentMethod,
+        bank_fee: BankFee,
+        note: Option<PaymentNote>,
+        allocations: Vec<PaymentAllocation>,
+        created_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            paid_on,
+            amount,
+            method,
+            bank_fee,
+            note,
+            allocations,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &PaymentId {
+        &self.id
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn paid_on(&self) -> &PaymentDate {
+        &self.paid_on
+    }
+
+    /// Amount actually received.
+    pub fn amount(&self) -> &PaymentAmount {
+        &self.amount
+    }
+
+    pub fn method(&self) -> &PaymentMethod {
+        &self.method
+    }
+
+    /// Transfer fee (振込手数料) the customer deducted from the payment and we absorb.
+    pub fn bank_fee(&self) -> &BankFee {
+        &self.bank_fee
+    }
+
+    pub fn note(&self) -> &Option<PaymentNote> {
+        &self.note
+    }
+
+    pub fn allocations(&self) -> &Vec<PaymentAllocation> {
+        &self.allocations
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+
+    /// What the payment settles: the amount received plus the deducted bank fee.
+    pub fn credit_amount(&self) -> PaymentAmount {
+        self.amount + self.bank_fee
+    }
+
+    pub fn allocated_amount(&self) -> PaymentAmount {
+        self.allocations
+            .iter()
+            .map(|allocation| allocation.amount)
+            .sum()
+    }
+
+    /// Over-payment not applied to any invoice yet.
+    pub fn unallocated_amount(&self) -> PaymentAmount {
+        self.credit_amount() - self.allocated_amount()
+    }
+}
+
+/// What is still owed on one invoice.
+#[derive(Serialize, Debug, Clone)]
+pub struct InvoiceBalance {
+    invoice_id: InvoiceId,
+    invoice_number: InvoiceNumber,
+    customer_id: customer::Id,
+    issued_on: InvoiceDate,
+    total_amount: PaymentAmount,
+    allocated_amount: PaymentAmount,
+    outstanding_amount: PaymentAmount,
+}
+impl InvoiceBalance {
+    pub fn new(
+        invoice_id: InvoiceId,
+        invoice_number: InvoiceNumber,
+        customer_id: customer::Id,
+        issued_on: InvoiceDate,
+        total_amount: PaymentAmount,
+        allocated_amount: PaymentAmount,
+    ) -> Self {
+        Self {
+            invoice_id,
+            invoice_number,
+            customer_id,
+            issued_on,
+            total_amount,
+            allocated_amount,
+            outstanding_amount: total_amount - allocated_amount,
+        }
+    }
+
+    pub fn invoice_id(&self) -> &InvoiceId {
+        &self.invoice_id
+    }
+
+    pub fn invoice_number(&self) -> &InvoiceNumber {
+        &self.invoice_number
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn issued_on(&self) -> &InvoiceDate {
+        &self.issued_on
+    }
+
+    pub fn total_amount(&self) -> &PaymentAmount {
+        &self.total_amount
+    }
+
+    pub fn allocated_amount(&self) -> &PaymentAmount {
+        &self.allocated_amount
+    }
+
+    pub fn outstanding_amount(&self) -> &PaymentAmount {
+        &self.outstanding_amount
+    }
+}
+
+/// Receivable position of one customer. `balance` is negative when the customer has paid
+/// more than has been invoiced.
+#[derive(Serialize, Debug)]
+pub struct CustomerBalance {
+    customer_id: customer::Id,
+    customer_name: customer::Name,
+    invoiced_amount: PaymentAmount,
+    credit_amount: PaymentAmount,
+    allocated_amount: PaymentAmount,
+    outstanding_amount: PaymentAmount,
+    unallocated_amount: PaymentAmount,
+    balance: PaymentAmount,
+}
+impl CustomerBalance {
+    pub fn new(
+        customer_id: customer::Id,
+        customer_name: customer::Name,
+        invoiced_amount: PaymentAmount,
+        credit_amount: PaymentAmount,
+        allocated_amount: PaymentAmount,
+    ) -> Self {
+        Self {
+            customer_id,
+            customer_name,
+            invoiced_amount,
+            credit_amount,
+            allocated_amount,
+            outstanding_amount: invoiced_amount - allocated_amount,
+            unallocated_amount: credit_amount - allocated_amount,
+            balance: invoiced_amount - credit_amount,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn customer_name(&self) -> &customer::Name {
+        &self.customer_name
+    }
+
+    pub fn invoiced_amount(&self) -> &PaymentAmount {
+        &self.invoiced_amount
+    }
+
+    pub fn credit_amount(&self) -> &PaymentAmount {
+        &self.credit_amount
+    }
+
+    pub fn allocated_amount(&self) -> &PaymentAmount {
+        &self.allocated_amount
+    }
+
+    /// Sum of what is still owed on the customer's invoices.
+    pub fn outstanding_amount(&self) -> &PaymentAmount {
+        &self.outstanding_amount
+    }
+
+    /// Payments received but not allocated to any invoice.
+    pub fn unallocated_amount(&self) -> &PaymentAmount {
+        &self.unallocated_amount
+    }
+
+    pub fn balance(&self) -> &PaymentAmount {
+        &self.balance
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AgingBucket {
+    Current,
+    Days30,
+    Days60,
+    Days90Plus,
+}
+impl AgingBucket {
+    /// Invoices carry no due date, so age is counted in days from the issue date:
+    /// 0–30 is current, then 31–60, 61–90 and over 90.
+    pub fn from_days(days: i64) -> Self {
+        match days {
+            i64::MIN..=30 => AgingBucket::Current,
+            31..=60 => AgingBucket::Days30,
+            61..=90 => AgingBucket::Days60,
+            _ => AgingBucket::Days90Plus,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct AgingReport {
+    customer_id: customer::Id,
+    as_of: PaymentDate,
+    current: PaymentAmount,
+    days_30: PaymentAmount,
+    days_60: PaymentAmount,
+    days_90_plus: PaymentAmount,
+    total: PaymentAmount,
+}
+impl AgingReport {
+    /// One report per customer, ordered by customer id, over invoices with money owed.
+    pub fn calculate(as_of: &PaymentDate, balances: &[InvoiceBalance]) -> Vec<Self> {
+        let mut reports: BTreeMap<customer::Id, AgingReport> = BTreeMap::new();
+        for balance in balances
+            .iter()
+            .filter(|balance| balance.outstanding_amount > 0)
+        {
+            let report = reports
+                .entry(balance.customer_id)
+                .or_insert_with(|| AgingReport::new(balance.customer_id, *as_of));
+            let days = (*as_of - balance.issued_on).whole_days();
+            let amount = balance.outstanding_amount;
+            match AgingBucket::from_days(days) {
+                AgingBucket::Current => report.current += amount,
+                AgingBucket::Days30 => report.days_30 += amount,
+                AgingBucket::Days60 => report.days_60 += amount,
+                AgingBucket::Days90Plus => report.days_90_plus += amount,
+            }
+            report.total += amount;
+        }
+
+        reports.into_values().collect()
+    }
+
+    fn new(customer_id: customer::Id, as_of: PaymentDate) -> Self {
+        Self {
+            customer_id,
+            as_of,
+            current: 0,
+            days_30: 0,
+            days_60: 0,
+            days_90_plus: 0,
+            total: 0,
+        }
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn as_of(&self) -> &PaymentDate {
+        &self.as_of
+    }
+
+    pub fn current(&self) -> &PaymentAmount {
+        &self.current
+    }
+
+    pub fn days_30(&self) -> &PaymentAmount {
+        &self.days_30
+    }
+
+    pub fn days_60(&self) -> &PaymentAmount {
+        &self.days_60
+    }
+
+    pub fn days_90_plus(&self) -> &PaymentAmount {
+        &self.days_90_plus
+    }
+
+    pub fn total(&self) -> &PaymentAmount {
+        &self.total
+    }
+}
+
+/// Spreads `amount` over the open invoices, oldest first. Whatever is left over stays
+/// unallocated on the payment.
+pub fn allocate_oldest_first(
+    amount: PaymentAmount,
+    balances: &[InvoiceBalance],
+) -> Vec<(InvoiceId, PaymentAmount)> {
+    let mut open: Vec<&InvoiceBalance> = balances
+        .iter()
+        .filter(|balance| balance.outstanding_amount > 0)
+        .collect();
+    open.sort_by_key(|balance| (balance.issued_on, balance.invoice_id));
+
+    let mut remaining = amount;
+    let mut allocations = vec![];
+    for balance in open {
+        if remaining <= 0 {
+            break;
+        }
+        let allocated = remaining.min(balance.outstanding_amount);
+        allocations.push((balance.invoice_id, allocated));
+        remaining -= allocated;
+    }
+
+    allocations
+}
+
+/// Checks allocations of `available` against the customer's invoice balances: amounts must
+/// be positive, each invoice must belong to `customer_id` and not be paid beyond its
+/// outstanding amount, and the total must not exceed `available`.
+pub fn validate_allocations(
+    customer_id: &customer::Id,
+    available: PaymentAmount,
+    balances: &[InvoiceBalance],
+    allocations: &[(InvoiceId, PaymentAmount)],
+) -> Result<(), DomainError> {
+    let mut requested: BTreeMap<InvoiceId, PaymentAmount> = BTreeMap::new();
+    for (invoice_id, amount) in allocations {
+        if *amount <= 0 {
+            return Err(DomainError::validation(
+                "allocations",
+                format!("allocation amount must be positive: invoice_id={}", invoice_id),
+            ));
+        }
+        *requested.entry(*invoice_id).or_insert(0) += amount;
+    }
+    for (invoice_id, amount) in &requested {
+        let balance = balances
+            .iter()
+            .find(|balance| balance.invoice_id == *invoice_id)
+            .ok_or_else(|| DomainError::not_found("invoice", invoice_id))?;
+        if balance.customer_id != *customer_id {
+            return Err(DomainError::validation(
+                "allocations",
+                format!("invoice belongs to another customer: invoice_id={}", invoice_id),
+            ));
+        }
+        if *amount > balance.outstanding_amount {
+            return Err(DomainError::validation(
+                "allocations",
+                format!(
+                    "allocation exceeds outstanding amount: invoice_id={}, outstanding={}, amount={}",
+                    invoice_id, balance.outstanding_amount, amount
+                ),
+            ));
+        }
+    }
+    let total: PaymentAmount = requested.values().sum();
+    if total > available {
+        return Err(DomainError::validation(
+            "allocations",
+            format!(
+                "allocations exceed the unallocated payment: available={}, amount={}",
+                available, total
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use crate::domain::receivable::{
+        allocate_oldest_first, validate_allocations, AgingBucket, AgingReport, InvoiceBalance,
+    };
+
+    fn balances() -> Vec<InvoiceBalance> {
+        vec![
+            InvoiceBalance::new(
+                2,
+                String::from("INV-202305-0001"),
+                1,
+                Date::from_calendar_date(2023, Month::May, 31).unwrap(),
+                5000,
+                0,
+            ),
+            InvoiceBalance::new(
+                1,
+                String::from("INV-202303-0001"),
+                1,
+                Date::from_calendar_date(2023, Month::March, 31).unwrap(),
+                3000,
+                1000,
+            ),
+            InvoiceBalance::new(
+                3,
+                String::from("INV-202306-0001"),
+                2,
+                Date::from_calendar_date(2023, Month::June, 30).unwrap(),
+                4000,
+                0,
+            ),
+        ]
+    }
+
+    #[test]
+    fn from_days_test() {
+        assert_eq!(AgingBucket::from_days(0), AgingBucket::Current);
+        assert_eq!(AgingBucket::from_days(30), AgingBucket::Current);
+        assert_eq!(AgingBucket::from_days(31), AgingBucket::Days30);
+        assert_eq!(AgingBucket::from_days(90), AgingBucket::Days60);
+        assert_eq!(AgingBucket::from_days(91), AgingBucket::Days90Plus);
+    }
+
+    #[test]
+    fn aging_report_test() {
+        let as_of = Date::from_calendar_date(2023, Month::July, 10).unwrap();
+        let reports = AgingReport::calculate(&as_of, &balances());
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(*reports[0].current(), 0);
+        assert_eq!(*reports[0].days_30(), 5000);
+        assert_eq!(*reports[0].days_90_plus(), 2000);
+        assert_eq!(*reports[0].total(), 7000);
+        assert_eq!(*reports[1].current(), 4000);
+    }
+
+    #[test]
+    fn allocate_oldest_first_test() {
+        let balances: Vec<InvoiceBalance> = balances()
+            .into_iter()
+            .filter(|b| *b.customer_id() == 1)
+            .collect();
+
+        assert_eq!(allocate_oldest_first(1500, &balances), vec![(1, 1500)]);
+        assert_eq!(
+            allocate_oldest_first(4000, &balances),
+            vec![(1, 2000), (2, 2000)]
+        );
+        assert_eq!(
+            allocate_oldest_first(9000, &balances),
+            vec![(1, 2000), (2, 5000)]
+        );
+    }
+
+    #[test]
+    fn validate_allocations_test() {
+        let balances = balances();
+
+        assert!(validate_allocations(&1, 3000, &balances, &[(1, 2000), (2, 1000)]).is_ok());
+        assert!(validate_allocations(&1, 3000, &balances, &[(1, 2001)]).is_err());
+        assert!(validate_allocations(&1, 3000, &balances, &[(1, 1000), (1, 1001)]).is_err());
+        assert!(validate_allocations(&1, 1000, &balances, &[(2, 1001)]).is_err());
+        assert!(validate_allocations(&1, 3000, &balances, &[(3, 100)]).is_err());
+        assert!(validate_allocations(&1, 3000, &balances, &[(2, 0)]).is_err());
+    }
+}
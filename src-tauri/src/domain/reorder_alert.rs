@@ -0,0 +1,87 @@
+use serde::Serialize;
+
+use crate::domain::{
+    product::{ProductId, ProductName},
+    stock_movement::OnHandQuantity,
+};
+
+/// A product whose on-hand quantity has fallen below its `standard_stock_quantity`.
+#[derive(Serialize, Debug, Clone)]
+pub struct ReorderAlert {
+    product_id: ProductId,
+    product_code: String,
+    product_name: ProductName,
+    standard_stock_quantity: i64,
+    on_hand_quantity: OnHandQuantity,
+    committed_quantity: i64,
+    shortfall: i64,
+    suggested_reorder_quantity: i64,
+}
+impl ReorderAlert {
+    /// Returns `None` while the product is at or above its standard quantity.
+    ///
+    /// `committed_quantity` is the quantity on open sales orders that has not been shipped yet;
+    /// it is added to the suggestion so that the standard quantity remains after delivery.
+    pub fn evaluate(
+        product_id: ProductId,
+        product_code: String,
+        product_name: ProductName,
+        standard_stock_quantity: i64,
+        on_hand_quantity: OnHandQuantity,
+        committed_quantity: i64,
+    ) -> Option<Self> {
+        if on_hand_quantity >= standard_stock_quantity {
+            return None;
+        }
+        let shortfall = standard_stock_quantity - on_hand_quantity;
+
+        Some(Self {
+            product_id,
+            product_code,
+            product_name,
+            standard_stock_quantity,
+            on_hand_quantity,
+            committed_quantity,
+            shortfall,
+            suggested_reorder_quantity: shortfall + committed_quantity.max(0),
+        })
+    }
+
+    /// Whether a stock change of `quantity_change` is what pushed the product below its
+    /// standard quantity, as opposed to it already having been below before the change.
+    pub fn is_triggered_by(&self, quantity_change: i64) -> bool {
+        self.on_hand_quantity - quantity_change >= self.standard_stock_quantity
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn product_code(&self) -> &str {
+        &self.product_code
+    }
+
+    pub fn product_name(&self) -> &str {
+        &self.product_name
+    }
+
+    pub fn standard_stock_quantity(&self) -> &i64 {
+        &self.standard_stock_quantity
+    }
+
+    pub fn on_hand_quantity(&self) -> &OnHandQuantity {
+        &self.on_hand_quantity
+    }
+
+    pub fn committed_quantity(&self) -> &i64 {
+        &self.committed_quantity
+    }
+
+    pub fn shortfall(&self) -> &i64 {
+        &self.shortfall
+    }
+
+    pub fn suggested_reorder_quantity(&self) -> &i64 {
+        &self.suggested_reorder_quantity
+    }
+}
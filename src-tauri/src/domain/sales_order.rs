@@ -0,0 +1,179 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{customer, product::ProductId};
+
+pub type SalesOrderId = i64;
+pub type SalesOrderLineId = i64;
+pub type SalesOrderDate = Date;
+pub type SalesOrderQuantity = i64;
+pub type SalesOrderUnitPrice = i64;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SalesOrderStatus {
+    Ordered,
+    Delivered,
+    Cancelled,
+}
+impl SalesOrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SalesOrderStatus::Ordered => "ordered",
+            SalesOrderStatus::Delivered => "delivered",
+            SalesOrderStatus::Cancelled => "cancelled",
+        }
+    }
+}
+impl FromStr for SalesOrderStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ordered" => Ok(SalesOrderStatus::Ordered),
+            "delivered" => Ok(SalesOrderStatus::Delivered),
+            "cancelled" => Ok(SalesOrderStatus::Cancelled),
+            _ => Err(format!("unknown sales order status: {}", value)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SalesOrderLine {
+    id: SalesOrderLineId,
+    product_id: ProductId,
+    quantity: SalesOrderQuantity,
+    unit_price: SalesOrderUnitPrice,
+    invoiced: bool,
+}
+impl SalesOrderLine {
+    pub fn new(
+        id: SalesOrderLineId,
+        product_id: ProductId,
+        quantity: SalesOrderQuantity,
+        unit_price: SalesOrderUnitPrice,
+        invoiced: bool,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            quantity,
+            unit_price,
+            invoiced,
+        }
+    }
+
+    pub fn id(&self) -> &SalesOrderLineId {
+        &self.id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn quantity(&self) -> &SalesOrderQuantity {
+        &self.quantity
+    }
+
+    pub fn unit_price(&self) -> &SalesOrderUnitPrice {
+        &self.unit_price
+    }
+
+    pub fn amount(&self) -> i64 {
+        self.quantity * self.unit_price
+    }
+
+    /// Whether an issued invoice bills this line.
+    pub fn is_invoiced(&self) -> bool {
+        self.invoiced
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct SalesOrder {
+    id: SalesOrderId,
+    customer_id: customer::Id,
+    order_date: SalesOrderDate,
+    status: SalesOrderStatus,
+    lines: Vec<SalesOrderLine>,
+    created_at: PrimitiveDateTime,
+    updated_at: PrimitiveDateTime,
+}
+impl SalesOrder {
+    pub fn new(
+        id: SalesOrderId,
+        customer_id: customer::Id,
+        order_date: SalesOrderDate,
+        status: SalesOrderStatus,
+        lines: Vec<SalesOrderLine>,
+        created_at: PrimitiveDateTime,
+        updated_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            customer_id,
+            order_date,
+            status,
+            lines,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn id(&self) -> &SalesOrderId {
+        &self.id
+    }
+
+    pub fn customer_id(&self) -> &customer::Id {
+        &self.customer_id
+    }
+
+    pub fn order_date(&self) -> &SalesOrderDate {
+        &self.order_date
+    }
+
+    pub fn status(&self) -> &SalesOrderStatus {
+        &self.status
+    }
+
+    pub fn lines(&self) -> &Vec<SalesOrderLine> {
+        &self.lines
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &PrimitiveDateTime {
+        &self.updated_at
+    }
+
+    pub fn total_amount(&self) -> i64 {
+        self.lines.iter().map(|line| line.amount()).sum()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.status == SalesOrderStatus::Cancelled
+    }
+
+    /// Once a line is billed, the lines, customer and status must stay as invoiced, and the
+    /// order can no longer be cancelled.
+    pub fn is_invoiced(&self) -> bool {
+        self.lines.iter().any(|line| line.is_invoiced())
+    }
+
+    /// Quantity of each product the order has taken out of stock: its line quantities once
+    /// delivered, nothing before that or once cancelled.
+    pub fn shipped_quantities(&self) -> BTreeMap<ProductId, SalesOrderQuantity> {
+        let mut quantities = BTreeMap::new();
+        if self.status == SalesOrderStatus::Delivered {
+            for line in &self.lines {
+                *quantities.entry(line.product_id).or_insert(0) += line.quantity;
+            }
+        }
+
+        quantities
+    }
+}
@@ -0,0 +1,153 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime};
+
+use crate::domain::{error::DomainError, product::ProductId};
+
+pub type StockMovementId = i64;
+pub type StockMovementQuantity = i64;
+pub type StockMovementDate = Date;
+pub type StockMovementNote = String;
+pub type OnHandQuantity = i64;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StockMovementType {
+    Receipt,
+    Shipment,
+    Adjustment,
+    Return,
+}
+impl StockMovementType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockMovementType::Receipt => "receipt",
+            StockMovementType::Shipment => "shipment",
+            StockMovementType::Adjustment => "adjustment",
+            StockMovementType::Return => "return",
+        }
+    }
+
+    /// Receipts, shipments and returns are entered as positive quantities; only adjustments
+    /// carry their own sign.
+    pub fn validate_quantity(&self, quantity: StockMovementQuantity) -> Result<(), DomainError> {
+        match self {
+            _ if quantity == 0 => Err(DomainError::validation(
+                "quantity",
+                "quantity must not be zero",
+            )),
+            StockMovementType::Adjustment => Ok(()),
+            _ if quantity < 0 => Err(DomainError::validation(
+                "quantity",
+                format!("{} quantity must be positive", self.as_str()),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// The change in on-hand quantity caused by a movement of this type.
+    pub fn signed_quantity(&self, quantity: StockMovementQuantity) -> StockMovementQuantity {
+        match self {
+            StockMovementType::Receipt | StockMovementType::Return => quantity.abs(),
+            StockMovementType::Shipment => -quantity.abs(),
+            StockMovementType::Adjustment => quantity,
+        }
+    }
+}
+impl FromStr for StockMovementType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "receipt" => Ok(StockMovementType::Receipt),
+            "shipment" => Ok(StockMovementType::Shipment),
+            "adjustment" => Ok(StockMovementType::Adjustment),
+            "return" => Ok(StockMovementType::Return),
+            _ => Err(format!("unknown stock movement type: {}", value)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StockMovement {
+    id: StockMovementId,
+    product_id: ProductId,
+    movement_type: StockMovementType,
+    quantity: StockMovementQuantity,
+    moved_on: StockMovementDate,
+    note: Option<StockMovementNote>,
+    created_at: PrimitiveDateTime,
+}
+impl StockMovement {
+    pub fn new(
+        id: StockMovementId,
+        product_id: ProductId,
+        movement_type: StockMovementType,
+        quantity: StockMovementQuantity,
+        moved_on: StockMovementDate,
+        note: Option<StockMovementNote>,
+        created_at: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            product_id,
+            movement_type,
+            quantity,
+            moved_on,
+            note,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &StockMovementId {
+        &self.id
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn movement_type(&self) -> &StockMovementType {
+        &self.movement_type
+    }
+
+    /// Signed change in on-hand quantity.
+    pub fn quantity(&self) -> &StockMovementQuantity {
+        &self.quantity
+    }
+
+    pub fn moved_on(&self) -> &StockMovementDate {
+        &self.moved_on
+    }
+
+    pub fn note(&self) -> &Option<StockMovementNote> {
+        &self.note
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StockLevel {
+    product_id: ProductId,
+    on_hand_quantity: OnHandQuantity,
+}
+impl StockLevel {
+    pub fn new(product_id: ProductId, on_hand_quantity: OnHandQuantity) -> Self {
+        Self {
+            product_id,
+            on_hand_quantity,
+        }
+    }
+
+    pub fn product_id(&self) -> &ProductId {
+        &self.product_id
+    }
+
+    pub fn on_hand_quantity(&self) -> &OnHandQuantity {
+        &self.on_hand_quantity
+    }
+}
@@ -0,0 +1,125 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Consumption tax rate. The reduced rate applies to food and beverages (軽減税率).
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxRate {
+    Standard,
+    Reduced,
+}
+impl Default for TaxRate {
+    fn default() -> Self {
+        TaxRate::Standard
+    }
+}
+impl TaxRate {
+    pub fn percent(&self) -> i64 {
+        match self {
+            TaxRate::Standard => 10,
+            TaxRate::Reduced => 8,
+        }
+    }
+}
+impl TryFrom<i64> for TaxRate {
+    type Error = String;
+
+    fn try_from(percent: i64) -> Result<Self, Self::Error> {
+        match percent {
+            10 => Ok(TaxRate::Standard),
+            8 => Ok(TaxRate::Reduced),
+            _ => Err(format!("unsupported tax rate: {}%", percent)),
+        }
+    }
+}
+
+/// How fractions of a yen are handled when computing tax. Negative amounts (returns) are
+/// rounded symmetrically, so a refund mirrors the original charge.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// 切り捨て
+    RoundDown,
+    /// 切り上げ
+    RoundUp,
+    /// 四捨五入
+    HalfUp,
+}
+impl Default for RoundingMode {
+    fn default() -> Self {
+        RoundingMode::RoundDown
+    }
+}
+impl RoundingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundingMode::RoundDown => "round_down",
+            RoundingMode::RoundUp => "round_up",
+            RoundingMode::HalfUp => "half_up",
+        }
+    }
+
+    /// Rounds `numerator / denominator` to an integer.
+    fn divide(&self, numerator: i64, denominator: i64) -> i64 {
+        let sign = numerator.signum();
+        let numerator = numerator.abs();
+        let quotient = match self {
+            RoundingMode::RoundDown => numerator / denominator,
+            RoundingMode::RoundUp => (numerator + denominator - 1) / denominator,
+            RoundingMode::HalfUp => (numerator * 2 + denominator) / (denominator * 2),
+        };
+
+        sign * quotient
+    }
+}
+impl FromStr for RoundingMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "round_down" => Ok(RoundingMode::RoundDown),
+            "round_up" => Ok(RoundingMode::RoundUp),
+            "half_up" => Ok(RoundingMode::HalfUp),
+            _ => Err(format!("unknown rounding mode: {}", value)),
+        }
+    }
+}
+
+/// Tax on a tax-exclusive `amount`.
+pub fn calculate_tax(amount: i64, tax_rate: TaxRate, rounding_mode: RoundingMode) -> i64 {
+    rounding_mode.divide(amount * tax_rate.percent(), 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::tax::{calculate_tax, RoundingMode, TaxRate};
+
+    #[test]
+    fn calculate_tax_test() {
+        assert_eq!(
+            calculate_tax(1005, TaxRate::Standard, RoundingMode::RoundDown),
+            100
+        );
+        assert_eq!(
+            calculate_tax(1005, TaxRate::Standard, RoundingMode::RoundUp),
+            101
+        );
+        assert_eq!(
+            calculate_tax(1005, TaxRate::Standard, RoundingMode::HalfUp),
+            101
+        );
+        assert_eq!(
+            calculate_tax(1004, TaxRate::Standard, RoundingMode::HalfUp),
+            100
+        );
+        assert_eq!(
+            calculate_tax(1010, TaxRate::Reduced, RoundingMode::RoundDown),
+            80
+        );
+        assert_eq!(
+            calculate_tax(-1005, TaxRate::Standard, RoundingMode::RoundDown),
+            -100
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+pub type UserId = i64;
+pub type UserName = String;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Sales,
+    Viewer,
+}
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Sales => "sales",
+            Role::Viewer => "viewer",
+        }
+    }
+
+    pub fn allows(&self, permission: Permission) -> bool {
+        match self {
+            Role::Admin => true,
+            Role::Sales => matches!(
+                permission,
+                Permission::ViewMasterData
+                    | Permission::EditMasterData
+                    | Permission::EditPriceLists
+                    | Permission::EditSalesDocuments
+                    | Permission::PostStockMovements
+                    | Permission::IssueInvoices
+                    | Permission::RecordPayments
+            ),
+            Role::Viewer => permission == Permission::ViewMasterData,
+        }
+    }
+}
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "admin" => Ok(Role::Admin),
+            "sales" => Ok(Role::Sales),
+            "viewer" => Ok(Role::Viewer),
+            _ => Err(format!("unknown role: {}", value)),
+        }
+    }
+}
+
+/// What a command needs the signed-in user's role to allow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Looking up products and customers.
+    ViewMasterData,
+    /// Creating, updating and importing products and customers.
+    EditMasterData,
+    /// Deleting and restoring products and customers.
+    DeleteMasterData,
+    /// Creating, updating and deleting customer price lists.
+    EditPriceLists,
+    /// Creating, updating and cancelling sales orders, and creating, answering and converting
+    /// quotations.
+    EditSalesDocuments,
+    /// Posting receipts, shipments, returns and adjustments to the stock ledger.
+    PostStockMovements,
+    IssueInvoices,
+    /// Recording payments and allocating them to invoices.
+    RecordPayments,
+    /// Saving the issuer details printed on invoices.
+    EditCompanyProfile,
+    /// Reading the audit trail.
+    ViewAuditLog,
+    ManageUsers,
+    /// Backing up and restoring the database, and creating, opening and switching company
+    /// databases.
+    ManageDatabase,
+}
+
+/// A local account. The password hash stays in the repository.
+#[derive(Serialize, Debug, Clone)]
+pub struct User {
+    id: UserId,
+    name: UserName,
+    role: Role,
+    created_at: PrimitiveDateTime,
+}
+impl User {
+    pub fn new(id: UserId, name: UserName, role: Role, created_at: PrimitiveDateTime) -> Self {
+        Self {
+            id,
+            name,
+            role,
+            created_at,
+        }
+    }
+
+    pub fn id(&self) -> &UserId {
+        &self.id
+    }
+
+    pub fn name(&self) -> &UserName {
+        &self.name
+    }
+
+    pub fn role(&self) -> &Role {
+        &self.role
+    }
+
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::user::{Permission, Role};
+
+    const PERMISSIONS: [Permission; 12] = [
+        Permission::ViewMasterData,
+        Permission::EditMasterData,
+        Permission::DeleteMasterData,
+        Permission::EditPriceLists,
+        Permission::EditSalesDocuments,
+        Permission::PostStockMovements,
+        Permission::IssueInvoices,
+        Permission::RecordPayments,
+        Permission::EditCompanyProfile,
+        Permission::ViewAuditLog,
+        Permission::ManageUsers,
+        Permission::ManageDatabase,
+    ];
+
+    #[test]
+    fn admin_allows_test() {
+        assert!(PERMISSIONS
+            .iter()
+            .all(|permission| Role::Admin.allows(*permission)));
+    }
+
+    #[test]
+    fn sales_allows_test() {
+        let allowed: Vec<_> = PERMISSIONS
+            .iter()
+            .copied()
+            .filter(|permission| Role::Sales.allows(*permission))
+            .collect();
+
+        assert_eq!(
+            allowed,
+            vec![
+                Permission::ViewMasterData,
+                Permission::EditMasterData,
+                Permission::EditPriceLists,
+                Permission::EditSalesDocuments,
+                Permission::PostStockMovements,
+                Permission::IssueInvoices,
+                Permission::RecordPayments,
+            ]
+        );
+    }
+
+    #[test]
+    fn viewer_allows_test() {
+        let allowed: Vec<_> = PERMISSIONS
+            .iter()
+            .copied()
+            .filter(|permission| Role::Viewer.allows(*permission))
+            .collect();
+
+        assert_eq!(allowed, vec![Permission::ViewMasterData]);
+    }
+}
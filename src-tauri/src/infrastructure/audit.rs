@@ -0,0 +1,234 @@
+use std::sync::{Arc, RwLock};
+
+use sqlx::{sqlite::SqlitePoolOptions, Executor, SqliteConnection};
+
+use crate::domain::audit_log::AuditEntity;
+
+/// The audited tables and the entity their rows are recorded as.
+const AUDITED_TABLES: [(&str, AuditEntity); 12] = [
+    ("m_products", AuditEntity::Product),
+    ("m_customers", AuditEntity::Customer),
+    ("m_price_lists", AuditEntity::PriceList),
+    ("t_sales_orders", AuditEntity::SalesOrder),
+    ("t_sales_order_lines", AuditEntity::SalesOrderLine),
+    ("t_invoices", AuditEntity::Invoice),
+    ("t_invoice_lines", AuditEntity::InvoiceLine),
+    ("t_payments", AuditEntity::Payment),
+    ("t_payment_allocations", AuditEntity::PaymentAllocation),
+    ("t_quotations", AuditEntity::Quotation),
+    ("t_quotation_lines", AuditEntity::QuotationLine),
+    ("t_stock_movements", AuditEntity::StockMovement),
+];
+
+const ACTOR: &str = "(SELECT actor FROM t_audit_actor)";
+
+/// Who changes are recorded for: the signed-in user, or `fallback` while nobody is signed in.
+/// Clones share the user, so that the pools see the user the commands sign in.
+#[derive(Clone, Debug, Default)]
+pub struct AuditActor {
+    user: Arc<RwLock<Option<String>>>,
+    fallback: Option<String>,
+}
+impl AuditActor {
+    pub fn new(fallback: Option<String>) -> Self {
+        Self {
+            user: Arc::new(RwLock::new(None)),
+            fallback,
+        }
+    }
+
+    pub fn set_user(&self, user: Option<String>) {
+        *self.user.write().unwrap_or_else(|error| error.into_inner()) = user;
+    }
+
+    pub fn name(&self) -> Option<String> {
+        self.user
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
+            .or_else(|| self.fallback.clone())
+    }
+}
+
+/// The operating system user, recorded in the audit log as the actor of changes while nobody
+/// is signed in.
+pub(crate) fn os_user() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+}
+
+/// Records changes made through the pool's connections in the audit log. Each connection
+/// creates the audit triggers as TEMP triggers of its own and names the actor in a TEMP table,
+/// so nothing about the session outlives the connection. The audited tables must exist, so
+/// migrate the database before connecting.
+pub(crate) fn audited(pool_options: SqlitePoolOptions, actor: AuditActor) -> SqlitePoolOptions {
+    let acquire_actor = actor.clone();
+
+    pool_options
+        .after_connect(move |conn, _| {
+            let actor = actor.name();
+            Box::pin(async move { install_triggers(conn, actor).await })
+        })
+        .before_acquire(move |conn, _| {
+            let actor = acquire_actor.name();
+            Box::pin(async move {
+                sqlx::query("UPDATE t_audit_actor SET actor = ?")
+                    .bind(actor)
+                    .execute(conn)
+                    .await?;
+
+                Ok(true)
+            })
+        })
+}
+
+async fn install_triggers(
+    conn: &mut SqliteConnection,
+    actor: Option<String>,
+) -> Result<(), sqlx::Error> {
+    conn.execute("CREATE TEMP TABLE t_audit_actor (actor VARCHAR(100))")
+        .await?;
+    sqlx::query("INSERT INTO t_audit_actor (actor) VALUES (?)")
+        .bind(actor)
+        .execute(&mut *conn)
+        .await?;
+
+    for (table, entity) in AUDITED_TABLES {
+        let columns: Vec<(String,)> =
+            sqlx::query_as("SELECT name FROM pragma_table_info(?) ORDER BY cid")
+                .bind(table)
+                .fetch_all(&mut *conn)
+                .await?;
+        let columns = columns
+            .into_iter()
+            .map(|(column,)| column)
+            .collect::<Vec<_>>();
+        for trigger in triggers(table, entity, &columns) {
+            conn.execute(trigger.as_str()).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The insert, update and delete triggers of a table, which record its rows as JSON before
+/// and after the change. Setting or clearing `deleted_at` is recorded as a delete or restore.
+fn triggers(table: &str, entity: AuditEntity, columns: &[String]) -> [String; 3] {
+    let row = |row: &str| {
+        let fields = columns
+            .iter()
+            .map(|column| format!("'{}', {}.{}", column, row, column))
+            .collect::<Vec<_>>();
+        format!("json_object({})", fields.join(", "))
+    };
+    let update_operation = if columns.iter().any(|column| column == "deleted_at") {
+        "CASE
+            WHEN OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL THEN 'delete'
+            WHEN OLD.deleted_at IS NOT NULL AND NEW.deleted_at IS NULL THEN 'restore'
+            ELSE 'update'
+        END"
+    } else {
+        "'update'"
+    };
+
+    [
+        format!(
+            "CREATE TEMP TRIGGER trg_{table}_audit_insert AFTER INSERT ON main.{table}
+            BEGIN
+                INSERT INTO t_audit_logs (entity_type, entity_id, operation, after_json, actor)
+                VALUES ('{entity}', NEW.id, 'create', {after}, {actor});
+            END",
+            table = table,
+            entity = entity.as_str(),
+            after = row("NEW"),
+            actor = ACTOR,
+        ),
+        format!(
+            "CREATE TEMP TRIGGER trg_{table}_audit_update AFTER UPDATE ON main.{table}
+            BEGIN
+                INSERT INTO t_audit_logs
+                    (entity_type, entity_id, operation, before_json, after_json, actor)
+                VALUES ('{entity}', NEW.id, {operation}, {before}, {after}, {actor});
+            END",
+            table = table,
+            entity = entity.as_str(),
+            operation = update_operation,
+            before = row("OLD"),
+            after = row("NEW"),
+            actor = ACTOR,
+        ),
+        format!(
+            "CREATE TEMP TRIGGER trg_{table}_audit_delete AFTER DELETE ON main.{table}
+            BEGIN
+                INSERT INTO t_audit_logs (entity_type, entity_id, operation, before_json, actor)
+                VALUES ('{entity}', OLD.id, 'delete', {before}, {actor});
+            END",
+            table = table,
+            entity = entity.as_str(),
+            before = row("OLD"),
+            actor = ACTOR,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+
+    use crate::{
+        adapters::gateway::fixtures,
+        infrastructure::{
+            audit::{audited, AuditActor},
+            database::MIGRATOR,
+        },
+    };
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn audited_test(pool_options: SqlitePoolOptions, connect_options: SqliteConnectOptions) {
+        let actor = AuditActor::new(Some(String::from("os_user")));
+        let pool = audited(pool_options, actor.clone())
+            .connect_with(connect_options.clone())
+            .await
+            .unwrap();
+        let actors = || async {
+            let actors: Vec<(String, Option<String>)> =
+                sqlx::query_as("SELECT operation, actor FROM t_audit_logs ORDER BY id")
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap();
+            actors
+        };
+
+        fixtures::create_product(&pool, "product001").await;
+        actor.set_user(Some(String::from("tanaka")));
+        sqlx::query("UPDATE m_products SET default_price = 2500")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Another connection, as a second window or process would open, names its own actor.
+        let other_pool = audited(SqlitePoolOptions::new(), AuditActor::new(None))
+            .connect_with(connect_options)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE m_products SET deleted_at = CURRENT_TIMESTAMP")
+            .execute(&other_pool)
+            .await
+            .unwrap();
+        actor.set_user(None);
+        sqlx::query("DELETE FROM m_products")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actors().await,
+            vec![
+                (String::from("create"), Some(String::from("os_user"))),
+                (String::from("update"), Some(String::from("tanaka"))),
+                (String::from("delete"), None),
+                (String::from("delete"), Some(String::from("os_user"))),
+            ]
+        );
+    }
+}
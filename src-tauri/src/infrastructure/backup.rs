@@ -0,0 +1,513 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    SqlitePool,
+};
+use tauri::Manager;
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+use crate::infrastructure::{
+    database::MIGRATOR,
+    settings::{self, Settings},
+    tauri::state::AppState,
+};
+
+type DbResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+const BACKUP_DIR: &str = "backups";
+const BACKUP_EXTENSION: &str = "sqlite";
+/// Label of the backups taken on schedule; only these are pruned.
+pub(crate) const AUTO_LABEL: &str = "auto";
+pub(crate) const MANUAL_LABEL: &str = "manual";
+pub(crate) const RESTORE_LABEL: &str = "restore";
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+pub(crate) const SCHEDULED_BACKUP_FAILED_EVENT: &str = "scheduled-backup-failed";
+
+/// A backup of a database, named `<database file stem>-<label>-<YYYYMMDDhhmmssSSS>.sqlite`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupFile {
+    file_name: String,
+    path: PathBuf,
+    label: String,
+    size: u64,
+    created_at: PrimitiveDateTime,
+}
+impl BackupFile {
+    pub fn file_name(&self) -> &String {
+        &self.file_name
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn label(&self) -> &String {
+        &self.label
+    }
+
+    pub fn size(&self) -> &u64 {
+        &self.size
+    }
+
+    /// In UTC, taken from the file name.
+    pub fn created_at(&self) -> &PrimitiveDateTime {
+        &self.created_at
+    }
+}
+
+/// Backups are kept in a directory next to the database. Databases sharing a directory share
+/// it too, and are told apart by the file name.
+pub(crate) fn backup_dir(database_file: &Path) -> PathBuf {
+    match database_file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.join(BACKUP_DIR),
+        _ => PathBuf::from(BACKUP_DIR),
+    }
+}
+
+fn database_stem(database_file: &Path) -> String {
+    database_file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn format_timestamp(timestamp: &PrimitiveDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}{:03}",
+        timestamp.year(),
+        u8::from(timestamp.month()),
+        timestamp.day(),
+        timestamp.hour(),
+        timestamp.minute(),
+        timestamp.second(),
+        timestamp.millisecond()
+    )
+}
+
+/// Parses a timestamp written by `format_timestamp`, or one without milliseconds as the backups
+/// taken before were named.
+fn parse_timestamp(timestamp: &str) -> Option<PrimitiveDateTime> {
+    if !matches!(timestamp.len(), 14 | 17) || !timestamp.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let field = |range: std::ops::Range<usize>| timestamp[range].parse::<u8>().ok();
+    let date = Date::from_calendar_date(
+        timestamp[0..4].parse().ok()?,
+        Month::try_from(field(4..6)?).ok()?,
+        field(6..8)?,
+    )
+    .ok()?;
+    let millisecond = match timestamp.get(14..17) {
+        Some(millisecond) => millisecond.parse().ok()?,
+        None => 0,
+    };
+    let time =
+        Time::from_hms_milli(field(8..10)?, field(10..12)?, field(12..14)?, millisecond).ok()?;
+
+    Some(PrimitiveDateTime::new(date, time))
+}
+
+/// Reads a backup of `database_file` at `path`, or `None` when the file is not one.
+fn backup_file(database_file: &Path, path: &Path) -> Option<BackupFile> {
+    let file_name = path.file_name()?.to_str()?;
+    let name = file_name
+        .strip_prefix(&format!("{}-", database_stem(database_file)))?
+        .strip_suffix(&format!(".{}", BACKUP_EXTENSION))?;
+    let (label, timestamp) = name.rsplit_once('-')?;
+    if label.is_empty() || label.contains('-') {
+        return None;
+    }
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+
+    Some(BackupFile {
+        file_name: file_name.to_string(),
+        path: path.to_path_buf(),
+        label: label.to_string(),
+        size: metadata.len(),
+        created_at: parse_timestamp(timestamp)?,
+    })
+}
+
+/// Writes a consistent copy of the database behind `pool` next to `database_file`. `VACUUM
+/// INTO` reads a single snapshot, so the commands can keep using the pool meanwhile. It also
+/// refuses to overwrite a file, so a backup taken within the same millisecond as another is
+/// stamped a millisecond later.
+pub(crate) async fn backup_database(
+    pool: &SqlitePool,
+    database_file: &Path,
+    label: &str,
+) -> DbResult<BackupFile> {
+    let backup_dir = backup_dir(database_file);
+    std::fs::create_dir_all(&backup_dir)?;
+    let now = time::OffsetDateTime::now_utc();
+    let mut timestamp =
+        PrimitiveDateTime::new(now.date(), now.time()).replace_millisecond(now.millisecond())?;
+    let path = loop {
+        let path = backup_dir.join(format!(
+            "{}-{}-{}.{}",
+            database_stem(database_file),
+            label,
+            format_timestamp(&timestamp),
+            BACKUP_EXTENSION
+        ));
+        if !path.exists() {
+            break path;
+        }
+        timestamp += time::Duration::milliseconds(1);
+    };
+    sqlx::query("VACUUM INTO ?")
+        .bind(path.to_string_lossy().to_string())
+        .execute(pool)
+        .await?;
+
+    backup_file(database_file, &path)
+        .ok_or_else(|| format!("failed to write the backup: {}", path.display()).into())
+}
+
+/// The backups of `database_file`, newest first.
+pub(crate) fn list_backups(database_file: &Path) -> DbResult<Vec<BackupFile>> {
+    let backup_dir = backup_dir(database_file);
+    if !backup_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut backups = Vec::new();
+    for entry in std::fs::read_dir(&backup_dir)? {
+        if let Some(backup) = backup_file(database_file, &entry?.path()) {
+            backups.push(backup);
+        }
+    }
+    backups.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| b.file_name.cmp(&a.file_name))
+    });
+
+    Ok(backups)
+}
+
+pub(crate) fn find_backup(database_file: &Path, file_name: &str) -> DbResult<Option<BackupFile>> {
+    Ok(list_backups(database_file)?
+        .into_iter()
+        .find(|backup| backup.file_name == file_name))
+}
+
+/// Deletes the oldest backups with `label` beyond the newest `retention`, returning how many
+/// were deleted.
+pub(crate) fn prune_backups(
+    database_file: &Path,
+    label: &str,
+    retention: usize,
+) -> DbResult<usize> {
+    let expired = list_backups(database_file)?
+        .into_iter()
+        .filter(|backup| backup.label == label)
+        .skip(retention)
+        .collect::<Vec<_>>();
+    for backup in &expired {
+        std::fs::remove_file(&backup.path)?;
+    }
+
+    Ok(expired.len())
+}
+
+/// Checks that `path` is an intact database of this app that the current version can open:
+/// every migration applied to it must be one of `MIGRATOR`'s, with the same checksum. Older
+/// backups are fine; their missing migrations are applied once restored.
+pub(crate) async fn verify_backup(path: &Path) -> DbResult<()> {
+    let connection_options = SqliteConnectOptions::new().filename(path).read_only(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(connection_options)
+        .await?;
+    let result = verify_backup_pool(&pool).await;
+    pool.close().await;
+
+    result
+}
+
+async fn verify_backup_pool(pool: &SqlitePool) -> DbResult<()> {
+    let integrity: Vec<(String,)> = sqlx::query_as("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await?;
+    if integrity.len() != 1 || integrity[0].0 != "ok" {
+        let report = integrity
+            .into_iter()
+            .map(|(message,)| message)
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(format!("the backup is corrupted: {}", report).into());
+    }
+
+    let (has_migrations,): (bool,) = sqlx::query_as(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+    )
+    .fetch_one(pool)
+    .await?;
+    if !has_migrations {
+        return Err("the backup is not a database of this app".into());
+    }
+    let applied: Vec<(i64, Vec<u8>)> =
+        sqlx::query_as("SELECT version, checksum FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await?;
+    for (version, checksum) in applied {
+        match MIGRATOR
+            .iter()
+            .find(|migration| migration.version == version)
+        {
+            Some(migration) if *migration.checksum == *checksum => {}
+            Some(_) => {
+                return Err(format!(
+                    "the backup was migrated with a different migration {}",
+                    version
+                )
+                .into())
+            }
+            None => {
+                return Err(format!(
+                    "the backup was made by a newer version of the app (migration {}); install the latest version to restore it",
+                    version
+                )
+                .into())
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies `backup_file` over `database_file`. The pool on `database_file` must be closed, and
+/// its write-ahead log is discarded so that it is not replayed onto the restored database.
+pub(crate) fn replace_database_file(backup_file: &Path, database_file: &Path) -> DbResult<()> {
+    for suffix in &["-wal", "-shm"] {
+        let mut sidecar = database_file.as_os_str().to_os_string();
+        sidecar.push(suffix);
+        let sidecar = PathBuf::from(sidecar);
+        if sidecar.exists() {
+            std::fs::remove_file(&sidecar)?;
+        }
+    }
+    std::fs::copy(backup_file, database_file)?;
+
+    Ok(())
+}
+
+fn is_backup_due(
+    latest: Option<&PrimitiveDateTime>,
+    interval_hours: u32,
+    now: &PrimitiveDateTime,
+) -> bool {
+    if interval_hours == 0 {
+        return false;
+    }
+    match latest {
+        Some(latest) => *latest + time::Duration::hours(interval_hours.into()) <= *now,
+        None => true,
+    }
+}
+
+/// Backs up the current company's database when the latest scheduled backup is older than the
+/// interval in the settings, then prunes the scheduled backups down to the retention count.
+async fn run_scheduled_backup(state: &AppState) -> DbResult<Option<BackupFile>> {
+    let settings = Settings::load(&settings::settings_file())?;
+    let schedule = settings.backup_schedule();
+    let database_file = settings.current_database_file();
+    let latest = list_backups(&database_file)?
+        .into_iter()
+        .find(|backup| backup.label == AUTO_LABEL);
+    let now = time::OffsetDateTime::now_utc();
+    if !is_backup_due(
+        latest.as_ref().map(BackupFile::created_at),
+        *schedule.interval_hours(),
+        &PrimitiveDateTime::new(now.date(), now.time()),
+    ) {
+        return Ok(None);
+    }
+
+    let backup = backup_database(&state.pool(), &database_file, AUTO_LABEL).await?;
+    prune_backups(&database_file, AUTO_LABEL, *schedule.retention() as usize)?;
+
+    Ok(Some(backup))
+}
+
+/// Runs the scheduled backups on a thread of their own for as long as the app is running.
+/// Nobody waits on the thread, so failures are sent to the windows as
+/// `SCHEDULED_BACKUP_FAILED_EVENT` with the error message.
+pub(crate) fn spawn_scheduled_backups(app_handle: tauri::AppHandle, state: AppState) {
+    std::thread::spawn(move || loop {
+        if let Err(error) = tauri::async_runtime::block_on(run_scheduled_backup(&state)) {
+            app_handle
+                .emit_all(SCHEDULED_BACKUP_FAILED_EVENT, error.to_string())
+                .ok();
+        }
+        std::thread::sleep(SCHEDULE_CHECK_INTERVAL);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use sqlx::SqlitePool;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use crate::{
+        adapters::gateway::fixtures,
+        infrastructure::{
+            audit::AuditActor,
+            backup::{
+                backup_database, find_backup, is_backup_due, list_backups, parse_timestamp,
+                prune_backups, replace_database_file, verify_backup, AUTO_LABEL, MANUAL_LABEL,
+            },
+            database::open_database,
+        },
+    };
+
+    fn timestamp(hour: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2023, Month::July, 10).unwrap(),
+            Time::from_hms(hour, 0, 0).unwrap(),
+        )
+    }
+
+    fn write_backup(database_file: &Path, name: &str) {
+        let backup_dir = database_file.parent().unwrap().join("backups");
+        std::fs::create_dir_all(&backup_dir).unwrap();
+        std::fs::write(backup_dir.join(name), "").unwrap();
+    }
+
+    #[test]
+    fn is_backup_due_test() {
+        assert_eq!(parse_timestamp("20230710120000"), Some(timestamp(12)));
+        assert_eq!(
+            parse_timestamp("20230710120000250"),
+            Some(timestamp(12).replace_millisecond(250).unwrap())
+        );
+        assert_eq!(parse_timestamp("20231310120000"), None);
+
+        assert!(is_backup_due(None, 24, &timestamp(12)));
+        assert!(!is_backup_due(None, 0, &timestamp(12)));
+        assert!(!is_backup_due(Some(&timestamp(0)), 24, &timestamp(12)));
+        assert!(is_backup_due(Some(&timestamp(0)), 12, &timestamp(12)));
+    }
+
+    #[test]
+    fn list_and_prune_backups_test() {
+        let database_dir = std::env::temp_dir().join(format!(
+            "sales-management-system-backups-{}",
+            std::process::id()
+        ));
+        let database_file = database_dir.join("company-1.sqlite");
+        write_backup(&database_file, "company-1-auto-20230710000000.sqlite");
+        write_backup(&database_file, "company-1-auto-20230711000000.sqlite");
+        write_backup(&database_file, "company-1-auto-20230712000000.sqlite");
+        write_backup(&database_file, "company-1-manual-20230709000000.sqlite");
+        write_backup(&database_file, "company-10-auto-20230712000000.sqlite");
+        write_backup(&database_file, "company-1-auto-broken.sqlite");
+
+        let backups = list_backups(&database_file).unwrap();
+        assert_eq!(
+            backups
+                .iter()
+                .map(|backup| backup.file_name().as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "company-1-auto-20230712000000.sqlite",
+                "company-1-auto-20230711000000.sqlite",
+                "company-1-auto-20230710000000.sqlite",
+                "company-1-manual-20230709000000.sqlite",
+            ]
+        );
+        assert!(
+            find_backup(&database_file, "company-10-auto-20230712000000.sqlite")
+                .unwrap()
+                .is_none()
+        );
+
+        assert_eq!(prune_backups(&database_file, AUTO_LABEL, 2).unwrap(), 1);
+        let backups = list_backups(&database_file).unwrap();
+        std::fs::remove_dir_all(&database_dir).unwrap();
+
+        assert_eq!(backups.len(), 3);
+        assert_eq!(backups[2].label(), MANUAL_LABEL);
+    }
+
+    #[sqlx::test(migrator = "crate::infrastructure::database::MIGRATOR")]
+    async fn verify_backup_test(pool: SqlitePool) {
+        let database_dir = std::env::temp_dir().join(format!(
+            "sales-management-system-verify-{}",
+            std::process::id()
+        ));
+        let database_file = database_dir.join("db.sqlite");
+        let backup = backup_database(&pool, &database_file, MANUAL_LABEL)
+            .await
+            .unwrap();
+        verify_backup(backup.path()).await.unwrap();
+        let another = backup_database(&pool, &database_file, MANUAL_LABEL)
+            .await
+            .unwrap();
+        assert_ne!(another.file_name(), backup.file_name());
+
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+                VALUES (99990101000000, 'future', TRUE, X'00', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let backup = backup_database(&pool, &database_file, AUTO_LABEL)
+            .await
+            .unwrap();
+        let newer = verify_backup(backup.path()).await.unwrap_err();
+
+        std::fs::write(backup.path(), "not a database").unwrap();
+        let corrupted = verify_backup(backup.path()).await;
+        std::fs::remove_dir_all(&database_dir).unwrap();
+
+        assert!(newer.to_string().contains("newer version"));
+        assert!(corrupted.is_err());
+    }
+
+    #[test]
+    fn restore_backup_test() {
+        let database_dir = std::env::temp_dir().join(format!(
+            "sales-management-system-restore-{}",
+            std::process::id()
+        ));
+        let database_file = database_dir.join("db.sqlite");
+        async_std::task::block_on(async {
+            let pool = open_database(&database_file, &AuditActor::default())
+                .await
+                .unwrap();
+            fixtures::create_product(&pool, "product001").await;
+            let backup = backup_database(&pool, &database_file, MANUAL_LABEL)
+                .await
+                .unwrap();
+            sqlx::query("DELETE FROM m_products")
+                .execute(&pool)
+                .await
+                .unwrap();
+            pool.close().await;
+
+            verify_backup(backup.path()).await.unwrap();
+            replace_database_file(backup.path(), &database_file).unwrap();
+            let pool = open_database(&database_file, &AuditActor::default())
+                .await
+                .unwrap();
+            let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM m_products")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            pool.close().await;
+
+            assert_eq!(count, 1);
+        });
+        std::fs::remove_dir_all(&database_dir).unwrap();
+    }
+}
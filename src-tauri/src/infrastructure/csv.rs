@@ -0,0 +1,233 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::{
+    adapters::presenter::response::export_response::ExportCell, infrastructure::export::SheetWriter,
+};
+
+const UTF8_BOM: &str = "\u{feff}";
+
+/// Decodes a CSV file as UTF-8 when it is valid UTF-8, and as Shift_JIS (CP932, as written by
+/// Excel in Japan) otherwise.
+pub(crate) fn decode(bytes: &[u8]) -> String {
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => encoding_rs::SHIFT_JIS.decode(bytes).0.into_owned(),
+    };
+
+    match text.strip_prefix(UTF8_BOM) {
+        Some(text) => text.to_string(),
+        None => text,
+    }
+}
+
+/// Splits `text` into records of fields as in RFC 4180: fields may be quoted, quotes inside
+/// them are doubled, and quoted fields may span lines. Blank lines are skipped.
+pub(crate) fn parse(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.len() > 1 || !record[0].is_empty() {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+                line += 1;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(format!("unterminated quoted field at line {}", line));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+pub(crate) fn read_file(path: &Path) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let records = parse(&decode(&std::fs::read(path)?))?;
+
+    Ok(records)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CsvEncoding {
+    /// Starts with a byte order mark, without which Excel reads UTF-8 as Shift_JIS.
+    Utf8Bom,
+    ShiftJis,
+}
+
+fn quote(field: &str) -> String {
+    if field.contains(&[',', '"', '\r', '\n'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Characters Shift_JIS cannot represent are written as `?`.
+fn encode_shift_jis(text: &str) -> Vec<u8> {
+    let (bytes, _, had_unmappable) = encoding_rs::SHIFT_JIS.encode(text);
+    if !had_unmappable {
+        return bytes.into_owned();
+    }
+
+    let mut buffer = [0; 4];
+    text.chars()
+        .flat_map(|c| {
+            let (bytes, _, had_unmappable) =
+                encoding_rs::SHIFT_JIS.encode(c.encode_utf8(&mut buffer));
+            if had_unmappable {
+                vec![b'?']
+            } else {
+                bytes.into_owned()
+            }
+        })
+        .collect()
+}
+
+/// Writes records as they come, with CRLF line endings as Excel does.
+pub(crate) struct CsvWriter<W: Write> {
+    out: W,
+    encoding: CsvEncoding,
+}
+impl CsvWriter<BufWriter<File>> {
+    pub(crate) fn create(path: &Path, encoding: CsvEncoding) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), encoding)
+    }
+}
+impl<W: Write> CsvWriter<W> {
+    pub(crate) fn new(mut out: W, encoding: CsvEncoding) -> io::Result<Self> {
+        if encoding == CsvEncoding::Utf8Bom {
+            out.write_all(UTF8_BOM.as_bytes())?;
+        }
+
+        Ok(Self { out, encoding })
+    }
+
+    pub(crate) fn write_record(&mut self, fields: &[String]) -> io::Result<()> {
+        let line = fields
+            .iter()
+            .map(|field| quote(field))
+            .collect::<Vec<_>>()
+            .join(",")
+            + "\r\n";
+        match self.encoding {
+            CsvEncoding::Utf8Bom => self.out.write_all(line.as_bytes()),
+            CsvEncoding::ShiftJis => self.out.write_all(&encode_shift_jis(&line)),
+        }
+    }
+
+    pub(crate) fn into_inner(mut self) -> io::Result<W> {
+        self.out.flush()?;
+
+        Ok(self.out)
+    }
+}
+impl<W: Write + Send> SheetWriter for CsvWriter<W> {
+    fn write_row(&mut self, row: &[ExportCell]) -> io::Result<()> {
+        let fields: Vec<String> = row
+            .iter()
+            .map(|cell| match cell {
+                ExportCell::Empty => String::new(),
+                ExportCell::Text(text) => text.clone(),
+                ExportCell::Number(number) => number.to_string(),
+            })
+            .collect();
+
+        self.write_record(&fields)
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        self.into_inner().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::csv::{decode, parse, CsvEncoding, CsvWriter};
+
+    #[test]
+    fn decode_test() {
+        assert_eq!(decode("\u{feff}商品名,単位".as_bytes()), "商品名,単位");
+        let (shift_jis, _, _) = encoding_rs::SHIFT_JIS.encode("商品名,単位");
+        assert_eq!(decode(&shift_jis), "商品名,単位");
+    }
+
+    #[test]
+    fn parse_test() {
+        let records =
+            parse("name,code\r\n\"ボールペン, 黒\",pen-001\r\n\r\n\"替芯\n\"\"0.5\"\"\",\n")
+                .unwrap();
+        assert_eq!(
+            records,
+            vec![
+                vec!["name", "code"],
+                vec!["ボールペン, 黒", "pen-001"],
+                vec!["替芯\n\"0.5\"", ""],
+            ]
+        );
+        assert_eq!(parse("a,b").unwrap(), vec![vec!["a", "b"]]);
+        assert!(parse("\"a,b\nc").is_err());
+    }
+
+    #[test]
+    fn csv_writer_test() {
+        let records = vec![
+            vec![String::from("商品名"), String::from("備考")],
+            vec![
+                String::from("ボールペン, 黒"),
+                String::from("\"替芯\"付き\n①"),
+            ],
+        ];
+        for encoding in [CsvEncoding::Utf8Bom, CsvEncoding::ShiftJis] {
+            let mut writer = CsvWriter::new(Vec::new(), encoding).unwrap();
+            for record in &records {
+                writer.write_record(record).unwrap();
+            }
+            let bytes = writer.into_inner().unwrap();
+
+            assert_eq!(
+                bytes.starts_with(b"\xef\xbb\xbf"),
+                encoding == CsvEncoding::Utf8Bom
+            );
+            assert_eq!(parse(&decode(&bytes)).unwrap(), records);
+        }
+
+        let mut writer = CsvWriter::new(Vec::new(), CsvEncoding::ShiftJis).unwrap();
+        writer.write_record(&[String::from("😀商品")]).unwrap();
+        assert_eq!(decode(&writer.into_inner().unwrap()), "?商品\r\n");
+    }
+}
@@ -1,4 +1,4 @@
-use std::error::Error;
+use std::path::{Path, PathBuf};
 
 use sqlx::{
     sqlite::{SqliteConnectOptions, SqlitePoolOptions},
@@ -6,50 +6,324 @@ use sqlx::{
 };
 use std::str::FromStr;
 
-type DbResult<T> = Result<T, Box<dyn std::error::Error>>;
+use crate::infrastructure::{
+    audit::{self, AuditActor},
+    backup,
+    settings::{self, Settings},
+};
+
+type DbResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-pub(crate) async fn create_sqlite_pool(database_url: &str) -> DbResult<SqlitePool> {
+pub(crate) async fn create_sqlite_pool(
+    database_url: &str,
+    pool_options: SqlitePoolOptions,
+) -> DbResult<SqlitePool> {
     let connection_options =
-        SqliteConnectOptions::from_str(*&database_url)?.create_if_missing(true);
+        SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
 
-    let sqlite_pool = SqlitePoolOptions::new()
+    let sqlite_pool = pool_options
         .connect_with(connection_options)
         .await?;
 
     Ok(sqlite_pool)
 }
 
-pub(crate) async fn migrate_database(pool: &SqlitePool) -> DbResult<()> {
-    sqlx::migrate!("./database/migrations").run(pool).await?;
+async fn table_exists(pool: &SqlitePool, table: &str) -> DbResult<bool> {
+    let (exists,): (bool,) = sqlx::query_as(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?)",
+    )
+    .bind(table)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(exists)
+}
+
+/// Versions of the migrations already applied to the database, in order.
+pub(crate) async fn applied_migrations(pool: &SqlitePool) -> DbResult<Vec<i64>> {
+    if !table_exists(pool, "_sqlx_migrations").await? {
+        return Ok(Vec::new());
+    }
+    let versions: Vec<(i64,)> =
+        sqlx::query_as("SELECT version FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await?;
+
+    Ok(versions.into_iter().map(|(version,)| version).collect())
+}
+
+/// Product codes became unique after products were first stored, and the index cannot be
+/// created while active products share a code. Such databases are reported with every clashing
+/// product before anything is migrated, as the app cannot open them to show the clash later.
+async fn check_duplicate_product_codes(pool: &SqlitePool) -> DbResult<()> {
+    if !table_exists(pool, "m_products").await? {
+        return Ok(());
+    }
+
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT code, id FROM m_products
+            WHERE deleted_at IS NULL
+            AND code IN (
+                SELECT code FROM m_products
+                    WHERE deleted_at IS NULL
+                    GROUP BY code
+                    HAVING COUNT(*) > 1
+            )
+            ORDER BY code, id",
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut duplicates: Vec<(String, Vec<i64>)> = Vec::new();
+    for (code, product_id) in rows {
+        match duplicates.last_mut() {
+            Some((last_code, product_ids)) if *last_code == code => product_ids.push(product_id),
+            _ => duplicates.push((code, vec![product_id])),
+        }
+    }
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+    let report = duplicates
+        .iter()
+        .map(|(code, product_ids)| format!("code={}, product_ids={:?}", code, product_ids))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    Err(format!(
+        "product codes must be unique; the database was left unchanged, so rename or delete these products with the previous version of the app first: {}",
+        report
+    )
+    .into())
+}
+
+/// Applies the migrations the database is missing. An existing database is backed up next to
+/// `database_file` first, and one last migrated by a newer version of the app is left untouched.
+pub(crate) async fn migrate_database(pool: &SqlitePool, database_file: &Path) -> DbResult<()> {
+    let applied = applied_migrations(pool).await?;
+    if let Some(version) = applied.iter().find(|version| {
+        MIGRATOR
+            .iter()
+            .all(|migration| migration.version != **version)
+    }) {
+        return Err(format!(
+            "the database was updated by a newer version of the app (migration {}); install the latest version to open it",
+            version
+        )
+        .into());
+    }
+    let has_pending = MIGRATOR
+        .iter()
+        .any(|migration| !applied.contains(&migration.version));
+    if !has_pending {
+        return Ok(());
+    }
+
+    check_duplicate_product_codes(pool).await?;
+    let backup_file = match applied.last() {
+        Some(version) => {
+            Some(backup::backup_database(pool, database_file, &version.to_string()).await?)
+        }
+        None => None,
+    };
+    if let Err(error) = MIGRATOR.run(pool).await {
+        return Err(match backup_file {
+            Some(backup_file) => format!(
+                "failed to migrate the database: {}; the database before migrating was saved to {}",
+                error,
+                backup_file.path().display()
+            ),
+            None => format!("failed to migrate the database: {}", error),
+        }
+        .into());
+    }
+
     Ok(())
 }
 
 pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./database/migrations");
 
-pub(crate) fn excute() -> Result<Pool<Sqlite>, Box<dyn Error>> {
+pub(crate) const DATABASE_FILE: &str = "db.sqlite";
+const DATA_DIR_ENV: &str = "SALES_MANAGEMENT_SYSTEM_DATA_DIR";
+const DATA_DIR_ARG: &str = "--data-dir";
+
+/// The data directory given with `--data-dir <dir>` or, failing that, with the
+/// `SALES_MANAGEMENT_SYSTEM_DATA_DIR` environment variable.
+pub(crate) fn data_dir_override() -> Option<PathBuf> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == DATA_DIR_ARG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(dir) = arg.strip_prefix(&format!("{}=", DATA_DIR_ARG)) {
+            return Some(PathBuf::from(dir));
+        }
+    }
+
+    std::env::var_os(DATA_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Directory holding the database and other per-user application files.
+pub(crate) fn data_dir() -> PathBuf {
     const DATABASE_DIR: &str = "sales-management-system";
-    const DATABASE_FILE: &str = "db.sqlite";
 
+    if let Some(dir) = data_dir_override() {
+        return dir;
+    }
     let home_dir = directories::UserDirs::new()
         .map(|dirs| dirs.home_dir().to_path_buf())
         .unwrap_or_else(|| std::env::current_dir().expect("Cannot access the current directory"));
-    let database_dir = home_dir.join(DATABASE_DIR);
-    let database_file = database_dir.join(DATABASE_FILE);
 
-    let db_exists = std::fs::metadata(&database_file).is_ok();
-    if !db_exists {
-        std::fs::create_dir(&database_dir)?;
-    }
-    let database_dir_str = dunce::canonicalize(&database_dir)
-        .unwrap()
+    home_dir.join(DATABASE_DIR)
+}
+
+/// Opens `database_file`, creating it and its directory when missing, and applies pending
+/// migrations. Changes made through the returned pool are recorded in the audit log for
+/// `actor`.
+pub(crate) async fn open_database(
+    database_file: &Path,
+    actor: &AuditActor,
+) -> DbResult<SqlitePool> {
+    let database_dir = match database_file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let file_name = database_file
+        .file_name()
+        .ok_or_else(|| format!("not a database file: {}", database_file.display()))?;
+    std::fs::create_dir_all(&database_dir)?;
+    let database_dir_str = dunce::canonicalize(&database_dir)?
         .to_string_lossy()
         .replace('\\', "/");
-    let database_url = format!("sqlite://{}/{}", database_dir_str, DATABASE_FILE);
+    let database_url = format!(
+        "sqlite://{}/{}",
+        database_dir_str,
+        file_name.to_string_lossy()
+    );
 
-    let sqlite_pool = tauri::async_runtime::block_on(create_sqlite_pool(&database_url))?;
-    if !db_exists {
-        tauri::async_runtime::block_on(migrate_database(&sqlite_pool))?;
-    }
+    // The audit triggers are created when connecting, so they need the migrated tables.
+    let migration_pool = create_sqlite_pool(&database_url, SqlitePoolOptions::new()).await?;
+    migrate_database(&migration_pool, database_file).await?;
+    migration_pool.close().await;
+    let sqlite_pool = create_sqlite_pool(
+        &database_url,
+        audit::audited(SqlitePoolOptions::new(), actor.clone()),
+    )
+    .await?;
 
     Ok(sqlite_pool)
 }
+
+pub(crate) fn excute(actor: &AuditActor) -> DbResult<Pool<Sqlite>> {
+    let settings = Settings::load(&settings::settings_file())?;
+    let sqlite_pool = tauri::async_runtime::block_on(open_database(
+        &settings.current_database_file(),
+        actor,
+    ))?;
+
+    Ok(sqlite_pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use crate::infrastructure::{
+        audit::AuditActor,
+        database::{
+            applied_migrations, check_duplicate_product_codes, migrate_database, open_database,
+            MIGRATOR,
+        },
+    };
+
+    #[sqlx::test(migrations = false)]
+    async fn check_duplicate_product_codes_test(pool: SqlitePool) {
+        check_duplicate_product_codes(&pool).await.unwrap();
+
+        sqlx::query(include_str!(
+            "../../database/migrations/20230226074512_m_products.sql"
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO m_products (name, code, unit, deleted_at) VALUES
+                ('商品1', 'product001', '個', NULL),
+                ('商品2', 'product002', '個', NULL),
+                ('商品3', 'product001', '個', NULL),
+                ('商品4', 'product001', '個', CURRENT_TIMESTAMP)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let error = check_duplicate_product_codes(&pool).await.unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("code=product001, product_ids=[1, 3]"));
+        assert!(!error.to_string().contains("product002"));
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn migrate_database_test(pool: SqlitePool) {
+        let database_dir = std::env::temp_dir().join(format!(
+            "sales-management-system-test-{}",
+            std::process::id()
+        ));
+        let database_file = database_dir.join("db.sqlite");
+        let backup_dir = database_dir.join("backups");
+        migrate_database(&pool, &database_file).await.unwrap();
+        assert_eq!(
+            applied_migrations(&pool).await.unwrap().len(),
+            MIGRATOR.iter().count()
+        );
+        assert!(!backup_dir.exists());
+
+        let (latest,): (i64,) = sqlx::query_as("SELECT MAX(version) FROM _sqlx_migrations")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+            .bind(latest)
+            .execute(&pool)
+            .await
+            .unwrap();
+        migrate_database(&pool, &database_file).await.unwrap();
+        assert_eq!(
+            applied_migrations(&pool).await.unwrap().len(),
+            MIGRATOR.iter().count()
+        );
+        assert_eq!(std::fs::read_dir(&backup_dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(&database_dir).unwrap();
+
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+                VALUES (99990101000000, 'future', TRUE, X'00', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let error = migrate_database(&pool, &database_file).await.unwrap_err();
+
+        assert!(error.to_string().contains("newer version"));
+    }
+
+    #[test]
+    fn open_database_test() {
+        let database_dir = std::env::temp_dir().join(format!(
+            "sales-management-system-open-{}",
+            std::process::id()
+        ));
+        let database_file = database_dir.join("companies").join("sample.sqlite");
+        async_std::task::block_on(async {
+            let pool = open_database(&database_file, &AuditActor::default()).await.unwrap();
+            assert_eq!(applied_migrations(&pool).await.unwrap().len(), MIGRATOR.iter().count());
+            pool.close().await;
+
+            let pool = open_database(&database_file, &AuditActor::default()).await.unwrap();
+            pool.close().await;
+        });
+        std::fs::remove_dir_all(&database_dir).unwrap();
+    }
+}
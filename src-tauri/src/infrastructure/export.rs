@@ -0,0 +1,32 @@
+use std::io;
+use std::path::Path;
+
+use crate::{
+    adapters::{
+        controller::request::export_request::ExportFormat,
+        presenter::response::export_response::ExportCell,
+    },
+    infrastructure::{
+        csv::{CsvEncoding, CsvWriter},
+        xlsx::XlsxWriter,
+    },
+};
+
+/// Writes an exported sheet a row at a time.
+pub(crate) trait SheetWriter: Send {
+    fn write_row(&mut self, row: &[ExportCell]) -> io::Result<()>;
+    /// Completes the file, which is not readable before.
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+pub(crate) fn create_writer(
+    path: &Path,
+    format: ExportFormat,
+    sheet_name: &str,
+) -> io::Result<Box<dyn SheetWriter>> {
+    Ok(match format {
+        ExportFormat::CsvUtf8 => Box::new(CsvWriter::create(path, CsvEncoding::Utf8Bom)?),
+        ExportFormat::CsvShiftJis => Box::new(CsvWriter::create(path, CsvEncoding::ShiftJis)?),
+        ExportFormat::Xlsx => Box::new(XlsxWriter::create(path, sheet_name)?),
+    })
+}
@@ -1,2 +1,9 @@
+pub mod audit;
+pub mod backup;
+pub mod csv;
 pub mod database;
+pub mod export;
+pub mod pdf;
+pub mod settings;
 pub mod tauri;
+pub mod xlsx;
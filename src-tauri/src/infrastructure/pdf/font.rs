@@ -0,0 +1,85 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+/// Overrides the font used for PDF output. Must point at a TrueType/OpenType font that covers
+/// Japanese.
+pub const PDF_FONT_ENV: &str = "SALES_MANAGEMENT_PDF_FONT";
+
+/// Well-known Japanese fonts, tried in order when no font is configured. Font collections
+/// (`.ttc`), which most systems ship their Japanese fonts as, cannot be embedded and are left
+/// out; Windows has no Japanese font in another format, so a font must be placed in the fonts
+/// directory there.
+const SYSTEM_FONT_CANDIDATES: [&str; 7] = [
+    // macOS
+    "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+    "/Library/Fonts/Osaka.ttf",
+    // Linux
+    "/usr/share/fonts/opentype/ipaexfont-gothic/ipaexg.ttf",
+    "/usr/share/fonts/opentype/ipafont-gothic/ipag.ttf",
+    "/usr/share/fonts/truetype/fonts-japanese-gothic.ttf",
+    "/usr/share/fonts/truetype/takao-gothic/TakaoPGothic.ttf",
+    "/usr/share/fonts/truetype/vlgothic/VL-Gothic-Regular.ttf",
+];
+
+/// Font collections start with this tag instead of a font's.
+const FONT_COLLECTION_TAG: &[u8] = b"ttcf";
+
+/// Looks for a font to embed: the [`PDF_FONT_ENV`] override first, then any font placed in
+/// `fonts_dir`, then the fonts shipped with the operating system.
+pub fn find_font(fonts_dir: Option<&Path>) -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(PDF_FONT_ENV) {
+        return Some(PathBuf::from(path));
+    }
+
+    if let Some(font) = fonts_dir.and_then(find_font_in_dir) {
+        return Some(font);
+    }
+
+    SYSTEM_FONT_CANDIDATES
+        .iter()
+        .map(PathBuf::from)
+        .find(|path| path.is_file())
+}
+
+fn find_font_in_dir(dir: &Path) -> Option<PathBuf> {
+    let mut fonts: Vec<PathBuf> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .map(|extension| {
+                    matches!(
+                        extension.to_ascii_lowercase().as_str(),
+                        "ttf" | "otf"
+                    )
+                })
+                .unwrap_or(false)
+        })
+        .collect();
+    fonts.sort();
+
+    fonts.into_iter().next()
+}
+
+pub fn load_font(fonts_dir: Option<&Path>) -> Result<Vec<u8>, Box<dyn Error>> {
+    let path = find_font(fonts_dir).ok_or_else(|| {
+        format!(
+            "no Japanese font found for PDF output; place a .ttf or .otf font in the fonts directory or set {}",
+            PDF_FONT_ENV
+        )
+    })?;
+    let font =
+        std::fs::read(&path).map_err(|e| format!("cannot read font {}: {}", path.display(), e))?;
+    if font.starts_with(FONT_COLLECTION_TAG) {
+        return Err(format!(
+            "{} is a font collection, which cannot be embedded in PDFs; use a .ttf or .otf font",
+            path.display()
+        )
+        .into());
+    }
+
+    Ok(font)
+}
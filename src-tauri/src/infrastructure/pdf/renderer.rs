@@ -0,0 +1,466 @@
+use std::{error::Error, ops::Range, path::Path};
+
+use printpdf::{
+    IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Point,
+};
+use time::Date;
+
+use crate::adapters::presenter::response::document_response::{DocumentParty, PrintDocument};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const ROW_HEIGHT: f32 = 7.0;
+/// Lowest baseline a table row may use; the page number sits below it.
+const BOTTOM: f32 = 25.0;
+const FIRST_PAGE_TABLE_TOP: f32 = 182.0;
+const NEXT_PAGE_TABLE_TOP: f32 = 265.0;
+
+const DESCRIPTION_X: f32 = MARGIN + 2.0;
+const DESCRIPTION_WIDTH: f32 = 103.0;
+const QUANTITY_RIGHT: f32 = 140.0;
+const UNIT_PRICE_RIGHT: f32 = 167.0;
+const AMOUNT_RIGHT: f32 = PAGE_WIDTH - MARGIN - 2.0;
+
+const PT_TO_MM: f32 = 0.352_778;
+
+/// Advance widths of the embedded font, used to right-align and truncate text.
+struct FontMetrics<'a> {
+    face: ttf_parser::Face<'a>,
+}
+impl<'a> FontMetrics<'a> {
+    fn parse(font: &'a [u8]) -> Result<Self, Box<dyn Error>> {
+        let face = ttf_parser::Face::parse(font, 0)?;
+
+        Ok(Self { face })
+    }
+
+    fn text_width(&self, text: &str, font_size: f32) -> f32 {
+        let units_per_em = self.face.units_per_em() as f32;
+        let advance: f32 = text
+            .chars()
+            .map(|c| {
+                let glyph = self.face.glyph_index(c).unwrap_or(ttf_parser::GlyphId(0));
+                self.face.glyph_hor_advance(glyph).unwrap_or(0) as f32
+            })
+            .sum();
+
+        advance / units_per_em * font_size * PT_TO_MM
+    }
+
+    /// Shortens `text` with an ellipsis until it fits in `width`.
+    fn truncate(&self, text: &str, font_size: f32, width: f32) -> String {
+        if self.text_width(text, font_size) <= width {
+            return text.to_string();
+        }
+        let mut truncated: String = text.to_string();
+        while !truncated.is_empty()
+            && self.text_width(&format!("{}…", truncated), font_size) > width
+        {
+            truncated.pop();
+        }
+
+        format!("{}…", truncated)
+    }
+}
+
+/// Splits `line_count` table rows over pages. The summary block must fit below the last row,
+/// so a trailing page without rows is added when it does not.
+fn paginate(
+    line_count: usize,
+    first_page_rows: usize,
+    next_page_rows: usize,
+    summary_rows: usize,
+) -> Vec<Range<usize>> {
+    let mut pages = Vec::new();
+    let mut start = 0;
+    let mut capacity = first_page_rows;
+    loop {
+        let end = usize::min(start + capacity, line_count);
+        pages.push(start..end);
+        if end == line_count {
+            if capacity - (end - start) < summary_rows {
+                pages.push(end..end);
+            }
+            break;
+        }
+        start = end;
+        capacity = next_page_rows;
+    }
+
+    pages
+}
+
+fn rows_between(top: f32) -> usize {
+    // One row is taken by the table header.
+    ((top - BOTTOM) / ROW_HEIGHT) as usize - 1
+}
+
+fn format_number(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let head = match digits.len() % 3 {
+        0 => 3,
+        n => n,
+    };
+    let mut groups = vec![&digits[..head]];
+    groups.extend(
+        digits.as_bytes()[head..]
+            .chunks(3)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap()),
+    );
+    let formatted = groups.join(",");
+
+    if value < 0 {
+        format!("-{}", formatted)
+    } else {
+        formatted
+    }
+}
+
+fn format_yen(value: i64) -> String {
+    format!("¥{}", format_number(value))
+}
+
+fn format_date(date: &Date) -> String {
+    format!(
+        "{}年{:02}月{:02}日",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+struct Page<'a> {
+    layer: PdfLayerReference,
+    font: &'a IndirectFontRef,
+    metrics: &'a FontMetrics<'a>,
+}
+impl<'a> Page<'a> {
+    fn text(&self, text: &str, font_size: f32, x: f32, y: f32) {
+        self.layer
+            .use_text(text, font_size, Mm(x), Mm(y), self.font);
+    }
+
+    fn text_right(&self, text: &str, font_size: f32, right: f32, y: f32) {
+        let width = self.metrics.text_width(text, font_size);
+        self.text(text, font_size, right - width, y);
+    }
+
+    fn text_center(&self, text: &str, font_size: f32, y: f32) {
+        let width = self.metrics.text_width(text, font_size);
+        self.text(text, font_size, (PAGE_WIDTH - width) / 2.0, y);
+    }
+
+    fn rule(&self, x1: f32, x2: f32, y: f32, thickness: f32) {
+        self.layer.set_outline_thickness(thickness);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(x1), Mm(y)), false),
+                (Point::new(Mm(x2), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+}
+
+fn render_party(
+    page: &Page,
+    party: &DocumentParty,
+    x: f32,
+    top: f32,
+    name_size: f32,
+    suffix: &str,
+) -> f32 {
+    let mut y = top;
+    if let Some(postal) = &party.postal {
+        page.text(postal, 9.0, x, y);
+        y -= 5.0;
+    }
+    page.text(&party.address, 9.0, x, y);
+    y -= 8.0;
+    page.text(&format!("{}{}", party.name, suffix), name_size, x, y);
+    y -= 2.0;
+    if let Some(registration_number) = &party.registration_number {
+        y -= 5.0;
+        page.text(&format!("登録番号: {}", registration_number), 9.0, x, y);
+    }
+
+    y
+}
+
+fn render_first_page_header(page: &Page, document: &PrintDocument) {
+    let top = PAGE_HEIGHT - MARGIN;
+    page.text_center(document.kind.title(), 20.0, top - 8.0);
+    page.text_right(
+        &format!("No. {}", document.number),
+        9.0,
+        AMOUNT_RIGHT,
+        top - 18.0,
+    );
+    page.text_right(
+        &format!("発行日 {}", format_date(&document.issued_on)),
+        9.0,
+        AMOUNT_RIGHT,
+        top - 23.0,
+    );
+
+    let recipient_bottom =
+        render_party(page, &document.recipient, MARGIN, top - 35.0, 14.0, " 御中");
+    page.rule(MARGIN, 110.0, recipient_bottom, 0.8);
+    if let Some(issuer) = &document.issuer {
+        render_party(page, issuer, 125.0, top - 35.0, 11.0, "");
+    }
+
+    let mut y = recipient_bottom - 10.0;
+    for note in &document.notes {
+        page.text(note, 9.0, MARGIN, y);
+        y -= 5.0;
+    }
+
+    let total_y = FIRST_PAGE_TABLE_TOP + 14.0;
+    page.text(
+        &format!("{}(税込)", document.kind.total_label()),
+        11.0,
+        MARGIN,
+        total_y,
+    );
+    page.text_right(&format_yen(document.total_amount), 16.0, 110.0, total_y);
+    page.rule(MARGIN, 110.0, total_y - 2.0, 0.8);
+}
+
+fn render_next_page_header(page: &Page, document: &PrintDocument) {
+    let top = PAGE_HEIGHT - MARGIN;
+    page.text(
+        &format!("{}(続き)", document.kind.title()),
+        12.0,
+        MARGIN,
+        top - 5.0,
+    );
+    page.text_right(
+        &format!("No. {}", document.number),
+        9.0,
+        AMOUNT_RIGHT,
+        top - 5.0,
+    );
+}
+
+/// Draws the table header and the rows, returning the baseline below the last row.
+fn render_table(page: &Page, document: &PrintDocument, rows: Range<usize>, top: f32) -> f32 {
+    let mut y = top;
+    page.rule(MARGIN, PAGE_WIDTH - MARGIN, y + 5.0, 0.8);
+    page.text("品名", 9.0, DESCRIPTION_X, y);
+    page.text_right("数量", 9.0, QUANTITY_RIGHT, y);
+    page.text_right("単価", 9.0, UNIT_PRICE_RIGHT, y);
+    page.text_right("金額", 9.0, AMOUNT_RIGHT, y);
+    page.rule(MARGIN, PAGE_WIDTH - MARGIN, y - 2.0, 0.5);
+
+    for line in &document.lines[rows] {
+        y -= ROW_HEIGHT;
+        let description = if line.is_reduced_tax_rate {
+            format!("{} ※", line.description)
+        } else {
+            line.description.clone()
+        };
+        let description = page.metrics.truncate(&description, 9.0, DESCRIPTION_WIDTH);
+        page.text(&description, 9.0, DESCRIPTION_X, y);
+        page.text_right(&format_number(line.quantity), 9.0, QUANTITY_RIGHT, y);
+        page.text_right(&format_yen(line.unit_price), 9.0, UNIT_PRICE_RIGHT, y);
+        page.text_right(&format_yen(line.amount), 9.0, AMOUNT_RIGHT, y);
+        page.rule(MARGIN, PAGE_WIDTH - MARGIN, y - 2.0, 0.2);
+    }
+
+    y
+}
+
+fn summary_rows(document: &PrintDocument) -> usize {
+    // 小計, one row per tax rate, 消費税, 合計 and the reduced rate note, after a blank row.
+    document.tax_summaries.len() + 5
+}
+
+fn render_summary(page: &Page, document: &PrintDocument, top: f32) {
+    let label_x = 110.0;
+    let mut y = top - ROW_HEIGHT * 2.0;
+    page.text("小計", 9.0, label_x, y);
+    page.text_right(&format_yen(document.subtotal), 9.0, AMOUNT_RIGHT, y);
+    for tax_summary in &document.tax_summaries {
+        y -= ROW_HEIGHT;
+        page.text(
+            &format!(
+                "{} {}",
+                tax_summary.label,
+                format_yen(tax_summary.taxable_amount)
+            ),
+            8.0,
+            label_x,
+            y,
+        );
+        page.text_right(
+            &format!("消費税 {}", format_yen(tax_summary.tax_amount)),
+            8.0,
+            AMOUNT_RIGHT,
+            y,
+        );
+    }
+    y -= ROW_HEIGHT;
+    page.text("消費税", 9.0, label_x, y);
+    page.text_right(&format_yen(document.tax_amount), 9.0, AMOUNT_RIGHT, y);
+    y -= ROW_HEIGHT;
+    page.rule(label_x, PAGE_WIDTH - MARGIN, y + 5.0, 0.5);
+    page.text("合計", 10.0, label_x, y);
+    page.text_right(&format_yen(document.total_amount), 10.0, AMOUNT_RIGHT, y);
+
+    if document.lines.iter().any(|line| line.is_reduced_tax_rate) {
+        page.text(
+            "※は軽減税率対象品目です",
+            8.0,
+            MARGIN,
+            top - ROW_HEIGHT * 2.0,
+        );
+    }
+}
+
+/// Renders `document` as an A4 PDF embedding `font`, which must cover Japanese.
+pub fn render(document: &PrintDocument, font: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    let metrics = FontMetrics::parse(font)?;
+    let title = format!("{} {}", document.kind.title(), document.number);
+    let (pdf, first_page, first_layer) =
+        PdfDocument::new(&title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+    let indirect_font = pdf.add_external_font(font)?;
+
+    let pages = paginate(
+        document.lines.len(),
+        rows_between(FIRST_PAGE_TABLE_TOP),
+        rows_between(NEXT_PAGE_TABLE_TOP),
+        summary_rows(document),
+    );
+    let page_count = pages.len();
+    for (index, rows) in pages.into_iter().enumerate() {
+        let layer = if index == 0 {
+            pdf.get_page(first_page).get_layer(first_layer)
+        } else {
+            add_page(&pdf)
+        };
+        let page = Page {
+            layer,
+            font: &indirect_font,
+            metrics: &metrics,
+        };
+
+        let table_top = if index == 0 {
+            render_first_page_header(&page, document);
+            FIRST_PAGE_TABLE_TOP
+        } else {
+            render_next_page_header(&page, document);
+            NEXT_PAGE_TABLE_TOP
+        };
+        // A trailing page that only carries the summary has no table.
+        let bottom = if rows.is_empty() && index > 0 {
+            table_top + ROW_HEIGHT
+        } else {
+            render_table(&page, document, rows, table_top)
+        };
+        if index + 1 == page_count {
+            render_summary(&page, document, bottom);
+        }
+        page.text_center(
+            &format!("{} / {}", index + 1, page_count),
+            8.0,
+            MARGIN - 5.0,
+        );
+    }
+
+    Ok(pdf.save_to_bytes()?)
+}
+
+fn add_page(pdf: &PdfDocumentReference) -> PdfLayerReference {
+    let (page, layer) = pdf.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+
+    pdf.get_page(page).get_layer(layer)
+}
+
+pub fn write(document: &PrintDocument, font: &[u8], path: &Path) -> Result<(), Box<dyn Error>> {
+    let bytes = render(document, font)?;
+    std::fs::write(path, bytes).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use crate::{
+        adapters::presenter::response::document_response::{
+            DocumentKind, DocumentLine, DocumentParty, DocumentTaxSummary, PrintDocument,
+        },
+        infrastructure::pdf::{
+            font::find_font,
+            renderer::{format_number, paginate, render},
+        },
+    };
+
+    #[test]
+    fn paginate_test() {
+        assert_eq!(paginate(0, 10, 30, 5), vec![0..0]);
+        assert_eq!(paginate(5, 10, 30, 5), vec![0..5]);
+        // The summary does not fit below the 6th row, so it moves to a page of its own.
+        assert_eq!(paginate(6, 10, 30, 5), vec![0..6, 6..6]);
+        assert_eq!(paginate(25, 10, 30, 5), vec![0..10, 10..25]);
+        assert_eq!(paginate(40, 10, 30, 5), vec![0..10, 10..40, 40..40]);
+    }
+
+    #[test]
+    fn format_number_test() {
+        assert_eq!(format_number(0), "0");
+        assert_eq!(format_number(999), "999");
+        assert_eq!(format_number(1000), "1,000");
+        assert_eq!(format_number(1234567), "1,234,567");
+        assert_eq!(format_number(-1234), "-1,234");
+    }
+
+    #[test]
+    fn render_test() {
+        // Rendering needs a real font; machines without one only run the layout tests above.
+        let font_path = match find_font(None) {
+            Some(font_path) => font_path,
+            None => return,
+        };
+        let font = std::fs::read(font_path).unwrap();
+        let party = DocumentParty {
+            name: String::from("sample.inc"),
+            postal: Some(String::from("〒123-4567")),
+            address: String::from("東京都"),
+            registration_number: None,
+        };
+        let lines = (0..60)
+            .map(|i| DocumentLine {
+                description: format!("商品{}", i),
+                quantity: 1,
+                unit_price: 1000,
+                amount: 1000,
+                is_reduced_tax_rate: i % 2 == 0,
+            })
+            .collect();
+        let document = PrintDocument {
+            kind: DocumentKind::Invoice,
+            number: String::from("INV-202307-0001"),
+            issued_on: Date::from_calendar_date(2023, Month::July, 5).unwrap(),
+            issuer: Some(party.clone()),
+            recipient: party,
+            notes: vec![],
+            lines,
+            tax_summaries: vec![DocumentTaxSummary {
+                label: String::from("10%対象"),
+                taxable_amount: 60000,
+                tax_amount: 6000,
+            }],
+            subtotal: 60000,
+            tax_amount: 6000,
+            total_amount: 66000,
+        };
+
+        let pdf = render(&document, &font).unwrap();
+
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+}
@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::infrastructure::database;
+
+const SETTINGS_FILE: &str = "settings.json";
+const DEFAULT_COMPANY: &str = "default";
+const DEFAULT_BACKUP_INTERVAL_HOURS: u32 = 24;
+const DEFAULT_BACKUP_RETENTION: u32 = 7;
+
+/// A company's bookkeeping, kept in a database file of its own.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Company {
+    name: String,
+    database_file: PathBuf,
+}
+impl Company {
+    pub fn new(name: String, database_file: PathBuf) -> Self {
+        Self {
+            name,
+            database_file,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn database_file(&self) -> &PathBuf {
+        &self.database_file
+    }
+}
+
+/// How often the open database is backed up automatically, and how many of those backups are
+/// kept. An interval of zero turns the automatic backups off.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BackupSchedule {
+    interval_hours: u32,
+    retention: u32,
+}
+impl Default for BackupSchedule {
+    fn default() -> Self {
+        Self {
+            interval_hours: DEFAULT_BACKUP_INTERVAL_HOURS,
+            retention: DEFAULT_BACKUP_RETENTION,
+        }
+    }
+}
+impl BackupSchedule {
+    pub fn new(interval_hours: u32, retention: u32) -> Self {
+        Self {
+            interval_hours,
+            retention,
+        }
+    }
+
+    pub fn interval_hours(&self) -> &u32 {
+        &self.interval_hours
+    }
+
+    pub fn retention(&self) -> &u32 {
+        &self.retention
+    }
+}
+
+/// The companies the app knows and the one opened at startup.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    current_company: String,
+    companies: Vec<Company>,
+    #[serde(default)]
+    backup_schedule: BackupSchedule,
+}
+impl Default for Settings {
+    /// Only the database of earlier versions, which kept a single company.
+    fn default() -> Self {
+        Self {
+            current_company: String::from(DEFAULT_COMPANY),
+            companies: vec![Company::new(
+                String::from(DEFAULT_COMPANY),
+                database::data_dir().join(database::DATABASE_FILE),
+            )],
+            backup_schedule: BackupSchedule::default(),
+        }
+    }
+}
+impl Settings {
+    /// Reads `path`, or returns the default settings when it does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let settings = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+        Ok(settings)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+
+        Ok(())
+    }
+
+    pub fn current_company(&self) -> &str {
+        &self.current_company
+    }
+
+    pub fn companies(&self) -> &Vec<Company> {
+        &self.companies
+    }
+
+    pub fn find_company(&self, name: &str) -> Option<&Company> {
+        self.companies.iter().find(|company| company.name == name)
+    }
+
+    pub fn backup_schedule(&self) -> &BackupSchedule {
+        &self.backup_schedule
+    }
+
+    pub fn set_backup_schedule(&mut self, backup_schedule: BackupSchedule) {
+        self.backup_schedule = backup_schedule;
+    }
+
+    /// The database to open at startup.
+    pub fn current_database_file(&self) -> PathBuf {
+        match self.find_company(&self.current_company) {
+            Some(company) => company.database_file.clone(),
+            None => database::data_dir().join(database::DATABASE_FILE),
+        }
+    }
+
+    /// Returns `false` when the name is already taken.
+    pub fn add_company(&mut self, company: Company) -> bool {
+        if self.find_company(&company.name).is_some() {
+            return false;
+        }
+        self.companies.push(company);
+
+        true
+    }
+
+    /// Returns `false` when there is no company with the name.
+    pub fn set_current_company(&mut self, name: &str) -> bool {
+        if self.find_company(name).is_none() {
+            return false;
+        }
+        self.current_company = name.to_string();
+
+        true
+    }
+}
+
+/// The settings live in the platform config directory, or next to the data when the data
+/// directory is overridden so that the whole installation can be moved together.
+pub(crate) fn settings_file() -> PathBuf {
+    if database::data_dir_override().is_some() {
+        return database::data_dir().join(SETTINGS_FILE);
+    }
+
+    match directories::ProjectDirs::from("", "", "sales-management-system") {
+        Some(dirs) => dirs.config_dir().join(SETTINGS_FILE),
+        None => database::data_dir().join(SETTINGS_FILE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::infrastructure::settings::{BackupSchedule, Company, Settings};
+
+    #[test]
+    fn settings_test() {
+        let path = std::env::temp_dir().join(format!(
+            "sales-management-system-settings-{}.json",
+            std::process::id()
+        ));
+        let mut settings = Settings::load(&path).unwrap();
+        assert_eq!(settings.current_company(), "default");
+
+        let company = Company::new(
+            String::from("株式会社サンプル"),
+            PathBuf::from("/tmp/sample.sqlite"),
+        );
+        assert!(settings.add_company(company.clone()));
+        assert!(!settings.add_company(company));
+        assert!(settings.set_current_company("株式会社サンプル"));
+        assert!(!settings.set_current_company("unknown"));
+        assert_eq!(settings.backup_schedule(), &BackupSchedule::new(24, 7));
+        settings.set_backup_schedule(BackupSchedule::new(12, 3));
+        settings.save(&path).unwrap();
+
+        let settings = Settings::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(settings.companies().len(), 2);
+        assert_eq!(settings.backup_schedule(), &BackupSchedule::new(12, 3));
+        assert_eq!(
+            settings.current_database_file(),
+            PathBuf::from("/tmp/sample.sqlite")
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    adapters::{
+        controller::{audit_log_controller, request::audit_log_request::SearchAuditLogRequest},
+        presenter::{
+            audit_log_presenter, error_presenter,
+            response::{audit_log_response::SearchAuditLogResponse, error_response::ErrorResponse},
+        },
+    },
+    application::usecase::audit_log::search_audit_log::SearchAuditLogUsecase,
+    domain::{error::DomainError, user::Permission},
+    infrastructure::tauri::state::{AppState, Repositories},
+};
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchAuditLogRequest,
+) -> Result<SearchAuditLogResponse, DomainError> {
+    let repository = repositories.audit_log.clone();
+    let usecase = SearchAuditLogUsecase::new(repository);
+    let output = audit_log_controller::search_audit_log(usecase, request).await?;
+
+    Ok(audit_log_presenter::search_audit_log(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_audit_log(
+    state: tauri::State<'_, AppState>,
+    request: SearchAuditLogRequest,
+) -> Result<SearchAuditLogResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewAuditLog)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    search(&repositories, request)
+        .await
+        .map_err(error_presenter::error)
+}
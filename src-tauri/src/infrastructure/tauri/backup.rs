@@ -0,0 +1,186 @@
+use crate::{
+    adapters::{
+        controller::request::backup_request::{RestoreBackupRequest, SaveBackupScheduleRequest},
+        presenter::{
+            error_presenter,
+            response::{
+                backup_response::{
+                    BackupForResponse, BackupScheduleForResponse, CreateBackupResponse,
+                    RestoreBackupResponse, SaveBackupScheduleResponse, SearchBackupResponse,
+                },
+                error_response::ErrorResponse,
+            },
+        },
+    },
+    domain::{error::DomainError, user::Permission},
+    infrastructure::{
+        backup::{self, BackupFile},
+        database,
+        settings::{self, BackupSchedule, Settings},
+        tauri::state::AppState,
+    },
+};
+
+fn load_settings() -> Result<Settings, DomainError> {
+    Settings::load(&settings::settings_file()).map_err(DomainError::unexpected)
+}
+
+fn backup_for_response(backup: &BackupFile) -> BackupForResponse {
+    BackupForResponse::new(
+        backup.file_name().clone(),
+        backup.label().clone(),
+        *backup.size(),
+        *backup.created_at(),
+    )
+}
+
+fn schedule_for_response(schedule: &BackupSchedule) -> BackupScheduleForResponse {
+    BackupScheduleForResponse::new(*schedule.interval_hours(), *schedule.retention())
+}
+
+/// Listing the backups reads the settings and the backup directory, which run on a blocking
+/// thread, off the async runtime.
+async fn search(state: &AppState) -> Result<SearchBackupResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    tauri::async_runtime::spawn_blocking(|| {
+        let settings = load_settings()?;
+        let backups = backup::list_backups(&settings.current_database_file())
+            .map_err(DomainError::unexpected)?
+            .iter()
+            .map(backup_for_response)
+            .collect();
+
+        Ok(SearchBackupResponse::new(
+            backups,
+            schedule_for_response(settings.backup_schedule()),
+        ))
+    })
+    .await
+    .map_err(DomainError::unexpected)?
+}
+
+#[tauri::command]
+pub(crate) async fn search_backup(
+    state: tauri::State<'_, AppState>,
+) -> Result<SearchBackupResponse, ErrorResponse> {
+    search(&state).await.map_err(error_presenter::error)
+}
+
+async fn create(state: &AppState) -> Result<CreateBackupResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    let settings = load_settings()?;
+    let backup = backup::backup_database(
+        &state.pool(),
+        &settings.current_database_file(),
+        backup::MANUAL_LABEL,
+    )
+    .await
+    .map_err(DomainError::unexpected)?;
+
+    Ok(CreateBackupResponse::new(backup_for_response(&backup)))
+}
+
+#[tauri::command]
+pub(crate) async fn create_backup(
+    state: tauri::State<'_, AppState>,
+) -> Result<CreateBackupResponse, ErrorResponse> {
+    create(&state).await.map_err(error_presenter::error)
+}
+
+/// Replaces the current database with the backup. The database is backed up first, and put
+/// back when the restored one cannot be opened.
+async fn restore(
+    state: &AppState,
+    request: RestoreBackupRequest,
+) -> Result<RestoreBackupResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    let database_file = load_settings()?.current_database_file();
+    let restored = backup::find_backup(&database_file, &request.file_name)
+        .map_err(DomainError::unexpected)?
+        .ok_or_else(|| DomainError::not_found("backup", &request.file_name))?;
+    backup::verify_backup(restored.path())
+        .await
+        .map_err(|error| DomainError::validation("file_name", error.to_string()))?;
+    let safety_backup =
+        backup::backup_database(&state.pool(), &database_file, backup::RESTORE_LABEL)
+            .await
+            .map_err(DomainError::unexpected)?;
+
+    state.pool().close().await;
+    let pool = match backup::replace_database_file(restored.path(), &database_file) {
+        Ok(()) => database::open_database(&database_file, state.audit_actor()).await,
+        Err(error) => Err(error),
+    };
+    let pool = match pool {
+        Ok(pool) => pool,
+        Err(error) => {
+            let message = format!(
+                "failed to restore the backup: {}; the database before restoring was saved to {}",
+                error,
+                safety_backup.path().display()
+            );
+            if backup::replace_database_file(safety_backup.path(), &database_file).is_ok() {
+                if let Ok(pool) = database::open_database(&database_file, state.audit_actor()).await
+                {
+                    state.replace_pool(pool);
+                }
+            }
+            return Err(DomainError::database_unavailable(message));
+        }
+    };
+    state.replace_pool(pool);
+
+    Ok(RestoreBackupResponse::new(
+        backup_for_response(&restored),
+        backup_for_response(&safety_backup),
+    ))
+}
+
+#[tauri::command]
+pub(crate) async fn restore_backup(
+    state: tauri::State<'_, AppState>,
+    request: RestoreBackupRequest,
+) -> Result<RestoreBackupResponse, ErrorResponse> {
+    restore(&state, request)
+        .await
+        .map_err(error_presenter::error)
+}
+
+async fn save_schedule(
+    state: &AppState,
+    request: SaveBackupScheduleRequest,
+) -> Result<SaveBackupScheduleResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    if request.interval_hours > 0 && request.retention == 0 {
+        return Err(DomainError::validation(
+            "retention",
+            "at least one automatic backup must be kept",
+        ));
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut settings = load_settings()?;
+        settings.set_backup_schedule(BackupSchedule::new(
+            request.interval_hours,
+            request.retention,
+        ));
+        settings
+            .save(&settings::settings_file())
+            .map_err(DomainError::unexpected)?;
+
+        Ok(SaveBackupScheduleResponse::new(schedule_for_response(
+            settings.backup_schedule(),
+        )))
+    })
+    .await
+    .map_err(DomainError::unexpected)?
+}
+
+#[tauri::command]
+pub(crate) async fn save_backup_schedule(
+    state: tauri::State<'_, AppState>,
+    request: SaveBackupScheduleRequest,
+) -> Result<SaveBackupScheduleResponse, ErrorResponse> {
+    save_schedule(&state, request)
+        .await
+        .map_err(error_presenter::error)
+}
@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    adapters::{
+        controller::request::company_database_request::{
+            CreateCompanyDatabaseRequest, OpenCompanyDatabaseRequest, SwitchCompanyDatabaseRequest,
+        },
+        presenter::{
+            error_presenter,
+            response::{
+                company_database_response::{
+                    CompanyDatabaseForResponse, CompanyDatabaseResponse,
+                    SearchCompanyDatabaseResponse,
+                },
+                error_response::ErrorResponse,
+            },
+        },
+    },
+    domain::{error::DomainError, user::Permission},
+    infrastructure::{
+        database,
+        settings::{self, Company, Settings},
+        tauri::state::AppState,
+    },
+};
+
+fn load_settings() -> Result<Settings, DomainError> {
+    Settings::load(&settings::settings_file()).map_err(DomainError::unexpected)
+}
+
+fn company_for_response(settings: &Settings, company: &Company) -> CompanyDatabaseForResponse {
+    CompanyDatabaseForResponse::new(
+        company.name().to_string(),
+        company.database_file().to_string_lossy().to_string(),
+        company.name() == settings.current_company(),
+    )
+}
+
+/// The first `companies/company-N.sqlite` in the data directory that does not exist yet.
+fn new_database_file() -> PathBuf {
+    let companies_dir = database::data_dir().join("companies");
+    (1..)
+        .map(|number| companies_dir.join(format!("company-{}.sqlite", number)))
+        .find(|database_file| !database_file.exists())
+        .unwrap()
+}
+
+/// Opens the company's database, records it as the current company and replaces the pool the
+/// commands use. The settings are left untouched when the database cannot be opened.
+async fn switch_to(
+    state: &AppState,
+    mut settings: Settings,
+    company: Company,
+) -> Result<CompanyDatabaseResponse, DomainError> {
+    let pool = database::open_database(company.database_file(), state.audit_actor())
+        .await
+        .map_err(DomainError::database_unavailable)?;
+    settings.add_company(company.clone());
+    settings.set_current_company(company.name());
+    settings
+        .save(&settings::settings_file())
+        .map_err(DomainError::unexpected)?;
+    state.replace_pool(pool).close().await;
+
+    Ok(CompanyDatabaseResponse::new(company_for_response(
+        &settings, &company,
+    )))
+}
+
+fn ensure_new_company(settings: &Settings, name: &str) -> Result<(), DomainError> {
+    if name.trim().is_empty() {
+        return Err(DomainError::validation("name", "company name is required"));
+    }
+    if settings.find_company(name).is_some() {
+        return Err(DomainError::conflict(format!(
+            "company already exists: name={}",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+async fn search(state: &AppState) -> Result<SearchCompanyDatabaseResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    let settings = tauri::async_runtime::spawn_blocking(load_settings)
+        .await
+        .map_err(DomainError::unexpected)??;
+    let companies = settings
+        .companies()
+        .iter()
+        .map(|company| company_for_response(&settings, company))
+        .collect();
+
+    Ok(SearchCompanyDatabaseResponse::new(companies))
+}
+
+#[tauri::command]
+pub(crate) async fn search_company_database(
+    state: tauri::State<'_, AppState>,
+) -> Result<SearchCompanyDatabaseResponse, ErrorResponse> {
+    search(&state).await.map_err(error_presenter::error)
+}
+
+async fn create(
+    state: &AppState,
+    request: CreateCompanyDatabaseRequest,
+) -> Result<CompanyDatabaseResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    let settings = load_settings()?;
+    ensure_new_company(&settings, &request.name)?;
+    let database_file = match request.database_file {
+        Some(database_file) => PathBuf::from(database_file),
+        None => new_database_file(),
+    };
+    if database_file.exists() {
+        return Err(DomainError::validation(
+            "database_file",
+            format!(
+                "database file already exists; open it instead: database_file={}",
+                database_file.display()
+            ),
+        ));
+    }
+
+    switch_to(state, settings, Company::new(request.name, database_file)).await
+}
+
+#[tauri::command]
+pub(crate) async fn create_company_database(
+    state: tauri::State<'_, AppState>,
+    request: CreateCompanyDatabaseRequest,
+) -> Result<CompanyDatabaseResponse, ErrorResponse> {
+    create(&state, request)
+        .await
+        .map_err(error_presenter::error)
+}
+
+async fn open(
+    state: &AppState,
+    request: OpenCompanyDatabaseRequest,
+) -> Result<CompanyDatabaseResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    let settings = load_settings()?;
+    ensure_new_company(&settings, &request.name)?;
+    let database_file = Path::new(&request.database_file);
+    if !database_file.is_file() {
+        return Err(DomainError::not_found(
+            "database_file",
+            database_file.display(),
+        ));
+    }
+
+    switch_to(
+        state,
+        settings,
+        Company::new(request.name, database_file.to_path_buf()),
+    )
+    .await
+}
+
+#[tauri::command]
+pub(crate) async fn open_company_database(
+    state: tauri::State<'_, AppState>,
+    request: OpenCompanyDatabaseRequest,
+) -> Result<CompanyDatabaseResponse, ErrorResponse> {
+    open(&state, request).await.map_err(error_presenter::error)
+}
+
+async fn switch(
+    state: &AppState,
+    request: SwitchCompanyDatabaseRequest,
+) -> Result<CompanyDatabaseResponse, DomainError> {
+    state.authorize(Permission::ManageDatabase)?;
+    let settings = load_settings()?;
+    let company = settings
+        .find_company(&request.name)
+        .cloned()
+        .ok_or_else(|| DomainError::not_found("company", &request.name))?;
+
+    switch_to(state, settings, company).await
+}
+
+#[tauri::command]
+pub(crate) async fn switch_company_database(
+    state: tauri::State<'_, AppState>,
+    request: SwitchCompanyDatabaseRequest,
+) -> Result<CompanyDatabaseResponse, ErrorResponse> {
+    switch(&state, request)
+        .await
+        .map_err(error_presenter::error)
+}
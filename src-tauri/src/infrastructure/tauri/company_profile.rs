@@ -0,0 +1,64 @@
+use crate::{
+    adapters::{
+        controller::{
+            company_profile_controller, request::company_profile_request::SaveCompanyProfileRequest,
+        },
+        presenter::{
+            company_profile_presenter,
+            response::company_profile_response::{
+                FindCompanyProfileResponse, SaveCompanyProfileResponse,
+            },
+        },
+    },
+    application::usecase::company_profile::{
+        find_company_profile::FindCompanyProfileUsecase,
+        save_company_profile::SaveCompanyProfileUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn find(repositories: &Repositories) -> Result<FindCompanyProfileResponse, DomainError> {
+    let repository = repositories.company_profile.clone();
+    let usecase = FindCompanyProfileUsecase::new(repository);
+    let output = company_profile_controller::find_company_profile(usecase).await?;
+
+    Ok(company_profile_presenter::find_company_profile(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_company_profile(
+    state: tauri::State<'_, AppState>,
+) -> Result<FindCompanyProfileResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find(&repositories).await.map_err(error_presenter::error)
+}
+
+async fn save(
+    repositories: &Repositories,
+    request: SaveCompanyProfileRequest,
+) -> Result<SaveCompanyProfileResponse, DomainError> {
+    let repository = repositories.company_profile.clone();
+    let save_company_profile_usecase = SaveCompanyProfileUsecase::new(repository.clone());
+    company_profile_controller::save_company_profile(save_company_profile_usecase, request).await?;
+
+    let find_company_profile_usecase = FindCompanyProfileUsecase::new(repository);
+    let company_profile = find_company_profile_usecase.find().await?.company_profile;
+
+    Ok(company_profile_presenter::save_company_profile(
+        company_profile,
+    ))
+}
+
+#[tauri::command]
+pub(crate) async fn save_company_profile(
+    state: tauri::State<'_, AppState>,
+    request: SaveCompanyProfileRequest,
+) -> Result<SaveCompanyProfileResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditCompanyProfile)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    save(&repositories, request).await.map_err(error_presenter::error)
+}
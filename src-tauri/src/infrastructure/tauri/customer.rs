@@ -1,36 +1,81 @@
-use std::{rc::Rc, error::Error};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+use crate::domain::{error::DomainError, user::Permission};
 
-use sqlx::SqlitePool;
+use crate::infrastructure::tauri::state::{AppState, Repositories};
 
 use crate::{
     adapters::{
         controller::{
             request::customer_request::{
                 CreateCustomerRequest,
-                UpdateCustomerRequest
+                UpdateCustomerRequest,
+                RestoreCustomerRequest,
+                FindByIDCustomerRequest,
+                SearchCustomerRequest,
+                DeleteCustomerRequest
             },
             customer_controller
         },
         presenter::{
             response::customer_response::{
                 CreateCustomerResponse,
-                UpdateCustomerResponse
+                UpdateCustomerResponse,
+                RestoreCustomerResponse,
+                FindByIDCustomerResponse,
+                SearchCustomerResponse,
+                DeleteCustomerResponse
             },
             customer_presenter
-        },
-        gateway::customer_repository::SqliteCustomerRespository
+        }
     },
     application::{
         usecase::customer::{
             create_customer::CreateCustomerUsecase,
             find_by_id_customer::FindByIDCustomerUsecase,
-            update_customer::UpdateCustomerUsecase
+            update_customer::UpdateCustomerUsecase,
+            restore_customer::RestoreCustomerUsecase,
+            search_customer::SearchCustomerUsecase,
+            delete_customer::DeleteCustomerUsecase
         }
     }
 };
 
-async fn create(pool: SqlitePool, request: CreateCustomerRequest) -> Result<CreateCustomerResponse, Rc<dyn Error>> {
-    let repository = Rc::new(SqliteCustomerRespository::new(pool));
+async fn find_by_id(repositories: &Repositories, request: FindByIDCustomerRequest) -> Result<FindByIDCustomerResponse, DomainError> {
+    let repository = repositories.customer.clone();
+    let find_by_id_customer_usecase = FindByIDCustomerUsecase::new(repository);
+    let customer = customer_controller::find_by_id(find_by_id_customer_usecase, request).await?;
+
+    Ok(customer_presenter::find_by_id_customer(customer))
+}
+
+#[tauri::command]
+pub(crate) async fn find_by_id_customer(state: tauri::State<'_, AppState>, request: FindByIDCustomerRequest) -> Result<FindByIDCustomerResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    find_by_id(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn search(repositories: &Repositories, request: SearchCustomerRequest) -> Result<SearchCustomerResponse, DomainError> {
+    let repository = repositories.customer.clone();
+    let search_customer_usecase = SearchCustomerUsecase::new(repository);
+    let output = customer_controller::search(search_customer_usecase, request).await?;
+
+    Ok(customer_presenter::search_customer(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_customer(state: tauri::State<'_, AppState>, request: SearchCustomerRequest) -> Result<SearchCustomerResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn create(repositories: &Repositories, request: CreateCustomerRequest) -> Result<CreateCustomerResponse, DomainError> {
+    let repository = repositories.customer.clone();
     let create_customer_usecase = CreateCustomerUsecase::new(repository.clone());
     let output = customer_controller::create(create_customer_usecase, request).await?;
 
@@ -41,15 +86,18 @@ async fn create(pool: SqlitePool, request: CreateCustomerRequest) -> Result<Crea
 }
 
 #[tauri::command]
-pub(crate) fn create_customer(state: tauri::State<'_, SqlitePool>, request: CreateCustomerRequest) -> Result<CreateCustomerResponse, String> {
-    let pool = state.inner().clone();
-    let result = tauri::async_runtime::block_on(create(pool, request)).map_err(|e| e.to_string());
+pub(crate) async fn create_customer(state: tauri::State<'_, AppState>, request: CreateCustomerRequest) -> Result<CreateCustomerResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let result = create(&repositories, request).await.map_err(error_presenter::error);
 
     result
 }
 
-async fn update(pool: SqlitePool, request: UpdateCustomerRequest) -> Result<UpdateCustomerResponse, Rc<dyn Error>> {
-    let repository = Rc::new(SqliteCustomerRespository::new(pool));
+async fn update(repositories: &Repositories, request: UpdateCustomerRequest) -> Result<UpdateCustomerResponse, DomainError> {
+    let repository = repositories.customer.clone();
     let update_customer_usecase = UpdateCustomerUsecase::new(repository.clone());
     let output = customer_controller::update(update_customer_usecase, request).await?;
 
@@ -60,9 +108,46 @@ async fn update(pool: SqlitePool, request: UpdateCustomerRequest) -> Result<Upda
 }
 
 #[tauri::command]
-pub(crate) fn update_customer(state: tauri::State<'_, SqlitePool>, request: UpdateCustomerRequest) -> Result<UpdateCustomerResponse, String> {
-    let pool = state.inner().clone();
-    let result = tauri::async_runtime::block_on(update(pool, request)).map_err(|e| e.to_string());
+pub(crate) async fn update_customer(state: tauri::State<'_, AppState>, request: UpdateCustomerRequest) -> Result<UpdateCustomerResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let result = update(&repositories, request).await.map_err(error_presenter::error);
 
     result
 }
+
+async fn delete(repositories: &Repositories, request: DeleteCustomerRequest) -> Result<DeleteCustomerResponse, DomainError> {
+    let repository = repositories.customer.clone();
+    let delete_customer_usecase = DeleteCustomerUsecase::new(repository);
+    let output = customer_controller::delete(delete_customer_usecase, request).await?;
+
+    Ok(customer_presenter::delete_customer(output.result()))
+}
+
+#[tauri::command]
+pub(crate) async fn delete_customer(state: tauri::State<'_, AppState>, request: DeleteCustomerRequest) -> Result<DeleteCustomerResponse, ErrorResponse> {
+    state
+        .authorize(Permission::DeleteMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    delete(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn restore(repositories: &Repositories, request: RestoreCustomerRequest) -> Result<RestoreCustomerResponse, DomainError> {
+    let repository = repositories.customer.clone();
+    let restore_customer_usecase = RestoreCustomerUsecase::new(repository);
+    let output = customer_controller::restore(restore_customer_usecase, request).await?;
+
+    Ok(customer_presenter::restore_customer(output.result()))
+}
+
+#[tauri::command]
+pub(crate) async fn restore_customer(state: tauri::State<'_, AppState>, request: RestoreCustomerRequest) -> Result<RestoreCustomerResponse, ErrorResponse> {
+    state
+        .authorize(Permission::DeleteMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    restore(&repositories, request).await.map_err(error_presenter::error)
+}
@@ -0,0 +1,129 @@
+use crate::{
+    adapters::{
+        controller::{
+            document_controller,
+            request::document_request::{
+                ExportDeliverySlipPdfRequest, ExportInvoicePdfRequest, ExportQuotationPdfRequest,
+            },
+        },
+        presenter::{
+            document_presenter,
+            response::document_response::{ExportPdfResponse, PrintDocument},
+        },
+    },
+    application::usecase::document::{
+        find_delivery_slip_document::FindDeliverySlipDocumentUsecase,
+        find_invoice_document::FindInvoiceDocumentUsecase,
+        find_quotation_document::FindQuotationDocumentUsecase,
+    },
+    domain::error::DomainError,
+    infrastructure::{
+        database,
+        pdf::{font, renderer},
+    },
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use std::path::PathBuf;
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+/// Writes `document` to `path`, adding a `.pdf` extension when there is none, and returns
+/// the path actually written. Loading the font, rendering and writing run on a blocking
+/// thread, off the async runtime.
+pub(crate) async fn write_pdf(document: PrintDocument, path: &str) -> Result<String, DomainError> {
+    let mut path = PathBuf::from(path);
+    if path.extension().is_none() {
+        path.set_extension("pdf");
+    }
+    tauri::async_runtime::spawn_blocking(move || {
+        let font = font::load_font(Some(&database::data_dir().join("fonts")))
+            .map_err(DomainError::unexpected)?;
+        renderer::write(&document, &font, &path).map_err(DomainError::unexpected)?;
+
+        Ok(path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(DomainError::unexpected)?
+}
+
+async fn export_invoice(
+    repositories: &Repositories,
+    request: ExportInvoicePdfRequest,
+) -> Result<ExportPdfResponse, DomainError> {
+    let repository = repositories.invoice.clone();
+    let customer_repository = repositories.customer.clone();
+    let usecase = FindInvoiceDocumentUsecase::new(repository, customer_repository);
+    let output = document_controller::find_invoice_document(usecase, &request).await?;
+    let document = document_presenter::invoice_document(output);
+    let path = write_pdf(document, &request.path).await?;
+
+    Ok(document_presenter::export_pdf(path))
+}
+
+#[tauri::command]
+pub(crate) async fn export_invoice_pdf(
+    state: tauri::State<'_, AppState>,
+    request: ExportInvoicePdfRequest,
+) -> Result<ExportPdfResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    export_invoice(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn export_delivery_slip(
+    repositories: &Repositories,
+    request: ExportDeliverySlipPdfRequest,
+) -> Result<ExportPdfResponse, DomainError> {
+    let repository = repositories.sales_order.clone();
+    let product_repository = repositories.product.clone();
+    let customer_repository = repositories.customer.clone();
+    let company_profile_repository = repositories.company_profile.clone();
+    let usecase = FindDeliverySlipDocumentUsecase::new(
+        repository,
+        product_repository,
+        customer_repository,
+        company_profile_repository,
+    );
+    let output = document_controller::find_delivery_slip_document(usecase, &request).await?;
+    let document = document_presenter::delivery_slip_document(output);
+    let path = write_pdf(document, &request.path).await?;
+
+    Ok(document_presenter::export_pdf(path))
+}
+
+#[tauri::command]
+pub(crate) async fn export_delivery_slip_pdf(
+    state: tauri::State<'_, AppState>,
+    request: ExportDeliverySlipPdfRequest,
+) -> Result<ExportPdfResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    export_delivery_slip(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn export_quotation(
+    repositories: &Repositories,
+    request: ExportQuotationPdfRequest,
+) -> Result<ExportPdfResponse, DomainError> {
+    let repository = repositories.quotation.clone();
+    let product_repository = repositories.product.clone();
+    let customer_repository = repositories.customer.clone();
+    let company_profile_repository = repositories.company_profile.clone();
+    let usecase = FindQuotationDocumentUsecase::new(
+        repository,
+        product_repository,
+        customer_repository,
+        company_profile_repository,
+    );
+    let output = document_controller::find_quotation_document(usecase, &request).await?;
+    let document = document_presenter::quotation_document(output);
+    let path = write_pdf(document, &request.path).await?;
+
+    Ok(document_presenter::export_pdf(path))
+}
+
+#[tauri::command]
+pub(crate) async fn export_quotation_pdf(
+    state: tauri::State<'_, AppState>,
+    request: ExportQuotationPdfRequest,
+) -> Result<ExportPdfResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    export_quotation(&repositories, request).await.map_err(error_presenter::error)
+}
@@ -0,0 +1,310 @@
+use std::path::PathBuf;
+
+use crate::{
+    adapters::{
+        controller::{
+            export_controller,
+            request::export_request::{
+                ExportCustomerRequest, ExportFormat, ExportInvoiceRequest, ExportProductRequest,
+                ExportSalesOrderRequest,
+            },
+        },
+        presenter::{
+            error_presenter, export_presenter,
+            response::{
+                error_response::ErrorResponse,
+                export_response::{ExportCell, ExportResponse},
+            },
+        },
+    },
+    application::usecase::{
+        customer::search_customer::SearchCustomerUsecase,
+        invoice::search_invoice::SearchInvoiceUsecase,
+        product::search_product::SearchProductUsecase,
+        sales_order::search_sales_order::SearchSalesOrderUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+    infrastructure::{
+        export::{self, SheetWriter},
+        tauri::state::{AppState, Repositories},
+    },
+};
+
+/// Records read from the database at a time, so that large exports are never held in memory.
+/// Each page starts after the last id read, so later pages cost no more than the first.
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+/// Adds the format's extension to `path` when it has none.
+fn export_path(path: &str, format: ExportFormat) -> PathBuf {
+    let mut path = PathBuf::from(path);
+    if path.extension().is_none() {
+        path.set_extension(format.extension());
+    }
+
+    path
+}
+
+fn write_row(writer: &mut dyn SheetWriter, row: &[ExportCell]) -> Result<(), DomainError> {
+    writer.write_row(row).map_err(DomainError::unexpected)
+}
+
+/// Creates the file and writes the header.
+fn create_sheet(
+    path: &str,
+    format: ExportFormat,
+    sheet_name: &str,
+    header: &[ExportCell],
+) -> Result<(PathBuf, Box<dyn SheetWriter>), DomainError> {
+    let path = export_path(path, format);
+    let mut writer =
+        export::create_writer(&path, format, sheet_name).map_err(DomainError::unexpected)?;
+    if let Err(error) = write_row(writer.as_mut(), header) {
+        let _ = std::fs::remove_file(&path);
+        return Err(error);
+    }
+
+    Ok((path, writer))
+}
+
+/// Completes the file once every row is written. A failed export leaves no file behind.
+fn finish_sheet(
+    path: PathBuf,
+    writer: Box<dyn SheetWriter>,
+    rows: Result<i64, DomainError>,
+) -> Result<ExportResponse, DomainError> {
+    let result = rows.and_then(|rows| {
+        writer.finish().map_err(DomainError::unexpected)?;
+        Ok(rows)
+    });
+    match result {
+        Ok(rows) => Ok(export_presenter::export(
+            path.to_string_lossy().to_string(),
+            rows,
+        )),
+        Err(error) => {
+            let _ = std::fs::remove_file(&path);
+            Err(error)
+        }
+    }
+}
+
+async fn write_products(
+    repositories: &Repositories,
+    request: &ExportProductRequest,
+    writer: &mut dyn SheetWriter,
+) -> Result<i64, DomainError> {
+    let usecase = SearchProductUsecase::new(repositories.product.clone());
+    let mut after_id = 0;
+    let mut rows = 0;
+    loop {
+        let output = export_controller::search_product_page(
+            &usecase,
+            &request.filter,
+            after_id,
+            EXPORT_PAGE_SIZE,
+        )
+        .await?;
+        for product in &output.products {
+            write_row(writer, &export_presenter::product_row(product))?;
+            after_id = *product.id();
+        }
+        rows += output.products.len() as i64;
+        if (output.products.len() as i64) < EXPORT_PAGE_SIZE {
+            return Ok(rows);
+        }
+    }
+}
+
+async fn export_products(
+    repositories: &Repositories,
+    request: ExportProductRequest,
+) -> Result<ExportResponse, DomainError> {
+    let (path, mut writer) = create_sheet(
+        &request.path,
+        request.format,
+        "products",
+        &export_presenter::product_header(),
+    )?;
+    let rows = write_products(repositories, &request, writer.as_mut()).await;
+
+    finish_sheet(path, writer, rows)
+}
+
+#[tauri::command]
+pub(crate) async fn export_product(
+    state: tauri::State<'_, AppState>,
+    request: ExportProductRequest,
+) -> Result<ExportResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    export_products(&repositories, request)
+        .await
+        .map_err(error_presenter::error)
+}
+
+async fn write_customers(
+    repositories: &Repositories,
+    request: &ExportCustomerRequest,
+    writer: &mut dyn SheetWriter,
+) -> Result<i64, DomainError> {
+    let usecase = SearchCustomerUsecase::new(repositories.customer.clone());
+    let mut after_id = 0;
+    let mut rows = 0;
+    loop {
+        let output = export_controller::search_customer_page(
+            &usecase,
+            &request.filter,
+            after_id,
+            EXPORT_PAGE_SIZE,
+        )
+        .await?;
+        for customer in &output.customers {
+            write_row(writer, &export_presenter::customer_row(customer))?;
+            after_id = *customer.id();
+        }
+        rows += output.customers.len() as i64;
+        if (output.customers.len() as i64) < EXPORT_PAGE_SIZE {
+            return Ok(rows);
+        }
+    }
+}
+
+async fn export_customers(
+    repositories: &Repositories,
+    request: ExportCustomerRequest,
+) -> Result<ExportResponse, DomainError> {
+    let (path, mut writer) = create_sheet(
+        &request.path,
+        request.format,
+        "customers",
+        &export_presenter::customer_header(),
+    )?;
+    let rows = write_customers(repositories, &request, writer.as_mut()).await;
+
+    finish_sheet(path, writer, rows)
+}
+
+#[tauri::command]
+pub(crate) async fn export_customer(
+    state: tauri::State<'_, AppState>,
+    request: ExportCustomerRequest,
+) -> Result<ExportResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    export_customers(&repositories, request)
+        .await
+        .map_err(error_presenter::error)
+}
+
+/// Counts the rows written, one per order line.
+async fn write_sales_orders(
+    repositories: &Repositories,
+    request: &ExportSalesOrderRequest,
+    writer: &mut dyn SheetWriter,
+) -> Result<i64, DomainError> {
+    let usecase = SearchSalesOrderUsecase::new(repositories.sales_order.clone());
+    let mut after_id = 0;
+    let mut rows = 0;
+    loop {
+        let output = export_controller::search_sales_order_page(
+            &usecase,
+            &request.filter,
+            after_id,
+            EXPORT_PAGE_SIZE,
+        )
+        .await?;
+        for sales_order in &output.sales_orders {
+            for row in export_presenter::sales_order_rows(sales_order) {
+                write_row(writer, &row)?;
+                rows += 1;
+            }
+            after_id = *sales_order.id();
+        }
+        if (output.sales_orders.len() as i64) < EXPORT_PAGE_SIZE {
+            return Ok(rows);
+        }
+    }
+}
+
+async fn export_sales_orders(
+    repositories: &Repositories,
+    request: ExportSalesOrderRequest,
+) -> Result<ExportResponse, DomainError> {
+    let (path, mut writer) = create_sheet(
+        &request.path,
+        request.format,
+        "sales_orders",
+        &export_presenter::sales_order_header(),
+    )?;
+    let rows = write_sales_orders(repositories, &request, writer.as_mut()).await;
+
+    finish_sheet(path, writer, rows)
+}
+
+#[tauri::command]
+pub(crate) async fn export_sales_order(
+    state: tauri::State<'_, AppState>,
+    request: ExportSalesOrderRequest,
+) -> Result<ExportResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    export_sales_orders(&repositories, request)
+        .await
+        .map_err(error_presenter::error)
+}
+
+async fn write_invoices(
+    repositories: &Repositories,
+    request: &ExportInvoiceRequest,
+    writer: &mut dyn SheetWriter,
+) -> Result<i64, DomainError> {
+    let usecase = SearchInvoiceUsecase::new(repositories.invoice.clone());
+    let mut after_id = 0;
+    let mut rows = 0;
+    loop {
+        let output = export_controller::search_invoice_page(
+            &usecase,
+            &request.filter,
+            after_id,
+            EXPORT_PAGE_SIZE,
+        )
+        .await?;
+        for invoice in &output.invoices {
+            write_row(writer, &export_presenter::invoice_row(invoice))?;
+            after_id = *invoice.id();
+        }
+        rows += output.invoices.len() as i64;
+        if (output.invoices.len() as i64) < EXPORT_PAGE_SIZE {
+            return Ok(rows);
+        }
+    }
+}
+
+async fn export_invoices(
+    repositories: &Repositories,
+    request: ExportInvoiceRequest,
+) -> Result<ExportResponse, DomainError> {
+    let (path, mut writer) = create_sheet(
+        &request.path,
+        request.format,
+        "invoices",
+        &export_presenter::invoice_header(),
+    )?;
+    let rows = write_invoices(repositories, &request, writer.as_mut()).await;
+
+    finish_sheet(path, writer, rows)
+}
+
+#[tauri::command]
+pub(crate) async fn export_invoice(
+    state: tauri::State<'_, AppState>,
+    request: ExportInvoiceRequest,
+) -> Result<ExportResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    export_invoices(&repositories, request)
+        .await
+        .map_err(error_presenter::error)
+}
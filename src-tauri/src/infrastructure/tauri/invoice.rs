@@ -0,0 +1,93 @@
+use crate::{
+    adapters::{
+        controller::{
+            invoice_controller,
+            request::invoice_request::{
+                FindByIDInvoiceRequest, IssueInvoiceRequest, SearchInvoiceRequest,
+            },
+        },
+        presenter::{
+            invoice_presenter,
+            response::invoice_response::{
+                FindByIDInvoiceResponse, IssueInvoiceResponse, SearchInvoiceResponse,
+            },
+        },
+    },
+    application::usecase::invoice::{
+        find_by_id_invoice::FindByIDInvoiceUsecase, issue_invoice::IssueInvoiceUsecase,
+        search_invoice::SearchInvoiceUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn find_by_id(
+    repositories: &Repositories,
+    request: FindByIDInvoiceRequest,
+) -> Result<FindByIDInvoiceResponse, DomainError> {
+    let repository = repositories.invoice.clone();
+    let usecase = FindByIDInvoiceUsecase::new(repository);
+    let output = invoice_controller::find_by_id(usecase, request).await?;
+
+    Ok(invoice_presenter::find_by_id(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_by_id_invoice(
+    state: tauri::State<'_, AppState>,
+    request: FindByIDInvoiceRequest,
+) -> Result<FindByIDInvoiceResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find_by_id(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchInvoiceRequest,
+) -> Result<SearchInvoiceResponse, DomainError> {
+    let repository = repositories.invoice.clone();
+    let usecase = SearchInvoiceUsecase::new(repository);
+    let output = invoice_controller::search_invoice(usecase, request).await?;
+
+    Ok(invoice_presenter::search_invoice(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_invoice(
+    state: tauri::State<'_, AppState>,
+    request: SearchInvoiceRequest,
+) -> Result<SearchInvoiceResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn issue(
+    repositories: &Repositories,
+    request: IssueInvoiceRequest,
+) -> Result<IssueInvoiceResponse, DomainError> {
+    let repository = repositories.invoice.clone();
+    let unit_of_work = repositories.unit_of_work.clone();
+    let issue_invoice_usecase = IssueInvoiceUsecase::new(unit_of_work);
+    let output = invoice_controller::issue_invoice(issue_invoice_usecase, request).await?;
+
+    let find_by_id_invoice_usecase = FindByIDInvoiceUsecase::new(repository);
+    let invoice = find_by_id_invoice_usecase
+        .find_by_id(output.result().invoice_id())
+        .await?
+        .invoice;
+
+    Ok(invoice_presenter::issue_invoice(invoice))
+}
+
+#[tauri::command]
+pub(crate) async fn issue_invoice(
+    state: tauri::State<'_, AppState>,
+    request: IssueInvoiceRequest,
+) -> Result<IssueInvoiceResponse, ErrorResponse> {
+    state
+        .authorize(Permission::IssueInvoices)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    issue(&repositories, request).await.map_err(error_presenter::error)
+}
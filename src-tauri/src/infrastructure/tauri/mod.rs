@@ -1,3 +1,17 @@
+pub mod audit_log;
+pub mod backup;
+pub mod company_database;
+pub mod company_profile;
 pub mod customer;
+pub mod document;
+pub mod export;
+pub mod invoice;
+pub mod price_list;
 pub mod product;
+pub mod quotation;
+pub mod receivable;
+pub mod reorder_alert;
+pub mod sales_order;
+pub mod stock_movement;
 pub mod state;
+pub mod user;
@@ -0,0 +1,170 @@
+use crate::{
+    adapters::{
+        controller::{
+            price_list_controller,
+            request::price_list_request::{
+                CreatePriceListRequest, DeletePriceListRequest, FindByIDPriceListRequest,
+                ResolvePriceRequest, SearchPriceListRequest, UpdatePriceListRequest,
+            },
+        },
+        presenter::{
+            price_list_presenter,
+            response::price_list_response::{
+                CreatePriceListResponse, DeletePriceListResponse, FindByIDPriceListResponse,
+                ResolvePriceResponse, SearchPriceListResponse, UpdatePriceListResponse,
+            },
+        },
+    },
+    application::usecase::price_list::{
+        create_price_list::CreatePriceListUsecase, delete_price_list::DeletePriceListUsecase,
+        find_by_id_price_list::FindByIDPriceListUsecase, resolve_price::ResolvePriceUsecase,
+        search_price_list::SearchPriceListUsecase, update_price_list::UpdatePriceListUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn find_by_id(
+    repositories: &Repositories,
+    request: FindByIDPriceListRequest,
+) -> Result<FindByIDPriceListResponse, DomainError> {
+    let repository = repositories.price_list.clone();
+    let usecase = FindByIDPriceListUsecase::new(repository);
+    let output = price_list_controller::find_by_id(usecase, request).await?;
+
+    Ok(price_list_presenter::find_by_id(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_by_id_price_list(
+    state: tauri::State<'_, AppState>,
+    request: FindByIDPriceListRequest,
+) -> Result<FindByIDPriceListResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find_by_id(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchPriceListRequest,
+) -> Result<SearchPriceListResponse, DomainError> {
+    let repository = repositories.price_list.clone();
+    let usecase = SearchPriceListUsecase::new(repository);
+    let output = price_list_controller::search_price_list(usecase, request).await?;
+
+    Ok(price_list_presenter::search_price_list(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_price_list(
+    state: tauri::State<'_, AppState>,
+    request: SearchPriceListRequest,
+) -> Result<SearchPriceListResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn create(
+    repositories: &Repositories,
+    request: CreatePriceListRequest,
+) -> Result<CreatePriceListResponse, DomainError> {
+    let repository = repositories.price_list.clone();
+    let create_price_list_usecase = CreatePriceListUsecase::new(repository.clone());
+    let output =
+        price_list_controller::create_price_list(create_price_list_usecase, request).await?;
+
+    let find_by_id_price_list_usecase = FindByIDPriceListUsecase::new(repository);
+    let price_list = find_by_id_price_list_usecase
+        .find_by_id(output.result().price_list_id())
+        .await?
+        .price_list;
+
+    Ok(price_list_presenter::create_price_list(price_list))
+}
+
+#[tauri::command]
+pub(crate) async fn create_price_list(
+    state: tauri::State<'_, AppState>,
+    request: CreatePriceListRequest,
+) -> Result<CreatePriceListResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditPriceLists)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    create(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn update(
+    repositories: &Repositories,
+    request: UpdatePriceListRequest,
+) -> Result<UpdatePriceListResponse, DomainError> {
+    let repository = repositories.price_list.clone();
+    let update_price_list_usecase = UpdatePriceListUsecase::new(repository.clone());
+    let output =
+        price_list_controller::update_price_list(update_price_list_usecase, request).await?;
+
+    let find_by_id_price_list_usecase = FindByIDPriceListUsecase::new(repository);
+    let price_list = find_by_id_price_list_usecase
+        .find_by_id(output.result().price_list_id())
+        .await?
+        .price_list;
+
+    Ok(price_list_presenter::update_price_list(price_list))
+}
+
+#[tauri::command]
+pub(crate) async fn update_price_list(
+    state: tauri::State<'_, AppState>,
+    request: UpdatePriceListRequest,
+) -> Result<UpdatePriceListResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditPriceLists)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    update(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn delete(
+    repositories: &Repositories,
+    request: DeletePriceListRequest,
+) -> Result<DeletePriceListResponse, DomainError> {
+    let repository = repositories.price_list.clone();
+    let usecase = DeletePriceListUsecase::new(repository);
+    let output = price_list_controller::delete_price_list(usecase, request).await?;
+
+    Ok(price_list_presenter::delete_price_list(*output.result()))
+}
+
+#[tauri::command]
+pub(crate) async fn delete_price_list(
+    state: tauri::State<'_, AppState>,
+    request: DeletePriceListRequest,
+) -> Result<DeletePriceListResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditPriceLists)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    delete(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn resolve(
+    repositories: &Repositories,
+    request: ResolvePriceRequest,
+) -> Result<ResolvePriceResponse, DomainError> {
+    let product_repository = repositories.product.clone();
+    let price_list_repository = repositories.price_list.clone();
+    let usecase = ResolvePriceUsecase::new(product_repository, price_list_repository);
+    let output = price_list_controller::resolve_price(usecase, request).await?;
+
+    Ok(price_list_presenter::resolve_price(output))
+}
+
+#[tauri::command]
+pub(crate) async fn resolve_price(
+    state: tauri::State<'_, AppState>,
+    request: ResolvePriceRequest,
+) -> Result<ResolvePriceResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    resolve(&repositories, request).await.map_err(error_presenter::error)
+}
@@ -4,130 +4,157 @@ use crate::{
             product_controller,
             request::product_request::{
                 CreateProductRequest, SearchProductRequest, UpdateProductRequest, FindByIDProductRequest, DeleteProductRequest,
+                RestoreProductRequest, ImportProductRequest,
             },
         },
-        gateway::product_repository::SqliteProductRepository,
         presenter::{
             product_presenter,
             response::product_response::{
                 CreateProductResponse, SearchProductResponse, UpdateProductResponse, FindByIDProductResponse, DeleteProductResponse,
+                RestoreProductResponse, ImportProductResponse,
             },
         },
     },
-    application::{usecase::{
+    application::usecase::{
         product::create_product::CreateProductUsecase,
         product::{
             find_by_id_product::{FindByIDProductUsecase}, search_product::SearchProductUsecase,
             update_product::UpdateProductUsecase, delete_product::DeleteProductUsecase,
+            restore_product::RestoreProductUsecase,
+            import_product::ImportProductUsecase,
         },
-    }},
+        stock_movement::find_on_hand_stock::FindOnHandStockUsecase,
+    },
 };
-use sqlx::SqlitePool;
-use std::{error::Error, rc::Rc};
+use crate::infrastructure::{csv, tauri::state::{AppState, Repositories}};
+use std::path::PathBuf;
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+use crate::domain::{error::DomainError, user::Permission};
 
 async fn find_by_id(
-    pool: SqlitePool,
+    repositories: &Repositories,
     request: FindByIDProductRequest
-) -> Result<FindByIDProductResponse, Box<dyn Error>> {
-    let repository = Rc::new(SqliteProductRepository::new(pool));
+) -> Result<FindByIDProductResponse, DomainError> {
+    let repository = repositories.product.clone();
+    let product_id = *request.product_id();
     let usecase = FindByIDProductUsecase::new(repository);
     let output = product_controller::find_by_id(usecase, request).await?;
 
-    Ok(product_presenter::find_by_id(output))
+    let stock_movement_repository = repositories.stock_movement.clone();
+    let find_on_hand_stock_usecase = FindOnHandStockUsecase::new(stock_movement_repository);
+    let stock_level = find_on_hand_stock_usecase.find_on_hand(&product_id).await?.stock_level;
+
+    Ok(product_presenter::find_by_id(output, *stock_level.on_hand_quantity()))
 }
 
 #[tauri::command]
-pub(crate) fn find_by_id_product(
-    state: tauri::State<'_, SqlitePool>,
+pub(crate) async fn find_by_id_product(
+    state: tauri::State<'_, AppState>,
     request: FindByIDProductRequest
-) -> Result<FindByIDProductResponse, String> {
-    let pool = state.inner().clone();
-    let result = tauri::async_runtime::block_on(find_by_id(pool, request)).map_err(|e| e.to_string())?;
+) -> Result<FindByIDProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let result = find_by_id(&repositories, request).await.map_err(error_presenter::error)?;
 
     Ok(result)
 }
 
 async fn search(
-    pool: SqlitePool,
+    repositories: &Repositories,
     request: SearchProductRequest,
-) -> Result<SearchProductResponse, Box<dyn Error>> {
-    let repository = SqliteProductRepository::new(pool);
-    let usecase = SearchProductUsecase::new(Rc::new(repository));
+) -> Result<SearchProductResponse, DomainError> {
+    let repository = repositories.product.clone();
+    let usecase = SearchProductUsecase::new(repository);
     let output = product_controller::search_product(usecase, request).await?;
 
     Ok(product_presenter::search_product(output))
 }
 
 #[tauri::command]
-pub(crate) fn search_product(
-    state: tauri::State<'_, SqlitePool>,
+pub(crate) async fn search_product(
+    state: tauri::State<'_, AppState>,
     request: SearchProductRequest,
-) -> Result<SearchProductResponse, String> {
-    let pool = state.inner().clone();
+) -> Result<SearchProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::ViewMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
     let result =
-        tauri::async_runtime::block_on(search(pool, request)).map_err(|e| e.to_string())?;
+        search(&repositories, request).await.map_err(error_presenter::error)?;
 
     Ok(result)
 }
 
 async fn create(
-    pool: SqlitePool,
+    repositories: &Repositories,
     request: CreateProductRequest,
-) -> Result<CreateProductResponse, Rc<dyn Error>> {
-    let repository = Rc::new(SqliteProductRepository::new(pool));
+) -> Result<CreateProductResponse, DomainError> {
+    let unit_of_work = repositories.unit_of_work.begin().await?;
+    let repository = unit_of_work.product_repository();
     let create_product_usecase = CreateProductUsecase::new(repository.clone());
     let output = product_controller::create_product(create_product_usecase, request).await?;
 
-    let find_by_id_product_usecase = FindByIDProductUsecase::new(repository.clone());
+    let find_by_id_product_usecase = FindByIDProductUsecase::new(repository);
     let product = find_by_id_product_usecase
         .find_by_id(output.result().product_id())
         .await?
         .product;
+    unit_of_work.commit().await?;
 
     Ok(product_presenter::create_product(output, product))
 }
 
 #[tauri::command]
-pub(crate) fn create_product(
-    state: tauri::State<'_, SqlitePool>,
+pub(crate) async fn create_product(
+    state: tauri::State<'_, AppState>,
     request: CreateProductRequest,
-) -> Result<CreateProductResponse, String> {
-    let pool = state.inner().clone();
-    let result = tauri::async_runtime::block_on(create(pool, request)).map_err(|e| e.to_string());
+) -> Result<CreateProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let result = create(&repositories, request).await.map_err(error_presenter::error);
 
     result
 }
 
 async fn update(
-    pool: SqlitePool,
+    repositories: &Repositories,
     request: UpdateProductRequest,
-) -> Result<UpdateProductResponse, Rc<dyn Error>> {
-    let respository = Rc::new(SqliteProductRepository::new(pool));
+) -> Result<UpdateProductResponse, DomainError> {
+    let unit_of_work = repositories.unit_of_work.begin().await?;
+    let respository = unit_of_work.product_repository();
     let update_product_usecase = UpdateProductUsecase::new(respository.clone());
     let output = product_controller::update_product(update_product_usecase, request).await?;
 
-    let find_by_id_product_usecase = FindByIDProductUsecase::new(respository.clone());
+    let find_by_id_product_usecase = FindByIDProductUsecase::new(respository);
     let product = find_by_id_product_usecase
         .find_by_id(output.result().product_id())
         .await?
         .product;
+    unit_of_work.commit().await?;
 
     Ok(product_presenter::update_product(output, product))
 }
 
 #[tauri::command]
-pub(crate) fn update_product(
-    state: tauri::State<'_, SqlitePool>,
+pub(crate) async fn update_product(
+    state: tauri::State<'_, AppState>,
     request: UpdateProductRequest,
-) -> Result<UpdateProductResponse, String> {
-    let pool = state.inner().clone();
-    let result = tauri::async_runtime::block_on(update(pool, request)).map_err(|e| e.to_string());
+) -> Result<UpdateProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let result = update(&repositories, request).await.map_err(error_presenter::error);
 
     result
 }
 
-async fn delete(pool: SqlitePool, request: DeleteProductRequest) -> Result<DeleteProductResponse, Rc<dyn Error>> {
-    let repository = Rc::new(SqliteProductRepository::new(pool));
+async fn delete(repositories: &Repositories, request: DeleteProductRequest) -> Result<DeleteProductResponse, DomainError> {
+    let repository = repositories.product.clone();
     let find_by_id_product_usecase = FindByIDProductUsecase::new(repository.clone());
     let product = find_by_id_product_usecase.find_by_id(request.product_id()).await?.product;
     match product {
@@ -145,9 +172,66 @@ async fn delete(pool: SqlitePool, request: DeleteProductRequest) -> Result<Delet
 }
 
 #[tauri::command]
-pub(crate) fn delete_product(state: tauri::State<'_, SqlitePool>, request: DeleteProductRequest) -> Result<DeleteProductResponse, String> {
-    let pool = state.inner().clone();
-    let result = tauri::async_runtime::block_on(delete(pool, request)).map_err(|e| e.to_string());
+pub(crate) async fn delete_product(state: tauri::State<'_, AppState>, request: DeleteProductRequest) -> Result<DeleteProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::DeleteMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let result = delete(&repositories, request).await.map_err(error_presenter::error);
 
     result
-}
\ No newline at end of file
+}
+
+async fn restore(
+    repositories: &Repositories,
+    request: RestoreProductRequest,
+) -> Result<RestoreProductResponse, DomainError> {
+    let repository = repositories.product.clone();
+    let usecase = RestoreProductUsecase::new(repository);
+    let output = product_controller::restore_product(usecase, request).await?;
+
+    Ok(product_presenter::restore_product(*output.result()))
+}
+
+#[tauri::command]
+pub(crate) async fn restore_product(
+    state: tauri::State<'_, AppState>,
+    request: RestoreProductRequest,
+) -> Result<RestoreProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::DeleteMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    restore(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn import(
+    repositories: &Repositories,
+    request: ImportProductRequest,
+) -> Result<ImportProductResponse, DomainError> {
+    let path = PathBuf::from(&request.path);
+    let records =
+        tauri::async_runtime::spawn_blocking(move || {
+            csv::read_file(&path).map_err(|error| error.to_string())
+        })
+        .await
+        .map_err(DomainError::unexpected)?
+        .map_err(|error| DomainError::validation("path", error))?;
+    let repository = repositories.product.clone();
+    let usecase = ImportProductUsecase::new(repository);
+    let output = product_controller::import_product(usecase, &request, records).await?;
+
+    Ok(product_presenter::import_product(output))
+}
+
+#[tauri::command]
+pub(crate) async fn import_product(
+    state: tauri::State<'_, AppState>,
+    request: ImportProductRequest,
+) -> Result<ImportProductResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditMasterData)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    import(&repositories, request).await.map_err(error_presenter::error)
+}
@@ -0,0 +1,204 @@
+use crate::{
+    adapters::{
+        controller::{
+            quotation_controller,
+            request::quotation_request::{
+                ChangeQuotationStatusRequest, ConvertQuotationRequest, CreateQuotationRequest,
+                FindByIDQuotationRequest, SearchQuotationRequest, UpdateQuotationRequest,
+            },
+        },
+        presenter::{
+            quotation_presenter,
+            response::quotation_response::{
+                ChangeQuotationStatusResponse, ConvertQuotationResponse, CreateQuotationResponse,
+                FindByIDQuotationResponse, SearchQuotationResponse, UpdateQuotationResponse,
+            },
+        },
+    },
+    application::usecase::{
+        quotation::{
+            change_quotation_status::ChangeQuotationStatusUsecase,
+            convert_quotation::ConvertQuotationUsecase, create_quotation::CreateQuotationUsecase,
+            find_by_id_quotation::FindByIDQuotationUsecase,
+            search_quotation::SearchQuotationUsecase, update_quotation::UpdateQuotationUsecase,
+        },
+        sales_order::find_by_id_sales_order::FindByIDSalesOrderUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn find_by_id(
+    repositories: &Repositories,
+    request: FindByIDQuotationRequest,
+) -> Result<FindByIDQuotationResponse, DomainError> {
+    let repository = repositories.quotation.clone();
+    let usecase = FindByIDQuotationUsecase::new(repository);
+    let output = quotation_controller::find_by_id(usecase, request).await?;
+
+    Ok(quotation_presenter::find_by_id(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_by_id_quotation(
+    state: tauri::State<'_, AppState>,
+    request: FindByIDQuotationRequest,
+) -> Result<FindByIDQuotationResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find_by_id(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchQuotationRequest,
+) -> Result<SearchQuotationResponse, DomainError> {
+    let repository = repositories.quotation.clone();
+    let usecase = SearchQuotationUsecase::new(repository);
+    let output = quotation_controller::search_quotation(usecase, request).await?;
+
+    Ok(quotation_presenter::search_quotation(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_quotation(
+    state: tauri::State<'_, AppState>,
+    request: SearchQuotationRequest,
+) -> Result<SearchQuotationResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn create(
+    repositories: &Repositories,
+    request: CreateQuotationRequest,
+) -> Result<CreateQuotationResponse, DomainError> {
+    let repository = repositories.quotation.clone();
+    let product_repository = repositories.product.clone();
+    let price_list_repository = repositories.price_list.clone();
+    let create_quotation_usecase = CreateQuotationUsecase::new(
+        repository.clone(),
+        repositories.customer.clone(),
+        product_repository,
+        price_list_repository,
+    );
+    let output = quotation_controller::create_quotation(create_quotation_usecase, request).await?;
+
+    let find_by_id_quotation_usecase = FindByIDQuotationUsecase::new(repository);
+    let quotation = find_by_id_quotation_usecase
+        .find_by_id(output.result().quotation_id())
+        .await?
+        .quotation;
+
+    Ok(quotation_presenter::create_quotation(quotation))
+}
+
+#[tauri::command]
+pub(crate) async fn create_quotation(
+    state: tauri::State<'_, AppState>,
+    request: CreateQuotationRequest,
+) -> Result<CreateQuotationResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    create(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn update(
+    repositories: &Repositories,
+    request: UpdateQuotationRequest,
+) -> Result<UpdateQuotationResponse, DomainError> {
+    let repository = repositories.quotation.clone();
+    let product_repository = repositories.product.clone();
+    let price_list_repository = repositories.price_list.clone();
+    let update_quotation_usecase = UpdateQuotationUsecase::new(
+        repository.clone(),
+        repositories.customer.clone(),
+        product_repository,
+        price_list_repository,
+    );
+    let output = quotation_controller::update_quotation(update_quotation_usecase, request).await?;
+
+    let find_by_id_quotation_usecase = FindByIDQuotationUsecase::new(repository);
+    let quotation = find_by_id_quotation_usecase
+        .find_by_id(output.result().quotation_id())
+        .await?
+        .quotation;
+
+    Ok(quotation_presenter::update_quotation(quotation))
+}
+
+#[tauri::command]
+pub(crate) async fn update_quotation(
+    state: tauri::State<'_, AppState>,
+    request: UpdateQuotationRequest,
+) -> Result<UpdateQuotationResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    update(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn change_status(
+    repositories: &Repositories,
+    request: ChangeQuotationStatusRequest,
+) -> Result<ChangeQuotationStatusResponse, DomainError> {
+    let repository = repositories.quotation.clone();
+    let change_quotation_status_usecase = ChangeQuotationStatusUsecase::new(repository.clone());
+    let output =
+        quotation_controller::change_quotation_status(change_quotation_status_usecase, request)
+            .await?;
+
+    let find_by_id_quotation_usecase = FindByIDQuotationUsecase::new(repository);
+    let quotation = find_by_id_quotation_usecase
+        .find_by_id(output.quotation_id())
+        .await?
+        .quotation;
+
+    Ok(quotation_presenter::change_quotation_status(quotation))
+}
+
+#[tauri::command]
+pub(crate) async fn change_quotation_status(
+    state: tauri::State<'_, AppState>,
+    request: ChangeQuotationStatusRequest,
+) -> Result<ChangeQuotationStatusResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    change_status(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn convert(
+    repositories: &Repositories,
+    request: ConvertQuotationRequest,
+) -> Result<ConvertQuotationResponse, DomainError> {
+    let unit_of_work = repositories.unit_of_work.clone();
+    let sales_order_repository = repositories.sales_order.clone();
+    let convert_quotation_usecase = ConvertQuotationUsecase::new(unit_of_work);
+    let output =
+        quotation_controller::convert_quotation(convert_quotation_usecase, request).await?;
+
+    let find_by_id_sales_order_usecase = FindByIDSalesOrderUsecase::new(sales_order_repository);
+    let sales_order = find_by_id_sales_order_usecase
+        .find_by_id(output.result().sales_order_id())
+        .await?
+        .sales_order;
+
+    Ok(quotation_presenter::convert_quotation(sales_order))
+}
+
+#[tauri::command]
+pub(crate) async fn convert_quotation(
+    state: tauri::State<'_, AppState>,
+    request: ConvertQuotationRequest,
+) -> Result<ConvertQuotationResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    convert(&repositories, request).await.map_err(error_presenter::error)
+}
@@ -0,0 +1,189 @@
+use crate::{
+    adapters::{
+        controller::{
+            receivable_controller,
+            request::receivable_request::{
+                AllocatePaymentRequest, FindByIDPaymentRequest, RecordPaymentRequest,
+                SearchAgingReportRequest, SearchCustomerBalanceRequest,
+                SearchInvoiceBalanceRequest, SearchPaymentRequest,
+            },
+        },
+        presenter::{
+            receivable_presenter,
+            response::receivable_response::{
+                AllocatePaymentResponse, FindByIDPaymentResponse, RecordPaymentResponse,
+                SearchAgingReportResponse, SearchCustomerBalanceResponse,
+                SearchInvoiceBalanceResponse, SearchPaymentResponse,
+            },
+        },
+    },
+    application::usecase::receivable::{
+        allocate_payment::AllocatePaymentUsecase, find_by_id_payment::FindByIDPaymentUsecase,
+        record_payment::RecordPaymentUsecase, search_aging_report::SearchAgingReportUsecase,
+        search_customer_balance::SearchCustomerBalanceUsecase,
+        search_invoice_balance::SearchInvoiceBalanceUsecase, search_payment::SearchPaymentUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn find_by_id(
+    repositories: &Repositories,
+    request: FindByIDPaymentRequest,
+) -> Result<FindByIDPaymentResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let usecase = FindByIDPaymentUsecase::new(repository);
+    let output = receivable_controller::find_by_id(usecase, request).await?;
+
+    Ok(receivable_presenter::find_by_id(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_by_id_payment(
+    state: tauri::State<'_, AppState>,
+    request: FindByIDPaymentRequest,
+) -> Result<FindByIDPaymentResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find_by_id(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchPaymentRequest,
+) -> Result<SearchPaymentResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let usecase = SearchPaymentUsecase::new(repository);
+    let output = receivable_controller::search_payment(usecase, request).await?;
+
+    Ok(receivable_presenter::search_payment(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_payment(
+    state: tauri::State<'_, AppState>,
+    request: SearchPaymentRequest,
+) -> Result<SearchPaymentResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn record(
+    repositories: &Repositories,
+    request: RecordPaymentRequest,
+) -> Result<RecordPaymentResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let unit_of_work = repositories.unit_of_work.clone();
+    let record_payment_usecase = RecordPaymentUsecase::new(unit_of_work);
+    let output = receivable_controller::record_payment(record_payment_usecase, request).await?;
+
+    let find_by_id_payment_usecase = FindByIDPaymentUsecase::new(repository);
+    let payment = find_by_id_payment_usecase
+        .find_by_id(output.result().payment_id())
+        .await?
+        .payment;
+
+    Ok(receivable_presenter::record_payment(payment))
+}
+
+#[tauri::command]
+pub(crate) async fn record_payment(
+    state: tauri::State<'_, AppState>,
+    request: RecordPaymentRequest,
+) -> Result<RecordPaymentResponse, ErrorResponse> {
+    state
+        .authorize(Permission::RecordPayments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    record(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn allocate(
+    repositories: &Repositories,
+    request: AllocatePaymentRequest,
+) -> Result<AllocatePaymentResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let unit_of_work = repositories.unit_of_work.clone();
+    let allocate_payment_usecase = AllocatePaymentUsecase::new(unit_of_work);
+    let output = receivable_controller::allocate_payment(allocate_payment_usecase, request).await?;
+
+    let find_by_id_payment_usecase = FindByIDPaymentUsecase::new(repository);
+    let payment = find_by_id_payment_usecase
+        .find_by_id(output.payment_id())
+        .await?
+        .payment;
+
+    Ok(receivable_presenter::allocate_payment(payment))
+}
+
+#[tauri::command]
+pub(crate) async fn allocate_payment(
+    state: tauri::State<'_, AppState>,
+    request: AllocatePaymentRequest,
+) -> Result<AllocatePaymentResponse, ErrorResponse> {
+    state
+        .authorize(Permission::RecordPayments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    allocate(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn invoice_balance(
+    repositories: &Repositories,
+    request: SearchInvoiceBalanceRequest,
+) -> Result<SearchInvoiceBalanceResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let usecase = SearchInvoiceBalanceUsecase::new(repository);
+    let output = receivable_controller::search_invoice_balance(usecase, request).await?;
+
+    Ok(receivable_presenter::search_invoice_balance(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_invoice_balance(
+    state: tauri::State<'_, AppState>,
+    request: SearchInvoiceBalanceRequest,
+) -> Result<SearchInvoiceBalanceResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    invoice_balance(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn customer_balance(
+    repositories: &Repositories,
+    request: SearchCustomerBalanceRequest,
+) -> Result<SearchCustomerBalanceResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let usecase = SearchCustomerBalanceUsecase::new(repository);
+    let output = receivable_controller::search_customer_balance(usecase, request).await?;
+
+    Ok(receivable_presenter::search_customer_balance(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_customer_balance(
+    state: tauri::State<'_, AppState>,
+    request: SearchCustomerBalanceRequest,
+) -> Result<SearchCustomerBalanceResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    customer_balance(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn aging_report(
+    repositories: &Repositories,
+    request: SearchAgingReportRequest,
+) -> Result<SearchAgingReportResponse, DomainError> {
+    let repository = repositories.receivable.clone();
+    let usecase = SearchAgingReportUsecase::new(repository);
+    let output = receivable_controller::search_aging_report(usecase, request).await?;
+
+    Ok(receivable_presenter::search_aging_report(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_aging_report(
+    state: tauri::State<'_, AppState>,
+    request: SearchAgingReportRequest,
+) -> Result<SearchAgingReportResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    aging_report(&repositories, request).await.map_err(error_presenter::error)
+}
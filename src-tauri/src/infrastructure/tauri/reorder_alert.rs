@@ -0,0 +1,62 @@
+use crate::{
+    adapters::{
+        controller::{
+            reorder_alert_controller, request::reorder_alert_request::SearchReorderAlertRequest,
+        },
+        presenter::{
+            reorder_alert_presenter, response::reorder_alert_response::SearchReorderAlertResponse,
+        },
+    },
+    application::usecase::reorder_alert::search_reorder_alert::{
+        SearchReorderAlertInput, SearchReorderAlertUsecase,
+    },
+    domain::{error::DomainError, product::ProductId, reorder_alert::ReorderAlert},
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+use tauri::Manager;
+
+pub(crate) const REORDER_ALERT_EVENT: &str = "reorder-alert";
+
+/// Finds the alert for a product whose stock just changed by `quantity_change`, if that change
+/// pushed it below its standard quantity.
+pub(crate) async fn find_triggered(
+    repositories: &Repositories,
+    product_id: ProductId,
+    quantity_change: i64,
+) -> Result<Option<ReorderAlert>, DomainError> {
+    let repository = repositories.reorder_alert.clone();
+    let usecase = SearchReorderAlertUsecase::new(repository);
+    let output = usecase.search(SearchReorderAlertInput::new(Some(product_id))).await?;
+
+    Ok(output
+        .reorder_alerts
+        .into_iter()
+        .find(|reorder_alert| reorder_alert.is_triggered_by(quantity_change)))
+}
+
+pub(crate) fn emit(app_handle: &tauri::AppHandle, reorder_alert: ReorderAlert) {
+    // The stock change is already committed, so a window that cannot be notified must not turn
+    // it into a failed command.
+    app_handle.emit_all(REORDER_ALERT_EVENT, reorder_alert).ok();
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchReorderAlertRequest,
+) -> Result<SearchReorderAlertResponse, DomainError> {
+    let repository = repositories.reorder_alert.clone();
+    let usecase = SearchReorderAlertUsecase::new(repository);
+    let output = reorder_alert_controller::search_reorder_alert(usecase, request).await?;
+
+    Ok(reorder_alert_presenter::search_reorder_alert(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_reorder_alert(
+    state: tauri::State<'_, AppState>,
+    request: SearchReorderAlertRequest,
+) -> Result<SearchReorderAlertResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
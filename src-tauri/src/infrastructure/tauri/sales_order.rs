@@ -0,0 +1,177 @@
+use crate::{
+    adapters::{
+        controller::{
+            request::sales_order_request::{
+                CancelSalesOrderRequest, CreateSalesOrderRequest, FindByIDSalesOrderRequest,
+                SearchSalesOrderRequest, UpdateSalesOrderRequest,
+            },
+            sales_order_controller,
+        },
+        presenter::{
+            response::sales_order_response::{
+                CancelSalesOrderResponse, CreateSalesOrderResponse, FindByIDSalesOrderResponse,
+                SearchSalesOrderResponse, UpdateSalesOrderResponse,
+            },
+            sales_order_presenter,
+        },
+    },
+    application::usecase::sales_order::{
+        cancel_sales_order::CancelSalesOrderUsecase, create_sales_order::CreateSalesOrderUsecase,
+        find_by_id_sales_order::FindByIDSalesOrderUsecase,
+        search_sales_order::SearchSalesOrderUsecase, update_sales_order::UpdateSalesOrderUsecase,
+    },
+    domain::{error::DomainError, reorder_alert::ReorderAlert, user::Permission},
+    infrastructure::tauri::reorder_alert,
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn find_by_id(
+    repositories: &Repositories,
+    request: FindByIDSalesOrderRequest,
+) -> Result<FindByIDSalesOrderResponse, DomainError> {
+    let repository = repositories.sales_order.clone();
+    let usecase = FindByIDSalesOrderUsecase::new(repository);
+    let output = sales_order_controller::find_by_id(usecase, request).await?;
+
+    Ok(sales_order_presenter::find_by_id(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_by_id_sales_order(
+    state: tauri::State<'_, AppState>,
+    request: FindByIDSalesOrderRequest,
+) -> Result<FindByIDSalesOrderResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find_by_id(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchSalesOrderRequest,
+) -> Result<SearchSalesOrderResponse, DomainError> {
+    let repository = repositories.sales_order.clone();
+    let usecase = SearchSalesOrderUsecase::new(repository);
+    let output = sales_order_controller::search_sales_order(usecase, request).await?;
+
+    Ok(sales_order_presenter::search_sales_order(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_sales_order(
+    state: tauri::State<'_, AppState>,
+    request: SearchSalesOrderRequest,
+) -> Result<SearchSalesOrderResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn create(
+    repositories: &Repositories,
+    request: CreateSalesOrderRequest,
+) -> Result<CreateSalesOrderResponse, DomainError> {
+    let repository = repositories.sales_order.clone();
+    let product_repository = repositories.product.clone();
+    let price_list_repository = repositories.price_list.clone();
+    let create_sales_order_usecase = CreateSalesOrderUsecase::new(
+        repository.clone(),
+        repositories.customer.clone(),
+        product_repository,
+        price_list_repository,
+    );
+    let output =
+        sales_order_controller::create_sales_order(create_sales_order_usecase, request).await?;
+
+    let find_by_id_sales_order_usecase = FindByIDSalesOrderUsecase::new(repository);
+    let sales_order = find_by_id_sales_order_usecase
+        .find_by_id(output.result().sales_order_id())
+        .await?
+        .sales_order;
+
+    Ok(sales_order_presenter::create_sales_order(sales_order))
+}
+
+#[tauri::command]
+pub(crate) async fn create_sales_order(
+    state: tauri::State<'_, AppState>,
+    request: CreateSalesOrderRequest,
+) -> Result<CreateSalesOrderResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    create(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn update(
+    repositories: &Repositories,
+    request: UpdateSalesOrderRequest,
+) -> Result<(UpdateSalesOrderResponse, Vec<ReorderAlert>), DomainError> {
+    let unit_of_work = repositories.unit_of_work.clone();
+    let update_sales_order_usecase = UpdateSalesOrderUsecase::new(unit_of_work);
+    let output =
+        sales_order_controller::update_sales_order(update_sales_order_usecase, request).await?;
+
+    let find_by_id_sales_order_usecase =
+        FindByIDSalesOrderUsecase::new(repositories.sales_order.clone());
+    let sales_order = find_by_id_sales_order_usecase
+        .find_by_id(output.result().sales_order_id())
+        .await?
+        .sales_order;
+    let mut reorder_alerts = Vec::new();
+    for (product_id, quantity_change) in output.stock_changes() {
+        if let Some(reorder_alert) =
+            reorder_alert::find_triggered(repositories, *product_id, *quantity_change).await?
+        {
+            reorder_alerts.push(reorder_alert);
+        }
+    }
+
+    Ok((
+        sales_order_presenter::update_sales_order(sales_order),
+        reorder_alerts,
+    ))
+}
+
+#[tauri::command]
+pub(crate) async fn update_sales_order(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    request: UpdateSalesOrderRequest,
+) -> Result<UpdateSalesOrderResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let (response, reorder_alerts) = update(&repositories, request)
+        .await
+        .map_err(error_presenter::error)?;
+    for reorder_alert in reorder_alerts {
+        reorder_alert::emit(&app_handle, reorder_alert);
+    }
+
+    Ok(response)
+}
+
+async fn cancel(
+    repositories: &Repositories,
+    request: CancelSalesOrderRequest,
+) -> Result<CancelSalesOrderResponse, DomainError> {
+    let unit_of_work = repositories.unit_of_work.clone();
+    let usecase = CancelSalesOrderUsecase::new(unit_of_work);
+    let output = sales_order_controller::cancel_sales_order(usecase, request).await?;
+
+    Ok(sales_order_presenter::cancel_sales_order(*output.result()))
+}
+
+#[tauri::command]
+pub(crate) async fn cancel_sales_order(
+    state: tauri::State<'_, AppState>,
+    request: CancelSalesOrderRequest,
+) -> Result<CancelSalesOrderResponse, ErrorResponse> {
+    state
+        .authorize(Permission::EditSalesDocuments)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    cancel(&repositories, request).await.map_err(error_presenter::error)
+}
@@ -1,5 +1,235 @@
+use std::sync::{Arc, RwLock};
+
 use sqlx::SqlitePool;
 
+use crate::{
+    adapters::gateway::{
+        audit_log_repository::SqliteAuditLogRepository,
+        company_profile_repository::SqliteCompanyProfileRepository,
+        customer_repository::SqliteCustomerRespository,
+        invoice_repository::SqliteInvoiceRepository,
+        price_list_repository::SqlitePriceListRepository,
+        product_repository::SqliteProductRepository,
+        quotation_repository::SqliteQuotationRepository,
+        receivable_repository::SqliteReceivableRepository,
+        reorder_alert_repository::SqliteReorderAlertRepository,
+        sales_order_repository::SqliteSalesOrderRepository,
+        stock_movement_repository::SqliteStockMovementRepository,
+        unit_of_work::SqliteUnitOfWorkFactory, user_repository::SqliteUserRepository,
+    },
+    application::repository::{
+        audit_log_repository::AuditLogAbstructRepository,
+        company_profile_repository::CompanyProfileAbstructRepository,
+        customer_repository::CustomerAbstructRepository,
+        invoice_repository::InvoiceAbstructRepository,
+        price_list_repository::PriceListAbstructRepository,
+        product_repository::ProductAbstructRepository,
+        quotation_repository::QuotationAbstructRepository,
+        receivable_repository::ReceivableAbstructRepository,
+        reorder_alert_repository::ReorderAlertAbstructRepository,
+        sales_order_repository::OrderAbstructRepository,
+        stock_movement_repository::StockMovementAbstructRepository,
+        unit_of_work::UnitOfWorkFactory, user_repository::UserAbstructRepository,
+    },
+    domain::{
+        error::DomainError,
+        user::{Permission, User},
+    },
+    infrastructure::audit::AuditActor,
+};
+
+/// The repositories the commands hand to usecases, built once for each database.
+pub struct Repositories {
+    pub audit_log: Arc<dyn AuditLogAbstructRepository>,
+    pub company_profile: Arc<dyn CompanyProfileAbstructRepository>,
+    pub customer: Arc<dyn CustomerAbstructRepository>,
+    pub invoice: Arc<dyn InvoiceAbstructRepository>,
+    pub price_list: Arc<dyn PriceListAbstructRepository>,
+    pub product: Arc<dyn ProductAbstructRepository>,
+    pub quotation: Arc<dyn QuotationAbstructRepository>,
+    pub receivable: Arc<dyn ReceivableAbstructRepository>,
+    pub reorder_alert: Arc<dyn ReorderAlertAbstructRepository>,
+    pub sales_order: Arc<dyn OrderAbstructRepository>,
+    pub stock_movement: Arc<dyn StockMovementAbstructRepository>,
+    pub unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    pub user: Arc<dyn UserAbstructRepository>,
+}
+impl Repositories {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            audit_log: Arc::new(SqliteAuditLogRepository::new(pool.clone())),
+            company_profile: Arc::new(SqliteCompanyProfileRepository::new(pool.clone())),
+            customer: Arc::new(SqliteCustomerRespository::new(pool.clone())),
+            invoice: Arc::new(SqliteInvoiceRepository::new(pool.clone())),
+            price_list: Arc::new(SqlitePriceListRepository::new(pool.clone())),
+            product: Arc::new(SqliteProductRepository::new(pool.clone())),
+            quotation: Arc::new(SqliteQuotationRepository::new(pool.clone())),
+            receivable: Arc::new(SqliteReceivableRepository::new(pool.clone())),
+            reorder_alert: Arc::new(SqliteReorderAlertRepository::new(pool.clone())),
+            sales_order: Arc::new(SqliteSalesOrderRepository::new(pool.clone())),
+            stock_movement: Arc::new(SqliteStockMovementRepository::new(pool.clone())),
+            unit_of_work: Arc::new(SqliteUnitOfWorkFactory::new(pool.clone())),
+            user: Arc::new(SqliteUserRepository::new(pool)),
+        }
+    }
+}
+
+/// The open database and the repositories built on it, which are replaced together.
+struct Database {
+    pool: SqlitePool,
+    repositories: Arc<Repositories>,
+}
+impl Database {
+    fn new(pool: SqlitePool) -> Self {
+        Self {
+            repositories: Arc::new(Repositories::new(pool.clone())),
+            pool,
+        }
+    }
+}
+
+/// State shared by the commands. The database is replaced when another company is opened.
+/// Clones share the database, so that background tasks see the replacement too.
+#[derive(Clone)]
 pub struct AppState {
-    pub pool: SqlitePool,
+    database: Arc<RwLock<Database>>,
+    /// The signed-in user. Users belong to a company's database, so the session ends when
+    /// the database is replaced.
+    session: Arc<RwLock<Option<User>>>,
+    /// Who the pools record changes for, which follows the session.
+    audit_actor: AuditActor,
+}
+impl AppState {
+    /// `audit_actor` must be the actor `pool` was opened with.
+    pub fn new(pool: SqlitePool, audit_actor: AuditActor) -> Self {
+        Self {
+            database: Arc::new(RwLock::new(Database::new(pool))),
+            session: Arc::new(RwLock::new(None)),
+            audit_actor,
+        }
+    }
+
+    pub fn pool(&self) -> SqlitePool {
+        self.database
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .pool
+            .clone()
+    }
+
+    pub fn repositories(&self) -> Arc<Repositories> {
+        self.database
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .repositories
+            .clone()
+    }
+
+    pub fn audit_actor(&self) -> &AuditActor {
+        &self.audit_actor
+    }
+
+    /// Returns the pool that was replaced, so that it can be closed. Ends the session.
+    pub fn replace_pool(&self, pool: SqlitePool) -> SqlitePool {
+        let mut current = self
+            .database
+            .write()
+            .unwrap_or_else(|error| error.into_inner());
+        self.sign_out();
+
+        std::mem::replace(&mut *current, Database::new(pool)).pool
+    }
+
+    pub fn session(&self) -> Option<User> {
+        self.session
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
+    }
+
+    pub fn sign_in(&self, user: User) {
+        self.audit_actor.set_user(Some(user.name().clone()));
+        *self
+            .session
+            .write()
+            .unwrap_or_else(|error| error.into_inner()) = Some(user);
+    }
+
+    pub fn sign_out(&self) {
+        self.audit_actor.set_user(None);
+        *self
+            .session
+            .write()
+            .unwrap_or_else(|error| error.into_inner()) = None;
+    }
+
+    /// The signed-in user, when their role allows the permission.
+    pub fn authorize(&self, permission: Permission) -> Result<User, DomainError> {
+        let user = self
+            .session()
+            .ok_or_else(|| DomainError::forbidden("sign in first"))?;
+        if !user.role().allows(permission) {
+            return Err(DomainError::forbidden(format!(
+                "{} users are not allowed to do this",
+                user.role().as_str()
+            )));
+        }
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use crate::{
+        domain::user::{Permission, Role, User},
+        infrastructure::{audit::AuditActor, database::MIGRATOR, tauri::state::AppState},
+    };
+
+    fn user(role: Role) -> User {
+        let created_at = PrimitiveDateTime::new(
+            Date::from_calendar_date(2023, Month::July, 1).unwrap(),
+            Time::MIDNIGHT,
+        );
+
+        User::new(1, String::from("user1"), role, created_at)
+    }
+
+    #[sqlx::test(migrator = "MIGRATOR")]
+    async fn authorize_test(pool: SqlitePool) {
+        let state = AppState::new(pool, AuditActor::default());
+        let error = state.authorize(Permission::ViewMasterData).unwrap_err();
+        assert_eq!(error.code(), "forbidden");
+
+        state.sign_in(user(Role::Viewer));
+        assert!(state.authorize(Permission::ViewMasterData).is_ok());
+        for permission in [
+            Permission::EditPriceLists,
+            Permission::EditSalesDocuments,
+            Permission::PostStockMovements,
+            Permission::IssueInvoices,
+            Permission::RecordPayments,
+            Permission::EditCompanyProfile,
+            Permission::ViewAuditLog,
+            Permission::ManageDatabase,
+        ] {
+            let error = state.authorize(permission).unwrap_err();
+            assert_eq!(error.code(), "forbidden");
+        }
+
+        state.sign_in(user(Role::Sales));
+        assert!(state.authorize(Permission::IssueInvoices).is_ok());
+        let error = state.authorize(Permission::ViewAuditLog).unwrap_err();
+        assert_eq!(error.code(), "forbidden");
+
+        state.sign_in(user(Role::Admin));
+        assert!(state.authorize(Permission::ViewAuditLog).is_ok());
+        assert_eq!(state.audit_actor().name().as_deref(), Some("user1"));
+
+        state.sign_out();
+        assert_eq!(state.audit_actor().name(), None);
+    }
 }
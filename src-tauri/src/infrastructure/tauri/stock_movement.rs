@@ -0,0 +1,109 @@
+use crate::{
+    adapters::{
+        controller::{
+            request::stock_movement_request::{
+                FindOnHandStockRequest, PostStockMovementRequest, SearchStockMovementRequest,
+            },
+            stock_movement_controller,
+        },
+        presenter::{
+            response::stock_movement_response::{
+                FindOnHandStockResponse, PostStockMovementResponse, SearchStockMovementResponse,
+            },
+            stock_movement_presenter,
+        },
+    },
+    application::usecase::stock_movement::{
+        find_on_hand_stock::FindOnHandStockUsecase, post_stock_movement::PostStockMovementUsecase,
+        search_stock_movement::SearchStockMovementUsecase,
+    },
+    domain::{error::DomainError, reorder_alert::ReorderAlert, user::Permission},
+    infrastructure::tauri::reorder_alert,
+};
+use crate::infrastructure::tauri::state::{AppState, Repositories};
+use crate::adapters::presenter::{error_presenter, response::error_response::ErrorResponse};
+
+async fn post(
+    repositories: &Repositories,
+    request: PostStockMovementRequest,
+) -> Result<(PostStockMovementResponse, Option<ReorderAlert>), DomainError> {
+    let repository = repositories.stock_movement.clone();
+    let product_repository = repositories.product.clone();
+    let product_id = request.product_id;
+    let quantity_change = request.movement_type.signed_quantity(request.quantity);
+    let post_stock_movement_usecase =
+        PostStockMovementUsecase::new(repository.clone(), product_repository);
+    let output =
+        stock_movement_controller::post_stock_movement(post_stock_movement_usecase, request).await?;
+
+    let find_on_hand_stock_usecase = FindOnHandStockUsecase::new(repository);
+    let stock_level = find_on_hand_stock_usecase
+        .find_on_hand(&product_id)
+        .await?
+        .stock_level;
+    let reorder_alert = reorder_alert::find_triggered(repositories, product_id, quantity_change).await?;
+
+    Ok((
+        stock_movement_presenter::post_stock_movement(output, stock_level),
+        reorder_alert,
+    ))
+}
+
+#[tauri::command]
+pub(crate) async fn post_stock_movement(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    request: PostStockMovementRequest,
+) -> Result<PostStockMovementResponse, ErrorResponse> {
+    state
+        .authorize(Permission::PostStockMovements)
+        .map_err(error_presenter::error)?;
+    let repositories = state.repositories();
+    let (response, reorder_alert) =
+        post(&repositories, request).await.map_err(error_presenter::error)?;
+    if let Some(reorder_alert) = reorder_alert {
+        reorder_alert::emit(&app_handle, reorder_alert);
+    }
+
+    Ok(response)
+}
+
+async fn search(
+    repositories: &Repositories,
+    request: SearchStockMovementRequest,
+) -> Result<SearchStockMovementResponse, DomainError> {
+    let repository = repositories.stock_movement.clone();
+    let usecase = SearchStockMovementUsecase::new(repository);
+    let output = stock_movement_controller::search_stock_movement(usecase, request).await?;
+
+    Ok(stock_movement_presenter::search_stock_movement(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_stock_movement(
+    state: tauri::State<'_, AppState>,
+    request: SearchStockMovementRequest,
+) -> Result<SearchStockMovementResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    search(&repositories, request).await.map_err(error_presenter::error)
+}
+
+async fn find_on_hand(
+    repositories: &Repositories,
+    request: FindOnHandStockRequest,
+) -> Result<FindOnHandStockResponse, DomainError> {
+    let repository = repositories.stock_movement.clone();
+    let usecase = FindOnHandStockUsecase::new(repository);
+    let output = stock_movement_controller::find_on_hand_stock(usecase, request).await?;
+
+    Ok(stock_movement_presenter::find_on_hand_stock(output))
+}
+
+#[tauri::command]
+pub(crate) async fn find_on_hand_stock(
+    state: tauri::State<'_, AppState>,
+    request: FindOnHandStockRequest,
+) -> Result<FindOnHandStockResponse, ErrorResponse> {
+    let repositories = state.repositories();
+    find_on_hand(&repositories, request).await.map_err(error_presenter::error)
+}
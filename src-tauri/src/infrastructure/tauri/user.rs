@@ -0,0 +1,111 @@
+use crate::{
+    adapters::{
+        controller::{
+            request::user_request::{CreateUserRequest, SignInUserRequest},
+            user_controller,
+        },
+        presenter::{
+            error_presenter,
+            response::{
+                error_response::ErrorResponse,
+                user_response::{
+                    CreateUserResponse, FindSessionResponse, SearchUserResponse, SignInUserResponse,
+                },
+            },
+            user_presenter,
+        },
+    },
+    application::usecase::user::{
+        create_user::CreateUserUsecase, search_user::SearchUserUsecase,
+        sign_in_user::SignInUserUsecase,
+    },
+    domain::{error::DomainError, user::Permission},
+    infrastructure::tauri::state::AppState,
+};
+
+async fn find(state: &AppState) -> Result<FindSessionResponse, DomainError> {
+    let repository = state.repositories().user.clone();
+    let usecase = SearchUserUsecase::new(repository);
+    let has_users = usecase.count().await? > 0;
+
+    Ok(user_presenter::find_session(state.session(), has_users))
+}
+
+#[tauri::command]
+pub(crate) async fn find_session(
+    state: tauri::State<'_, AppState>,
+) -> Result<FindSessionResponse, ErrorResponse> {
+    find(&state).await.map_err(error_presenter::error)
+}
+
+/// Changes made from now on are recorded in the audit log for the signed-in user.
+async fn sign_in(
+    state: &AppState,
+    request: SignInUserRequest,
+) -> Result<SignInUserResponse, DomainError> {
+    let repository = state.repositories().user.clone();
+    let usecase = SignInUserUsecase::new(repository);
+    let output = user_controller::sign_in_user(usecase, request).await?;
+    state.sign_in(output.user.clone());
+
+    Ok(user_presenter::sign_in_user(output))
+}
+
+#[tauri::command]
+pub(crate) async fn sign_in_user(
+    state: tauri::State<'_, AppState>,
+    request: SignInUserRequest,
+) -> Result<SignInUserResponse, ErrorResponse> {
+    sign_in(&state, request)
+        .await
+        .map_err(error_presenter::error)
+}
+
+#[tauri::command]
+pub(crate) async fn sign_out_user(state: tauri::State<'_, AppState>) -> Result<(), ErrorResponse> {
+    state.sign_out();
+
+    Ok(())
+}
+
+async fn search(state: &AppState) -> Result<SearchUserResponse, DomainError> {
+    state.authorize(Permission::ManageUsers)?;
+    let repository = state.repositories().user.clone();
+    let usecase = SearchUserUsecase::new(repository);
+    let output = user_controller::search_user(usecase).await?;
+
+    Ok(user_presenter::search_user(output))
+}
+
+#[tauri::command]
+pub(crate) async fn search_user(
+    state: tauri::State<'_, AppState>,
+) -> Result<SearchUserResponse, ErrorResponse> {
+    search(&state).await.map_err(error_presenter::error)
+}
+
+/// Anyone may create the first user, which must be an admin; after that only admins may.
+async fn create(
+    state: &AppState,
+    request: CreateUserRequest,
+) -> Result<CreateUserResponse, DomainError> {
+    let repository = state.repositories().user.clone();
+    let search_user_usecase = SearchUserUsecase::new(repository.clone());
+    if search_user_usecase.count().await? > 0 {
+        state.authorize(Permission::ManageUsers)?;
+    }
+    let create_user_usecase = CreateUserUsecase::new(repository);
+    let output = user_controller::create_user(create_user_usecase, request).await?;
+
+    Ok(user_presenter::create_user(output))
+}
+
+#[tauri::command]
+pub(crate) async fn create_user(
+    state: tauri::State<'_, AppState>,
+    request: CreateUserRequest,
+) -> Result<CreateUserResponse, ErrorResponse> {
+    create(&state, request)
+        .await
+        .map_err(error_presenter::error)
+}
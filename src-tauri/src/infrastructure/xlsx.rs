@@ -0,0 +1,167 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, Write};
+use std::path::Path;
+
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    adapters::presenter::response::export_response::ExportCell, infrastructure::export::SheetWriter,
+};
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#;
+const ROOT_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+const WORKBOOK_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
+const SHEET_START: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#;
+const SHEET_END: &str = "</sheetData></worksheet>";
+
+/// Escapes text for XML, dropping the control characters XML cannot carry.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn workbook(sheet_name: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+        escape(sheet_name)
+    )
+}
+
+/// Writes a workbook of a single sheet. The sheet is compressed into the file as rows come,
+/// with strings stored inline rather than in a shared table that would have to be held until
+/// the end.
+pub(crate) struct XlsxWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+}
+impl XlsxWriter<BufWriter<File>> {
+    pub(crate) fn create(path: &Path, sheet_name: &str) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?), sheet_name)
+    }
+}
+impl<W: Write + Seek> XlsxWriter<W> {
+    pub(crate) fn new(out: W, sheet_name: &str) -> io::Result<Self> {
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut zip = ZipWriter::new(out);
+        for (name, data) in [
+            ("[Content_Types].xml", CONTENT_TYPES.to_string()),
+            ("_rels/.rels", ROOT_RELATIONSHIPS.to_string()),
+            ("xl/workbook.xml", workbook(sheet_name)),
+            (
+                "xl/_rels/workbook.xml.rels",
+                WORKBOOK_RELATIONSHIPS.to_string(),
+            ),
+        ] {
+            zip.start_file(name, options)?;
+            zip.write_all(data.as_bytes())?;
+        }
+        // The sheet may grow past 4 GiB, which needs the ZIP64 extensions.
+        zip.start_file("xl/worksheets/sheet1.xml", options.large_file(true))?;
+        zip.write_all(SHEET_START.as_bytes())?;
+
+        Ok(Self { zip })
+    }
+
+    pub(crate) fn into_inner(mut self) -> io::Result<W> {
+        self.zip.write_all(SHEET_END.as_bytes())?;
+
+        Ok(self.zip.finish()?)
+    }
+}
+impl<W: Write + Seek + Send> SheetWriter for XlsxWriter<W> {
+    fn write_row(&mut self, row: &[ExportCell]) -> io::Result<()> {
+        let mut xml = String::from("<row>");
+        for cell in row {
+            match cell {
+                ExportCell::Empty => xml.push_str("<c/>"),
+                ExportCell::Text(text) => {
+                    xml.push_str(r#"<c t="inlineStr"><is><t xml:space="preserve">"#);
+                    xml.push_str(&escape(text));
+                    xml.push_str("</t></is></c>");
+                }
+                ExportCell::Number(number) => {
+                    xml.push_str(&format!("<c><v>{}</v></c>", number));
+                }
+            }
+        }
+        xml.push_str("</row>");
+
+        self.zip.write_all(xml.as_bytes())
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        self.into_inner()?.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use crate::{
+        adapters::presenter::response::export_response::ExportCell,
+        infrastructure::{export::SheetWriter, xlsx::XlsxWriter},
+    };
+
+    fn unzip(bytes: Vec<u8>) -> Vec<(String, String)> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        (0..archive.len())
+            .map(|index| {
+                let mut file = archive.by_index(index).unwrap();
+                let mut data = String::new();
+                file.read_to_string(&mut data).unwrap();
+                (file.name().to_string(), data)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn xlsx_writer_test() {
+        let mut writer = XlsxWriter::new(Cursor::new(Vec::new()), "products").unwrap();
+        writer
+            .write_row(&[
+                ExportCell::Text(String::from("A&B <株>")),
+                ExportCell::Number(-1200),
+                ExportCell::Empty,
+            ])
+            .unwrap();
+        for number in 0..20000 {
+            writer.write_row(&[ExportCell::Number(number)]).unwrap();
+        }
+        let entries = unzip(writer.into_inner().unwrap().into_inner());
+
+        let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "[Content_Types].xml",
+                "_rels/.rels",
+                "xl/workbook.xml",
+                "xl/_rels/workbook.xml.rels",
+                "xl/worksheets/sheet1.xml",
+            ]
+        );
+        assert!(entries[2].1.contains(r#"<sheet name="products""#));
+        let sheet = &entries[4].1;
+        assert!(sheet.contains(
+            r#"<row><c t="inlineStr"><is><t xml:space="preserve">A&amp;B &lt;株&gt;</t></is></c><c><v>-1200</v></c><c/></row>"#
+        ));
+        assert!(sheet.ends_with("<row><c><v>19999</v></c></row></sheetData></worksheet>"));
+    }
+}
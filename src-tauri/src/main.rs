@@ -8,16 +8,39 @@ pub mod application;
 pub mod domain;
 pub mod infrastructure;
 
-use infrastructure::tauri::customer;
+use infrastructure::tauri::{customer, state::AppState};
 use tauri::Manager;
 
-use crate::infrastructure::{database, tauri::product};
+use crate::infrastructure::{
+    audit::{self, AuditActor},
+    database,
+    tauri::{
+        audit_log, backup, company_database, company_profile, document, export, invoice,
+        price_list, product, quotation, receivable, reorder_alert, sales_order, stock_movement,
+        user,
+    },
+};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let sqlite_pool = database::excute()?;
+    let audit_actor = AuditActor::new(audit::os_user());
+    let sqlite_pool = match database::excute(&audit_actor) {
+        Ok(sqlite_pool) => sqlite_pool,
+        Err(error) => {
+            tauri::api::dialog::blocking::message(
+                None::<&tauri::Window>,
+                "sales-management-system",
+                format!("データベースを開けませんでした。\n\n{}", error),
+            );
+            return Err(error);
+        }
+    };
+
+    let state = AppState::new(sqlite_pool, audit_actor);
+    let scheduled_backup_state = state.clone();
 
     tauri::Builder::default()
-        .setup(|app| {
+        .setup(move |app| {
+            infrastructure::backup::spawn_scheduled_backups(app.handle(), scheduled_backup_state);
             #[cfg(debug_assertions)] // only include this code on debug builds
             {
                 let window = app.get_window("main").unwrap();
@@ -26,15 +49,75 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             Ok(())
         })
-        .manage(sqlite_pool)
+        .manage(state)
         .invoke_handler(tauri::generate_handler![
             product::find_by_id_product,
             product::search_product,
             product::create_product,
             product::update_product,
             product::delete_product,
+            product::restore_product,
+            product::import_product,
+            customer::find_by_id_customer,
+            customer::search_customer,
             customer::create_customer,
             customer::update_customer,
+            customer::delete_customer,
+            customer::restore_customer,
+            sales_order::find_by_id_sales_order,
+            sales_order::search_sales_order,
+            sales_order::create_sales_order,
+            sales_order::update_sales_order,
+            sales_order::cancel_sales_order,
+            stock_movement::post_stock_movement,
+            stock_movement::search_stock_movement,
+            stock_movement::find_on_hand_stock,
+            reorder_alert::search_reorder_alert,
+            company_profile::find_company_profile,
+            company_profile::save_company_profile,
+            invoice::find_by_id_invoice,
+            invoice::search_invoice,
+            invoice::issue_invoice,
+            document::export_invoice_pdf,
+            document::export_delivery_slip_pdf,
+            quotation::find_by_id_quotation,
+            quotation::search_quotation,
+            quotation::create_quotation,
+            quotation::update_quotation,
+            quotation::change_quotation_status,
+            quotation::convert_quotation,
+            document::export_quotation_pdf,
+            export::export_product,
+            export::export_customer,
+            export::export_sales_order,
+            export::export_invoice,
+            receivable::find_by_id_payment,
+            receivable::search_payment,
+            receivable::record_payment,
+            receivable::allocate_payment,
+            receivable::search_invoice_balance,
+            receivable::search_customer_balance,
+            receivable::search_aging_report,
+            price_list::find_by_id_price_list,
+            price_list::search_price_list,
+            price_list::create_price_list,
+            price_list::update_price_list,
+            price_list::delete_price_list,
+            price_list::resolve_price,
+            company_database::search_company_database,
+            company_database::create_company_database,
+            company_database::open_company_database,
+            company_database::switch_company_database,
+            backup::search_backup,
+            backup::create_backup,
+            backup::restore_backup,
+            backup::save_backup_schedule,
+            audit_log::search_audit_log,
+            user::find_session,
+            user::sign_in_user,
+            user::sign_out_user,
+            user::search_user,
+            user::create_user,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
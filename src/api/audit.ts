@@ -0,0 +1,45 @@
+import { invoke } from '@/utils/tauri'
+
+export type AuditEntity =
+  | 'product'
+  | 'customer'
+  | 'price_list'
+  | 'sales_order'
+  | 'sales_order_line'
+  | 'invoice'
+  | 'invoice_line'
+  | 'payment'
+  | 'payment_allocation'
+  | 'quotation'
+  | 'quotation_line'
+  | 'stock_movement'
+
+export type AuditLog = {
+  id: number
+  entity_type: AuditEntity
+  entity_id: number
+  operation: 'create' | 'update' | 'delete' | 'restore'
+  before?: Record<string, unknown>
+  after?: Record<string, unknown>
+  actor?: string
+  created_at: string
+}
+
+export type AuditLogSearchParameters = {
+  entity_type?: AuditEntity
+  entity_id?: number
+  from?: string
+  to?: string
+  limit?: number
+  offset?: number
+}
+
+type AuditLogSearchResponse = {
+  audit_logs: AuditLog[]
+}
+
+export const searchAuditLog = (params: AuditLogSearchParameters) => {
+  return invoke<AuditLogSearchResponse>('search_audit_log', {
+    request: params,
+  })
+}
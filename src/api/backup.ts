@@ -0,0 +1,56 @@
+import { invoke, listen } from '@/utils/tauri'
+
+export type Backup = {
+  file_name: string
+  label: string
+  size: number
+  created_at: string
+}
+
+export type BackupSchedule = {
+  interval_hours: number
+  retention: number
+}
+
+type SearchBackupResponse = {
+  backups: Backup[]
+  schedule: BackupSchedule
+}
+
+type CreateBackupResponse = {
+  backup: Backup
+}
+
+type RestoreBackupResponse = {
+  restored: Backup
+  safety_backup: Backup
+}
+
+type SaveBackupScheduleResponse = {
+  schedule: BackupSchedule
+}
+
+export const searchBackup = () => {
+  return invoke<SearchBackupResponse>('search_backup')
+}
+
+export const createBackup = () => {
+  return invoke<CreateBackupResponse>('create_backup')
+}
+
+export const restoreBackup = (file_name: string) => {
+  return invoke<RestoreBackupResponse>('restore_backup', {
+    request: { file_name },
+  })
+}
+
+export const saveBackupSchedule = (params: BackupSchedule) => {
+  return invoke<SaveBackupScheduleResponse>('save_backup_schedule', {
+    request: params,
+  })
+}
+
+/** Calls `handler` with the error message whenever a scheduled backup fails. */
+export const listenScheduledBackupFailed = (handler: (message: string) => void) => {
+  return listen<string>('scheduled-backup-failed', handler)
+}
@@ -0,0 +1,47 @@
+import { invoke } from '@/utils/tauri'
+
+export type CompanyDatabase = {
+  name: string
+  database_file: string
+  current: boolean
+}
+
+type SearchCompanyDatabaseResponse = {
+  companies: CompanyDatabase[]
+}
+
+type CompanyDatabaseResponse = {
+  company: CompanyDatabase
+}
+
+export const searchCompanyDatabase = () => {
+  return invoke<SearchCompanyDatabaseResponse>('search_company_database')
+}
+
+type CreateCompanyDatabaseParameters = {
+  name: string
+  database_file?: string
+}
+
+export const createCompanyDatabase = (params: CreateCompanyDatabaseParameters) => {
+  return invoke<CompanyDatabaseResponse>('create_company_database', {
+    request: params,
+  })
+}
+
+type OpenCompanyDatabaseParameters = {
+  name: string
+  database_file: string
+}
+
+export const openCompanyDatabase = (params: OpenCompanyDatabaseParameters) => {
+  return invoke<CompanyDatabaseResponse>('open_company_database', {
+    request: params,
+  })
+}
+
+export const switchCompanyDatabase = (name: string) => {
+  return invoke<CompanyDatabaseResponse>('switch_company_database', {
+    request: { name },
+  })
+}
@@ -0,0 +1,85 @@
+import { invoke } from '@/utils/tauri'
+
+export type Customer = {
+  id: number
+  name: string
+  postal: string
+  address: string
+  created_at: string
+  updated_at: string
+  deleted_at?: string
+}
+
+export type CustomerSearchParameters = {
+  name?: string
+  address?: string
+  postal?: string
+  include_deleted?: boolean
+  offset?: number
+  limit?: number
+}
+
+type FindByIdCustomerResponse = {
+  customer?: Customer
+}
+
+export const findByIdCustomer = (customerId: number) => {
+  return invoke<FindByIdCustomerResponse>('find_by_id_customer', {
+    request: {
+      id: customerId,
+    },
+  })
+}
+
+type CustomerSearchResponse = {
+  customers: Customer[]
+}
+
+export const searchCustomer = (params: CustomerSearchParameters) => {
+  return invoke<CustomerSearchResponse>('search_customer', {
+    request: params,
+  })
+}
+
+type CustomerCreateParameters = {
+  name: Customer['name']
+  postal: Customer['postal']
+  address: Customer['address']
+}
+
+export const createCustomer = (params: CustomerCreateParameters) => {
+  return invoke('create_customer', {
+    request: params,
+  })
+}
+
+type CustomerUpdateParameters = {
+  id: Customer['id']
+  name?: Customer['name']
+  postal?: Customer['postal']
+  address?: Customer['address']
+  /** The updated_at that was read; the update fails with a conflict when it has changed. */
+  expected_updated_at?: Customer['updated_at']
+}
+
+export const updateCustomer = (params: CustomerUpdateParameters) => {
+  return invoke('update_customer', {
+    request: params,
+  })
+}
+
+type CustomerDeleteParameters = {
+  id: Customer['id']
+}
+
+export const deleteCustomer = (params: CustomerDeleteParameters) => {
+  return invoke('delete_customer', {
+    request: params,
+  })
+}
+
+export const restoreCustomer = (params: CustomerDeleteParameters) => {
+  return invoke('restore_customer', {
+    request: params,
+  })
+}
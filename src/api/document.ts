@@ -0,0 +1,43 @@
+import { invoke } from '@/utils/tauri'
+
+type ExportPdfResponse = {
+  path: string
+}
+
+const choosePdfPath = async (defaultPath: string) => {
+  const { save } = await import('@tauri-apps/api/dialog')
+  return save({
+    defaultPath,
+    filters: [{ name: 'PDF', extensions: ['pdf'] }],
+  })
+}
+
+export const exportInvoicePdf = async (invoiceId: number) => {
+  const path = await choosePdfPath(`invoice-${invoiceId}.pdf`)
+  if (!path) {
+    return null
+  }
+  return invoke<ExportPdfResponse>('export_invoice_pdf', {
+    request: { invoice_id: invoiceId, path },
+  })
+}
+
+export const exportDeliverySlipPdf = async (salesOrderId: number) => {
+  const path = await choosePdfPath(`delivery-slip-${salesOrderId}.pdf`)
+  if (!path) {
+    return null
+  }
+  return invoke<ExportPdfResponse>('export_delivery_slip_pdf', {
+    request: { sales_order_id: salesOrderId, path },
+  })
+}
+
+export const exportQuotationPdf = async (quotationId: number) => {
+  const path = await choosePdfPath(`quotation-${quotationId}.pdf`)
+  if (!path) {
+    return null
+  }
+  return invoke<ExportPdfResponse>('export_quotation_pdf', {
+    request: { quotation_id: quotationId, path },
+  })
+}
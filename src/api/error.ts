@@ -0,0 +1,26 @@
+export type ErrorCode =
+  | 'validation'
+  | 'not_found'
+  | 'conflict'
+  | 'forbidden'
+  | 'database_unavailable'
+  | 'unexpected'
+
+export type FieldError = {
+  field: string
+  message: string
+}
+
+/** Error rejected by every command. */
+export type ApiError<T = unknown> = {
+  code: ErrorCode
+  message: string
+  fields: FieldError[]
+  current?: T
+}
+
+export const isApiError = (error: unknown): error is ApiError =>
+  typeof error === 'object' && error !== null && 'code' in error && 'message' in error
+
+export const fieldMessage = (error: unknown, field: string) =>
+  isApiError(error) ? error.fields.find((fieldError) => fieldError.field === field)?.message : undefined
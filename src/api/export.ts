@@ -0,0 +1,53 @@
+import { invoke } from '@/utils/tauri'
+import { CustomerSearchParameters } from '@/api/customer'
+import { ProductSearchParameters } from '@/api/product'
+
+export type ExportFormat = 'csv_utf8' | 'csv_shift_jis' | 'xlsx'
+
+type ExportParameters<Filter> = {
+  path: string
+  format: ExportFormat
+  filter: Filter
+}
+
+type SalesOrderSearchParameters = {
+  customer_id?: number
+  status?: 'ordered' | 'delivered' | 'cancelled'
+  order_date_from?: string
+  order_date_to?: string
+}
+
+type InvoiceSearchParameters = {
+  customer_id?: number
+  issued_on_from?: string
+  issued_on_to?: string
+}
+
+type ExportResponse = {
+  path: string
+  rows: number
+}
+
+export const exportProduct = (params: ExportParameters<ProductSearchParameters>) => {
+  return invoke<ExportResponse>('export_product', {
+    request: params,
+  })
+}
+
+export const exportCustomer = (params: ExportParameters<CustomerSearchParameters>) => {
+  return invoke<ExportResponse>('export_customer', {
+    request: params,
+  })
+}
+
+export const exportSalesOrder = (params: ExportParameters<SalesOrderSearchParameters>) => {
+  return invoke<ExportResponse>('export_sales_order', {
+    request: params,
+  })
+}
+
+export const exportInvoice = (params: ExportParameters<InvoiceSearchParameters>) => {
+  return invoke<ExportResponse>('export_invoice', {
+    request: params,
+  })
+}
@@ -1,4 +1,5 @@
 import { invoke } from '@/utils/tauri'
+import type { FieldError } from '@/api/error'
 
 export type Product = {
   id: number
@@ -7,20 +8,41 @@ export type Product = {
   unit: string
   default_price: number
   standard_stock_quantity: number
+  tax_rate: 'standard' | 'reduced'
   created_at: string
   updated_at: string
   deleted_at?: string
 }
 
-type ProductSearchParameters = {
+export type TextMatch = 'partial' | 'prefix' | 'exact'
+
+export type ProductSortKey =
+  | 'id'
+  | 'name'
+  | 'code'
+  | 'default_price'
+  | 'standard_stock_quantity'
+  | 'created_at'
+  | 'updated_at'
+
+export type ProductSearchParameters = {
   name?: string
   code?: string
+  name_match?: TextMatch
+  code_match?: TextMatch
+  default_price_min?: number
+  default_price_max?: number
+  standard_stock_quantity_min?: number
+  standard_stock_quantity_max?: number
+  sort_key?: ProductSortKey
+  sort_direction?: 'asc' | 'desc'
   offset?: number
   limit?: number
+  include_deleted?: boolean
 }
 
 type FindByIdProductResponse = {
-  product: Product
+  product: Product & { on_hand_quantity: number }
 }
 
 export const findByIdProduct = (productId: number) => {
@@ -33,6 +55,7 @@ export const findByIdProduct = (productId: number) => {
 
 type ProductSearchResponse = {
   products: Product[]
+  total: number
 }
 
 export const searchProduct = (params: ProductSearchParameters) => {
@@ -47,6 +70,7 @@ type ProductCreateParameters = {
   unit: Product['unit']
   default_price: Product['default_price']
   standard_stock_quantity: Product['standard_stock_quantity']
+  tax_rate?: Product['tax_rate']
 }
 
 export const createProduct = (params: ProductCreateParameters) => {
@@ -61,6 +85,9 @@ type ProductUpdateParameters = {
   code?: Product['code']
   default_price?: Product['default_price']
   standard_stock_quantity?: Product['standard_stock_quantity']
+  tax_rate?: Product['tax_rate']
+  /** The updated_at that was read; the update fails with a conflict when it has changed. */
+  expected_updated_at?: Product['updated_at']
 }
 
 export const updateProduct = (params: ProductUpdateParameters) => {
@@ -78,3 +105,34 @@ export const deleteProduct = (params: ProductDeleteParameters) => {
     request: params,
   })
 }
+
+type ProductRestoreParameters = {
+  product_id: Product['id']
+}
+
+export const restoreProduct = (params: ProductRestoreParameters) => {
+  return invoke('restore_product', {
+    request: params,
+  })
+}
+
+export type ImportProductRow = {
+  row: number
+  code: string
+  action: 'create' | 'update'
+  product_id: number | null
+  errors: FieldError[]
+}
+
+type ImportProductResponse = {
+  applied: boolean
+  created: number
+  updated: number
+  rows: ImportProductRow[]
+}
+
+export const importProduct = (path: string, dry_run: boolean) => {
+  return invoke<ImportProductResponse>('import_product', {
+    request: { path, dry_run },
+  })
+}
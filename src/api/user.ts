@@ -0,0 +1,62 @@
+import { invoke } from '@/utils/tauri'
+
+export type Role = 'admin' | 'sales' | 'viewer'
+
+export type User = {
+  id: number
+  name: string
+  role: Role
+  created_at: string
+}
+
+type FindSessionResponse = {
+  user?: User
+  has_users: boolean
+}
+
+type SignInUserResponse = {
+  user: User
+}
+
+type SearchUserResponse = {
+  users: User[]
+}
+
+type CreateUserResponse = {
+  user_id: number
+}
+
+export type SignInUserParameters = {
+  name: string
+  password: string
+}
+
+export type CreateUserParameters = {
+  name: string
+  password: string
+  role: Role
+}
+
+export const findSession = () => {
+  return invoke<FindSessionResponse>('find_session')
+}
+
+export const signInUser = (params: SignInUserParameters) => {
+  return invoke<SignInUserResponse>('sign_in_user', {
+    request: params,
+  })
+}
+
+export const signOutUser = () => {
+  return invoke<void>('sign_out_user')
+}
+
+export const searchUser = () => {
+  return invoke<SearchUserResponse>('search_user')
+}
+
+export const createUser = (params: CreateUserParameters) => {
+  return invoke<CreateUserResponse>('create_user', {
+    request: params,
+  })
+}
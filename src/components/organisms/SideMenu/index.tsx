@@ -4,6 +4,9 @@ export const SideMenu = () => {
   return (
     <div>
       <ul>
+        <li>
+          <Link href="/sign-in">サインイン</Link>
+        </li>
         <li>
           <Link href="/product">商品マスター</Link>
         </li>
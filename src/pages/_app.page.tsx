@@ -1,9 +1,20 @@
 import '@/styles/globals.css'
 import type { AppProps } from 'next/app'
+import React from 'react'
 
+import { listenScheduledBackupFailed } from '@/api/backup'
 import { ModalProvider } from '@/providers/ModalProvider'
 
 export default function App({ Component, pageProps }: AppProps) {
+  React.useEffect(() => {
+    const unlisten = listenScheduledBackupFailed((message) => {
+      window.alert(`自動バックアップに失敗しました。\n\n${message}`)
+    })
+    return () => {
+      unlisten.then((unlisten) => unlisten())
+    }
+  }, [])
+
   return (
     <ModalProvider>
       <Component {...pageProps} />
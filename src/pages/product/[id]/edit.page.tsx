@@ -34,6 +34,7 @@ const Page: NextPage = () => {
         unit: data.unit,
         default_price: data.defaultPrice,
         standard_stock_quantity: data.standardStockQuantity,
+        expected_updated_at: product?.updated_at,
       }
       updateProduct(params)
     }
@@ -10,7 +10,7 @@ import { setModalContextProvider } from '@/providers'
 const Page: NextPage = () => {
   const router = useRouter()
   const [loading, setLoading] = React.useState<boolean>(false)
-  const [product, setProduct] = React.useState<Product>()
+  const [product, setProduct] = React.useState<Product & { on_hand_quantity: number }>()
   const modal = React.useContext(setModalContextProvider)
 
   React.useEffect(() => {
@@ -72,6 +72,10 @@ const Page: NextPage = () => {
         <dt>標準在庫数</dt>
         <dd>{product?.standard_stock_quantity}</dd>
       </dl>
+      <dl>
+        <dt>現在庫数</dt>
+        <dd>{product?.on_hand_quantity}</dd>
+      </dl>
     </div>
   )
 }
@@ -0,0 +1,73 @@
+import { useRouter } from 'next/router'
+import React, { useEffect, useState } from 'react'
+
+import { isApiError } from '@/api/error'
+import { createUser, findSession, signInUser, signOutUser, User } from '@/api/user'
+import { FormField } from '@/components'
+
+export default () => {
+  const router = useRouter()
+  const [user, setUser] = useState<User>()
+  const [hasUsers, setHasUsers] = useState<boolean>()
+  const [name, setName] = useState('')
+  const [password, setPassword] = useState('')
+  const [errorMessage, setErrorMessage] = useState<string>()
+
+  useEffect(() => {
+    findSession().then((session) => {
+      setUser(session.user)
+      setHasUsers(session.has_users)
+    })
+  }, [])
+
+  const submitHandler = async (event: React.FormEvent<HTMLFormElement>) => {
+    event.preventDefault()
+    try {
+      // 利用者が一人もいないときは、最初の利用者を管理者として作成する
+      if (!hasUsers) {
+        await createUser({ name, password, role: 'admin' })
+      }
+      const response = await signInUser({ name, password })
+      setUser(response.user)
+      router.push('/')
+    } catch (error) {
+      setErrorMessage(isApiError(error) ? error.message : String(error))
+    }
+  }
+
+  const signOutHandler = async () => {
+    await signOutUser()
+    setUser(undefined)
+  }
+
+  if (hasUsers === undefined) {
+    return null
+  }
+
+  if (user) {
+    return (
+      <div>
+        <p>{user.name} としてサインインしています。</p>
+        <button onClick={signOutHandler}>サインアウト</button>
+      </div>
+    )
+  }
+
+  return (
+    <div>
+      {!hasUsers && <p>利用者が登録されていません。最初の管理者を作成してください。</p>}
+      <form onSubmit={submitHandler}>
+        <FormField label="利用者名" name="name" value={name} onChange={(event) => setName(event.target.value)} />
+        <FormField
+          label="パスワード"
+          name="password"
+          type="password"
+          value={password}
+          onChange={(event) => setPassword(event.target.value)}
+        />
+        <p>{errorMessage}</p>
+        <button type="submit">{hasUsers ? 'サインイン' : '作成してサインイン'}</button>
+      </form>
+    </div>
+  )
+}
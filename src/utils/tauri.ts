@@ -15,4 +15,15 @@ export async function invoke<T>(
     const tauriAppsApi = await import("@tauri-apps/api");
     const tauriInvoke = tauriAppsApi.invoke;
     return tauriInvoke(cmd, args);
-  }
\ No newline at end of file
+  }
+
+export async function listen<T>(
+    event: string,
+    handler: (payload: T) => void
+  ): Promise<() => void> {
+    if (isNode()) {
+      return Promise.resolve(() => {});
+    }
+    const tauriEvent = await import("@tauri-apps/api/event");
+    return tauriEvent.listen<T>(event, (e) => handler(e.payload));
+  }